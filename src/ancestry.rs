@@ -0,0 +1,247 @@
+//! # Ancestry: Every Timeline's Lineage, Worked Out Once
+//!
+//! A branch's history is its own events plus the frozen prefix of each
+//! ancestor's, up to where it split off. [`Multiverse::event_visible_in`] works
+//! that out by walking up the parent chain every time it's asked, which is fine
+//! for one question and slow for a property asking it once per memory in a deep
+//! branch tree.
+//!
+//! An [`AncestorIndex`] walks every chain once and keeps the answers: each
+//! timeline's ancestors, and how much of each one it inherits. It's a snapshot,
+//! and nothing invalidates it: a property builds a fresh one with
+//! [`Multiverse::ancestor_cache`] each time it validates, so a branch created
+//! since the last validation can never be missing from it.
+
+use crate::narrative_core::*;
+use std::collections::{HashMap, HashSet, VecDeque};
+
+/// One ancestor of a timeline, and how much of its history the timeline inherits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Inheritance {
+    pub timeline: TimelineId,
+    /// How many of the ancestor's events, from the first, are inherited: up to
+    /// and including the event the line diverged at
+    pub through: usize,
+}
+
+/// Every timeline's ancestors, nearest first, computed once.
+///
+/// **Invariant**: agrees with [`Multiverse::event_visible_in`] for every event
+/// and timeline of the multiverse it was built from. A chain is cut where it
+/// breaks, at a missing parent or a divergence event the parent never
+/// recorded, just as the walk gives up there.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AncestorIndex {
+    ancestors: HashMap<TimelineId, Vec<Inheritance>>,
+    /// Where each event sits among its timeline's events
+    positions: HashMap<EventId, (TimelineId, usize)>,
+}
+
+impl AncestorIndex {
+    /// The ancestors of `timeline`, parent first. Empty for the root, or a
+    /// timeline that isn't in the index.
+    pub fn ancestors(&self, timeline: TimelineId) -> &[Inheritance] {
+        self.ancestors.get(&timeline).map_or(&[], Vec::as_slice)
+    }
+
+    /// How many ancestors `timeline` inherits from.
+    pub fn depth(&self, timeline: TimelineId) -> usize {
+        self.ancestors(timeline).len()
+    }
+
+    /// Whether `ancestor` is somewhere up `timeline`'s line.
+    pub fn is_ancestor(&self, ancestor: TimelineId, timeline: TimelineId) -> bool {
+        self.ancestors(timeline).iter().any(|a| a.timeline == ancestor)
+    }
+
+    /// Like [`Multiverse::event_visible_in`], without the walk.
+    pub fn event_visible_in(&self, event: EventId, timeline: TimelineId) -> bool {
+        let Some(&(home, position)) = self.positions.get(&event) else {
+            return false;
+        };
+        home == timeline
+            || self
+                .ancestors(timeline)
+                .iter()
+                .any(|a| a.timeline == home && position < a.through)
+    }
+}
+
+impl Multiverse {
+    /// Works out every timeline's ancestry, for lookups that would otherwise
+    /// walk the parent chain each time. See [`AncestorIndex`].
+    pub fn ancestor_cache(&self) -> AncestorIndex {
+        let mut index = AncestorIndex::default();
+        for timeline in self.timelines.values() {
+            for (position, event) in timeline.events.iter().enumerate() {
+                index.positions.insert(*event, (timeline.id, position));
+            }
+        }
+
+        for &id in self.timelines.keys() {
+            // Climb until a timeline whose line is already known, then fill in
+            // the lines on the way back down, each from its parent's
+            let mut climbed: Vec<(TimelineId, Option<Inheritance>)> = Vec::new();
+            let mut current = id;
+            while !index.ancestors.contains_key(&current) {
+                // A loop in the parent links can't come from branching; cut it
+                if climbed.len() > self.timelines.len() {
+                    break;
+                }
+                let parent = self.inherits_from(current);
+                climbed.push((current, parent));
+                match parent {
+                    Some(parent) => current = parent.timeline,
+                    None => break,
+                }
+            }
+            for (timeline, parent) in climbed.into_iter().rev() {
+                let line = match parent {
+                    Some(parent) => std::iter::once(parent)
+                        .chain(index.ancestors(parent.timeline).iter().copied())
+                        .collect(),
+                    None => Vec::new(),
+                };
+                index.ancestors.insert(timeline, line);
+            }
+        }
+        index
+    }
+
+    /// Every timeline, each after its parent, siblings by id. Branching hands
+    /// out ids in order, but a multiverse read back from disk or stitched
+    /// together needn't keep to that, so this follows the parent links rather
+    /// than the ids. A timeline whose parent is missing counts as a root; any
+    /// caught in a loop of parent links come last, by id.
+    pub fn timelines_parents_first(&self) -> Vec<&Timeline> {
+        let mut children: HashMap<Option<TimelineId>, Vec<&Timeline>> = HashMap::new();
+        for timeline in self.timelines.values() {
+            let parent = timeline.parent.filter(|p| self.timelines.contains_key(p));
+            children.entry(parent).or_default().push(timeline);
+        }
+        for siblings in children.values_mut() {
+            siblings.sort_by_key(|t| t.id);
+        }
+
+        let mut order = Vec::with_capacity(self.timelines.len());
+        let mut queue: VecDeque<&Timeline> = children.remove(&None).unwrap_or_default().into();
+        while let Some(timeline) = queue.pop_front() {
+            order.push(timeline);
+            queue.extend(children.remove(&Some(timeline.id)).unwrap_or_default());
+        }
+        if order.len() < self.timelines.len() {
+            let placed: HashSet<TimelineId> = order.iter().map(|t| t.id).collect();
+            let mut looped: Vec<&Timeline> = self.timelines.values().filter(|t| !placed.contains(&t.id)).collect();
+            looped.sort_by_key(|t| t.id);
+            order.extend(looped);
+        }
+        order
+    }
+
+    /// The parent `timeline` inherits from, if the link holds up: the parent
+    /// exists and recorded the divergence event.
+    fn inherits_from(&self, timeline: TimelineId) -> Option<Inheritance> {
+        let current = self.timelines.get(&timeline)?;
+        let (parent, divergence) = (current.parent?, current.divergence_event?);
+        let at = self.timelines.get(&parent)?.events.iter().position(|e| *e == divergence)?;
+        Some(Inheritance {
+            timeline: parent,
+            through: at + 1,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::properties::prop_branch_history_frozen;
+
+    /// A chain of `depth` branches, each diverging at a scene in the one above,
+    /// with a scene after each divergence that its branch never sees.
+    fn deep_tree(depth: usize) -> (Multiverse, Vec<TimelineId>) {
+        let mut multiverse = Multiverse::new();
+        let vera = multiverse.create_character("Vera Kandros".to_string(), multiverse.root_timeline);
+        let mut line = vec![multiverse.root_timeline];
+        for level in 0..depth {
+            let parent = *line.last().unwrap();
+            let scene = |multiverse: &mut Multiverse, description: String| {
+                multiverse.record_event(Event {
+                    id: EventId(0),
+                    timeline: parent,
+                    description,
+                    participants: [vera].into_iter().collect(),
+                    effects: vec![],
+                    causality_violation: None,
+                })
+            };
+            let choice = scene(&mut multiverse, format!("Vera folds, jump #{}", level));
+            let branch = multiverse.create_timeline_branch(parent, choice);
+            scene(&mut multiverse, format!("The crew left behind at jump #{}", level));
+            let memory = multiverse.create_witnessed_memory(choice, branch, vera);
+            multiverse.characters.get_mut(&vera).unwrap().memories.insert(memory);
+            line.push(branch);
+        }
+        (multiverse, line)
+    }
+
+    #[test]
+    fn test_index_agrees_with_the_walk() {
+        let (mut multiverse, line) = deep_tree(40);
+        let index = multiverse.ancestor_cache();
+        assert_eq!(index.depth(line[40]), 40);
+        assert!(index.is_ancestor(line[3], line[40]));
+        assert!(!index.is_ancestor(line[40], line[3]));
+
+        for event in multiverse.events.keys() {
+            for timeline in &line {
+                assert_eq!(
+                    index.event_visible_in(*event, *timeline),
+                    multiverse.event_visible_in(*event, *timeline),
+                    "{} in {}",
+                    event,
+                    timeline
+                );
+            }
+        }
+        assert_eq!(prop_branch_history_frozen(&multiverse), Ok(()));
+
+        // A memory of a scene the branch split away from is still caught
+        let behind = multiverse.timelines[&line[0]].events[1];
+        let stray = multiverse.create_witnessed_memory(behind, line[40], CharacterId(0));
+        multiverse.characters.get_mut(&CharacterId(0)).unwrap().memories.insert(stray);
+        assert!(prop_branch_history_frozen(&multiverse).is_err());
+    }
+
+    // A benchmark rather than a check: ignored by default, run it with
+    // `cargo test deep_tree -- --ignored`, ideally with `--release`.
+    #[test]
+    #[ignore]
+    fn test_deep_tree_lookups_are_faster_indexed() {
+        use std::time::Instant;
+        let (multiverse, line) = deep_tree(400);
+        // Every event, asked about every tenth timeline down the line
+        let questions: Vec<(EventId, TimelineId)> = multiverse
+            .events
+            .keys()
+            .flat_map(|e| line.iter().step_by(10).map(move |t| (*e, *t)))
+            .collect();
+
+        let start = Instant::now();
+        let walked: Vec<bool> = questions.iter().map(|&(e, t)| multiverse.event_visible_in(e, t)).collect();
+        let walking = start.elapsed();
+
+        let start = Instant::now();
+        let index = multiverse.ancestor_cache();
+        let looked_up: Vec<bool> = questions.iter().map(|&(e, t)| index.event_visible_in(e, t)).collect();
+        let indexed = start.elapsed();
+
+        assert_eq!(walked, looked_up);
+        assert!(
+            indexed < walking,
+            "{} lookups: walking {:?}, indexed {:?} (building included)",
+            questions.len(),
+            walking,
+            indexed
+        );
+    }
+}
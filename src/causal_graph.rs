@@ -0,0 +1,310 @@
+//! # The Causal Graph: Events as a DAG
+//!
+//! Timelines store events as flat lists, but a story's causality is a graph:
+//! a scene depends on every earlier scene its characters were in, and on
+//! every scene whose memories it trades in. `Multiverse::causal_dag` builds
+//! that graph, and `CausalGraph::topo_sort` orders it.
+//!
+//! ## Edges
+//!
+//! - **Shared participants**: for each character, an edge runs from each event
+//!   they took part in to the next one, in recording order.
+//! - **Causality links**: an event that transfers a memory depends on the
+//!   event the memory is *of*.
+//!
+//! Recording order alone can never produce a cycle. A causality link can: a
+//! memory of a scene that hasn't happened yet, handed to someone who is in that
+//! scene later, closes a loop. Events flagged with a `causality_violation` are
+//! the story's *justified* paradoxes, so their causality links are left out. A
+//! cycle that remains is an unjustified paradox, and `topo_sort` reports it.
+//!
+//! ## Explaining a Scene
+//!
+//! "How did we get from the crystal's discovery to the duel?" is a path
+//! question. `Multiverse::causal_path` answers it with the shortest chain of
+//! events joining the two, through the edges above plus timeline order: each
+//! event in a timeline follows the one before it, and a branch's first event
+//! follows the event it diverged at.
+
+use crate::narrative_core::*;
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+
+/// Events and the "must happen before" edges between them.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CausalGraph {
+    /// Each event, with the events that depend on it
+    pub edges: BTreeMap<EventId, BTreeSet<EventId>>,
+}
+
+impl CausalGraph {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an event with no edges. Adding one twice is harmless.
+    pub fn add_node(&mut self, event: EventId) {
+        self.edges.entry(event).or_default();
+    }
+
+    /// Records that `cause` must happen before `effect`, adding either as needed.
+    pub fn add_edge(&mut self, cause: EventId, effect: EventId) {
+        self.add_node(effect);
+        self.edges.entry(cause).or_default().insert(effect);
+    }
+
+    /// The events in an order where every cause precedes its effects.
+    ///
+    /// Ties are broken by lowest `EventId`, so for an acyclic multiverse this is
+    /// recording order wherever recording order is consistent. If the graph has
+    /// a cycle, returns `Err` with the events on (or between) its cycles.
+    pub fn topo_sort(&self) -> Result<Vec<EventId>, Vec<EventId>> {
+        let mut in_degree: BTreeMap<EventId, usize> = self.edges.keys().map(|&e| (e, 0)).collect();
+        for effects in self.edges.values() {
+            for effect in effects {
+                *in_degree.get_mut(effect).expect("edge targets are nodes") += 1;
+            }
+        }
+
+        let mut ready: BTreeSet<EventId> = in_degree
+            .iter()
+            .filter(|(_, &degree)| degree == 0)
+            .map(|(&event, _)| event)
+            .collect();
+        let mut order = Vec::with_capacity(self.edges.len());
+        while let Some(event) = ready.pop_first() {
+            order.push(event);
+            for effect in &self.edges[&event] {
+                let degree = in_degree.get_mut(effect).expect("edge targets are nodes");
+                *degree -= 1;
+                if *degree == 0 {
+                    ready.insert(*effect);
+                }
+            }
+        }
+
+        if order.len() == self.edges.len() {
+            return Ok(order);
+        }
+
+        // What's left is the cycles plus everything downstream of them; peel off
+        // the downstream part by repeatedly dropping events with no successors left
+        let mut stuck: BTreeSet<EventId> = in_degree
+            .into_iter()
+            .filter(|(_, degree)| *degree > 0)
+            .map(|(event, _)| event)
+            .collect();
+        loop {
+            let sinks: Vec<EventId> = stuck
+                .iter()
+                .copied()
+                .filter(|event| self.edges[event].iter().all(|effect| !stuck.contains(effect)))
+                .collect();
+            if sinks.is_empty() {
+                break;
+            }
+            for sink in sinks {
+                stuck.remove(&sink);
+            }
+        }
+        Err(stuck.into_iter().collect())
+    }
+
+    /// The shortest chain of edges leading from `from` to `to`, both included,
+    /// or `None` if `to` can't be reached. Among equally short chains, the one
+    /// through the lowest `EventId`s wins.
+    pub fn shortest_path(&self, from: EventId, to: EventId) -> Option<Vec<EventId>> {
+        if !self.edges.contains_key(&from) || !self.edges.contains_key(&to) {
+            return None;
+        }
+        let mut reached_from: BTreeMap<EventId, EventId> = BTreeMap::new();
+        let mut frontier = VecDeque::from([from]);
+        while let Some(event) = frontier.pop_front() {
+            if event == to {
+                let mut path = vec![to];
+                while let Some(&previous) = reached_from.get(path.last()?) {
+                    path.push(previous);
+                }
+                path.reverse();
+                return Some(path);
+            }
+            for &effect in &self.edges[&event] {
+                if effect != from && !reached_from.contains_key(&effect) {
+                    reached_from.insert(effect, event);
+                    frontier.push_back(effect);
+                }
+            }
+        }
+        None
+    }
+}
+
+impl Multiverse {
+    /// Builds the causal graph of every recorded event. See the module docs for
+    /// which edges it contains.
+    pub fn causal_dag(&self) -> CausalGraph {
+        let mut graph = CausalGraph::new();
+        let mut events: Vec<&Event> = self.events.values().collect();
+        events.sort_by_key(|e| e.id.0);
+
+        let mut last_scene: BTreeMap<CharacterId, EventId> = BTreeMap::new();
+        for event in &events {
+            graph.add_node(event.id);
+            let mut participants: Vec<CharacterId> = event.participants.iter().copied().collect();
+            participants.sort_by_key(|c| c.0);
+            for participant in participants {
+                if let Some(previous) = last_scene.insert(participant, event.id) {
+                    graph.add_edge(previous, event.id);
+                }
+            }
+
+            if event.causality_violation.is_some() {
+                continue;
+            }
+            for effect in &event.effects {
+                if let EventEffect::MemoryTransfer { memory, .. } = effect {
+                    let source = self.memories.get(memory).map(|m| m.event);
+                    // Memories of events never recorded have no node to link from
+                    if let Some(source) = source.filter(|s| self.events.contains_key(s)) {
+                        if source != event.id {
+                            graph.add_edge(source, event.id);
+                        }
+                    }
+                }
+            }
+        }
+        graph
+    }
+
+    /// The shortest chain of events by which `from` led to `to`, both
+    /// included, or `None` if nothing connects them (or either was never
+    /// recorded). See the module docs for what counts as a link.
+    pub fn causal_path(&self, from: EventId, to: EventId) -> Option<Vec<EventId>> {
+        let mut graph = self.causal_dag();
+        for timeline in self.timelines.values() {
+            let first = timeline.events.first().copied();
+            if let (Some(divergence), Some(first)) = (timeline.divergence_event, first) {
+                if self.events.contains_key(&divergence) && self.events.contains_key(&first) {
+                    graph.add_edge(divergence, first);
+                }
+            }
+            for pair in timeline.events.windows(2) {
+                if self.events.contains_key(&pair[0]) && self.events.contains_key(&pair[1]) {
+                    graph.add_edge(pair[0], pair[1]);
+                }
+            }
+        }
+        graph.shortest_path(from, to)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    fn scene(multiverse: &mut Multiverse, who: &[CharacterId], effects: Vec<EventEffect>) -> EventId {
+        let timeline = multiverse.root_timeline;
+        multiverse.record_event(Event {
+            id: EventId(0),
+            timeline,
+            description: "A scene".to_string(),
+            participants: who.iter().copied().collect::<HashSet<_>>(),
+            effects,
+            causality_violation: None,
+        })
+    }
+
+    #[test]
+    fn test_linear_chain_sorts_in_order() {
+        let mut multiverse = Multiverse::new();
+        let root = multiverse.root_timeline;
+        let riven = multiverse.create_character("Riven Blackwood".to_string(), root);
+        let vera = multiverse.create_character("Vera Kandros".to_string(), root);
+
+        let draw = scene(&mut multiverse, &[riven], vec![]);
+        let standoff = scene(&mut multiverse, &[riven, vera], vec![]);
+        let shot = scene(&mut multiverse, &[vera], vec![]);
+
+        let graph = multiverse.causal_dag();
+        assert!(graph.edges[&draw].contains(&standoff));
+        assert!(graph.edges[&standoff].contains(&shot));
+        assert_eq!(graph.topo_sort(), Ok(vec![draw, standoff, shot]));
+    }
+
+    #[test]
+    fn test_unjustified_paradox_is_a_cycle() {
+        let mut multiverse = Multiverse::new();
+        let root = multiverse.root_timeline;
+        let mara = multiverse.create_character("Mara Vex".to_string(), root);
+        let khelis = multiverse.create_character("Khelis Tev".to_string(), root);
+
+        // Khelis is handed a memory of a scene that hasn't been recorded yet...
+        let vision = multiverse.create_memory(EventId(2), root, MemoryProvenance::Traded {
+            original_owner: mara,
+            acquired_via: "Precognition".to_string(),
+        });
+        let prelude = scene(&mut multiverse, &[mara], vec![]);
+        let handoff = scene(&mut multiverse, &[khelis], vec![EventEffect::MemoryTransfer {
+            memory: vision,
+            from: None,
+            to: khelis,
+            exclusive: false,
+        }]);
+        // ...and then takes part in it, so it both causes and follows the handoff
+        let foreseen = scene(&mut multiverse, &[khelis], vec![]);
+        assert_eq!(foreseen, EventId(2));
+        let epilogue = scene(&mut multiverse, &[khelis], vec![]);
+
+        assert_eq!(multiverse.causal_dag().topo_sort(), Err(vec![handoff, foreseen]));
+
+        // A handoff with a causality violation is a justified paradox
+        multiverse.events.get_mut(&handoff).unwrap().causality_violation =
+            Some(CausalityViolation::EffectBeforeCause {
+                mechanism: "Precognition".to_string(),
+            });
+        assert_eq!(
+            multiverse.causal_dag().topo_sort(),
+            Ok(vec![prelude, handoff, foreseen, epilogue])
+        );
+    }
+
+    #[test]
+    fn test_causal_path_explains_how_we_got_here() {
+        let mut multiverse = Multiverse::new();
+        let root = multiverse.root_timeline;
+        let khelis = multiverse.create_character("Khelis Tev".to_string(), root);
+        let vera = multiverse.create_character("Vera Kandros".to_string(), root);
+        let riven = multiverse.create_character("Riven Blackwood".to_string(), root);
+
+        let discovery = scene(&mut multiverse, &[khelis], vec![]);
+        let passage = scene(&mut multiverse, &[khelis, vera], vec![]);
+        let ambush = scene(&mut multiverse, &[vera, riven], vec![]);
+        let duel = scene(&mut multiverse, &[riven], vec![]);
+        assert_eq!(multiverse.causal_path(discovery, duel), Some(vec![discovery, passage, ambush, duel]));
+        assert_eq!(multiverse.causal_path(passage, passage), Some(vec![passage]));
+        // Effects don't explain their causes
+        assert_eq!(multiverse.causal_path(duel, discovery), None);
+
+        // Two futures split at the duel; neither explains the other
+        let left = multiverse.create_timeline_branch(root, duel);
+        let right = multiverse.create_timeline_branch(root, duel);
+        let record = |multiverse: &mut Multiverse, timeline, who| {
+            multiverse.record_event(Event {
+                id: EventId(0),
+                timeline,
+                description: "A future".to_string(),
+                participants: HashSet::from([who]),
+                effects: vec![],
+                causality_violation: None,
+            })
+        };
+        let wins = record(&mut multiverse, left, riven);
+        let loses = record(&mut multiverse, right, vera);
+        assert_eq!(multiverse.causal_path(wins, loses), None);
+        assert_eq!(multiverse.causal_path(loses, wins), None);
+        // Both follow from the duel they diverged at; Vera's also straight from their last scene
+        assert_eq!(multiverse.causal_path(duel, wins), Some(vec![duel, wins]));
+        assert_eq!(multiverse.causal_path(ambush, loses), Some(vec![ambush, loses]));
+        assert_eq!(multiverse.causal_path(discovery, EventId(99)), None);
+    }
+}
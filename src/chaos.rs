@@ -0,0 +1,229 @@
+//! # Chaos Runs: Random Testing on a Wall-Clock Budget
+//!
+//! Proptest decides how many cases to run from its config (`PROPTEST_CASES`),
+//! which is the right knob for CI but the wrong one for exploration. When you
+//! want to say "hammer the narrative engine for thirty seconds and tell me what
+//! broke," you want a time budget instead.
+//!
+//! `run_chaos_until` keeps generating random action sequences, applying them to
+//! a fresh multiverse, and checking every property after every action until the
+//! budget runs out. When a sequence breaks a property, it is shrunk with
+//! proptest's own value trees, so the report carries a minimal reproduction
+//! exactly like a failing `proptest!` would.
+//!
+//! A minimal failing sequence is still a list of actions, and it takes replaying
+//! it in your head to see why it fails. [`format_shrink_trace`] replays it for
+//! you, one line per action: what the action did to the world, and where a
+//! property first broke.
+
+use crate::generators::*;
+use crate::narrative_core::*;
+use crate::properties::*;
+use proptest::strategy::{Strategy, ValueTree};
+use proptest::test_runner::TestRunner;
+use std::time::{Duration, Instant};
+
+/// Summary of a time-bounded chaos run.
+#[derive(Debug, Clone)]
+pub struct ChaosReport {
+    /// How many random action sequences were generated and applied
+    pub sequences_run: usize,
+    /// How many of those sequences broke at least one property
+    pub violations_found: usize,
+    /// The smallest failing sequence found, with the violation it produces
+    pub minimal_failure: Option<(Vec<NarrativeAction>, String)>,
+    /// Wall-clock time actually spent
+    pub elapsed: Duration,
+}
+
+/// Upper bound on shrink steps per failure, so one stubborn case can't eat the budget.
+const MAX_SHRINK_STEPS: usize = 1024;
+
+/// Runs random action sequences against fresh multiverses until `budget` elapses.
+///
+/// `multiverse_factory` builds the starting state for each sequence (e.g. an empty
+/// multiverse, or one populated with the thirteen protagonists). At least one
+/// sequence is always run, even with a zero budget.
+pub fn run_chaos_until<F>(budget: Duration, multiverse_factory: F) -> ChaosReport
+where
+    F: Fn() -> Multiverse,
+{
+    let start = Instant::now();
+    let strategy = proptest::collection::vec(narrative_action_strategy(), 10..50);
+    let mut runner = TestRunner::default();
+
+    let mut report = ChaosReport {
+        sequences_run: 0,
+        violations_found: 0,
+        minimal_failure: None,
+        elapsed: Duration::ZERO,
+    };
+
+    loop {
+        let mut tree = match strategy.new_tree(&mut runner) {
+            Ok(tree) => tree,
+            Err(_) => break,
+        };
+        report.sequences_run += 1;
+
+        if let Err(violation) = check_sequence(multiverse_factory(), &tree.current()) {
+            report.violations_found += 1;
+            let shrunk = shrink_failure(&mut tree, violation, &multiverse_factory);
+
+            let is_smaller = report
+                .minimal_failure
+                .as_ref()
+                .map(|(actions, _)| shrunk.0.len() < actions.len())
+                .unwrap_or(true);
+            if is_smaller {
+                report.minimal_failure = Some(shrunk);
+            }
+        }
+
+        if start.elapsed() >= budget {
+            break;
+        }
+    }
+
+    report.elapsed = start.elapsed();
+    report
+}
+
+/// Applies each action in turn, validating all properties after every step.
+fn check_sequence(mut multiverse: Multiverse, actions: &[NarrativeAction]) -> Result<(), String> {
+    for action in actions {
+        apply_narrative_action(&mut multiverse, action);
+        validate_all_properties(&multiverse)
+            .map_err(|e| format!("after action {}: {}", multiverse.describe_action(action), e.narrate(&multiverse)))?;
+    }
+    Ok(())
+}
+
+/// Shrinks a failing value tree the same way proptest does: simplify while the
+/// case keeps failing, complicate when a simplification made it pass.
+fn shrink_failure<T, F>(
+    tree: &mut T,
+    violation: String,
+    multiverse_factory: &F,
+) -> (Vec<NarrativeAction>, String)
+where
+    T: ValueTree<Value = Vec<NarrativeAction>>,
+    F: Fn() -> Multiverse,
+{
+    let mut best = (tree.current(), violation);
+    let mut passed = false;
+
+    for _ in 0..MAX_SHRINK_STEPS {
+        let moved = if passed {
+            tree.complicate()
+        } else {
+            tree.simplify()
+        };
+        if !moved {
+            break;
+        }
+
+        let candidate = tree.current();
+        match check_sequence(multiverse_factory(), &candidate) {
+            Ok(()) => passed = true,
+            Err(violation) => {
+                passed = false;
+                best = (candidate, violation);
+            }
+        }
+    }
+
+    best
+}
+
+/// Replays `actions` on a fresh multiverse, annotating each with what it changed.
+///
+/// Each line is numbered and shows the action, its outcome, and a
+/// [`MultiverseDiff`](crate::diff::MultiverseDiff) of the state before and after.
+/// The first action after which a property fails is marked with the violation.
+/// Like a saved trace, the actions must include any setup (e.g. the
+/// [`chaos_cast_actions`]) to replay from scratch.
+pub fn format_shrink_trace(actions: &[NarrativeAction]) -> String {
+    let mut multiverse = Multiverse::new();
+    let mut broken = false;
+    let mut lines = Vec::with_capacity(actions.len());
+    for (i, action) in actions.iter().enumerate() {
+        let before = multiverse.clone();
+        let outcome = apply_narrative_action(&mut multiverse, action);
+        let delta = match &outcome {
+            ActionOutcome::Skipped(reason) => format!("skipped: {:?}", reason),
+            ActionOutcome::Rejected(error) => format!("rejected: {}", error),
+            ActionOutcome::Applied(_) | ActionOutcome::Created(_) => before.diff(&multiverse).to_string(),
+        };
+        let mut line = format!("{:>3}. {:<22} {}", i + 1, action.name(), delta);
+        if !broken {
+            if let Err(violation) = validate_all_properties(&multiverse) {
+                broken = true;
+                line.push_str(&format!("  ✗ {}", violation));
+            }
+        }
+        lines.push(line);
+    }
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chaos_run_respects_budget() {
+        let budget = Duration::from_millis(200);
+        let report = run_chaos_until(budget, || {
+            let mut multiverse = Multiverse::new();
+            crate::protagonists::create_thirteen_protagonists(&mut multiverse);
+            multiverse
+        });
+
+        assert!(report.sequences_run > 0);
+        // The thirteen are held to their own invariants, and nothing breaks them
+        assert_eq!(report.violations_found, 0, "{:?}", report.minimal_failure);
+        assert!(report.minimal_failure.is_none());
+        // One sequence may straddle the deadline, but not by much
+        assert!(report.elapsed < budget + Duration::from_secs(5));
+    }
+
+    #[test]
+    fn test_shrink_trace_annotates_every_action() {
+        let cast = chaos_cast_multiverse();
+        let root = cast.root_timeline;
+        let named = |name: &str| cast.characters.values().find(|c| c.name == name).unwrap().id;
+        let (riven, nameless) = (named("Riven Blackwood"), named("Nameless"));
+        let mut actions = chaos_cast_actions();
+        actions.extend([
+            NarrativeAction::KillCharacter {
+                character: nameless,
+                timeline: root,
+            },
+            NarrativeAction::KillCharacter {
+                character: nameless,
+                timeline: root,
+            },
+            NarrativeAction::ResurrectCharacter {
+                character: nameless,
+                timeline: root,
+                mechanism: "Living Gate".to_string(),
+            },
+            NarrativeAction::KillCharacter {
+                character: riven,
+                timeline: root,
+            },
+        ]);
+
+        let trace = format_shrink_trace(&actions);
+        let lines: Vec<&str> = trace.lines().collect();
+        assert_eq!(lines.len(), actions.len());
+        let cast = chaos_cast_actions().len();
+        assert!(lines[0].starts_with("  1. CreateCharacter"));
+        assert!(lines[cast].contains(&format!("{} dies", nameless)));
+        assert!(lines[cast + 1].contains("skipped: CharacterDead"));
+        assert!(lines[cast + 2].contains(&format!("{} returns from the dead", nameless)));
+        assert!(lines[cast + 3].contains(&format!("{} dies", riven)));
+        assert!(!trace.contains('✗'));
+    }
+}
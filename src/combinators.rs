@@ -0,0 +1,213 @@
+//! # Property Combinators: Saying the Rule, Not the Loop
+//!
+//! Most properties have the shape "for every character (or event, or
+//! timeline), if this holds then that must too," and written out by hand the
+//! loops and the `if let`s bury the one line that is actually the rule. These
+//! combinators take care of the shape:
+//!
+//! - [`for_all_characters`], [`for_all_events`], and [`for_all_timelines`] run a
+//!   check against each item in id order and stop at the first violation,
+//!   noting which item it was about (see [`ViolationContext`])
+//! - [`implies`] only runs a check when its condition holds
+//! - [`ensure`] turns a condition into a check
+//! - [`and`] and [`or`] compose whole properties
+//!
+//! Everything they build is an ordinary property, `Fn(&Multiverse) ->
+//! Result<(), PropertyViolation>`, so it can be called directly or handed to
+//! [`PropertyRegistry::register`](crate::properties::PropertyRegistry::register).
+//!
+//! ## Example: Only the Conductor Roams
+//!
+//! A game where nobody but the Conductor may stand in two timelines at once:
+//!
+//! ```rust
+//! use propyarn::combinators::*;
+//! use propyarn::narrative_core::Multiverse;
+//!
+//! let only_the_conductor_roams = for_all_characters(|multiverse, c| {
+//!     let timelines = multiverse.timelines.values().filter(|t| t.characters.contains(&c.id)).count();
+//!     implies(timelines > 1, || ensure(c.name == "The Conductor", "only_the_conductor_roams", format!("{} is in {} timelines", c.name, timelines)))
+//! });
+//!
+//! let mut multiverse = Multiverse::new();
+//! multiverse.create_character("Vera Kandros".to_string(), multiverse.root_timeline);
+//! assert!(only_the_conductor_roams(&multiverse).is_ok());
+//! ```
+
+use crate::narrative_core::*;
+use crate::properties::{PropertyViolation, ViolationContext};
+
+/// A check against one item of the multiverse.
+type ItemCheck<'a, T> = &'a (dyn Fn(&Multiverse, &T) -> Result<(), PropertyViolation> + Send + Sync);
+
+/// Runs `check` on every character, by id.
+pub fn for_all_characters<F>(check: F) -> impl Fn(&Multiverse) -> Result<(), PropertyViolation> + Send + Sync
+where
+    F: Fn(&Multiverse, &Character) -> Result<(), PropertyViolation> + Send + Sync,
+{
+    move |multiverse| {
+        let mut characters: Vec<&Character> = multiverse.characters.values().collect();
+        characters.sort_by_key(|c| c.id);
+        for_each(multiverse, characters, &check, |c| ViolationContext::Character(c.id))
+    }
+}
+
+/// Runs `check` on every event, in the order they were recorded.
+pub fn for_all_events<F>(check: F) -> impl Fn(&Multiverse) -> Result<(), PropertyViolation> + Send + Sync
+where
+    F: Fn(&Multiverse, &Event) -> Result<(), PropertyViolation> + Send + Sync,
+{
+    move |multiverse| {
+        let mut events: Vec<&Event> = multiverse.events.values().collect();
+        events.sort_by_key(|e| e.id);
+        for_each(multiverse, events, &check, |e| ViolationContext::Event(e.id))
+    }
+}
+
+/// Runs `check` on every timeline, parents before their branches.
+pub fn for_all_timelines<F>(check: F) -> impl Fn(&Multiverse) -> Result<(), PropertyViolation> + Send + Sync
+where
+    F: Fn(&Multiverse, &Timeline) -> Result<(), PropertyViolation> + Send + Sync,
+{
+    move |multiverse| {
+        for_each(multiverse, multiverse.timelines_parents_first(), &check, |t| ViolationContext::Timeline(t.id))
+    }
+}
+
+fn for_each<T>(
+    multiverse: &Multiverse,
+    items: Vec<&T>,
+    check: ItemCheck<'_, T>,
+    context: impl Fn(&T) -> ViolationContext,
+) -> Result<(), PropertyViolation> {
+    items
+        .into_iter()
+        .try_for_each(|item| check(multiverse, item).map_err(|v| v.about(context(item))))
+}
+
+/// Runs `check` only if `condition` holds; otherwise passes.
+pub fn implies(
+    condition: bool,
+    check: impl FnOnce() -> Result<(), PropertyViolation>,
+) -> Result<(), PropertyViolation> {
+    if condition {
+        check()
+    } else {
+        Ok(())
+    }
+}
+
+/// Passes if `condition` holds, and fails `property` with `message` otherwise.
+pub fn ensure(
+    condition: bool,
+    property: &str,
+    message: impl Into<String>,
+) -> Result<(), PropertyViolation> {
+    if condition {
+        Ok(())
+    } else {
+        Err(PropertyViolation::new(property, message))
+    }
+}
+
+/// Both properties must hold. Reports the first one's violation if both fail.
+pub fn and<P, Q>(first: P, second: Q) -> impl Fn(&Multiverse) -> Result<(), PropertyViolation> + Send + Sync
+where
+    P: Fn(&Multiverse) -> Result<(), PropertyViolation> + Send + Sync,
+    Q: Fn(&Multiverse) -> Result<(), PropertyViolation> + Send + Sync,
+{
+    move |multiverse| first(multiverse).and_then(|()| second(multiverse))
+}
+
+/// Either property must hold. Reports the first one's violation if both fail.
+pub fn or<P, Q>(first: P, second: Q) -> impl Fn(&Multiverse) -> Result<(), PropertyViolation> + Send + Sync
+where
+    P: Fn(&Multiverse) -> Result<(), PropertyViolation> + Send + Sync,
+    Q: Fn(&Multiverse) -> Result<(), PropertyViolation> + Send + Sync,
+{
+    move |multiverse| first(multiverse).or_else(|violation| second(multiverse).map_err(|_| violation))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    fn nobody_named(name: &'static str) -> impl Fn(&Multiverse) -> Result<(), PropertyViolation> + Send + Sync {
+        for_all_characters(move |_, c| ensure(c.name != name, "nobody_named", format!("{} is here", name)))
+    }
+
+    #[test]
+    fn test_violations_note_what_they_are_about() {
+        let mut multiverse = Multiverse::new();
+        let root = multiverse.root_timeline;
+        let vera = multiverse.create_character("Vera Kandros".to_string(), root);
+        let kael = multiverse.create_character("Kael Thornwick".to_string(), root);
+
+        let violation = nobody_named("Kael Thornwick")(&multiverse).unwrap_err();
+        assert_eq!(violation.context, vec![ViolationContext::Character(kael)]);
+
+        let crowded = multiverse.record_event(Event {
+            id: EventId(0),
+            timeline: root,
+            description: "Everyone crowds the Fold Drive bay".to_string(),
+            participants: HashSet::from([vera, kael]),
+            effects: vec![],
+            causality_violation: None,
+        });
+        let pairs_only = for_all_timelines(|multiverse, t| {
+            for_all_events(|_, e| {
+                implies(e.participants.len() > 1, || ensure(false, "solo_scenes", "a crowd"))
+            })(multiverse)
+            .and_then(|()| ensure(t.events.len() < 10, "solo_scenes", "too long"))
+        });
+        let violation = pairs_only(&multiverse).unwrap_err();
+        assert_eq!(
+            violation.context,
+            vec![ViolationContext::Event(crowded), ViolationContext::Timeline(root)]
+        );
+        assert_eq!(violation.trace, vec![crowded]);
+    }
+
+    #[test]
+    fn test_timelines_are_checked_parents_first() {
+        let mut multiverse = Multiverse::new();
+        let root = multiverse.root_timeline;
+        let decision = multiverse.record_event(Event {
+            id: EventId(0),
+            timeline: root,
+            description: "Vera weighs the Gate".to_string(),
+            participants: HashSet::new(),
+            effects: vec![],
+            causality_violation: None,
+        });
+        let child = multiverse.create_timeline_branch(root, decision);
+        let parent = multiverse.create_timeline_branch(root, decision);
+        // Stitched together out of order: the branch has the lower id
+        multiverse.timelines.get_mut(&child).unwrap().parent = Some(parent);
+        assert!(child < parent);
+
+        let visited = std::sync::Mutex::new(Vec::new());
+        let record = for_all_timelines(|_, t| {
+            visited.lock().unwrap().push(t.id);
+            Ok(())
+        });
+        assert_eq!(record(&multiverse), Ok(()));
+        assert_eq!(*visited.lock().unwrap(), vec![root, parent, child]);
+    }
+
+    #[test]
+    fn test_and_or_report_the_first_violation() {
+        let mut multiverse = Multiverse::new();
+        multiverse.create_character("Vera Kandros".to_string(), multiverse.root_timeline);
+
+        let pass = nobody_named("Nameless");
+        let fail = |message: &'static str| move |_: &Multiverse| ensure(false, "always", message);
+        assert!(and(&pass, &pass)(&multiverse).is_ok());
+        assert_eq!(and(&pass, fail("second"))(&multiverse).unwrap_err().message, "second");
+        assert_eq!(and(fail("first"), fail("second"))(&multiverse).unwrap_err().message, "first");
+        assert!(or(fail("first"), &pass)(&multiverse).is_ok());
+        assert_eq!(or(fail("first"), fail("second"))(&multiverse).unwrap_err().message, "first");
+        assert_eq!(implies(false, || fail("never")(&multiverse)), Ok(()));
+    }
+}
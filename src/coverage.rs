@@ -0,0 +1,304 @@
+//! # Coverage: What Did the Tests Actually Exercise?
+//!
+//! A property that holds over ten thousand generated sequences proves little if
+//! none of them ever wiped a memory or pulled a character back to their era. A
+//! `CoverageTracker` counts what the generators produced and what the story
+//! actually did with it:
+//!
+//! - **Actions**: each `NarrativeAction` variant, applied or skipped
+//! - **Effects**: each `EventEffect` variant recorded
+//! - **Abilities**: each ability gained or lost through an event
+//! - **Properties**: each property checked against a multiverse where it had
+//!   something to check (death finality with nobody dead is vacuous)
+//! - **Causality violations**: each kind recorded
+//!
+//! Trackers are plain values. Give each proptest case its own, then `merge`
+//! them into an accumulator the test owns, and assert on its `report()`.
+
+use crate::generators::{apply_narrative_action_recorded, ActionOutcome, NarrativeAction};
+use crate::narrative_core::*;
+use crate::properties::PROPERTIES;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// Counts of what generated scenarios exercised. See the module docs.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CoverageTracker {
+    applied: BTreeMap<String, usize>,
+    skipped: BTreeMap<String, usize>,
+    effects: BTreeMap<String, usize>,
+    abilities: BTreeMap<String, usize>,
+    properties: BTreeMap<String, usize>,
+    causality_violations: BTreeMap<String, usize>,
+    events: usize,
+    validations: usize,
+}
+
+fn bump(counts: &mut BTreeMap<String, usize>, key: &str) {
+    *counts.entry(key.to_string()).or_default() += 1;
+}
+
+impl CoverageTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Counts an action, as applied or not depending on its outcome.
+    pub fn record_action(&mut self, action: &NarrativeAction, outcome: &ActionOutcome) {
+        if outcome.is_applied() {
+            bump(&mut self.applied, action.name());
+        } else {
+            bump(&mut self.skipped, action.name());
+        }
+    }
+
+    /// Counts an event's effects, the abilities they touch, and its causality
+    /// violation, if any.
+    pub fn record_event(&mut self, event: &Event) {
+        self.events += 1;
+        for effect in &event.effects {
+            bump(&mut self.effects, effect.name());
+            match effect {
+                EventEffect::AbilityGained { ability, .. } => {
+                    bump(&mut self.abilities, &format!("{:?}", ability.kind()))
+                }
+                EventEffect::AbilityLost { ability, .. } => {
+                    bump(&mut self.abilities, &format!("{:?}", ability))
+                }
+                _ => {}
+            }
+        }
+        if let Some(violation) = &event.causality_violation {
+            bump(&mut self.causality_violations, violation.name());
+        }
+    }
+
+    /// Counts each property that a check of `multiverse` would not pass vacuously.
+    pub fn record_validation(&mut self, multiverse: &Multiverse) {
+        self.validations += 1;
+        for (property, _) in PROPERTIES {
+            if exercise(property).is_some_and(|exercised| exercised(multiverse)) {
+                bump(&mut self.properties, property);
+            }
+        }
+    }
+
+    /// Adds another tracker's counts to this one.
+    pub fn merge(&mut self, other: &CoverageTracker) {
+        let pairs = [
+            (&mut self.applied, &other.applied),
+            (&mut self.skipped, &other.skipped),
+            (&mut self.effects, &other.effects),
+            (&mut self.abilities, &other.abilities),
+            (&mut self.properties, &other.properties),
+            (&mut self.causality_violations, &other.causality_violations),
+        ];
+        for (mine, theirs) in pairs {
+            for (key, count) in theirs {
+                *mine.entry(key.clone()).or_default() += count;
+            }
+        }
+        self.events += other.events;
+        self.validations += other.validations;
+    }
+
+    /// A snapshot of the counts, with the variants never seen spelled out.
+    pub fn report(&self) -> CoverageReport {
+        let missing = |names: &[&str], counts: &BTreeMap<String, usize>| {
+            names
+                .iter()
+                .filter(|name| !counts.contains_key(**name))
+                .map(|name| name.to_string())
+                .collect()
+        };
+        CoverageReport {
+            applied: self.applied.clone(),
+            skipped: self.skipped.clone(),
+            effects: self.effects.clone(),
+            abilities: self.abilities.clone(),
+            properties: self.properties.clone(),
+            causality_violations: self.causality_violations.clone(),
+            events: self.events,
+            validations: self.validations,
+            missing_actions: missing(NarrativeAction::NAMES, &self.applied),
+            missing_effects: missing(EventEffect::NAMES, &self.effects),
+            missing_properties: missing(&PROPERTIES.map(|(name, _)| name), &self.properties),
+        }
+    }
+}
+
+/// Applies `action` like [`apply_narrative_action_recorded`], and records it
+/// and every event it caused in `tracker`.
+pub fn apply_tracked(
+    multiverse: &mut Multiverse,
+    tracker: &mut CoverageTracker,
+    action: &NarrativeAction,
+) -> ActionOutcome {
+    let (outcome, recorded) = apply_narrative_action_recorded(multiverse, action);
+    tracker.record_action(action, &outcome);
+    for id in &recorded {
+        tracker.record_event(&multiverse.events[id]);
+    }
+    outcome
+}
+
+/// Whether a multiverse gives a property something to check.
+type Exercised = fn(&Multiverse) -> bool;
+
+/// What gives the property named `property` something to check, or `None`
+/// for one nobody has said how to exercise yet. Properties come from
+/// [`PROPERTIES`], so a new one without an entry here shows up as never
+/// exercised rather than dropping out of the report.
+fn exercise(property: &str) -> Option<Exercised> {
+    let exercised: Exercised = match property {
+        "memory_consistency" => |m| m.characters.values().any(|c| !c.memories.is_empty()),
+        "timeline_perception" => |m| {
+            m.timelines.len() > 1 && m.characters.values().any(|c| !c.memories.is_empty())
+        },
+        "causality_justification" => |m| m.events.values().any(|e| e.causality_violation.is_some()),
+        "relationship_consistency" => |m| {
+            has_effect(m, "RelationshipChange") || has_effect(m, "AffinityShift")
+        },
+        "death_finality" => |m| has_effect(m, "CharacterDeath"),
+        "knowledge_flags" => |m| m.characters.values().any(|c| !c.knowledge_flags.is_empty()),
+        "emotional_state_validity" => |m| {
+            m.characters.values().any(|c| !c.emotional_state.emotions.is_empty())
+        },
+        "anchor_departure_final" => |m| has_effect(m, "TemporalDeparture"),
+        "ability_justification" => |m| m.characters.values().any(|c| !c.abilities.is_empty()),
+        "branch_history_frozen" => |m| m.timelines.len() > 1,
+        "memory_wipe_final" => |m| has_effect(m, "MemoryWipe"),
+        "exclusive_trade_final" => |m| {
+            m.events.values().flat_map(|e| &e.effects).any(|effect| {
+                matches!(effect, EventEffect::MemoryTransfer { exclusive: true, .. })
+            })
+        },
+        "memory_recall_usable" => |m| has_effect(m, "MemoryRecall"),
+        "collective_memory_shared" => |m| !m.collectives.is_empty(),
+        "participants_in_timeline" => |m| m.events.values().any(|e| !e.participants.is_empty()),
+        "event_ids_consistent" => |m| !m.events.is_empty(),
+        "memory_references_resolve" => |m| !m.memories.is_empty(),
+        "social_emotions_witnessed" => |m| has_effect(m, "SocialAppraisal"),
+        "precog_contradictions_allowed" => |m| !m.mutually_exclusive.is_empty(),
+        "anchor_timeline_respected" => |m| m.characters.values().any(|c| c.anchor_timeline.is_some()),
+        "character_invariants" => |m| !m.character_invariants.is_empty(),
+        _ => return None,
+    };
+    Some(exercised)
+}
+
+fn has_effect(multiverse: &Multiverse, name: &str) -> bool {
+    multiverse
+        .events
+        .values()
+        .flat_map(|e| &e.effects)
+        .any(|effect| effect.name() == name)
+}
+
+/// What a [`CoverageTracker`] counted, ready to print or save.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CoverageReport {
+    /// Actions that changed the multiverse, by variant
+    pub applied: BTreeMap<String, usize>,
+    /// Actions that were skipped or rejected, by variant
+    pub skipped: BTreeMap<String, usize>,
+    /// Recorded effects, by variant
+    pub effects: BTreeMap<String, usize>,
+    /// Abilities gained or lost, by kind
+    pub abilities: BTreeMap<String, usize>,
+    /// Validations in which each property had something to check
+    pub properties: BTreeMap<String, usize>,
+    /// Recorded causality violations, by kind
+    pub causality_violations: BTreeMap<String, usize>,
+    /// Events recorded
+    pub events: usize,
+    /// Validations counted
+    pub validations: usize,
+    /// Action variants never applied
+    pub missing_actions: Vec<String>,
+    /// Effect variants never recorded
+    pub missing_effects: Vec<String>,
+    /// Properties never given anything to check
+    pub missing_properties: Vec<String>,
+}
+
+impl fmt::Display for CoverageReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Coverage over {} events and {} validations", self.events, self.validations)?;
+        let sections = [
+            ("Actions applied", &self.applied),
+            ("Actions skipped", &self.skipped),
+            ("Effects", &self.effects),
+            ("Abilities", &self.abilities),
+            ("Properties exercised", &self.properties),
+            ("Causality violations", &self.causality_violations),
+        ];
+        for (title, counts) in sections {
+            writeln!(f, "{}:", title)?;
+            for (name, count) in counts {
+                writeln!(f, "  {:<26}{}", name, count)?;
+            }
+        }
+        if !self.missing_actions.is_empty() {
+            writeln!(f, "Never applied: {}", self.missing_actions.join(", "))?;
+        }
+        if !self.missing_effects.is_empty() {
+            writeln!(f, "Never recorded: {}", self.missing_effects.join(", "))?;
+        }
+        if !self.missing_properties.is_empty() {
+            writeln!(f, "Never exercised: {}", self.missing_properties.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tracker_counts_and_merges() {
+        let mut multiverse = Multiverse::new();
+        let root = multiverse.root_timeline;
+        let kor = multiverse.create_character("Kor-Valeth".to_string(), root);
+
+        let mut first = CoverageTracker::new();
+        let anchor = NarrativeAction::AnchorCharacter {
+            character: kor,
+            anchor: TemporalAnchor { origin_era: -1000, stability: 0.1 },
+        };
+        // The anchoring scene exhausts the anchor, so a departure follows it
+        assert!(apply_tracked(&mut multiverse, &mut first, &anchor).is_applied());
+        assert!(!apply_tracked(&mut multiverse, &mut first, &anchor).is_applied());
+        first.record_validation(&multiverse);
+
+        let mut second = CoverageTracker::new();
+        let kill = NarrativeAction::KillCharacter { character: kor, timeline: root };
+        assert!(!apply_tracked(&mut multiverse, &mut second, &kill).is_applied());
+
+        let mut total = CoverageTracker::new();
+        total.merge(&first);
+        total.merge(&second);
+        let report = total.report();
+
+        assert_eq!(report.applied["AnchorCharacter"], 1);
+        assert_eq!(report.skipped["AnchorCharacter"], 1);
+        assert_eq!(report.skipped["KillCharacter"], 1);
+        assert_eq!(report.effects["AnchorGained"], 1);
+        assert_eq!(report.effects["TemporalDeparture"], 1);
+        assert_eq!(report.causality_violations["RetroactiveChange"], 1);
+        assert_eq!(report.properties["anchor_departure_final"], 1);
+        assert!(!report.properties.contains_key("death_finality"));
+        assert!(report.missing_properties.contains(&"death_finality".to_string()));
+        assert!(report.missing_effects.contains(&"CharacterDeath".to_string()));
+        assert!(report.to_string().contains("Never recorded: CharacterDeath"));
+    }
+
+    #[test]
+    fn test_every_property_can_be_exercised() {
+        let unknown: Vec<&str> =
+            PROPERTIES.iter().map(|(name, _)| *name).filter(|name| exercise(name).is_none()).collect();
+        assert!(unknown.is_empty(), "no exercise for: {}", unknown.join(", "));
+    }
+}
@@ -0,0 +1,269 @@
+//! # Diffs: What an Action Changed
+//!
+//! Two snapshots of a multiverse say what the world looked like; a
+//! [`MultiverseDiff`] says what happened in between, in story terms: who died,
+//! who moved, who gained or lost which memory. It covers the state properties
+//! care about, not every field—emotions drift continuously with every
+//! appraisal and are left out.
+//!
+//! Changes come out in a fixed order (timelines, events, then characters by id),
+//! so the same pair of snapshots always diffs to the same text.
+
+use crate::narrative_core::*;
+use std::collections::HashSet;
+use std::fmt;
+
+/// One thing that changed between two snapshots.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Change {
+    TimelineCreated(TimelineId),
+    EventRecorded(EventId),
+    CharacterCreated { character: CharacterId, name: String },
+    Died(CharacterId),
+    Resurrected(CharacterId),
+    Renamed { character: CharacterId, from: String, to: String },
+    Departed(CharacterId),
+    Moved {
+        character: CharacterId,
+        from: TimelineId,
+        to: TimelineId,
+    },
+    MemoryGained { character: CharacterId, memory: MemoryId },
+    MemoryLost { character: CharacterId, memory: MemoryId },
+    MemoryFaded { memory: MemoryId, from: f32, to: f32 },
+    KnowledgeGained { character: CharacterId, flag: String },
+    KnowledgeLost { character: CharacterId, flag: String },
+    AbilityGained { character: CharacterId, ability: AbilityKind },
+    AbilityLost { character: CharacterId, ability: AbilityKind },
+    RelationshipChanged {
+        character: CharacterId,
+        other: CharacterId,
+        from: Option<RelationshipState>,
+        to: Option<RelationshipState>,
+    },
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Change::TimelineCreated(timeline) => write!(f, "{} branches off", timeline),
+            Change::EventRecorded(event) => write!(f, "{} recorded", event),
+            Change::CharacterCreated { character, name } => write!(f, "{} ({}) arrives", character, name),
+            Change::Died(character) => write!(f, "{} dies", character),
+            Change::Resurrected(character) => write!(f, "{} returns from the dead", character),
+            Change::Renamed { character, from, to } => write!(f, "{} ({}) is now {}", character, from, to),
+            Change::Departed(character) => write!(f, "{} is pulled back to their era", character),
+            Change::Moved { character, from, to } => write!(f, "{} moves {} → {}", character, from, to),
+            Change::MemoryGained { character, memory } => write!(f, "{} gains {}", character, memory),
+            Change::MemoryLost { character, memory } => write!(f, "{} loses {}", character, memory),
+            Change::MemoryFaded { memory, from, to } => write!(f, "{} fades {:.2} → {:.2}", memory, from, to),
+            Change::KnowledgeGained { character, flag } => write!(f, "{} learns '{}'", character, flag),
+            Change::KnowledgeLost { character, flag } => write!(f, "{} forgets '{}'", character, flag),
+            Change::AbilityGained { character, ability } => write!(f, "{} gains {:?}", character, ability),
+            Change::AbilityLost { character, ability } => write!(f, "{} loses {:?}", character, ability),
+            Change::RelationshipChanged {
+                character,
+                other,
+                from,
+                to,
+            } => {
+                let state = |s: &Option<RelationshipState>| match s {
+                    Some(s) => format!("{:?}", s),
+                    None => "strangers".to_string(),
+                };
+                write!(f, "{} & {}: {} → {}", character, other, state(from), state(to))
+            }
+        }
+    }
+}
+
+/// Everything that changed between two snapshots of a multiverse.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MultiverseDiff {
+    pub changes: Vec<Change>,
+}
+
+impl MultiverseDiff {
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+}
+
+impl fmt::Display for MultiverseDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.changes.is_empty() {
+            return write!(f, "nothing changes");
+        }
+        let changes: Vec<String> = self.changes.iter().map(ToString::to_string).collect();
+        write!(f, "{}", changes.join("; "))
+    }
+}
+
+impl Multiverse {
+    /// What changed from this snapshot to `after`.
+    pub fn diff(&self, after: &Multiverse) -> MultiverseDiff {
+        let mut changes = Vec::new();
+
+        let mut timelines: Vec<TimelineId> =
+            after.timelines.keys().filter(|t| !self.timelines.contains_key(t)).copied().collect();
+        timelines.sort();
+        changes.extend(timelines.into_iter().map(Change::TimelineCreated));
+
+        let mut events: Vec<EventId> =
+            after.events.keys().filter(|e| !self.events.contains_key(e)).copied().collect();
+        events.sort();
+        changes.extend(events.into_iter().map(Change::EventRecorded));
+
+        let mut characters: Vec<&Character> = after.characters.values().collect();
+        characters.sort_by_key(|c| c.id);
+        for now in characters {
+            let character = now.id;
+            let Some(before) = self.characters.get(&character) else {
+                changes.push(Change::CharacterCreated {
+                    character,
+                    name: now.name.clone(),
+                });
+                continue;
+            };
+
+            match (before.alive, now.alive) {
+                (true, false) => changes.push(Change::Died(character)),
+                (false, true) => changes.push(Change::Resurrected(character)),
+                _ => {}
+            }
+            if before.name != now.name {
+                changes.push(Change::Renamed {
+                    character,
+                    from: before.name.clone(),
+                    to: now.name.clone(),
+                });
+            }
+            if !before.has_departed() && now.has_departed() {
+                changes.push(Change::Departed(character));
+            }
+            if before.current_timeline != now.current_timeline {
+                changes.push(Change::Moved {
+                    character,
+                    from: before.current_timeline,
+                    to: now.current_timeline,
+                });
+            }
+
+            let (gained, lost) = set_changes(&before.memories, &now.memories);
+            changes.extend(gained.into_iter().map(|memory| Change::MemoryGained { character, memory }));
+            changes.extend(lost.into_iter().map(|memory| Change::MemoryLost { character, memory }));
+
+            let (gained, lost) = set_changes(&before.knowledge_flags, &now.knowledge_flags);
+            changes.extend(gained.into_iter().map(|flag| Change::KnowledgeGained { character, flag }));
+            changes.extend(lost.into_iter().map(|flag| Change::KnowledgeLost { character, flag }));
+
+            let kinds = |c: &Character| c.abilities.keys().copied().collect::<HashSet<_>>();
+            let (gained, lost) = set_changes(&kinds(before), &kinds(now));
+            changes.extend(gained.into_iter().map(|ability| Change::AbilityGained { character, ability }));
+            changes.extend(lost.into_iter().map(|ability| Change::AbilityLost { character, ability }));
+
+            let mut others: Vec<CharacterId> =
+                before.relationships.keys().chain(now.relationships.keys()).copied().collect();
+            others.sort();
+            others.dedup();
+            for other in others {
+                // Read each side's own record rather than `relationship_between`:
+                // `None` tells a pair with no history apart from one set to Neutral
+                let from = before.relationships.get(&other).copied();
+                let to = now.relationships.get(&other).copied();
+                // A mutual change is reported once, from the lower id's side
+                let theirs = |m: &Multiverse| {
+                    m.characters.get(&other).and_then(|o| o.relationships.get(&character).copied())
+                };
+                let mirrored = || (theirs(self), theirs(after)) == (from, to);
+                if from != to && (character < other || !mirrored()) {
+                    changes.push(Change::RelationshipChanged {
+                        character,
+                        other,
+                        from,
+                        to,
+                    });
+                }
+            }
+        }
+
+        let mut memories: Vec<&Memory> = after.memories.values().collect();
+        memories.sort_by_key(|m| m.id);
+        for memory in memories {
+            if let Some(before) = self.memories.get(&memory.id) {
+                if before.fidelity != memory.fidelity {
+                    changes.push(Change::MemoryFaded {
+                        memory: memory.id,
+                        from: before.fidelity,
+                        to: memory.fidelity,
+                    });
+                }
+            }
+        }
+
+        MultiverseDiff { changes }
+    }
+}
+
+/// What `after` has that `before` doesn't, and the reverse, each sorted.
+fn set_changes<T>(before: &HashSet<T>, after: &HashSet<T>) -> (Vec<T>, Vec<T>)
+where
+    T: Clone + Ord + std::hash::Hash,
+{
+    let mut gained: Vec<T> = after.difference(before).cloned().collect();
+    let mut lost: Vec<T> = before.difference(after).cloned().collect();
+    gained.sort();
+    lost.sort();
+    (gained, lost)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diff_tells_who_died() {
+        let mut before = Multiverse::new();
+        let root = before.root_timeline;
+        let nameless = before.create_character("Nameless".to_string(), root);
+        assert!(before.diff(&before.clone()).is_empty());
+
+        let mut after = before.clone();
+        let vera = after.create_character("Vera Kandros".to_string(), root);
+        let death = after.record_event(Event {
+            id: EventId(0),
+            timeline: root,
+            description: "Nameless falls at the Living Gate".to_string(),
+            participants: [nameless, vera].into_iter().collect(),
+            effects: vec![
+                EventEffect::RelationshipChange {
+                    character1: vera,
+                    character2: nameless,
+                    new_state: RelationshipState::Allied,
+                },
+                EventEffect::CharacterDeath { character: nameless },
+            ],
+            causality_violation: None,
+        });
+
+        let diff = before.diff(&after);
+        assert_eq!(
+            diff.changes,
+            vec![
+                Change::EventRecorded(death),
+                Change::Died(nameless),
+                Change::RelationshipChanged {
+                    character: nameless,
+                    other: vera,
+                    from: None,
+                    to: Some(RelationshipState::Allied),
+                },
+                Change::CharacterCreated {
+                    character: vera,
+                    name: "Vera Kandros".to_string(),
+                },
+            ]
+        );
+        assert!(diff.to_string().contains(&format!("{} dies", nameless)));
+    }
+}
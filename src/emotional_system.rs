@@ -1,5 +1,6 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use thiserror::Error;
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub enum EmotionType {
@@ -22,6 +23,69 @@ pub enum EmotionType {
 }
 
 impl EmotionType {
+    /// Every emotion type, in declaration order.
+    pub fn all() -> &'static [EmotionType] {
+        &[
+            EmotionType::Distress,
+            EmotionType::Fear,
+            EmotionType::Hope,
+            EmotionType::Joy,
+            EmotionType::Satisfaction,
+            EmotionType::FearConfirmed,
+            EmotionType::Disappointment,
+            EmotionType::Relief,
+            EmotionType::HappyFor,
+            EmotionType::Resentment,
+            EmotionType::Pity,
+            EmotionType::Gloating,
+            EmotionType::Gratitude,
+            EmotionType::Anger,
+            EmotionType::Gratification,
+            EmotionType::Remorse,
+        ]
+    }
+
+    /// Whether this is a pleasant emotion to feel.
+    ///
+    /// Gloating counts as unpleasant: pleasure at someone else's misfortune is
+    /// a hostile feeling, and Gamygdala's PAD mapping scores it that way.
+    pub fn is_positive(&self) -> bool {
+        matches!(
+            self,
+            EmotionType::Hope
+                | EmotionType::Joy
+                | EmotionType::Satisfaction
+                | EmotionType::Relief
+                | EmotionType::HappyFor
+                | EmotionType::Gratitude
+                | EmotionType::Gratification
+        )
+    }
+
+    /// The emotion OCC pairs this one with, of the opposite valence: Joy and
+    /// Distress, Hope and Fear, Gratitude and Anger, and so on. Pairing twice
+    /// gets back where it started.
+    pub fn opposite(&self) -> EmotionType {
+        match self {
+            EmotionType::Distress => EmotionType::Joy,
+            EmotionType::Joy => EmotionType::Distress,
+            EmotionType::Fear => EmotionType::Hope,
+            EmotionType::Hope => EmotionType::Fear,
+            EmotionType::Satisfaction => EmotionType::Disappointment,
+            EmotionType::Disappointment => EmotionType::Satisfaction,
+            EmotionType::FearConfirmed => EmotionType::Relief,
+            EmotionType::Relief => EmotionType::FearConfirmed,
+            EmotionType::HappyFor => EmotionType::Resentment,
+            EmotionType::Resentment => EmotionType::HappyFor,
+            EmotionType::Pity => EmotionType::Gloating,
+            EmotionType::Gloating => EmotionType::Pity,
+            EmotionType::Gratitude => EmotionType::Anger,
+            EmotionType::Anger => EmotionType::Gratitude,
+            EmotionType::Gratification => EmotionType::Remorse,
+            EmotionType::Remorse => EmotionType::Gratification,
+        }
+    }
+
     pub fn as_str(&self) -> &'static str {
         match self {
             EmotionType::Distress => "distress",
@@ -43,6 +107,12 @@ impl EmotionType {
         }
     }
 
+    /// Where this emotion sits in PAD (pleasure, arousal, dominance) space,
+    /// after Gamygdala's mapping.
+    ///
+    /// **Invariant**: every component is within [-1, 1], and pleasure is
+    /// positive exactly for the [positive](EmotionType::is_positive) emotions.
+    /// [`validate_pad_table`] checks both; extend the table and it will tell you.
     pub fn pad_values(&self) -> [f64; 3] {
         match self {
             EmotionType::Distress => [-0.61, 0.28, -0.36],
@@ -65,12 +135,48 @@ impl EmotionType {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Checks [`EmotionType::pad_values`] against its invariants, for every
+/// emotion type. Returns every problem found.
+pub fn validate_pad_table() -> Result<(), Vec<String>> {
+    let mut problems = Vec::new();
+    for emotion in EmotionType::all() {
+        let pad = emotion.pad_values();
+        for (axis, value) in ["pleasure", "arousal", "dominance"].iter().zip(pad) {
+            if !(-1.0..=1.0).contains(&value) {
+                problems.push(format!("{} has {} {} outside [-1, 1]", emotion.as_str(), axis, value));
+            }
+        }
+        if (pad[0] > 0.0) != emotion.is_positive() {
+            let valence = if emotion.is_positive() { "positive" } else { "negative" };
+            problems.push(format!(
+                "{} is a {} emotion but has pleasure {}",
+                emotion.as_str(),
+                valence,
+                pad[0]
+            ));
+        }
+    }
+    if problems.is_empty() {
+        Ok(())
+    } else {
+        Err(problems)
+    }
+}
+
+/// How much stronger Fear is when a maintenance goal is threatened. Something
+/// that has to be kept up is never safe, so bad news about it weighs more.
+pub const MAINTENANCE_FEAR_FACTOR: f64 = 1.5;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Goal {
     pub name: String,
     pub utility: f64,      // -1.0 to 1.0 (desire)
-    pub likelihood: f64,   // 0.0 to 1.0
+    pub likelihood: f64,   // 0.0 (lost) to 1.0 (achieved), as in Gamygdala
     pub is_maintenance: bool,
+    /// The outcome this goal is about, if any. Goals concerning the same outcome
+    /// with opposite utilities are in conflict.
+    #[serde(default)]
+    pub concerns: Option<String>,
 }
 
 impl Goal {
@@ -80,21 +186,216 @@ impl Goal {
             utility,
             likelihood: 0.5,
             is_maintenance,
+            concerns: None,
+        }
+    }
+
+    /// Whether the utility is in [-1, 1] and the likelihood in [0, 1]. The
+    /// fields are public, so a literal can still be out of range.
+    pub fn validate(&self) -> Result<(), GoalError> {
+        if !(-1.0..=1.0).contains(&self.utility) {
+            return Err(GoalError::UtilityOutOfRange(self.utility));
+        }
+        if !(0.0..=1.0).contains(&self.likelihood) {
+            return Err(GoalError::LikelihoodOutOfRange(self.likelihood));
         }
+        Ok(())
+    }
+
+    /// Starts an achievement goal named `name`, wanted fully (utility 1) and
+    /// as likely as not; see [`GoalBuilder`].
+    pub fn builder(name: impl Into<String>) -> GoalBuilder {
+        GoalBuilder(Goal::new(name.into(), 1.0, false))
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Builds a [`Goal`], checking its ranges:
+///
+/// ```
+/// # use propyarn::emotional_system::Goal;
+/// let crew = Goal::builder("Protect Crew").utility(0.9).maintenance().build()?;
+/// assert!(crew.is_maintenance);
+/// # Ok::<(), propyarn::emotional_system::GoalError>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct GoalBuilder(Goal);
+
+impl GoalBuilder {
+    /// How much it is wanted, in [-1, 1]; below zero, it's dreaded.
+    pub fn utility(mut self, utility: f64) -> Self {
+        self.0.utility = utility;
+        self
+    }
+
+    /// How likely it looks to begin with, in [0, 1].
+    pub fn likelihood(mut self, likelihood: f64) -> Self {
+        self.0.likelihood = likelihood;
+        self
+    }
+
+    /// It has to be kept up rather than achieved once.
+    pub fn maintenance(mut self) -> Self {
+        self.0.is_maintenance = true;
+        self
+    }
+
+    /// The outcome it is about; see [`Goal::concerns`].
+    pub fn concerns(mut self, outcome: impl Into<String>) -> Self {
+        self.0.concerns = Some(outcome.into());
+        self
+    }
+
+    /// The goal, refused if its utility or likelihood is out of range; see
+    /// [`Goal::validate`].
+    pub fn build(self) -> Result<Goal, GoalError> {
+        self.0.validate()?;
+        Ok(self.0)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Emotion {
     pub emotion_type: EmotionType,
     pub intensity: f64,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+/// How emotions fade with each tick of narrative time, after Gamygdala.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum DecayMode {
+    /// Intensity is multiplied by this factor, within [0, 1], each tick: it
+    /// fades fast at first and never quite reaches zero
+    Exponential(f64),
+    /// Intensity drops by this much each tick, down to zero and no further
+    Linear(f64),
+}
+
+/// How a character's emotions fade. Emotions don't all fade alike: Fear
+/// lingers, Relief evaporates, so any emotion can have its own rate.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DecayConfig {
+    pub mode: DecayMode,
+    /// Rates that replace the mode's for particular emotions: a factor if the
+    /// mode is exponential, an amount per tick if it's linear
+    #[serde(default)]
+    pub per_emotion_overrides: HashMap<EmotionType, f64>,
+}
+
+impl Default for DecayConfig {
+    fn default() -> Self {
+        Self {
+            mode: DecayMode::Exponential(0.9),
+            per_emotion_overrides: HashMap::new(),
+        }
+    }
+}
+
+impl DecayConfig {
+    /// Gives `emotion` its own rate.
+    pub fn with_override(mut self, emotion: EmotionType, rate: f64) -> Self {
+        self.per_emotion_overrides.insert(emotion, rate);
+        self
+    }
+
+    /// What's left of `intensity` of `emotion` after `ticks`. Never negative.
+    pub fn fade(&self, emotion: &EmotionType, intensity: f64, ticks: u32) -> f64 {
+        let faded = match self.mode {
+            DecayMode::Exponential(factor) => {
+                let factor = self.per_emotion_overrides.get(emotion).copied().unwrap_or(factor);
+                intensity * factor.clamp(0.0, 1.0).powf(ticks as f64)
+            }
+            DecayMode::Linear(amount) => {
+                let amount = self.per_emotion_overrides.get(emotion).copied().unwrap_or(amount);
+                intensity - amount.max(0.0) * ticks as f64
+            }
+        };
+        faded.max(0.0)
+    }
+}
+
+/// The most reactive a character can be. Gamygdala recommends gains up to
+/// about 20; past that, any emotion at all pins PAD to the edges.
+pub const MAX_GAIN: f64 = 20.0;
+
+/// How much of their old mood a character keeps at each update unless told
+/// otherwise: moods take tens of appraisals to turn.
+pub const DEFAULT_MOOD_INERTIA: f64 = 0.9;
+
+fn default_mood_inertia() -> f64 {
+    DEFAULT_MOOD_INERTIA
+}
+
+/// Why an emotional state change was refused.
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum EmotionError {
+    #[error("gain {0} is outside (0, {MAX_GAIN}]")]
+    GainOutOfRange(f64),
+    #[error("no goal named {0:?}")]
+    UnknownGoal(String),
+    #[error("mood inertia {0} is outside [0, 1]")]
+    InertiaOutOfRange(f64),
+}
+
+/// Why a belief was refused; see [`Belief::validate`].
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum BeliefError {
+    #[error("belief names {names} goals but gives {congruences} congruences")]
+    LengthMismatch { names: usize, congruences: usize },
+    #[error("likelihood {0} is outside [0, 1]")]
+    LikelihoodOutOfRange(f64),
+    #[error("congruence {congruence} for {goal:?} is outside [-1, 1]")]
+    CongruenceOutOfRange { goal: String, congruence: f64 },
+    #[error("praiseworthiness {0} is outside [-1, 1]")]
+    PraiseworthinessOutOfRange(f64),
+    #[error("belief affects no goals")]
+    NoGoals,
+}
+
+/// Why a goal was refused; see [`Goal::validate`].
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum GoalError {
+    #[error("utility {0} is outside [-1, 1]")]
+    UtilityOutOfRange(f64),
+    #[error("likelihood {0} is outside [0, 1]")]
+    LikelihoodOutOfRange(f64),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct EmotionalState {
     pub emotions: Vec<Emotion>,
     pub goals: HashMap<String, Goal>,
+    /// Reactivity: how hard emotions push PAD toward its edges. Always in
+    /// (0, [`MAX_GAIN`]]; set it through [`EmotionalState::set_gain`]
     pub gain: f64,
+    /// How this character feels about other agents, by name: Anger at whoever
+    /// set back their goals, Gratitude toward whoever helped them. These also
+    /// count toward the character's own mood.
+    #[serde(default)]
+    pub relations: HashMap<String, Vec<Emotion>>,
+    /// What each goal has made this character feel, by goal name, so the Hope
+    /// and Fear from a belief touching several goals can be told apart. These
+    /// are the same emotions as `emotions`, attributed; see
+    /// [`EmotionalState::emotions_for_goal`]
+    #[serde(default)]
+    pub goal_emotions: HashMap<String, Vec<Emotion>>,
+    /// How this character's emotions fade; see [`EmotionalState::decay`]
+    #[serde(default)]
+    pub decay: DecayConfig,
+    /// The long-term baseline beneath the emotions of the moment: a moving
+    /// average of PAD, taken whenever this character appraises or their
+    /// emotions decay, so being ground down outlasts the fear that did it.
+    /// See [`EmotionalState::get_mood`]
+    #[serde(default)]
+    pub mood: [f64; 3],
+    /// How much of the old mood each update keeps, within [0, 1]: 0 tracks PAD
+    /// exactly, 1 never changes. Set it through [`EmotionalState::set_mood_inertia`]
+    #[serde(default = "default_mood_inertia")]
+    pub mood_inertia: f64,
+}
+
+impl Default for EmotionalState {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl EmotionalState {
@@ -103,79 +404,374 @@ impl EmotionalState {
             emotions: Vec::new(),
             goals: HashMap::new(),
             gain: 1.0,
+            relations: HashMap::new(),
+            goal_emotions: HashMap::new(),
+            decay: DecayConfig::default(),
+            mood: [0.0; 3],
+            mood_inertia: DEFAULT_MOOD_INERTIA,
+        }
+    }
+
+    /// An empty state with the given reactivity. Higher gain pushes the same
+    /// emotions further toward the edges of PAD space.
+    pub fn with_gain(gain: f64) -> Self {
+        Self { gain, ..Self::new() }
+    }
+
+    /// Changes this character's reactivity, refusing gains outside
+    /// (0, [`MAX_GAIN`]]: zero or less would flatten or invert every emotion.
+    pub fn set_gain(&mut self, gain: f64) -> Result<(), EmotionError> {
+        Self::check_gain(gain)?;
+        self.gain = gain;
+        Ok(())
+    }
+
+    /// Whether `gain` is a usable reactivity; NaN isn't.
+    pub fn check_gain(gain: f64) -> Result<(), EmotionError> {
+        if gain > 0.0 && gain <= MAX_GAIN {
+            Ok(())
+        } else {
+            Err(EmotionError::GainOutOfRange(gain))
+        }
+    }
+
+    /// Changes how slowly this character's mood turns, refusing inertia
+    /// outside [0, 1]: past either end the average would overshoot PAD.
+    pub fn set_mood_inertia(&mut self, inertia: f64) -> Result<(), EmotionError> {
+        if !(0.0..=1.0).contains(&inertia) {
+            return Err(EmotionError::InertiaOutOfRange(inertia));
         }
+        self.mood_inertia = inertia;
+        Ok(())
     }
 
     pub fn add_goal(&mut self, goal: Goal) {
         self.goals.insert(goal.name.clone(), goal);
     }
 
+    /// The goal has come true: its likelihood goes to 1 and is appraised
+    /// (Joy, and Satisfaction if it was expected, for a goal that was wanted).
+    /// Done is done, so it's dropped, unless it's a maintenance goal, which has
+    /// to be kept up. Returns the emotions felt.
+    pub fn complete_goal(&mut self, name: &str) -> Result<Vec<Emotion>, EmotionError> {
+        let goal = self.goals.get_mut(name).ok_or_else(|| EmotionError::UnknownGoal(name.to_string()))?;
+        let delta_likelihood = 1.0 - goal.likelihood;
+        goal.likelihood = 1.0;
+        let (utility, is_maintenance) = (goal.utility, goal.is_maintenance);
+        if !is_maintenance {
+            self.goals.remove(name);
+        }
+        Ok(self.evaluate_internal_emotion(name, utility, delta_likelihood, 1.0, is_maintenance))
+    }
+
+    /// The goal is given up on: its likelihood goes to 0 and it's dropped.
+    /// Losing what was wanted is a Disappointment; losing what was dreaded, a
+    /// Relief, as strong as the hope or dread was. Returns the emotions felt.
+    pub fn abandon_goal(&mut self, name: &str) -> Result<Vec<Emotion>, EmotionError> {
+        let goal = self.goals.remove(name).ok_or_else(|| EmotionError::UnknownGoal(name.to_string()))?;
+        let intensity = (goal.utility * goal.likelihood).abs();
+        if intensity == 0.0 {
+            return Ok(Vec::new());
+        }
+        let emotion = Emotion {
+            emotion_type: if goal.utility >= 0.0 {
+                EmotionType::Disappointment
+            } else {
+                EmotionType::Relief
+            },
+            intensity,
+        };
+        Self::accumulate(self.goal_emotions.entry(name.to_string()).or_default(), emotion.clone());
+        self.update_emotional_state(emotion.clone());
+        Ok(vec![emotion])
+    }
+
+    /// Pairs of goals that concern the same outcome but want opposite things
+    /// from it. Each pair is ordered by name, and the list is sorted.
+    pub fn conflicting_goals(&self) -> Vec<(String, String)> {
+        let mut goals: Vec<&Goal> = self.goals.values().filter(|g| g.concerns.is_some()).collect();
+        goals.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let mut conflicts = Vec::new();
+        for (i, a) in goals.iter().enumerate() {
+            for b in &goals[i + 1..] {
+                if a.concerns == b.concerns && a.utility * b.utility < 0.0 {
+                    conflicts.push((a.name.clone(), b.name.clone()));
+                }
+            }
+        }
+        conflicts
+    }
+
     pub fn update_emotional_state(&mut self, new_emotion: Emotion) {
-        for emotion in &mut self.emotions {
+        Self::accumulate(&mut self.emotions, new_emotion);
+    }
+
+    /// The emotion felt most strongly, or `None` if nothing is felt. Of
+    /// equally strong emotions, the one felt first wins.
+    pub fn dominant_emotion(&self) -> Option<&Emotion> {
+        self.emotions
+            .iter()
+            .reduce(|strongest, e| if e.intensity > strongest.intensity { e } else { strongest })
+    }
+
+    /// The `n` strongest emotions, strongest first; ties keep the order they
+    /// were first felt in.
+    pub fn strongest_n(&self, n: usize) -> Vec<&Emotion> {
+        let mut emotions: Vec<&Emotion> = self.emotions.iter().collect();
+        emotions.sort_by(|a, b| b.intensity.total_cmp(&a.intensity));
+        emotions.truncate(n);
+        emotions
+    }
+
+    /// Pleasant minus unpleasant: every emotion's intensity, signed by its
+    /// pleasure. Unlike [`get_pad`](Self::get_pad) this isn't squashed.
+    pub fn net_valence(&self) -> f64 {
+        self.emotions
+            .iter()
+            .map(|e| e.intensity * e.emotion_type.pad_values()[0].signum())
+            .sum()
+    }
+
+    /// How strongly `emotion_type` is felt right now; 0 if it isn't.
+    pub fn feels(&self, emotion_type: &EmotionType) -> f64 {
+        self.emotions
+            .iter()
+            .find(|e| e.emotion_type == *emotion_type)
+            .map_or(0.0, |e| e.intensity)
+    }
+
+    /// What this character feels toward `agent`, strongest first. Empty if
+    /// `agent` has never been the cause of anything they care about.
+    pub fn emotion_toward(&self, agent: &str) -> Vec<&Emotion> {
+        let mut emotions: Vec<&Emotion> = self.relations.get(agent).into_iter().flatten().collect();
+        emotions.sort_by(|a, b| b.intensity.total_cmp(&a.intensity));
+        emotions
+    }
+
+    /// What `goal` has made this character feel, strongest first: the part of
+    /// their emotions that came from news about it. Empty for a goal nothing
+    /// has touched yet, or one they never held.
+    pub fn emotions_for_goal(&self, goal: &str) -> Vec<&Emotion> {
+        let mut emotions: Vec<&Emotion> = self.goal_emotions.get(goal).into_iter().flatten().collect();
+        emotions.sort_by(|a, b| b.intensity.total_cmp(&a.intensity));
+        emotions
+    }
+
+    /// Feels `emotion` about `agent`: it's kept against them, and felt.
+    pub fn feel_toward(&mut self, agent: &str, emotion: Emotion) {
+        Self::accumulate(self.relations.entry(agent.to_string()).or_default(), emotion.clone());
+        self.update_emotional_state(emotion);
+    }
+
+    /// How good `belief` is for this character's goals: each affected goal's
+    /// utility times the congruence, weighted by how likely the belief is.
+    /// Goals they don't hold don't count.
+    pub fn desirability(&self, belief: &Belief) -> f64 {
+        belief
+            .affected_goal_names
+            .iter()
+            .zip(&belief.goal_congruences)
+            .filter_map(|(name, congruence)| self.goals.get(name).map(|goal| goal.utility * congruence))
+            .sum::<f64>()
+            * belief.likelihood
+    }
+
+    /// Adds `new_emotion` to `emotions`, on top of any of the same type.
+    fn accumulate(emotions: &mut Vec<Emotion>, new_emotion: Emotion) {
+        for emotion in emotions.iter_mut() {
             if emotion.emotion_type == new_emotion.emotion_type {
                 emotion.intensity += new_emotion.intensity;
                 return;
             }
         }
-        self.emotions.push(new_emotion);
+        emotions.push(new_emotion);
     }
 
     pub fn get_pad(&self) -> [f64; 3] {
-        let mut p = 0.0;
-        let mut a = 0.0;
-        let mut d = 0.0;
+        let mut totals = [0.0; 3];
+        for (_, pad) in self.pad_contributions() {
+            for axis in 0..3 {
+                totals[axis] += pad[axis];
+            }
+        }
+
+        totals.map(|x| Self::squash(self.gain, x))
+    }
 
-        for e in &self.emotions {
-            let pad = e.emotion_type.pad_values();
-            p += e.intensity * pad[0];
-            a += e.intensity * pad[1];
-            d += e.intensity * pad[2];
+    /// This character's long-term mood; see [`EmotionalState::mood`].
+    pub fn get_mood(&self) -> [f64; 3] {
+        self.mood
+    }
+
+    /// How this character comes across right now: the mean of their mood and
+    /// their current PAD, so a calm moment still shows what wore them down.
+    pub fn get_affect(&self) -> [f64; 3] {
+        let pad = self.get_pad();
+        [0, 1, 2].map(|axis| (self.mood[axis] + pad[axis]) / 2.0)
+    }
+
+    /// Moves mood a step toward the current PAD, keeping `mood_inertia` of it.
+    fn update_mood(&mut self) {
+        let pad = self.get_pad();
+        let inertia = self.mood_inertia;
+        for (mood, pad) in self.mood.iter_mut().zip(pad) {
+            *mood = inertia * *mood + (1.0 - inertia) * pad;
         }
+    }
 
-        let p_final = if p >= 0.0 { self.gain * p / (self.gain * p + 1.0) } else { -self.gain * p / (self.gain * p - 1.0) };
-        let a_final = if a >= 0.0 { self.gain * a / (self.gain * a + 1.0) } else { -self.gain * a / (self.gain * a - 1.0) };
-        let d_final = if d >= 0.0 { self.gain * d / (self.gain * d + 1.0) } else { -self.gain * d / (self.gain * d - 1.0) };
+    /// Each active emotion's contribution to pleasure, arousal, and dominance
+    /// (intensity times the emotion's PAD vector), before squashing.
+    pub fn pad_contributions(&self) -> Vec<(EmotionType, [f64; 3])> {
+        self.emotions
+            .iter()
+            .map(|e| (e.emotion_type.clone(), e.emotion_type.pad_values().map(|v| e.intensity * v)))
+            .collect()
+    }
 
-        [p_final, a_final, d_final]
+    /// Maps an unbounded PAD total into (-1, 1), steeper with higher gain.
+    fn squash(gain: f64, x: f64) -> f64 {
+        if x >= 0.0 {
+            gain * x / (gain * x + 1.0)
+        } else {
+            -gain * x / (gain * x - 1.0)
+        }
+    }
+
+    /// Moves the goals a belief bears on, feels whatever that brings, and lets
+    /// the mood follow. A malformed belief (see [`Belief::validate`]) is
+    /// ignored whole rather than half applied;
+    /// [`crate::properties::warn_malformed_beliefs`] points out any that were
+    /// recorded in events. Use [`EmotionalState::try_appraise`] to hear why.
+    ///
+    /// Returns what the belief made them feel, one entry per emotion type with
+    /// everything it added up to: the change, not where it left them.
+    pub fn appraise(&mut self, belief: &Belief) -> Vec<Emotion> {
+        self.try_appraise(belief).unwrap_or_default()
     }
 
-    pub fn appraise(&mut self, belief: &Belief) {
+    /// [`EmotionalState::appraise`], but a malformed belief (a congruence
+    /// outside [-1, 1], or goals and congruences that don't pair up) is
+    /// refused with the reason, leaving the state untouched.
+    pub fn try_appraise(&mut self, belief: &Belief) -> Result<Vec<Emotion>, BeliefError> {
+        belief.validate()?;
         let mut updates = Vec::new();
 
-        for (i, goal_name) in belief.affected_goal_names.iter().enumerate() {
+        for (goal_name, &congruence) in belief.affected_goal_names.iter().zip(&belief.goal_congruences) {
             if let Some(goal) = self.goals.get_mut(goal_name) {
-                let congruence = belief.goal_congruences[i];
                 let utility = goal.utility;
-                
+
                 let delta_likelihood = Self::static_calculate_delta_likelihood(goal, congruence, belief.likelihood, belief.is_incremental);
-                
-                updates.push((utility, delta_likelihood, goal.likelihood));
+
+                updates.push((goal_name, utility, delta_likelihood, goal.likelihood, goal.is_maintenance));
             }
         }
 
-        for (utility, delta_likelihood, likelihood) in updates {
-            self.evaluate_internal_emotion(utility, delta_likelihood, likelihood);
+        let mut felt = Vec::new();
+        for (goal_name, utility, delta_likelihood, likelihood, is_maintenance) in updates {
+            let internal =
+                self.evaluate_internal_emotion(goal_name, utility, delta_likelihood, likelihood, is_maintenance);
+            let desirability = utility * delta_likelihood;
+            let attributed = if belief.self_caused {
+                self.evaluate_self_emotion(goal_name, desirability, belief.praiseworthiness)
+            } else {
+                belief
+                    .causal_agent_name
+                    .as_ref()
+                    .and_then(|agent| self.evaluate_social_emotion(agent, desirability))
+            };
+            for emotion in internal.into_iter().chain(attributed) {
+                Self::accumulate(&mut felt, emotion);
+            }
         }
+        self.update_mood();
+        Ok(felt)
     }
 
+    /// Someone else caused this: Gratitude toward them if it was good for my
+    /// goal (`desirability` above zero), Anger if it was bad. The emotion is
+    /// both kept against the agent and felt, and returned.
+    fn evaluate_social_emotion(&mut self, agent: &str, desirability: f64) -> Option<Emotion> {
+        if desirability == 0.0 {
+            return None;
+        }
+        let emotion_type = if desirability > 0.0 {
+            EmotionType::Gratitude
+        } else {
+            EmotionType::Anger
+        };
+        let emotion = Emotion {
+            emotion_type,
+            intensity: desirability.abs(),
+        };
+        self.feel_toward(agent, emotion.clone());
+        Some(emotion)
+    }
+
+    /// I caused this, so I answer to my own standards. Harm to my goal, or
+    /// anything blameworthy (`praiseworthiness` below zero), brings Remorse; a
+    /// praiseworthy act that helped brings Gratification. Harm weighs in full,
+    /// while a good outcome counts only as far as the act is judged, so a lucky
+    /// break with no standard at stake stirs nothing. The emotion is felt, put
+    /// down to `goal`, and returned.
+    fn evaluate_self_emotion(
+        &mut self,
+        goal: &str,
+        desirability: f64,
+        praiseworthiness: Option<f64>,
+    ) -> Option<Emotion> {
+        let praiseworthiness = praiseworthiness.unwrap_or(0.0);
+        let (emotion_type, intensity) = if desirability < 0.0 {
+            (EmotionType::Remorse, -desirability)
+        } else if praiseworthiness < 0.0 {
+            (EmotionType::Remorse, desirability * -praiseworthiness)
+        } else {
+            (EmotionType::Gratification, desirability * praiseworthiness)
+        };
+        if intensity <= 0.0 {
+            return None;
+        }
+        let emotion = Emotion {
+            emotion_type,
+            intensity,
+        };
+        Self::accumulate(self.goal_emotions.entry(goal.to_string()).or_default(), emotion.clone());
+        self.update_emotional_state(emotion.clone());
+        Some(emotion)
+    }
+
+    /// Moves `goal`'s likelihood as the belief says, keeping it within [0, 1],
+    /// and returns how far it moved. A belief that has no bearing on the goal
+    /// (zero congruence) leaves it alone, and so does any belief about an
+    /// achievement goal that's already been achieved (1) or lost (0).
     fn static_calculate_delta_likelihood(goal: &mut Goal, congruence: f64, likelihood: f64, is_incremental: bool) -> f64 {
         let old_likelihood = goal.likelihood;
-        if !goal.is_maintenance && (old_likelihood >= 1.0 || old_likelihood <= -1.0) {
+        if congruence == 0.0 || (!goal.is_maintenance && (old_likelihood >= 1.0 || old_likelihood <= 0.0)) {
             return 0.0;
         }
 
         let new_likelihood = if is_incremental {
-            let next = old_likelihood + likelihood * congruence;
-            next.max(-1.0).min(1.0)
+            old_likelihood + likelihood * congruence
         } else {
             (congruence * likelihood + 1.0) / 2.0
-        };
+        }
+        .clamp(0.0, 1.0);
 
         goal.likelihood = new_likelihood;
         new_likelihood - old_likelihood
     }
 
-    fn evaluate_internal_emotion(&mut self, utility: f64, delta_likelihood: f64, likelihood: f64) {
+    /// Maintenance goals are never done, so any setback to one also brings Fear
+    /// (even once the goal looks lost), scaled by `MAINTENANCE_FEAR_FACTOR`.
+    /// Returns the emotions felt, which are also put down to `goal`.
+    fn evaluate_internal_emotion(
+        &mut self,
+        goal: &str,
+        utility: f64,
+        delta_likelihood: f64,
+        likelihood: f64,
+        is_maintenance: bool,
+    ) -> Vec<Emotion> {
         let positive = if utility >= 0.0 {
             delta_likelihood >= 0.0
         } else {
@@ -216,37 +812,407 @@ impl EmotionalState {
             }
         }
 
+        let vigilant = is_maintenance && !positive;
+        if vigilant && !emotion_types.contains(&EmotionType::Fear) {
+            emotion_types.push(EmotionType::Fear);
+        }
+
         let intensity = (utility * delta_likelihood).abs();
+        let mut felt = Vec::new();
         if intensity > 0.0 {
             for et in emotion_types {
-                self.update_emotional_state(Emotion {
+                let intensity = if vigilant && et == EmotionType::Fear {
+                    intensity * MAINTENANCE_FEAR_FACTOR
+                } else {
+                    intensity
+                };
+                let emotion = Emotion {
                     emotion_type: et,
                     intensity,
-                });
+                };
+                Self::accumulate(self.goal_emotions.entry(goal.to_string()).or_default(), emotion.clone());
+                self.update_emotional_state(emotion.clone());
+                felt.push(emotion);
             }
         }
+        felt
     }
-    
-    pub fn decay(&mut self, decay_factor: f64) {
-        self.emotions.retain_mut(|e| {
-            e.intensity *= decay_factor;
+
+    /// Lets `ticks` of narrative time pass (events, say), fading every emotion
+    /// as the [`DecayConfig`] says. Emotions that fade to nothing are dropped.
+    /// Mood takes one step toward what's left, however many ticks pass.
+    pub fn decay(&mut self, ticks: u32) {
+        let config = &self.decay;
+        let fade = |e: &mut Emotion| {
+            e.intensity = config.fade(&e.emotion_type, e.intensity, ticks);
             e.intensity > 0.001 // Threshold for removal
-        });
+        };
+        self.emotions.retain_mut(fade);
+        for emotions in self.relations.values_mut().chain(self.goal_emotions.values_mut()) {
+            emotions.retain_mut(fade);
+        }
+        self.relations.retain(|_, emotions| !emotions.is_empty());
+        self.goal_emotions.retain(|_, emotions| !emotions.is_empty());
+        self.update_mood();
     }
+
+    /// One state from two, as when Yash-Tel absorbs a parallel self or a body
+    /// of Synthesis rejoins the rest. Emotions (and those felt toward others
+    /// or put down to goals) add up, each side's intensities scaled by its weight. Everything else is
+    /// a weighted average: gain, mood, mood inertia, and the utility and
+    /// likelihood of a goal both sides hold; a goal only one side holds is kept
+    /// as it is. Negative or NaN weights count as zero, and two zero weights
+    /// average evenly. Decay follows the heavier side.
+    pub fn merge(&self, other: &EmotionalState, weights: (f64, f64)) -> EmotionalState {
+        let (a, b) = (weights.0.max(0.0), weights.1.max(0.0));
+        let (share_a, share_b) = if a + b > 0.0 { (a / (a + b), b / (a + b)) } else { (0.5, 0.5) };
+        let average = |x: f64, y: f64| share_a * x + share_b * y;
+        let sum = |mine: &[Emotion], theirs: &[Emotion]| {
+            let mut emotions = Vec::new();
+            for (emotion, weight) in mine.iter().map(|e| (e, a)).chain(theirs.iter().map(|e| (e, b))) {
+                Self::accumulate(
+                    &mut emotions,
+                    Emotion {
+                        emotion_type: emotion.emotion_type.clone(),
+                        intensity: emotion.intensity * weight,
+                    },
+                );
+            }
+            emotions.retain(|e: &Emotion| e.intensity > 0.0);
+            emotions
+        };
+
+        let mut goals = self.goals.clone();
+        for (name, theirs) in &other.goals {
+            let goal = goals.entry(name.clone()).or_insert_with(|| theirs.clone());
+            if self.goals.contains_key(name) {
+                goal.utility = average(goal.utility, theirs.utility);
+                goal.likelihood = average(goal.likelihood, theirs.likelihood);
+                goal.is_maintenance |= theirs.is_maintenance;
+                goal.concerns = goal.concerns.take().or_else(|| theirs.concerns.clone());
+            }
+        }
+
+        let by_name = |mine: &HashMap<String, Vec<Emotion>>, theirs: &HashMap<String, Vec<Emotion>>| {
+            let mut merged = HashMap::new();
+            for name in mine.keys().chain(theirs.keys()) {
+                let emotions = sum(
+                    mine.get(name).map_or(&[][..], Vec::as_slice),
+                    theirs.get(name).map_or(&[][..], Vec::as_slice),
+                );
+                if !emotions.is_empty() {
+                    merged.insert(name.clone(), emotions);
+                }
+            }
+            merged
+        };
+
+        EmotionalState {
+            emotions: sum(&self.emotions, &other.emotions),
+            goals,
+            gain: average(self.gain, other.gain),
+            relations: by_name(&self.relations, &other.relations),
+            goal_emotions: by_name(&self.goal_emotions, &other.goal_emotions),
+            decay: if b > a { other.decay.clone() } else { self.decay.clone() },
+            mood: [0, 1, 2].map(|axis| average(self.mood[axis], other.mood[axis])),
+            mood_inertia: average(self.mood_inertia, other.mood_inertia),
+        }
+    }
+
+    /// Two states from one, as when Synthesis diverges: the first side feels
+    /// `fraction` of every emotion and the second the rest, while both keep
+    /// the goals, gain, mood and decay whole. `fraction` is clamped into
+    /// [0, 1], NaN counting as 0. Merging the two back with weights (1, 1)
+    /// gives the original.
+    pub fn split(&self, fraction: f64) -> (EmotionalState, EmotionalState) {
+        let fraction = if fraction >= 0.0 { fraction.min(1.0) } else { 0.0 };
+        let part = |share: f64| {
+            let scale = |emotions: &[Emotion]| -> Vec<Emotion> {
+                emotions
+                    .iter()
+                    .map(|e| Emotion {
+                        emotion_type: e.emotion_type.clone(),
+                        intensity: e.intensity * share,
+                    })
+                    .filter(|e| e.intensity > 0.0)
+                    .collect()
+            };
+            let by_name = |map: &HashMap<String, Vec<Emotion>>| -> HashMap<String, Vec<Emotion>> {
+                map.iter()
+                    .map(|(name, emotions)| (name.clone(), scale(emotions)))
+                    .filter(|(_, emotions)| !emotions.is_empty())
+                    .collect()
+            };
+            let mut state = self.clone();
+            state.emotions = scale(&self.emotions);
+            state.relations = by_name(&self.relations);
+            state.goal_emotions = by_name(&self.goal_emotions);
+            state
+        };
+        (part(fraction), part(1.0 - fraction))
+    }
+}
+
+/// What an onlooker feels about someone else's fortune, after Gamygdala:
+/// those who wish them well (`regard` above zero) are happy for them or pity
+/// them, those who don't resent their luck or gloat over their misfortune.
+///
+/// `regard` runs from -1 (enemies) to 1 (allies) and scales the intensity, as
+/// does how much it matters to them (`desirability`). Nothing is felt when
+/// either is zero.
+pub fn fortune_of_others(regard: f64, desirability: f64) -> Option<Emotion> {
+    let emotion_type = match (regard > 0.0, desirability > 0.0) {
+        _ if regard == 0.0 || desirability == 0.0 => return None,
+        (true, true) => EmotionType::HappyFor,
+        (true, false) => EmotionType::Pity,
+        (false, true) => EmotionType::Resentment,
+        (false, false) => EmotionType::Gloating,
+    };
+    Some(Emotion {
+        emotion_type,
+        intensity: (regard * desirability).abs(),
+    })
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Belief {
     pub likelihood: f64,
     pub causal_agent_name: Option<String>,
     pub affected_goal_names: Vec<String>,
     pub goal_congruences: Vec<f64>,
     pub is_incremental: bool,
+    /// Whether the believer brought this about themselves. Self-caused beliefs
+    /// are appraised against their own standards (Gratification or Remorse)
+    /// instead of toward `causal_agent_name`
+    #[serde(default)]
+    pub self_caused: bool,
+    /// How well the act measures up to the believer's standards, in [-1, 1];
+    /// `None` if it doesn't bear on them. Only self-caused beliefs use it
+    #[serde(default)]
+    pub praiseworthiness: Option<f64>,
+}
+
+impl Belief {
+    /// A belief, refused if it is malformed; see [`Belief::validate`].
+    pub fn new(
+        likelihood: f64,
+        causal_agent_name: Option<String>,
+        affected_goal_names: Vec<String>,
+        goal_congruences: Vec<f64>,
+        is_incremental: bool,
+    ) -> Result<Self, BeliefError> {
+        let belief = Self {
+            likelihood,
+            causal_agent_name,
+            affected_goal_names,
+            goal_congruences,
+            is_incremental,
+            self_caused: false,
+            praiseworthiness: None,
+        };
+        belief.validate()?;
+        Ok(belief)
+    }
+
+    /// The same belief, but brought about by the believer themselves and
+    /// judged `praiseworthiness` against their standards.
+    pub fn caused_by_self(self, praiseworthiness: Option<f64>) -> Result<Self, BeliefError> {
+        let belief = Self {
+            self_caused: true,
+            praiseworthiness,
+            ..self
+        };
+        belief.validate()?;
+        Ok(belief)
+    }
+
+    /// Whether this belief can be appraised: at least one affected goal, one
+    /// congruence per affected goal, each in [-1, 1], a likelihood in [0, 1],
+    /// and a praiseworthiness (if any) in [-1, 1]. The fields are public, so a
+    /// literal can still be malformed; [`EmotionalState::appraise`] ignores those.
+    pub fn validate(&self) -> Result<(), BeliefError> {
+        if self.affected_goal_names.len() != self.goal_congruences.len() {
+            return Err(BeliefError::LengthMismatch {
+                names: self.affected_goal_names.len(),
+                congruences: self.goal_congruences.len(),
+            });
+        }
+        if self.affected_goal_names.is_empty() {
+            return Err(BeliefError::NoGoals);
+        }
+        if !(0.0..=1.0).contains(&self.likelihood) {
+            return Err(BeliefError::LikelihoodOutOfRange(self.likelihood));
+        }
+        for (goal, &congruence) in self.affected_goal_names.iter().zip(&self.goal_congruences) {
+            if !(-1.0..=1.0).contains(&congruence) {
+                return Err(BeliefError::CongruenceOutOfRange {
+                    goal: goal.clone(),
+                    congruence,
+                });
+            }
+        }
+        if let Some(praiseworthiness) = self.praiseworthiness.filter(|p| !(-1.0..=1.0).contains(p)) {
+            return Err(BeliefError::PraiseworthinessOutOfRange(praiseworthiness));
+        }
+        Ok(())
+    }
+
+    /// Starts a belief that is certain, not incremental, and nobody's doing,
+    /// about no goals yet; see [`BeliefBuilder`].
+    pub fn builder() -> BeliefBuilder {
+        BeliefBuilder(Belief {
+            likelihood: 1.0,
+            causal_agent_name: None,
+            affected_goal_names: Vec::new(),
+            goal_congruences: Vec::new(),
+            is_incremental: false,
+            self_caused: false,
+            praiseworthiness: None,
+        })
+    }
+}
+
+/// Builds a [`Belief`] one goal at a time, so every goal comes with its
+/// congruence:
+///
+/// ```
+/// # use propyarn::emotional_system::Belief;
+/// let risk = Belief::builder()
+///     .likelihood(0.2)
+///     .caused_by("Khelis Tev")
+///     .affects("Protect Crew", -0.1)
+///     .incremental()
+///     .build()?;
+/// assert_eq!(risk.goal_congruences, vec![-0.1]);
+/// # Ok::<(), propyarn::emotional_system::BeliefError>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct BeliefBuilder(Belief);
+
+impl BeliefBuilder {
+    /// How likely the believer thinks it is, in [0, 1].
+    pub fn likelihood(mut self, likelihood: f64) -> Self {
+        self.0.likelihood = likelihood;
+        self
+    }
+
+    /// Someone else brought it about.
+    pub fn caused_by(mut self, agent: impl Into<String>) -> Self {
+        self.0.causal_agent_name = Some(agent.into());
+        self
+    }
+
+    /// The believer brought it about, and judges it `praiseworthiness`
+    /// against their standards; see [`Belief::caused_by_self`].
+    pub fn caused_by_self(mut self, praiseworthiness: Option<f64>) -> Self {
+        self.0.self_caused = true;
+        self.0.praiseworthiness = praiseworthiness;
+        self
+    }
+
+    /// It bears on `goal`, for better (`congruence` above zero) or worse.
+    pub fn affects(mut self, goal: impl Into<String>, congruence: f64) -> Self {
+        self.0.affected_goal_names.push(goal.into());
+        self.0.goal_congruences.push(congruence);
+        self
+    }
+
+    /// It moves the goals by its likelihood rather than setting them.
+    pub fn incremental(mut self) -> Self {
+        self.0.is_incremental = true;
+        self
+    }
+
+    /// The belief, refused if it is malformed; see [`Belief::validate`].
+    pub fn build(self) -> Result<Belief, BeliefError> {
+        self.0.validate()?;
+        Ok(self.0)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::generators::{belief_strategy, goal_strategy, GENERATED_GOAL_NAMES};
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn test_likelihood_stays_in_range(
+            goal in goal_strategy(),
+            beliefs in prop::collection::vec(belief_strategy(vec!["Survive".to_string()]), 1..20),
+        ) {
+            let mut state = EmotionalState::new();
+            state.add_goal(Goal { name: "Survive".to_string(), ..goal });
+            for belief in &beliefs {
+                state.appraise(belief);
+                let likelihood = state.goals["Survive"].likelihood;
+                prop_assert!((0.0..=1.0).contains(&likelihood), "likelihood {} after {:?}", likelihood, belief);
+            }
+        }
+
+        #[test]
+        fn test_appraise_survives_any_belief(
+            goals in prop::collection::vec(goal_strategy(), 0..4),
+            names in prop::collection::vec(prop::sample::select(GENERATED_GOAL_NAMES.to_vec()), 0..5),
+            congruences in prop::collection::vec(-2.0f64..2.0, 0..5),
+            likelihood in -0.5f64..1.5,
+            is_incremental in any::<bool>(),
+        ) {
+            let mut state = EmotionalState::new();
+            for goal in goals {
+                state.add_goal(goal);
+            }
+            let names: Vec<String> = names.into_iter().map(String::from).collect();
+            let belief = Belief {
+                likelihood,
+                causal_agent_name: Some("Dr. Theo Lux".to_string()),
+                affected_goal_names: names.clone(),
+                goal_congruences: congruences.clone(),
+                is_incremental,
+                self_caused: false,
+                praiseworthiness: None,
+            };
+            let agent = belief.causal_agent_name.clone();
+            let constructed = Belief::new(likelihood, agent, names, congruences, is_incremental);
+            prop_assert_eq!(constructed.clone().err(), belief.validate().err());
+
+            // Whatever comes in over the wire is appraised or refused whole, never half
+            let belief: Belief = serde_json::from_str(&serde_json::to_string(&belief).unwrap()).unwrap();
+            let before = state.clone();
+            let felt = state.appraise(&belief);
+            if constructed.is_err() {
+                prop_assert!(felt.is_empty());
+                prop_assert_eq!(&state, &before);
+                prop_assert!(state.try_appraise(&belief).is_err());
+            }
+            prop_assert!(state.goals.values().all(|g| (0.0..=1.0).contains(&g.likelihood)));
+        }
+
+        #[test]
+        fn test_irrelevant_beliefs_change_nothing(
+            goal in goal_strategy(),
+            likelihood in 0.0f64..=1.0,
+            is_incremental in any::<bool>(),
+        ) {
+            let mut state = EmotionalState::new();
+            let before = goal.likelihood;
+            let name = goal.name.clone();
+            state.add_goal(goal);
+            let felt = state.appraise(&Belief {
+                likelihood,
+                causal_agent_name: None,
+                affected_goal_names: vec![name.clone()],
+                goal_congruences: vec![0.0],
+                is_incremental,
+                self_caused: false,
+                praiseworthiness: None,
+            });
+            prop_assert_eq!(state.goals[&name].likelihood, before);
+            prop_assert!(felt.is_empty());
+            prop_assert!(state.emotions.is_empty());
+        }
+    }
 
     #[test]
     fn test_appraisal_joy() {
@@ -260,11 +1226,14 @@ mod tests {
             affected_goal_names: vec!["Test Goal".to_string()],
             goal_congruences: vec![1.0],
             is_incremental: false,
+            self_caused: false,
+            praiseworthiness: None,
         };
         
-        state.appraise(&belief);
+        let felt = state.appraise(&belief);
         
-        assert!(state.emotions.iter().any(|e| e.emotion_type == EmotionType::Joy));
+        assert!(felt.iter().any(|e| e.emotion_type == EmotionType::Joy));
+        assert_eq!(felt, state.emotions);
         let pad = state.get_pad();
         assert!(pad[0] > 0.0); // Pleasure should be positive
     }
@@ -281,11 +1250,15 @@ mod tests {
             affected_goal_names: vec!["Test Goal".to_string()],
             goal_congruences: vec![1.0],
             is_incremental: true,
+            self_caused: false,
+            praiseworthiness: None,
         };
         
-        state.appraise(&belief);
+        let felt = state.appraise(&belief);
         
-        assert!(state.emotions.iter().any(|e| e.emotion_type == EmotionType::Hope));
+        assert_eq!(felt.len(), 1);
+        assert_eq!(felt[0].emotion_type, EmotionType::Hope);
+        assert!((felt[0].intensity - 0.1).abs() < 1e-12);
         
         // Event that decreases likelihood
         let belief2 = Belief {
@@ -294,9 +1267,616 @@ mod tests {
             affected_goal_names: vec!["Test Goal".to_string()],
             goal_congruences: vec![-1.0],
             is_incremental: true,
+            self_caused: false,
+            praiseworthiness: None,
         };
         
-        state.appraise(&belief2);
-        assert!(state.emotions.iter().any(|e| e.emotion_type == EmotionType::Fear));
+        // Only the Fear is new; the Hope from before is still there, but wasn't just felt
+        let felt = state.appraise(&belief2);
+        assert_eq!(felt.iter().map(|e| &e.emotion_type).collect::<Vec<_>>(), [&EmotionType::Fear]);
+        assert!(state.emotions.iter().any(|e| e.emotion_type == EmotionType::Hope));
+    }
+
+    #[test]
+    fn test_maintenance_goals_fear_more() {
+        let intensity_of = |felt: &Vec<Emotion>, emotion_type: EmotionType| {
+            felt.iter()
+                .find(|e| e.emotion_type == emotion_type)
+                .map(|e| e.intensity)
+                .unwrap_or(0.0)
+        };
+        let threatened = |is_maintenance: bool, belief: &Belief| {
+            let mut state = EmotionalState::new();
+            state.add_goal(Goal::new("Maintain Coherence".to_string(), 0.9, is_maintenance));
+            state.appraise(belief)
+        };
+
+        // Coherence slips a little
+        let slipping = Belief {
+            likelihood: 0.2,
+            causal_agent_name: None,
+            affected_goal_names: vec!["Maintain Coherence".to_string()],
+            goal_congruences: vec![-1.0],
+            is_incremental: true,
+            self_caused: false,
+            praiseworthiness: None,
+        };
+        let (maintained, achieved) = (threatened(true, &slipping), threatened(false, &slipping));
+        assert!(intensity_of(&achieved, EmotionType::Fear) > 0.0);
+        assert!(
+            intensity_of(&maintained, EmotionType::Fear)
+                > intensity_of(&achieved, EmotionType::Fear)
+        );
+
+        // Coherence is lost outright: an achievement goal is simply over, but a
+        // maintenance goal keeps its owner afraid
+        let lost = Belief {
+            likelihood: 1.0,
+            goal_congruences: vec![-1.0],
+            is_incremental: false,
+            self_caused: false,
+            praiseworthiness: None,
+            ..slipping
+        };
+        let (maintained, achieved) = (threatened(true, &lost), threatened(false, &lost));
+        assert!(intensity_of(&achieved, EmotionType::Distress) > 0.0);
+        assert_eq!(intensity_of(&achieved, EmotionType::Fear), 0.0);
+        assert!(intensity_of(&maintained, EmotionType::Distress) > 0.0);
+        assert!(intensity_of(&maintained, EmotionType::Fear) > 0.0);
+    }
+
+    #[test]
+    fn test_conflicting_goals() {
+        let mut state = EmotionalState::new();
+        let mut return_to_past = Goal::new("Return to Past".to_string(), 1.0, false);
+        return_to_past.concerns = Some("Kor-Valeth's era".to_string());
+        let mut protect_present = Goal::new("Protect Present".to_string(), -1.0, true);
+        protect_present.concerns = Some("Kor-Valeth's era".to_string());
+        // Same sign, same concern: no tension
+        let mut honor_ancestors = Goal::new("Honor Ancestors".to_string(), 0.5, true);
+        honor_ancestors.concerns = Some("Kor-Valeth's era".to_string());
+        // Opposite sign but untagged: nothing to compare against
+        state.add_goal(Goal::new("Survive".to_string(), -0.8, true));
+        state.add_goal(return_to_past);
+        state.add_goal(protect_present);
+        state.add_goal(honor_ancestors);
+
+        assert_eq!(
+            state.conflicting_goals(),
+            vec![
+                ("Honor Ancestors".to_string(), "Protect Present".to_string()),
+                ("Protect Present".to_string(), "Return to Past".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_pad_table_is_valid() {
+        assert_eq!(validate_pad_table(), Ok(()));
+
+        // all() lists each emotion once
+        let names: std::collections::HashSet<&str> = EmotionType::all().iter().map(EmotionType::as_str).collect();
+        assert_eq!(names.len(), EmotionType::all().len());
+        assert_eq!(EmotionType::all().len(), 16);
+    }
+
+    #[test]
+    fn test_agents_are_blamed_and_thanked() {
+        let mut state = EmotionalState::new();
+        state.add_goal(Goal::new("Protect Crew".to_string(), 1.0, true));
+        let caused_by = |agent: &str, congruence: f64| Belief {
+            likelihood: 0.3,
+            causal_agent_name: Some(agent.to_string()),
+            affected_goal_names: vec!["Protect Crew".to_string()],
+            goal_congruences: vec![congruence],
+            is_incremental: true,
+            self_caused: false,
+            praiseworthiness: None,
+        };
+
+        let blamed = state.appraise(&caused_by("Riven Blackwood", -1.0));
+        state.appraise(&caused_by("Dr. Saros", 1.0));
+        let toward = |state: &EmotionalState, agent: &str| {
+            state.emotion_toward(agent).iter().map(|e| e.emotion_type.clone()).collect::<Vec<_>>()
+        };
+        assert_eq!(toward(&state, "Riven Blackwood"), vec![EmotionType::Anger]);
+        assert_eq!(toward(&state, "Dr. Saros"), vec![EmotionType::Gratitude]);
+        assert!(state.emotion_toward("Nameless").is_empty());
+        // Felt, not just remembered
+        assert!(blamed.iter().any(|e| e.emotion_type == EmotionType::Anger));
+        assert!(state.emotions.iter().any(|e| e.emotion_type == EmotionType::Anger));
+
+        // Nobody to blame, nobody to resent
+        let mut state = EmotionalState::new();
+        state.add_goal(Goal::new("Protect Crew".to_string(), 1.0, true));
+        state.appraise(&Belief { causal_agent_name: None, ..caused_by("", -1.0) });
+        assert!(state.relations.is_empty());
+
+        let mut state = EmotionalState::new();
+        state.add_goal(Goal::new("Protect Crew".to_string(), 1.0, true));
+        state.appraise(&caused_by("Riven Blackwood", -1.0));
+        state.decay(1000);
+        assert!(state.relations.is_empty());
+    }
+
+    #[test]
+    fn test_goals_resolve() {
+        let types = |emotions: Vec<Emotion>| emotions.into_iter().map(|e| e.emotion_type).collect::<Vec<_>>();
+        let mut state = EmotionalState::new();
+        state.add_goal(Goal { likelihood: 0.9, ..Goal::new("Return to Past".to_string(), 1.0, false) });
+        state.add_goal(Goal::new("Protect Crew".to_string(), 1.0, true));
+        state.add_goal(Goal::new("Gate Cult Wins".to_string(), -0.8, false));
+        state.add_goal(Goal::new("Find Origin".to_string(), 0.8, false));
+
+        // Expected, so Satisfaction as well as Joy; then it's done with
+        let felt = state.complete_goal("Return to Past").unwrap();
+        assert_eq!(types(felt), vec![EmotionType::Satisfaction, EmotionType::Joy]);
+        assert!(!state.goals.contains_key("Return to Past"));
+        // A crew is never done being protected
+        state.complete_goal("Protect Crew").unwrap();
+        assert_eq!(state.goals["Protect Crew"].likelihood, 1.0);
+
+        let felt = state.abandon_goal("Gate Cult Wins").unwrap();
+        assert_eq!(types(felt.clone()), vec![EmotionType::Relief]);
+        assert!((felt[0].intensity - 0.4).abs() < 1e-9);
+        assert_eq!(types(state.abandon_goal("Find Origin").unwrap()), vec![EmotionType::Disappointment]);
+        assert_eq!(state.goals.len(), 1);
+
+        let unknown = Err(EmotionError::UnknownGoal("Find Origin".to_string()));
+        assert_eq!(state.abandon_goal("Find Origin"), unknown);
+        assert_eq!(state.complete_goal("Find Origin"), unknown);
+    }
+
+    #[test]
+    fn test_dominant_emotion() {
+        let felt = |emotion_type: EmotionType, intensity: f64| Emotion { emotion_type, intensity };
+        let types = |emotions: Vec<&Emotion>| emotions.iter().map(|e| e.emotion_type.clone()).collect::<Vec<_>>();
+
+        let mut state = EmotionalState::new();
+        assert_eq!(state.dominant_emotion(), None);
+        assert!(state.strongest_n(3).is_empty());
+        assert_eq!(state.net_valence(), 0.0);
+        assert_eq!(state.feels(&EmotionType::Fear), 0.0);
+
+        state.update_emotional_state(felt(EmotionType::Fear, 0.5));
+        state.update_emotional_state(felt(EmotionType::Hope, 0.8));
+        state.update_emotional_state(felt(EmotionType::Anger, 0.8));
+        // A tie goes to whichever was felt first
+        assert_eq!(state.dominant_emotion().unwrap().emotion_type, EmotionType::Hope);
+        assert_eq!(types(state.strongest_n(2)), vec![EmotionType::Hope, EmotionType::Anger]);
+        assert_eq!(types(state.strongest_n(10)).len(), 3);
+        assert!((state.net_valence() - -0.5).abs() < 1e-9);
+        assert_eq!(state.feels(&EmotionType::Anger), 0.8);
+
+        // More of the same breaks the tie
+        state.update_emotional_state(felt(EmotionType::Anger, 0.1));
+        assert_eq!(state.dominant_emotion().unwrap().emotion_type, EmotionType::Anger);
+    }
+
+    #[test]
+    fn test_decay_modes() {
+        let felt = |emotion_type: EmotionType, intensity: f64| Emotion { emotion_type, intensity };
+        let intensity_of = |state: &EmotionalState, emotion_type: EmotionType| {
+            state.emotions.iter().find(|e| e.emotion_type == emotion_type).map(|e| e.intensity)
+        };
+
+        // Linear decay reaches zero exactly, and stops there
+        let linear = DecayConfig {
+            mode: DecayMode::Linear(0.25),
+            per_emotion_overrides: HashMap::new(),
+        };
+        assert_eq!(linear.fade(&EmotionType::Fear, 0.5, 1), 0.25);
+        assert_eq!(linear.fade(&EmotionType::Fear, 0.5, 2), 0.0);
+        assert_eq!(linear.fade(&EmotionType::Fear, 0.5, 7), 0.0);
+        let mut state = EmotionalState::new();
+        state.decay = linear;
+        state.update_emotional_state(felt(EmotionType::Fear, 0.5));
+        state.decay(2);
+        assert!(state.emotions.is_empty());
+
+        // Exponential decay never goes below zero, even with a nonsense factor
+        for factor in [0.9, 0.0, -0.5] {
+            let exponential = DecayConfig {
+                mode: DecayMode::Exponential(factor),
+                per_emotion_overrides: HashMap::new(),
+            };
+            for ticks in 0..5 {
+                assert!(exponential.fade(&EmotionType::Joy, 1.0, ticks) >= 0.0);
+            }
+        }
+
+        // Nor grows back after more ticks than an i32 holds
+        let exponential = DecayConfig {
+            mode: DecayMode::Exponential(0.9),
+            per_emotion_overrides: HashMap::new(),
+        };
+        assert_eq!(exponential.fade(&EmotionType::Joy, 0.5, u32::MAX), 0.0);
+
+        // Fear lingers while Relief evaporates
+        let mut state = EmotionalState::new();
+        state.decay = DecayConfig::default()
+            .with_override(EmotionType::Fear, 0.99)
+            .with_override(EmotionType::Relief, 0.05);
+        for emotion in [EmotionType::Fear, EmotionType::Relief, EmotionType::Joy] {
+            state.update_emotional_state(felt(emotion, 1.0));
+        }
+        state.decay(3);
+        assert!((intensity_of(&state, EmotionType::Fear).unwrap() - 0.99f64.powi(3)).abs() < 1e-12);
+        assert!((intensity_of(&state, EmotionType::Joy).unwrap() - 0.9f64.powi(3)).abs() < 1e-12);
+        assert_eq!(intensity_of(&state, EmotionType::Relief), None);
+    }
+
+    #[test]
+    fn test_fortune_of_others() {
+        let kind = |regard: f64, desirability: f64| fortune_of_others(regard, desirability).map(|e| e.emotion_type);
+        assert_eq!(kind(1.0, 0.5), Some(EmotionType::HappyFor));
+        assert_eq!(kind(0.5, -0.5), Some(EmotionType::Pity));
+        assert_eq!(kind(-1.0, 0.5), Some(EmotionType::Resentment));
+        assert_eq!(kind(-0.5, -0.5), Some(EmotionType::Gloating));
+        assert_eq!(kind(0.0, 0.5), None);
+        assert_eq!(kind(1.0, 0.0), None);
+        // Allies feel it twice as keenly as friends
+        assert_eq!(fortune_of_others(1.0, -0.4).unwrap().intensity, 0.4);
+        assert_eq!(fortune_of_others(0.5, -0.4).unwrap().intensity, 0.2);
+    }
+
+    #[test]
+    fn test_mood_outlasts_emotions() {
+        let mut state = EmotionalState::new();
+        assert_eq!(state.set_mood_inertia(1.5), Err(EmotionError::InertiaOutOfRange(1.5)));
+        assert_eq!(state.mood_inertia, DEFAULT_MOOD_INERTIA);
+        state.set_mood_inertia(0.5).unwrap();
+        state.add_goal(Goal::new("Survive".to_string(), 1.0, false));
+
+        let threat = Belief::new(0.8, None, vec!["Survive".to_string()], vec![-1.0], true).unwrap();
+        state.appraise(&threat);
+        let pad = state.get_pad();
+        assert!(pad[0] < 0.0);
+        assert_eq!(state.get_mood(), pad.map(|v| 0.5 * v));
+        assert_eq!(state.get_affect(), pad.map(|v| (0.5 * v + v) / 2.0));
+
+        // The fear fades; half the mood it left behind doesn't
+        state.decay(1000);
+        assert!(state.emotions.is_empty());
+        assert_eq!(state.get_mood(), pad.map(|v| 0.25 * v));
+        assert_eq!(state.get_affect(), pad.map(|v| 0.125 * v));
+    }
+
+    #[test]
+    fn test_malformed_beliefs_are_refused() {
+        let survive = || vec!["Survive".to_string()];
+        assert!(Belief::new(0.5, None, survive(), vec![-0.5], false).is_ok());
+        let three_goals = ["Survive", "Find Origin", "Return to Past"].map(String::from).to_vec();
+        assert_eq!(
+            Belief::new(0.5, None, three_goals, vec![0.1, 0.2], false),
+            Err(BeliefError::LengthMismatch { names: 3, congruences: 2 })
+        );
+        assert_eq!(
+            Belief::new(1.5, None, survive(), vec![0.5], false),
+            Err(BeliefError::LikelihoodOutOfRange(1.5))
+        );
+        assert!(matches!(
+            Belief::new(f64::NAN, None, survive(), vec![0.5], false),
+            Err(BeliefError::LikelihoodOutOfRange(_))
+        ));
+        assert_eq!(
+            Belief::new(0.5, None, survive(), vec![-2.0], false),
+            Err(BeliefError::CongruenceOutOfRange { goal: "Survive".to_string(), congruence: -2.0 })
+        );
+
+        // A literal slips past the constructor, and is ignored rather than panicking
+        let mut state = EmotionalState::new();
+        state.add_goal(Goal::new("Survive".to_string(), 1.0, false));
+        let before = state.clone();
+        state.appraise(&Belief {
+            likelihood: 1.0,
+            causal_agent_name: Some("Riven".to_string()),
+            affected_goal_names: vec!["Survive".to_string(), "Find Origin".to_string()],
+            goal_congruences: vec![-1.0],
+            is_incremental: false,
+            self_caused: false,
+            praiseworthiness: None,
+        });
+        assert_eq!(state, before);
+    }
+
+    #[test]
+    fn test_try_appraise_says_what_is_wrong() {
+        let mut state = EmotionalState::new();
+        state.add_goal(Goal::new("Survive".to_string(), 1.0, false));
+        state.add_goal(Goal::new("Find Origin".to_string(), 0.6, false));
+        let before = state.clone();
+        let belief = |names: &[&str], congruences: Vec<f64>| Belief {
+            likelihood: 1.0,
+            causal_agent_name: None,
+            affected_goal_names: names.iter().map(|n| n.to_string()).collect(),
+            goal_congruences: congruences,
+            is_incremental: false,
+            self_caused: false,
+            praiseworthiness: None,
+        };
+
+        // More congruences than goals, and fewer
+        let extra = belief(&["Survive"], vec![-0.5, 0.5]);
+        assert_eq!(state.try_appraise(&extra), Err(BeliefError::LengthMismatch { names: 1, congruences: 2 }));
+        let short = belief(&["Survive", "Find Origin"], vec![]);
+        let error = state.try_appraise(&short).unwrap_err();
+        assert_eq!(error.to_string(), "belief names 2 goals but gives 0 congruences");
+
+        // A congruence past certainty would push a non-incremental likelihood outside [0, 1]
+        let overstated = belief(&["Find Origin", "Survive"], vec![0.5, 3.0]);
+        let error = state.try_appraise(&overstated).unwrap_err();
+        assert_eq!(error, BeliefError::CongruenceOutOfRange { goal: "Survive".to_string(), congruence: 3.0 });
+        assert_eq!(error.to_string(), "congruence 3 for \"Survive\" is outside [-1, 1]");
+        assert!(state.try_appraise(&belief(&["Survive"], vec![f64::NAN])).is_err());
+        assert_eq!(state, before);
+
+        // The edges of the range are fine
+        let felt = state.try_appraise(&belief(&["Survive", "Find Origin"], vec![-1.0, 1.0])).unwrap();
+        assert!(!felt.is_empty());
+        assert_eq!(state.goals["Survive"].likelihood, 0.0);
+        assert_eq!(state.goals["Find Origin"].likelihood, 1.0);
+    }
+
+    #[test]
+    fn test_gain_shapes_the_same_appraisal() {
+        let belief = Belief {
+            likelihood: 1.0,
+            causal_agent_name: None,
+            affected_goal_names: vec!["Keep the Crew Alive".to_string()],
+            goal_congruences: vec![-0.6],
+            is_incremental: false,
+            self_caused: false,
+            praiseworthiness: None,
+        };
+        let pad_at = |gain: f64| {
+            let mut state = EmotionalState::new();
+            state.set_gain(gain).unwrap();
+            state.add_goal(Goal::new("Keep the Crew Alive".to_string(), 1.0, false));
+            state.appraise(&belief);
+            state.get_pad()
+        };
+
+        let (calm, reactive) = (pad_at(0.5), pad_at(10.0));
+        for axis in 0..3 {
+            assert!(reactive[axis].abs() > calm[axis].abs() + 0.1, "{:?} vs {:?}", calm, reactive);
+            assert_eq!(reactive[axis].signum(), calm[axis].signum());
+        }
+
+        let mut state = EmotionalState::new();
+        for bad in [0.0, -1.0, 20.5, f64::NAN] {
+            assert!(matches!(state.set_gain(bad), Err(EmotionError::GainOutOfRange(_))));
+        }
+        assert_eq!(state.gain, 1.0);
+        assert_eq!(EmotionalState::default().gain, 1.0);
+    }
+
+    #[test]
+    fn test_pad_contributions_sum_to_pad() {
+        let mut state = EmotionalState::new();
+        state.gain = 2.0;
+        for (emotion_type, intensity) in [
+            (EmotionType::Joy, 0.8),
+            (EmotionType::Fear, 1.5),
+            (EmotionType::Anger, 0.3),
+        ] {
+            state.update_emotional_state(Emotion { emotion_type, intensity });
+        }
+
+        let contributions = state.pad_contributions();
+        assert_eq!(contributions.len(), 3);
+        assert_eq!(contributions[1].0, EmotionType::Fear);
+
+        let pad = state.get_pad();
+        for axis in 0..3 {
+            let total: f64 = contributions.iter().map(|(_, c)| c[axis]).sum();
+            assert!((EmotionalState::squash(state.gain, total) - pad[axis]).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_emotions_are_put_down_to_their_goals() {
+        // The Conductor's deal gets Khelis their identity back, at the price of
+        // the memory they were going to sell
+        let mut state = EmotionalState::new();
+        state.add_goal(Goal::new("Find Origin".to_string(), 0.8, false));
+        state.add_goal(Goal::new("Repay the Debt".to_string(), 1.0, false));
+        let felt = state.appraise(&Belief {
+            likelihood: 0.3,
+            causal_agent_name: None,
+            affected_goal_names: vec!["Find Origin".to_string(), "Repay the Debt".to_string()],
+            goal_congruences: vec![1.0, -1.0],
+            is_incremental: true,
+            self_caused: false,
+            praiseworthiness: None,
+        });
+        assert_eq!(felt.len(), 2);
+
+        let types = |goal: &str| {
+            state.emotions_for_goal(goal).iter().map(|e| e.emotion_type.clone()).collect::<Vec<_>>()
+        };
+        assert_eq!(types("Find Origin"), vec![EmotionType::Hope]);
+        assert_eq!(types("Repay the Debt"), vec![EmotionType::Fear]);
+        assert!(state.emotions_for_goal("Survive").is_empty());
+        assert!((state.emotions_for_goal("Find Origin")[0].intensity - 0.8 * 0.3).abs() < 1e-12);
+
+        // Attribution fades with the emotions it accounts for
+        state.decay(1000);
+        assert!(state.emotions_for_goal("Find Origin").is_empty());
+        assert!(state.goal_emotions.is_empty());
+    }
+
+    #[test]
+    fn test_own_doing_is_judged_by_own_standards() {
+        // Jax talks their way past a checkpoint: it helped, but they lied to do it
+        let helped = |praiseworthiness| {
+            let mut state = EmotionalState::new();
+            state.add_goal(Goal::new("Reach the Spoke".to_string(), 1.0, false));
+            let belief = Belief::new(1.0, Some("Jax".to_string()), vec!["Reach the Spoke".to_string()], vec![0.4], true)
+                .and_then(|b| b.caused_by_self(praiseworthiness))
+                .unwrap();
+            let felt = state.appraise(&belief);
+            (felt, state)
+        };
+
+        let (felt, state) = helped(Some(0.5));
+        assert!(felt.iter().any(|e| e.emotion_type == EmotionType::Gratification && (e.intensity - 0.2).abs() < 1e-12));
+        // Self-caused, so nobody is owed gratitude; not even themselves
+        assert!(state.relations.is_empty());
+        let attributed = state.emotions_for_goal("Reach the Spoke");
+        assert!(attributed.iter().any(|e| e.emotion_type == EmotionType::Gratification));
+
+        let (felt, _) = helped(Some(-0.5));
+        assert!(felt.iter().any(|e| e.emotion_type == EmotionType::Remorse));
+        assert!(felt.iter().all(|e| e.emotion_type != EmotionType::Gratification));
+
+        // No standard at stake, no credit taken
+        let (felt, _) = helped(None);
+        assert!(felt.iter().all(|e| !matches!(e.emotion_type, EmotionType::Gratification | EmotionType::Remorse)));
+
+        assert_eq!(
+            Belief::new(1.0, None, vec!["Reach the Spoke".to_string()], vec![0.5], true)
+                .and_then(|b| b.caused_by_self(Some(2.0))),
+            Err(BeliefError::PraiseworthinessOutOfRange(2.0))
+        );
+    }
+
+    #[test]
+    fn test_builders_check_what_they_build() {
+        let built = Belief::builder()
+            .likelihood(0.2)
+            .caused_by("Khelis Tev")
+            .affects("Protect Crew", -0.1)
+            .affects("Acquire Unique Memories", 0.5)
+            .incremental()
+            .build();
+        let by_hand = Belief::new(
+            0.2,
+            Some("Khelis Tev".to_string()),
+            vec!["Protect Crew".to_string(), "Acquire Unique Memories".to_string()],
+            vec![-0.1, 0.5],
+            true,
+        );
+        assert_eq!(built, by_hand);
+
+        assert_eq!(Belief::builder().likelihood(0.5).build(), Err(BeliefError::NoGoals));
+        assert_eq!(Belief::new(0.5, None, vec![], vec![], false), Err(BeliefError::NoGoals));
+        assert_eq!(
+            Belief::builder().likelihood(1.5).affects("Survive", 0.5).build(),
+            Err(BeliefError::LikelihoodOutOfRange(1.5))
+        );
+        assert!(matches!(
+            Belief::builder().affects("Survive", -2.0).build(),
+            Err(BeliefError::CongruenceOutOfRange { congruence, .. }) if congruence == -2.0
+        ));
+        assert_eq!(
+            Belief::builder().affects("Survive", 0.5).caused_by_self(Some(3.0)).build(),
+            Err(BeliefError::PraiseworthinessOutOfRange(3.0))
+        );
+
+        let goal = Goal::builder("Protect Crew").utility(0.9).likelihood(0.7).maintenance().concerns("crew").build();
+        assert_eq!(
+            goal,
+            Ok(Goal {
+                likelihood: 0.7,
+                concerns: Some("crew".to_string()),
+                ..Goal::new("Protect Crew".to_string(), 0.9, true)
+            })
+        );
+        assert_eq!(Goal::builder("Survive").utility(1.1).build(), Err(GoalError::UtilityOutOfRange(1.1)));
+        assert_eq!(Goal::builder("Survive").likelihood(-0.1).build(), Err(GoalError::LikelihoodOutOfRange(-0.1)));
+        assert!(Goal::builder("Survive").utility(f64::NAN).build().is_err());
+    }
+
+    #[test]
+    fn test_merge_and_split() {
+        // Yash-Tel, and a parallel self who has nearly given up on the Gate
+        let mut yash = EmotionalState::with_gain(2.0);
+        yash.add_goal(Goal::new("Find the Gate".to_string(), 1.0, false));
+        yash.update_emotional_state(Emotion {
+            emotion_type: EmotionType::Hope,
+            intensity: 0.6,
+        });
+        yash.feel_toward(
+            "Vera Kandros",
+            Emotion {
+                emotion_type: EmotionType::Gratitude,
+                intensity: 0.4,
+            },
+        );
+        let mut parallel = EmotionalState::with_gain(4.0);
+        let mut gate = Goal::new("Find the Gate".to_string(), 0.2, false);
+        gate.likelihood = 0.1;
+        parallel.add_goal(gate);
+        parallel.add_goal(Goal::new("Survive".to_string(), 0.8, true));
+        parallel.update_emotional_state(Emotion {
+            emotion_type: EmotionType::Hope,
+            intensity: 0.2,
+        });
+        parallel.update_emotional_state(Emotion {
+            emotion_type: EmotionType::Fear,
+            intensity: 0.5,
+        });
+
+        let absorbed = yash.merge(&parallel, (3.0, 1.0));
+        let close = |a: f64, b: f64| (a - b).abs() < 1e-12;
+        assert!(close(absorbed.feels(&EmotionType::Hope), 3.0 * 0.6 + 0.2));
+        assert!(close(absorbed.feels(&EmotionType::Fear), 0.5));
+        assert!(close(absorbed.gain, 2.5));
+        assert!(close(absorbed.goals["Find the Gate"].utility, 0.8));
+        assert!(close(absorbed.goals["Find the Gate"].likelihood, 0.75 * 0.5 + 0.25 * 0.1));
+        assert_eq!(absorbed.goals["Survive"], parallel.goals["Survive"]);
+        assert!(close(absorbed.emotion_toward("Vera Kandros")[0].intensity, 1.2));
+
+        // Splitting shares out the feelings, and merging the halves restores them
+        let (kept, given) = absorbed.split(0.25);
+        assert!(close(kept.feels(&EmotionType::Fear), 0.125));
+        assert!(close(given.feels(&EmotionType::Fear), 0.375));
+        assert_eq!((&kept.goals, kept.gain), (&absorbed.goals, absorbed.gain));
+        let whole = kept.merge(&given, (1.0, 1.0));
+        for emotion in &absorbed.emotions {
+            assert!(close(whole.feels(&emotion.emotion_type), emotion.intensity));
+        }
+        assert_eq!(whole.goals, absorbed.goals);
+
+        // Nothing is carried over with a weight of nothing
+        assert!(yash.merge(&parallel, (f64::NAN, -1.0)).emotions.is_empty());
+        assert!(yash.split(1.0).1.emotions.is_empty());
+    }
+
+    proptest! {
+        #[test]
+        fn test_merge_and_split_keep_pad_in_range(
+            gains in (0.01f64..=MAX_GAIN, 0.01f64..=MAX_GAIN),
+            goals in prop::collection::vec(goal_strategy(), 0..6),
+            beliefs in prop::collection::vec(
+                belief_strategy(GENERATED_GOAL_NAMES.iter().map(|g| g.to_string()).collect()),
+                0..20,
+            ),
+            weights in (-1.0f64..10.0, -1.0f64..10.0),
+            fraction in -0.5f64..1.5,
+        ) {
+            // Two states that have been through something, from the same goals and beliefs
+            let (mut first, mut second) = (EmotionalState::with_gain(gains.0), EmotionalState::with_gain(gains.1));
+            for (i, goal) in goals.into_iter().enumerate() {
+                let state = if i % 2 == 0 { &mut first } else { &mut second };
+                state.add_goal(goal);
+            }
+            for (i, belief) in beliefs.iter().enumerate() {
+                let state = if i % 2 == 0 { &mut first } else { &mut second };
+                state.appraise(belief);
+            }
+
+            let merged = first.merge(&second, weights);
+            let (kept, given) = first.split(fraction);
+            for state in [&merged, &kept, &given] {
+                prop_assert!(EmotionalState::check_gain(state.gain).is_ok());
+                for value in state.get_pad().into_iter().chain(state.get_mood()) {
+                    prop_assert!((-1.0..=1.0).contains(&value), "{:?}", state);
+                }
+                prop_assert!(state.goals.values().all(|g| (0.0..=1.0).contains(&g.likelihood)));
+                prop_assert!(state.emotions.iter().all(|e| e.intensity > 0.0));
+            }
+        }
     }
 }
@@ -21,8 +21,21 @@
 //! When a property fails, proptest **shrinks** the failing case to the minimal
 //! reproduction—just like TLA+ counterexamples, but much faster.
 
+use crate::emotional_system::{Belief, Goal};
 use crate::narrative_core::*;
+use crate::properties::{validate_all_properties, CharacterInvariant, PropertyViolation};
 use proptest::prelude::*;
+use proptest::sample::Index;
+use proptest::strategy::{Union, ValueTree};
+use proptest::test_runner::{Config, RngAlgorithm, TestRng, TestRunner};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::io;
+use std::ops::Range;
+use std::path::Path;
 
 /// Strategy for generating TimelineIds
 pub fn timeline_id_strategy() -> impl Strategy<Value = TimelineId> {
@@ -55,19 +68,153 @@ pub fn relationship_state_strategy() -> impl Strategy<Value = RelationshipState>
     ]
 }
 
-/// Strategy for generating character abilities
+/// Strategy for a web of relationships among characters `0..num_chars`.
+///
+/// Each unordered pair appears at most once, as `(lower id, higher id)`, and
+/// may be left out entirely, so the web is a random graph rather than always
+/// complete. Shrinking drops relationships, down to an empty web.
+pub fn relationship_web_strategy(
+    num_chars: usize,
+) -> impl Strategy<Value = Vec<(CharacterId, CharacterId, RelationshipState)>> {
+    let pairs: Vec<(CharacterId, CharacterId)> = (0..num_chars as u64)
+        .flat_map(|a| (a + 1..num_chars as u64).map(move |b| (CharacterId(a), CharacterId(b))))
+        .collect();
+    prop::collection::vec(prop::option::of(relationship_state_strategy()), pairs.len()).prop_map(
+        move |states| {
+            pairs
+                .iter()
+                .zip(states)
+                .filter_map(|(&(a, b), state)| state.map(|state| (a, b, state)))
+                .collect()
+        },
+    )
+}
+
+/// How a [`relationship_network_strategy_with`] network is organized.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NetworkClustering {
+    /// Every relationship is independent of the rest
+    Random,
+    /// Characters split into up to this many cliques. Everyone in a clique is
+    /// Allied with everyone else in it; relationships between cliques are random
+    AlliedCliques(usize),
+    /// Characters split into two camps, Hostile across the divide and no worse
+    /// than Neutral within it
+    HostileBipartition,
+}
+
+/// Strategy for a social graph among characters `0..num_characters`, each pair
+/// related with probability `density` (clamped to 0.0-1.0).
+///
+/// Edges are ordered `(lower id, higher id)` pairs, as in
+/// [`relationship_web_strategy`], so install them with
+/// [`install_relationship_network`] rather than reading them as directed.
+pub fn relationship_network_strategy(
+    num_characters: usize,
+    density: f64,
+) -> impl Strategy<Value = Vec<(CharacterId, CharacterId, RelationshipState)>> {
+    relationship_network_strategy_with(num_characters, density, NetworkClustering::Random)
+}
+
+/// [`relationship_network_strategy`] with clustering. Clique members are
+/// always related to each other regardless of `density`; a clique is complete.
+pub fn relationship_network_strategy_with(
+    num_characters: usize,
+    density: f64,
+    clustering: NetworkClustering,
+) -> impl Strategy<Value = Vec<(CharacterId, CharacterId, RelationshipState)>> {
+    let density = density.clamp(0.0, 1.0);
+    let groups = match clustering {
+        NetworkClustering::Random => 1,
+        NetworkClustering::AlliedCliques(cliques) => cliques.max(1),
+        NetworkClustering::HostileBipartition => 2,
+    };
+    let pairs: Vec<(CharacterId, CharacterId)> = (0..num_characters as u64)
+        .flat_map(|a| (a + 1..num_characters as u64).map(move |b| (CharacterId(a), CharacterId(b))))
+        .collect();
+    let agreeable = prop_oneof![
+        Just(RelationshipState::Neutral),
+        Just(RelationshipState::Friendly),
+        Just(RelationshipState::Allied),
+    ];
+    (
+        prop::collection::vec(0..groups, num_characters),
+        prop::collection::vec(
+            (prop::bool::weighted(density), relationship_state_strategy(), agreeable),
+            pairs.len(),
+        ),
+    )
+        .prop_map(move |(membership, draws)| {
+            pairs
+                .iter()
+                .zip(draws)
+                .filter_map(|(&(a, b), (related, state, agreeable))| {
+                    let together = membership[a.0 as usize] == membership[b.0 as usize];
+                    match clustering {
+                        NetworkClustering::Random => related.then_some(state),
+                        NetworkClustering::AlliedCliques(_) if together => Some(RelationshipState::Allied),
+                        NetworkClustering::AlliedCliques(_) => related.then_some(state),
+                        NetworkClustering::HostileBipartition if together => related.then_some(agreeable),
+                        NetworkClustering::HostileBipartition => related.then_some(RelationshipState::Hostile),
+                    }
+                    .map(|state| (a, b, state))
+                })
+                .collect()
+        })
+}
+
+/// Installs a generated network in `timeline`, one `ChangeRelationship` action
+/// (and so one RelationshipChange event) per edge. Returns each edge's outcome;
+/// edges naming characters who are missing, dead, or elsewhere are not applied.
+pub fn install_relationship_network(
+    multiverse: &mut Multiverse,
+    timeline: TimelineId,
+    edges: &[(CharacterId, CharacterId, RelationshipState)],
+) -> Vec<ActionOutcome> {
+    edges
+        .iter()
+        .map(|&(char1, char2, new_state)| {
+            apply_narrative_action(
+                multiverse,
+                &NarrativeAction::ChangeRelationship {
+                    char1,
+                    char2,
+                    new_state,
+                    timeline,
+                },
+            )
+        })
+        .collect()
+}
+
+/// Strategy for generating timeline perception scopes
+pub fn perception_scope_strategy() -> impl Strategy<Value = PerceptionScope> {
+    prop_oneof![
+        Just(PerceptionScope::SiblingBranches),
+        Just(PerceptionScope::AllTimelines),
+    ]
+}
+
+/// Strategy for generating character abilities, with randomized parameters
 pub fn ability_strategy() -> impl Strategy<Value = Ability> {
     prop_oneof![
-        Just(Ability::TimelinePerception),
-        Just(Ability::Precognition),
-        Just(Ability::MemoryImmunity),
-        Just(Ability::LoopMemory),
-        Just(Ability::CausalityHacking),
+        perception_scope_strategy().prop_map(|scope| Ability::TimelinePerception { scope }),
+        prop::option::of(1u32..50)
+            .prop_map(|horizon_events| Ability::Precognition { horizon_events }),
+        Just(Ability::memory_immunity()),
+        (0.0f32..=1.0).prop_map(Ability::loop_memory_with),
+        Just(Ability::causality_hacking()),
+        Just(Ability::ansible_link()),
     ]
 }
 
-/// Strategy for generating memory provenance
-pub fn memory_provenance_strategy() -> impl Strategy<Value = MemoryProvenance> {
+/// Compound provenances reference source memories among the first this-many
+/// MemoryIds, so a test can create them all up front and the references resolve.
+pub const PLAUSIBLE_MEMORY_IDS: u64 = 32;
+
+/// Strategy for generating provenances of memories made from scratch
+/// (everything but Compound). Forgeries name one of the [`KNOWN_FORGERS`].
+pub fn leaf_provenance_strategy() -> impl Strategy<Value = MemoryProvenance> {
     prop_oneof![
         character_id_strategy().prop_map(|id| MemoryProvenance::Witnessed { character: id }),
         (character_id_strategy(), "[a-z]{5,15}")
@@ -75,10 +222,76 @@ pub fn memory_provenance_strategy() -> impl Strategy<Value = MemoryProvenance> {
                 original_owner: id,
                 acquired_via: mechanism,
             }),
-        "[A-Z][a-z]{5,15}".prop_map(|forger| MemoryProvenance::Forged { forger }),
+        prop::sample::select(&KNOWN_FORGERS[..])
+            .prop_map(|forger| MemoryProvenance::Forged { forger: forger.to_string() }),
+    ]
+}
+
+/// Strategy for generating memory provenance, including Compound blends of
+/// 1–3 sources drawn from the first [`PLAUSIBLE_MEMORY_IDS`] ids
+pub fn memory_provenance_strategy() -> impl Strategy<Value = MemoryProvenance> {
+    prop_oneof![
+        3 => leaf_provenance_strategy(),
+        1 => prop::collection::vec((0..PLAUSIBLE_MEMORY_IDS).prop_map(MemoryId), 1..4)
+            .prop_map(|sources| MemoryProvenance::Compound { sources }),
     ]
 }
 
+/// ## Compound Memory Trees
+///
+/// A compound memory's sources can themselves be compounds, so the interesting
+/// shapes are trees. A `MemoryTree` is a recipe for one: build it with
+/// [`create_memory_tree`], which creates every source before the blend that
+/// references it.
+#[derive(Debug, Clone)]
+pub enum MemoryTree {
+    /// A memory made from scratch
+    Leaf(MemoryProvenance),
+    /// A blend of other memories
+    Blend(Vec<MemoryTree>),
+}
+
+impl MemoryTree {
+    /// Total number of memories the tree creates, blends included.
+    pub fn size(&self) -> usize {
+        match self {
+            MemoryTree::Leaf(_) => 1,
+            MemoryTree::Blend(sources) => 1 + sources.iter().map(MemoryTree::size).sum::<usize>(),
+        }
+    }
+}
+
+/// Strategy for generating compound memory trees, at most `depth` blends deep.
+pub fn memory_tree_strategy(depth: u32) -> impl Strategy<Value = MemoryTree> {
+    leaf_provenance_strategy()
+        .prop_map(MemoryTree::Leaf)
+        .prop_recursive(depth, 32, 3, |inner| {
+            prop::collection::vec(inner, 1..4).prop_map(MemoryTree::Blend)
+        })
+}
+
+/// Creates every memory in `tree`, sources first, all of `event` in `timeline`.
+/// Returns the root memory; nobody holds it yet.
+pub fn create_memory_tree(
+    multiverse: &mut Multiverse,
+    tree: &MemoryTree,
+    event: EventId,
+    timeline: TimelineId,
+) -> MemoryId {
+    match tree {
+        MemoryTree::Leaf(provenance) => multiverse.create_memory(event, timeline, provenance.clone()),
+        MemoryTree::Blend(sources) => {
+            let sources: Vec<MemoryId> = sources
+                .iter()
+                .map(|source| create_memory_tree(multiverse, source, event, timeline))
+                .collect();
+            multiverse
+                .blend_memories(&sources)
+                .expect("sources were just created")
+        }
+    }
+}
+
 /// Strategy for generating causality violations
 pub fn causality_violation_strategy() -> impl Strategy<Value = CausalityViolation> {
     prop_oneof![
@@ -89,9 +302,87 @@ pub fn causality_violation_strategy() -> impl Strategy<Value = CausalityViolatio
             mechanism
         }),
         "[A-Z][a-z]{5,20}".prop_map(|mechanism| CausalityViolation::Superposition { mechanism }),
+        ("[A-Z][a-z]{5,20}", "[A-Z][a-z]{5,20}").prop_map(|(mechanism, looped_item)| {
+            CausalityViolation::Bootstrap { mechanism, looped_item }
+        }),
     ]
 }
 
+/// Goal names the generators draw from. A small shared pool means generated
+/// beliefs actually hit generated goals instead of naming goals nobody holds.
+pub const GENERATED_GOAL_NAMES: [&str; 6] = [
+    "Survive",
+    "Return to Past",
+    "Protect Present",
+    "Find the Gate",
+    "Repay the Debt",
+    "Learn the Truth",
+];
+
+/// Strategy for generating emotional goals, named from [`GENERATED_GOAL_NAMES`]
+pub fn goal_strategy() -> impl Strategy<Value = Goal> {
+    (
+        prop::sample::select(GENERATED_GOAL_NAMES.to_vec()),
+        -1.0f64..=1.0,
+        0.0f64..=1.0,
+        any::<bool>(),
+        prop::option::of(prop::sample::select(vec!["the Ring", "the Gate", "the past"])),
+    )
+        .prop_map(|(name, utility, likelihood, is_maintenance, concerns)| Goal {
+            name: name.to_string(),
+            utility,
+            likelihood,
+            is_maintenance,
+            concerns: concerns.map(str::to_string),
+        })
+}
+
+/// Strategy for generating beliefs about some of `existing_goal_names`.
+///
+/// `affected_goal_names` and `goal_congruences` always have matching lengths,
+/// and name at least one goal. With no goal names to draw from, the belief
+/// affects no goals, which [`Belief::validate`] refuses.
+pub fn belief_strategy(existing_goal_names: Vec<String>) -> impl Strategy<Value = Belief> {
+    let max_affected = existing_goal_names.len();
+    let min_affected = max_affected.min(1);
+    (
+        prop::sample::subsequence(existing_goal_names, min_affected..=max_affected).prop_flat_map(|names| {
+            let len = names.len();
+            (Just(names), prop::collection::vec(-1.0f64..=1.0, len))
+        }),
+        0.0f64..=1.0,
+        prop::option::of("[A-Z][a-z]{3,10}"),
+        any::<bool>(),
+        prop::bool::weighted(0.2),
+        prop::option::of(-1.0f64..=1.0),
+    )
+        .prop_map(
+            |(
+                (affected_goal_names, goal_congruences),
+                likelihood,
+                causal_agent_name,
+                is_incremental,
+                self_caused,
+                praiseworthiness,
+            )| {
+                Belief {
+                    likelihood,
+                    causal_agent_name,
+                    affected_goal_names,
+                    goal_congruences,
+                    is_incremental,
+                    self_caused,
+                    praiseworthiness,
+                }
+            },
+        )
+}
+
+/// Beliefs about the generated goal pool
+fn generated_belief_strategy() -> impl Strategy<Value = Belief> {
+    belief_strategy(GENERATED_GOAL_NAMES.iter().map(|n| n.to_string()).collect())
+}
+
 /// Strategy for generating event effects
 pub fn event_effect_strategy(
     num_characters: usize,
@@ -128,11 +419,12 @@ pub fn event_effect_strategy(
             }
         }),
         // Memory transfer
-        (mem_range, prop::option::of(char_range.clone()), char_range)
-            .prop_map(|(mem_id, from, to)| EventEffect::MemoryTransfer {
+        (mem_range, prop::option::of(char_range.clone()), char_range, any::<bool>())
+            .prop_map(|(mem_id, from, to, exclusive)| EventEffect::MemoryTransfer {
                 memory: MemoryId(mem_id),
                 from: from.map(CharacterId),
                 to: CharacterId(to),
+                exclusive,
             }),
     ]
 }
@@ -145,7 +437,7 @@ pub fn event_effect_strategy(
 /// Each action is a valid narrative operation (create character, kill character,
 /// trade memory, etc.). By applying random sequences of actions and checking
 /// properties after each one, we explore the state space thoroughly.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum NarrativeAction {
     CreateCharacter {
         name: String,
@@ -179,6 +471,9 @@ pub enum NarrativeAction {
     },
     BranchTimeline {
         parent: TimelineId,
+        /// Characters in `parent` who cross into the new branch
+        #[serde(default)]
+        migrants: Vec<CharacterId>,
     },
     CreateWitnessedMemory {
         event: EventId,
@@ -192,279 +487,2619 @@ pub enum NarrativeAction {
     GrantAbility {
         character: CharacterId,
         ability: Ability,
+        mechanism: String,
+    },
+    TriggerAppraisal {
+        character: CharacterId,
+        belief: Belief,
     },
+    AddCharacterGoal {
+        character: CharacterId,
+        goal: Goal,
+    },
+    ShareKnowledge {
+        from: CharacterId,
+        to: CharacterId,
+        flag: String,
+    },
+    DegradeMemories {
+        character: CharacterId,
+        /// Fraction of fidelity lost, 0.0-1.0
+        amount: f32,
+    },
+    WipeMemories {
+        character: CharacterId,
+    },
+    LoseAbility {
+        character: CharacterId,
+        ability: AbilityKind,
+    },
+    AnchorCharacter {
+        character: CharacterId,
+        anchor: TemporalAnchor,
+    },
+    RecallMemory {
+        character: CharacterId,
+        memory: MemoryId,
+    },
+    /// Nudges how two characters feel about each other without setting the
+    /// level outright; see [`EventEffect::AffinityShift`]
+    NudgeAffinity {
+        char1: CharacterId,
+        char2: CharacterId,
+        delta: f32,
+        timeline: TimelineId,
+    },
+    /// Repairs every causality violation in a timeline, then stabilizes it
+    RepairCausality {
+        timeline: TimelineId,
+        mechanism: String,
+    },
+    /// A character's goal comes true (`success`) or is given up on; see
+    /// [`EventEffect::GoalResolved`]
+    ResolveGoal {
+        character: CharacterId,
+        goal: String,
+        success: bool,
+    },
+    /// Lets feelings spread through a timeline; see
+    /// [`Multiverse::propagate_emotions`]
+    PropagateEmotions { timeline: TimelineId, strength: f64 },
+    /// A character takes a new name; see [`EventEffect::CharacterRename`]
+    RenameCharacter { character: CharacterId, new_name: String },
 }
 
-/// Strategy for generating narrative actions
-pub fn narrative_action_strategy() -> impl Strategy<Value = NarrativeAction> {
-    prop_oneof![
-        // Create character
-        ("[A-Z][a-z]{3,10}", timeline_id_strategy()).prop_map(|(name, timeline)| {
-            NarrativeAction::CreateCharacter { name, timeline }
-        }),
-        // Kill character
-        (character_id_strategy(), timeline_id_strategy()).prop_map(|(character, timeline)| {
-            NarrativeAction::KillCharacter {
+named_variants!(NarrativeAction {
+    CreateCharacter,
+    KillCharacter,
+    ResurrectCharacter,
+    ChangeRelationship,
+    GrantKnowledge,
+    TradeMemory,
+    BranchTimeline,
+    CreateWitnessedMemory,
+    ViolateCausality,
+    GrantAbility,
+    TriggerAppraisal,
+    AddCharacterGoal,
+    ShareKnowledge,
+    DegradeMemories,
+    WipeMemories,
+    LoseAbility,
+    AnchorCharacter,
+    RecallMemory,
+    NudgeAffinity,
+    RepairCausality,
+    ResolveGoal,
+    PropagateEmotions,
+    RenameCharacter,
+});
+
+impl NarrativeAction {
+    /// Writes the one-line summary, calling characters whatever `who` says.
+    fn write_summary(&self, f: &mut impl fmt::Write, who: &dyn Fn(CharacterId) -> String) -> fmt::Result {
+        match self {
+            NarrativeAction::CreateCharacter { name, timeline } => write!(f, "Create {:?} @ {}", name, timeline),
+            NarrativeAction::KillCharacter { character, timeline } => {
+                write!(f, "Kill {} @ {}", who(*character), timeline)
+            }
+            NarrativeAction::ResurrectCharacter {
                 character,
                 timeline,
-            }
-        }),
-        // Resurrect character
-        (
-            character_id_strategy(),
-            timeline_id_strategy(),
-            "[A-Z][a-z]{5,15}"
-        )
-            .prop_map(|(character, timeline, mechanism)| {
-                NarrativeAction::ResurrectCharacter {
-                    character,
-                    timeline,
-                    mechanism,
-                }
-            }),
-        // Change relationship
-        (
-            character_id_strategy(),
-            character_id_strategy(),
-            relationship_state_strategy(),
-            timeline_id_strategy()
-        )
-            .prop_map(|(char1, char2, new_state, timeline)| {
-                NarrativeAction::ChangeRelationship {
-                    char1,
-                    char2,
-                    new_state,
-                    timeline,
-                }
-            }),
-        // Grant knowledge
-        (character_id_strategy(), "[a-z_]{5,20}", timeline_id_strategy()).prop_map(
-            |(character, flag, timeline)| NarrativeAction::GrantKnowledge {
+                mechanism,
+            } => write!(f, "Resurrect {} @ {} via {}", who(*character), timeline, mechanism),
+            NarrativeAction::ChangeRelationship {
+                char1,
+                char2,
+                new_state,
+                timeline,
+            } => write!(f, "Make {} and {} {:?} @ {}", who(*char1), who(*char2), new_state, timeline),
+            NarrativeAction::GrantKnowledge {
                 character,
                 flag,
                 timeline,
-            }
-        ),
-        // Trade memory
-        (
-            memory_id_strategy(),
-            character_id_strategy(),
-            character_id_strategy(),
-            "[a-z]{5,15}"
-        )
-            .prop_map(|(memory, from, to, mechanism)| NarrativeAction::TradeMemory {
+            } => write!(f, "Teach {} {:?} @ {}", who(*character), flag, timeline),
+            NarrativeAction::TradeMemory {
                 memory,
                 from,
                 to,
                 mechanism,
-            }),
-        // Branch timeline
-        timeline_id_strategy().prop_map(|parent| NarrativeAction::BranchTimeline { parent }),
-        // Grant ability
-        (character_id_strategy(), ability_strategy()).prop_map(|(character, ability)| {
-            NarrativeAction::GrantAbility { character, ability }
-        }),
-    ]
+            } => write!(f, "Trade {} from {} to {} via {}", memory, who(*from), who(*to), mechanism),
+            NarrativeAction::BranchTimeline { parent, migrants } => {
+                write!(f, "Branch {}", parent)?;
+                if !migrants.is_empty() {
+                    let migrants: Vec<String> = migrants.iter().map(|&c| who(c)).collect();
+                    write!(f, " taking {}", migrants.join(", "))?;
+                }
+                Ok(())
+            }
+            NarrativeAction::CreateWitnessedMemory {
+                event,
+                character,
+                timeline,
+            } => write!(f, "Witness {} as {} @ {}", event, who(*character), timeline),
+            NarrativeAction::ViolateCausality {
+                timeline,
+                violation_type,
+            } => {
+                let mechanism = match violation_type {
+                    CausalityViolation::EffectBeforeCause { mechanism }
+                    | CausalityViolation::RetroactiveChange { mechanism }
+                    | CausalityViolation::Superposition { mechanism }
+                    | CausalityViolation::Bootstrap { mechanism, .. } => mechanism,
+                };
+                write!(f, "Violate causality @ {}: {} via {}", timeline, violation_type.name(), mechanism)
+            }
+            NarrativeAction::GrantAbility {
+                character,
+                ability,
+                mechanism,
+            } => write!(f, "Grant {:?} to {} via {}", ability.kind(), who(*character), mechanism),
+            NarrativeAction::TriggerAppraisal { character, belief } => {
+                write!(f, "Appraise for {}:", who(*character))?;
+                if belief.affected_goal_names.is_empty() {
+                    write!(f, " nothing")?;
+                }
+                for (goal, congruence) in belief.affected_goal_names.iter().zip(&belief.goal_congruences) {
+                    write!(f, " {:?} {:+.2}", goal, congruence)?;
+                }
+                Ok(())
+            }
+            NarrativeAction::AddCharacterGoal { character, goal } => {
+                write!(f, "Give {} goal {:?} ({:+.2})", who(*character), goal.name, goal.utility)
+            }
+            NarrativeAction::ShareKnowledge { from, to, flag } => {
+                write!(f, "Share {:?} from {} to {}", flag, who(*from), who(*to))
+            }
+            NarrativeAction::DegradeMemories { character, amount } => {
+                write!(f, "Degrade memories of {} by {:.2}", who(*character), amount)
+            }
+            NarrativeAction::WipeMemories { character } => write!(f, "Wipe memories of {}", who(*character)),
+            NarrativeAction::LoseAbility { character, ability } => {
+                write!(f, "Strip {:?} from {}", ability, who(*character))
+            }
+            NarrativeAction::AnchorCharacter { character, anchor } => write!(
+                f,
+                "Anchor {} to era {} ({:.2})",
+                who(*character),
+                anchor.origin_era,
+                anchor.stability
+            ),
+            NarrativeAction::RecallMemory { character, memory } => {
+                write!(f, "Recall {} as {}", memory, who(*character))
+            }
+            NarrativeAction::NudgeAffinity {
+                char1,
+                char2,
+                delta,
+                timeline,
+            } => write!(f, "Nudge {} and {} by {:+.2} @ {}", who(*char1), who(*char2), delta, timeline),
+            NarrativeAction::RepairCausality { timeline, mechanism } => {
+                write!(f, "Repair {} via {}", timeline, mechanism)
+            }
+            NarrativeAction::ResolveGoal {
+                character,
+                goal,
+                success,
+            } => {
+                let verb = if *success { "Achieve" } else { "Abandon" };
+                write!(f, "{} {:?} for {}", verb, goal, who(*character))
+            }
+            NarrativeAction::PropagateEmotions { timeline, strength } => {
+                write!(f, "Spread feelings @ {} ({:.2})", timeline, strength)
+            }
+            NarrativeAction::RenameCharacter { character, new_name } => {
+                write!(f, "Rename {} to {:?}", who(*character), new_name)
+            }
+        }
+    }
 }
 
-/// ## Applying Actions to Multiverse
+/// A concise one-liner such as `Kill Char#3 @ Timeline#0`, for shrunk failure
+/// output; [`Multiverse::describe_action`] does the same with names.
+impl fmt::Display for NarrativeAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_summary(f, &|character| character.to_string())
+    }
+}
+
+impl Multiverse {
+    /// `action` as a one-liner, like its `Display` but calling characters by
+    /// their current names. Ids this multiverse doesn't know stay ids.
+    pub fn describe_action(&self, action: &NarrativeAction) -> String {
+        let who = |character: CharacterId| {
+            self.characters.get(&character).map_or_else(|| character.to_string(), |c| c.name.clone())
+        };
+        let mut line = String::new();
+        // Writing to a String can't fail
+        let _ = action.write_summary(&mut line, &who);
+        line
+    }
+}
+
+/// ## Arbitrary Core Values
 ///
-/// This function takes a narrative action and applies it to a Multiverse,
-/// creating appropriate events and updating state.
+/// Tests that make claims about a single `Character`, `Event`, `Memory`, or
+/// `Timeline` need values that are valid in context: an event's participants
+/// must exist, a witnessed memory's witness must have been there. The `arb_*`
+/// strategies below draw every reference from an [`ArbBounds`], usually taken
+/// from the multiverse the value will be embedded in.
 ///
-/// This is where the "action interpreter" lives—it translates abstract
-/// actions into concrete state changes.
-pub fn apply_narrative_action(multiverse: &mut Multiverse, action: &NarrativeAction) {
-    match action {
-        NarrativeAction::CreateCharacter { name, timeline } => {
-            // Only create if timeline exists
-            if multiverse.timelines.contains_key(timeline) {
-                multiverse.create_character(name.clone(), *timeline);
-            }
+/// Ids that the multiverse assigns on insertion (an event's or memory's own id)
+/// are left at 0 and should be replaced, as with `record_event`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ArbBounds {
+    /// Characters that may take part in events, witness them, or trade memories
+    pub characters: Vec<CharacterId>,
+    /// Timelines events may happen in
+    pub timelines: Vec<TimelineId>,
+    /// Recorded events, each with the timeline it happened in and who took
+    /// part. Memories are of these events, and branches diverge at them
+    pub events: Vec<(EventId, TimelineId, Vec<CharacterId>)>,
+    /// Existing memories that compound memories may blend
+    pub memories: Vec<MemoryId>,
+    /// Registered forgers that forged memories may name
+    pub forgers: Vec<String>,
+}
+
+impl ArbBounds {
+    /// Everything in `multiverse`, sorted by id.
+    pub fn from_multiverse(multiverse: &Multiverse) -> Self {
+        let mut characters: Vec<CharacterId> = multiverse.characters.keys().copied().collect();
+        characters.sort();
+        let mut timelines: Vec<TimelineId> = multiverse.timelines.keys().copied().collect();
+        timelines.sort();
+        let mut events: Vec<(EventId, TimelineId, Vec<CharacterId>)> = multiverse
+            .events
+            .values()
+            .map(|e| {
+                let mut participants: Vec<CharacterId> = e.participants.iter().copied().collect();
+                participants.sort();
+                (e.id, e.timeline, participants)
+            })
+            .collect();
+        events.sort_by_key(|(id, _, _)| *id);
+        let mut memories: Vec<MemoryId> = multiverse.memories.keys().copied().collect();
+        memories.sort();
+        ArbBounds {
+            characters,
+            timelines,
+            events,
+            memories,
+            forgers: multiverse.forger_registry.iter().cloned().collect(),
         }
+    }
+}
 
-        NarrativeAction::KillCharacter {
-            character,
-            timeline,
-        } => {
-            // Create death event
-            if let Some(c) = multiverse.characters.get(character) {
-                if c.alive && c.current_timeline == *timeline && multiverse.timelines.contains_key(timeline) {
-                    multiverse.record_event(Event {
-                        id: EventId(0), // Will be overwritten
-                        timeline: *timeline,
-                        description: format!("Character {} dies", character),
-                        participants: vec![*character].into_iter().collect(),
-                        effects: vec![EventEffect::CharacterDeath {
-                            character: *character,
-                        }],
-                        causality_violation: None,
-                    });
-                }
+/// Strategy for a living protagonist in the root timeline, with their profile's
+/// starting abilities, goals, and anchor, plus up to two generated goals. They
+/// hold no memories, flags, or relationships, since those need events.
+pub fn arb_character() -> impl Strategy<Value = Character> {
+    let profiles = crate::protagonists::protagonist_profiles();
+    (
+        character_id_strategy(),
+        0..profiles.len(),
+        prop::collection::vec(goal_strategy(), 0..3),
+    )
+        .prop_map(move |(id, profile, extra_goals)| {
+            let profile = &profiles[profile];
+            let root = TimelineId(0);
+            let mut emotional_state = crate::emotional_system::EmotionalState::with_gain(profile.emotional_gain);
+            for goal in profile.starting_goals.iter().cloned().chain(extra_goals) {
+                emotional_state.add_goal(goal);
             }
-        }
+            Character {
+                id,
+                name: profile.name.to_string(),
+                former_names: Vec::new(),
+                current_timeline: root,
+                timeline_history: Vec::new(),
+                native_timeline: root,
+                memories: HashSet::new(),
+                knowledge_flags: HashSet::new(),
+                alive: true,
+                abilities: profile.starting_abilities.iter().map(|a| (a.kind(), a.clone())).collect(),
+                relationships: HashMap::new(),
+                affinities: HashMap::new(),
+                emotional_state,
+                temporal_anchor: profile.temporal_anchor.clone(),
+                anchor_timeline: profile.temporal_anchor.as_ref().map(|_| root),
+                pull_strength: 0.0,
+            }
+        })
+}
 
-        NarrativeAction::ResurrectCharacter {
-            character,
+/// Strategy for an event in one of `bounds.timelines`, with one to four
+/// participants from `bounds.characters` and up to three effects on them:
+/// knowledge, goals, relationships between participants, and deaths.
+/// Nothing happens to a participant after their death in it.
+///
+/// An event with a `causality_violation` is only valid once its timeline is
+/// marked unstable, as `ViolateCausality` does. Bootstraps are left out, since
+/// whether a participant remembers the loop isn't known from `bounds`.
+///
+/// # Panics
+///
+/// Panics if `bounds` has no timelines or no characters.
+pub fn arb_event(bounds: &ArbBounds) -> impl Strategy<Value = Event> {
+    assert!(
+        !bounds.timelines.is_empty() && !bounds.characters.is_empty(),
+        "arb_event needs a timeline and a character to draw from"
+    );
+    let most = bounds.characters.len().min(4);
+    (
+        prop::sample::select(bounds.timelines.clone()),
+        prop::sample::subsequence(bounds.characters.clone(), 1..=most),
+        prop::option::weighted(
+            0.2,
+            causality_violation_strategy()
+                .prop_filter("bootstraps need a looper", |v| !matches!(v, CausalityViolation::Bootstrap { .. })),
+        ),
+    )
+        .prop_flat_map(|(timeline, participants, violation)| {
+            let effects = prop::collection::vec(participant_effect_strategy(participants.clone()), 0..4);
+            (Just(timeline), Just(participants), effects, Just(violation))
+        })
+        .prop_map(|(timeline, participants, effects, causality_violation)| Event {
+            id: EventId(0),
             timeline,
-            mechanism,
-        } => {
-            if let Some(c) = multiverse.characters.get(character) {
-                if c.current_timeline == *timeline && multiverse.timelines.contains_key(timeline) {
-                    multiverse.record_event(Event {
-                        id: EventId(0),
-                        timeline: *timeline,
-                        description: format!("Character {} is resurrected", character),
-                        participants: vec![*character].into_iter().collect(),
-                        effects: vec![EventEffect::CharacterResurrection {
-                            character: *character,
-                            mechanism: mechanism.clone(),
-                        }],
-                        causality_violation: None,
-                    });
+            description: "An arbitrary scene".to_string(),
+            participants: participants.into_iter().collect(),
+            effects,
+            causality_violation,
+        })
+        .prop_filter("nothing happens to the dead", |event| event.validate_effect_order().is_ok())
+}
+
+/// An effect on one or two of `participants`.
+fn participant_effect_strategy(participants: Vec<CharacterId>) -> impl Strategy<Value = EventEffect> {
+    let mut arms: Vec<(u32, BoxedStrategy<EventEffect>)> = vec![
+        (
+            3,
+            (prop::sample::select(participants.clone()), "[a-z_]{5,20}")
+                .prop_map(|(character, flag)| EventEffect::KnowledgeGained { character, flag })
+                .boxed(),
+        ),
+        (
+            2,
+            (prop::sample::select(participants.clone()), goal_strategy())
+                .prop_map(|(character, goal)| EventEffect::AddGoal { character, goal })
+                .boxed(),
+        ),
+        (
+            1,
+            (
+                prop::sample::select(participants.clone()),
+                prop::sample::select(GENERATED_GOAL_NAMES.to_vec()),
+                any::<bool>(),
+            )
+                .prop_map(|(character, goal, success)| EventEffect::GoalResolved {
+                    character,
+                    goal: goal.to_string(),
+                    success,
+                })
+                .boxed(),
+        ),
+        (
+            1,
+            prop::sample::select(participants.clone())
+                .prop_map(|character| EventEffect::CharacterDeath { character })
+                .boxed(),
+        ),
+    ];
+    if participants.len() >= 2 {
+        arms.push((
+            3,
+            (prop::sample::subsequence(participants, 2), relationship_state_strategy())
+                .prop_map(|(pair, new_state)| EventEffect::RelationshipChange {
+                    character1: pair[0],
+                    character2: pair[1],
+                    new_state,
+                })
+                .boxed(),
+        ));
+    }
+    Union::new_weighted(arms)
+}
+
+/// Strategy for a memory of one of `bounds.events`, from that event's timeline.
+/// Witnessed memories name one of its participants, traded ones an owner from
+/// `bounds.characters`, forged ones a forger from `bounds.forgers` (or any
+/// name, if there are none), and compounds blend one to three of `bounds.memories`.
+/// Fidelity stays above [`MEMORY_FIDELITY_FLOOR`].
+///
+/// # Panics
+///
+/// Panics if `bounds` has no events or no characters.
+pub fn arb_memory(bounds: &ArbBounds) -> impl Strategy<Value = Memory> {
+    assert!(
+        !bounds.events.is_empty() && !bounds.characters.is_empty(),
+        "arb_memory needs an event and a character to draw from"
+    );
+    let characters = bounds.characters.clone();
+    let memories = bounds.memories.clone();
+    let forgers = bounds.forgers.clone();
+    prop::sample::select(bounds.events.clone())
+        .prop_flat_map(move |(event, timeline, participants)| {
+            let mut arms: Vec<BoxedStrategy<MemoryProvenance>> = vec![
+                (prop::sample::select(characters.clone()), "[a-z]{5,15}")
+                    .prop_map(|(original_owner, acquired_via)| MemoryProvenance::Traded {
+                        original_owner,
+                        acquired_via,
+                    })
+                    .boxed(),
+                if forgers.is_empty() {
+                    "[A-Z][a-z]{5,15}".boxed()
+                } else {
+                    prop::sample::select(forgers.clone()).boxed()
                 }
+                .prop_map(|forger| MemoryProvenance::Forged { forger })
+                .boxed(),
+            ];
+            if !participants.is_empty() {
+                arms.push(
+                    prop::sample::select(participants)
+                        .prop_map(|character| MemoryProvenance::Witnessed { character })
+                        .boxed(),
+                );
+            }
+            if !memories.is_empty() {
+                let most = memories.len().min(3);
+                arms.push(
+                    prop::sample::subsequence(memories.clone(), 1..=most)
+                        .prop_map(|sources| MemoryProvenance::Compound { sources })
+                        .boxed(),
+                );
             }
+            (Just(event), Just(timeline), Union::new(arms), MEMORY_FIDELITY_FLOOR..=1.0f32)
+        })
+        .prop_map(|(event, source_timeline, provenance, fidelity)| Memory {
+            id: MemoryId(0),
+            event,
+            content: MemoryContent::Event,
+            source_timeline,
+            provenance,
+            fidelity,
+        })
+}
+
+/// Strategy for a branch diverging at one of `bounds.events`, from that
+/// event's timeline. It has no events of its own yet, and its cast is drawn
+/// from `bounds.characters`. Its id is left at 0.
+///
+/// # Panics
+///
+/// Panics if `bounds` has no events.
+pub fn arb_timeline(bounds: &ArbBounds) -> impl Strategy<Value = Timeline> {
+    assert!(!bounds.events.is_empty(), "arb_timeline needs an event to branch from");
+    let most = bounds.characters.len();
+    (
+        prop::sample::select(bounds.events.clone()),
+        prop::sample::subsequence(bounds.characters.clone(), 0..=most),
+        any::<bool>(),
+    )
+        .prop_map(|((event, parent, _), characters, causality_stable)| Timeline {
+            id: TimelineId(0),
+            parent: Some(parent),
+            divergence_event: Some(event),
+            events: Vec::new(),
+            characters: characters.into_iter().collect(),
+            causality_stable,
+        })
+}
+
+/// ## Action Weights
+///
+/// `prop_oneof!` gives every arm equal weight by default, but different
+/// invariants need different mixes: death-finality bugs hide behind long runs of
+/// kills and resurrections, memory bugs behind trades. `ActionWeights` assigns a
+/// relative weight to each `NarrativeAction` variant.
+///
+/// A weight of 0 stops the variant from being generated at all, shrinking
+/// included. At least one weight must be non-zero.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ActionWeights {
+    pub create_character: u32,
+    pub kill_character: u32,
+    pub resurrect_character: u32,
+    pub change_relationship: u32,
+    pub grant_knowledge: u32,
+    pub trade_memory: u32,
+    pub branch_timeline: u32,
+    pub create_witnessed_memory: u32,
+    pub violate_causality: u32,
+    pub grant_ability: u32,
+    pub trigger_appraisal: u32,
+    pub add_character_goal: u32,
+    pub share_knowledge: u32,
+    pub degrade_memories: u32,
+    pub wipe_memories: u32,
+    pub lose_ability: u32,
+    pub anchor_character: u32,
+    pub recall_memory: u32,
+    pub nudge_affinity: u32,
+    pub repair_causality: u32,
+    pub resolve_goal: u32,
+    pub propagate_emotions: u32,
+    pub rename_character: u32,
+}
+
+impl Default for ActionWeights {
+    /// Uniform weights: every action is equally likely.
+    fn default() -> Self {
+        ActionWeights {
+            create_character: 1,
+            kill_character: 1,
+            resurrect_character: 1,
+            change_relationship: 1,
+            grant_knowledge: 1,
+            trade_memory: 1,
+            branch_timeline: 1,
+            create_witnessed_memory: 1,
+            violate_causality: 1,
+            grant_ability: 1,
+            trigger_appraisal: 1,
+            add_character_goal: 1,
+            share_knowledge: 1,
+            degrade_memories: 1,
+            wipe_memories: 1,
+            lose_ability: 1,
+            anchor_character: 1,
+            recall_memory: 1,
+            nudge_affinity: 1,
+            repair_causality: 1,
+            resolve_goal: 1,
+            propagate_emotions: 1,
+            rename_character: 1,
         }
+    }
+}
 
-        NarrativeAction::ChangeRelationship {
-            char1,
-            char2,
-            new_state,
-            timeline,
-        } => {
-            if let (Some(c1), Some(c2)) = (multiverse.characters.get(char1), multiverse.characters.get(char2)) {
-                if c1.alive && c2.alive && c1.current_timeline == *timeline && c2.current_timeline == *timeline {
-                    multiverse.record_event(Event {
-                        id: EventId(0),
-                        timeline: *timeline,
-                        description: format!("Relationship changes between {} and {}", char1, char2),
-                        participants: vec![*char1, *char2].into_iter().collect(),
-                        effects: vec![EventEffect::RelationshipChange {
-                            character1: *char1,
-                            character2: *char2,
-                            new_state: *new_state,
-                        }],
-                        causality_violation: None,
-                    });
-                }
-            }
+impl ActionWeights {
+    /// Mostly memory traffic: witnessed memories and trades, with enough
+    /// relationships and kills to give them something to be about.
+    pub fn memory_heavy() -> Self {
+        ActionWeights {
+            trade_memory: 8,
+            create_witnessed_memory: 6,
+            ..Self::default()
         }
+    }
 
-        NarrativeAction::GrantKnowledge {
-            character,
-            flag,
-            timeline,
-        } => {
-            if let Some(c) = multiverse.characters.get(character) {
-                if c.alive && c.current_timeline == *timeline {
-                    multiverse.record_event(Event {
-                        id: EventId(0),
-                        timeline: *timeline,
-                        description: format!("Knowledge {} granted to {}", flag, character),
-                        participants: vec![*character].into_iter().collect(),
-                        effects: vec![EventEffect::KnowledgeGained {
-                            character: *character,
-                            flag: flag.clone(),
-                        }],
-                        causality_violation: None,
-                    });
-                }
-            }
+    /// Kills and resurrections, for hammering death finality.
+    pub fn lethal() -> Self {
+        ActionWeights {
+            kill_character: 8,
+            resurrect_character: 6,
+            create_character: 2,
+            ..Self::default()
         }
+    }
 
-        NarrativeAction::TradeMemory {
-            memory,
-            from,
-            to,
-            mechanism,
-        } => {
-            if let (Some(f), Some(t)) = (multiverse.characters.get(from), multiverse.characters.get(to)) {
-                if f.alive && t.alive {
-                    // Get timeline from recipient
-                    let timeline = t.current_timeline;
-
-                    // Only trade if both are in same timeline (for simplicity in random tests)
-                    if f.current_timeline == timeline {
-                        // Create traded memory if it doesn't exist
-                        if !multiverse.memories.contains_key(memory) {
-                            let traded_memory = Memory {
-                                id: *memory,
-                                event: EventId(0), // Dummy event
-                                source_timeline: timeline,
-                                provenance: MemoryProvenance::Traded {
-                                    original_owner: *from,
-                                    acquired_via: mechanism.clone(),
-                                },
-                                fidelity: 0.9,
-                            };
-                            multiverse.memories.insert(*memory, traded_memory);
-                        }
-
-                        multiverse.record_event(Event {
-                            id: EventId(0),
-                            timeline,
-                            description: format!("Memory traded from {} to {}", from, to),
-                            participants: vec![*from, *to].into_iter().collect(),
-                            effects: vec![EventEffect::MemoryTransfer {
-                                memory: *memory,
-                                from: Some(*from),
-                                to: *to,
-                            }],
-                            causality_violation: None,
-                        });
-                    }
-                }
-            }
+    /// Branching and causality violations, for the timeline-structure properties.
+    pub fn timeline_chaos() -> Self {
+        ActionWeights {
+            branch_timeline: 8,
+            violate_causality: 6,
+            ..Self::default()
         }
+    }
 
-        NarrativeAction::BranchTimeline { parent } => {
-            if let Some(parent_timeline) = multiverse.timelines.get(parent) {
-                if !parent_timeline.events.is_empty() {
-                    let divergence_event = *parent_timeline.events.last().unwrap();
-                    multiverse.create_timeline_branch(*parent, divergence_event);
-                }
-            }
+    /// Goals and appraisals, for pushing emotional state toward saturation.
+    pub fn emotional() -> Self {
+        ActionWeights {
+            trigger_appraisal: 10,
+            add_character_goal: 4,
+            ..Self::default()
         }
+    }
 
-        NarrativeAction::CreateWitnessedMemory {
-            event,
-            character,
+    /// A mix dialed from cozy (0.0) to the full Incoherence (1.0) by `chaos`,
+    /// which is clamped to that range.
+    ///
+    /// Relationship, knowledge, and witnessed or traded memory actions keep a
+    /// fixed weight. Kills, causality violations, and branches scale from never
+    /// to the bulk of the mix; the rest (resurrections, abilities, anchors,
+    /// wipes, goals, appraisals, newcomers) scale more gently. At 0.0 only the
+    /// fixed actions remain, so no timeline ever becomes causality-unstable.
+    pub fn with_chaos(chaos: f64) -> Self {
+        let chaos = chaos.clamp(0.0, 1.0);
+        let disruptive = (chaos * 12.0).round() as u32;
+        let unsettling = (chaos * 2.0).round() as u32;
+        ActionWeights {
+            create_character: unsettling,
+            kill_character: disruptive,
+            resurrect_character: unsettling,
+            change_relationship: 4,
+            grant_knowledge: 4,
+            trade_memory: 4,
+            branch_timeline: disruptive,
+            create_witnessed_memory: 4,
+            violate_causality: disruptive,
+            grant_ability: unsettling,
+            trigger_appraisal: unsettling,
+            add_character_goal: unsettling,
+            share_knowledge: 4,
+            degrade_memories: unsettling,
+            wipe_memories: unsettling,
+            lose_ability: unsettling,
+            anchor_character: unsettling,
+            recall_memory: 4,
+            nudge_affinity: 4,
+            repair_causality: unsettling,
+            resolve_goal: unsettling,
+            propagate_emotions: unsettling,
+            rename_character: unsettling,
+        }
+    }
+
+    /// Chaos 0.0: a slice-of-life story where nobody dies and causality holds.
+    pub fn cozy() -> Self {
+        Self::with_chaos(0.0)
+    }
+
+    /// Chaos 1.0: the Great Incoherence at full strength.
+    pub fn incoherence() -> Self {
+        Self::with_chaos(1.0)
+    }
+
+    /// Sum of all weights.
+    pub fn total(&self) -> u32 {
+        self.create_character
+            + self.kill_character
+            + self.resurrect_character
+            + self.change_relationship
+            + self.grant_knowledge
+            + self.trade_memory
+            + self.branch_timeline
+            + self.create_witnessed_memory
+            + self.violate_causality
+            + self.grant_ability
+            + self.trigger_appraisal
+            + self.add_character_goal
+            + self.share_knowledge
+            + self.degrade_memories
+            + self.wipe_memories
+            + self.lose_ability
+            + self.anchor_character
+            + self.recall_memory
+            + self.nudge_affinity
+            + self.repair_causality
+            + self.resolve_goal
+            + self.propagate_emotions
+            + self.rename_character
+    }
+}
+
+/// Strategy for generating narrative actions, every variant equally likely
+pub fn narrative_action_strategy() -> impl Strategy<Value = NarrativeAction> {
+    narrative_action_strategy_weighted(ActionWeights::default())
+}
+
+/// Strategy for generating narrative actions with a custom mix.
+///
+/// # Panics
+///
+/// Panics if every weight is zero.
+pub fn narrative_action_strategy_weighted(
+    weights: ActionWeights,
+) -> impl Strategy<Value = NarrativeAction> {
+    assert!(
+        weights.total() > 0,
+        "ActionWeights must have a non-zero weight"
+    );
+    let arms: Vec<(u32, BoxedStrategy<NarrativeAction>)> = vec![
+        // Create character
+        (
+            weights.create_character,
+            ("[A-Z][a-z]{3,10}", timeline_id_strategy())
+                .prop_map(|(name, timeline)| NarrativeAction::CreateCharacter { name, timeline })
+                .boxed(),
+        ),
+        // Kill character
+        (
+            weights.kill_character,
+            (character_id_strategy(), timeline_id_strategy())
+                .prop_map(|(character, timeline)| NarrativeAction::KillCharacter {
+                    character,
+                    timeline,
+                })
+                .boxed(),
+        ),
+        // Resurrect character
+        (
+            weights.resurrect_character,
+            (
+                character_id_strategy(),
+                timeline_id_strategy(),
+                "[A-Z][a-z]{5,15}",
+            )
+                .prop_map(
+                    |(character, timeline, mechanism)| NarrativeAction::ResurrectCharacter {
+                        character,
+                        timeline,
+                        mechanism,
+                    },
+                )
+                .boxed(),
+        ),
+        // Change relationship
+        (
+            weights.change_relationship,
+            (
+                character_id_strategy(),
+                character_id_strategy(),
+                relationship_state_strategy(),
+                timeline_id_strategy(),
+            )
+                .prop_map(|(char1, char2, new_state, timeline)| {
+                    NarrativeAction::ChangeRelationship {
+                        char1,
+                        char2,
+                        new_state,
+                        timeline,
+                    }
+                })
+                .boxed(),
+        ),
+        // Grant knowledge
+        (
+            weights.grant_knowledge,
+            (
+                character_id_strategy(),
+                "[a-z_]{5,20}",
+                timeline_id_strategy(),
+            )
+                .prop_map(
+                    |(character, flag, timeline)| NarrativeAction::GrantKnowledge {
+                        character,
+                        flag,
+                        timeline,
+                    },
+                )
+                .boxed(),
+        ),
+        // Trade memory
+        (
+            weights.trade_memory,
+            (
+                memory_id_strategy(),
+                character_id_strategy(),
+                character_id_strategy(),
+                "[a-z]{5,15}",
+            )
+                .prop_map(
+                    |(memory, from, to, mechanism)| NarrativeAction::TradeMemory {
+                        memory,
+                        from,
+                        to,
+                        mechanism,
+                    },
+                )
+                .boxed(),
+        ),
+        // Branch timeline
+        (
+            weights.branch_timeline,
+            (timeline_id_strategy(), prop::collection::vec(character_id_strategy(), 0..3))
+                .prop_map(|(parent, migrants)| NarrativeAction::BranchTimeline { parent, migrants })
+                .boxed(),
+        ),
+        // Create witnessed memory (event ids bounded to ones a short sequence can reach)
+        (
+            weights.create_witnessed_memory,
+            (
+                (0u64..64).prop_map(EventId),
+                character_id_strategy(),
+                timeline_id_strategy(),
+            )
+                .prop_map(
+                    |(event, character, timeline)| NarrativeAction::CreateWitnessedMemory {
+                        event,
+                        character,
+                        timeline,
+                    },
+                )
+                .boxed(),
+        ),
+        // Violate causality
+        (
+            weights.violate_causality,
+            (timeline_id_strategy(), causality_violation_strategy())
+                .prop_map(
+                    |(timeline, violation_type)| NarrativeAction::ViolateCausality {
+                        timeline,
+                        violation_type,
+                    },
+                )
+                .boxed(),
+        ),
+        // Grant ability
+        (
+            weights.grant_ability,
+            (
+                character_id_strategy(),
+                ability_strategy(),
+                "[A-Z][a-z]{5,15}",
+            )
+                .prop_map(
+                    |(character, ability, mechanism)| NarrativeAction::GrantAbility {
+                        character,
+                        ability,
+                        mechanism,
+                    },
+                )
+                .boxed(),
+        ),
+        // Trigger appraisal
+        (
+            weights.trigger_appraisal,
+            (character_id_strategy(), generated_belief_strategy())
+                .prop_map(|(character, belief)| NarrativeAction::TriggerAppraisal {
+                    character,
+                    belief,
+                })
+                .boxed(),
+        ),
+        // Add goal
+        (
+            weights.add_character_goal,
+            (character_id_strategy(), goal_strategy())
+                .prop_map(|(character, goal)| NarrativeAction::AddCharacterGoal { character, goal })
+                .boxed(),
+        ),
+        // Share knowledge
+        (
+            weights.share_knowledge,
+            (character_id_strategy(), character_id_strategy(), "[a-z_]{5,20}")
+                .prop_map(|(from, to, flag)| NarrativeAction::ShareKnowledge { from, to, flag })
+                .boxed(),
+        ),
+        // Degrade memories
+        (
+            weights.degrade_memories,
+            (character_id_strategy(), 0.0f32..=1.0)
+                .prop_map(|(character, amount)| NarrativeAction::DegradeMemories { character, amount })
+                .boxed(),
+        ),
+        // Wipe memories
+        (
+            weights.wipe_memories,
+            character_id_strategy()
+                .prop_map(|character| NarrativeAction::WipeMemories { character })
+                .boxed(),
+        ),
+        // Lose ability
+        (
+            weights.lose_ability,
+            (character_id_strategy(), ability_strategy())
+                .prop_map(|(character, ability)| NarrativeAction::LoseAbility {
+                    character,
+                    ability: ability.kind(),
+                })
+                .boxed(),
+        ),
+        // Anchor character
+        (
+            weights.anchor_character,
+            (character_id_strategy(), -2000i64..-100, 0.1f32..=1.0)
+                .prop_map(|(character, origin_era, stability)| NarrativeAction::AnchorCharacter {
+                    character,
+                    anchor: TemporalAnchor { origin_era, stability },
+                })
+                .boxed(),
+        ),
+        // Recall memory
+        (
+            weights.recall_memory,
+            (character_id_strategy(), memory_id_strategy())
+                .prop_map(|(character, memory)| NarrativeAction::RecallMemory { character, memory })
+                .boxed(),
+        ),
+        // Nudge affinity
+        (
+            weights.nudge_affinity,
+            (
+                character_id_strategy(),
+                character_id_strategy(),
+                -1.0f32..=1.0,
+                timeline_id_strategy(),
+            )
+                .prop_map(|(char1, char2, delta, timeline)| NarrativeAction::NudgeAffinity {
+                    char1,
+                    char2,
+                    delta,
+                    timeline,
+                })
+                .boxed(),
+        ),
+        // Repair causality
+        (
+            weights.repair_causality,
+            (timeline_id_strategy(), "[A-Z][a-z]{5,15}")
+                .prop_map(|(timeline, mechanism)| NarrativeAction::RepairCausality { timeline, mechanism })
+                .boxed(),
+        ),
+        // Resolve goal
+        (
+            weights.resolve_goal,
+            (
+                character_id_strategy(),
+                prop::sample::select(GENERATED_GOAL_NAMES.to_vec()),
+                any::<bool>(),
+            )
+                .prop_map(|(character, goal, success)| NarrativeAction::ResolveGoal {
+                    character,
+                    goal: goal.to_string(),
+                    success,
+                })
+                .boxed(),
+        ),
+        // Propagate emotions
+        (
+            weights.propagate_emotions,
+            (timeline_id_strategy(), 0.0f64..=1.0)
+                .prop_map(|(timeline, strength)| NarrativeAction::PropagateEmotions { timeline, strength })
+                .boxed(),
+        ),
+        // Rename character
+        (
+            weights.rename_character,
+            (character_id_strategy(), "[A-Z][a-z]{5,12}")
+                .prop_map(|(character, new_name)| NarrativeAction::RenameCharacter { character, new_name })
+                .boxed(),
+        ),
+    ];
+
+    // Union rejects zero weights, so disabled actions are left out entirely
+    Union::new_weighted(arms.into_iter().filter(|(weight, _)| *weight > 0).collect())
+}
+
+/// ## Stateful Action Generation
+///
+/// `narrative_action_strategy` picks ids blindly (characters 0..13, timelines
+/// 0..10), so most of what it generates targets characters that are dead, in
+/// another timeline, or don't exist—and `apply_narrative_action` quietly skips
+/// them. The state space gets explored shallowly.
+///
+/// The stateful generator instead draws *raw choices* (which kind of action,
+/// plus a few [`Index`] picks) and resolves them against a model multiverse that
+/// evolves as the sequence is built. KillCharacter picks someone currently alive,
+/// BranchTimeline picks a timeline with events, TradeMemory picks a memory the
+/// seller actually holds, and so on. The model is simply a scratch `Multiverse`
+/// driven by the same interpreter as the test, so the two can't drift apart.
+///
+/// Shrinking operates on the raw choices, so shrunk sequences stay valid.
+pub fn valid_action_sequence_strategy(
+    len: Range<usize>,
+) -> impl Strategy<Value = Vec<NarrativeAction>> {
+    valid_action_sequence_strategy_from(chaos_cast_multiverse(), ActionWeights::default(), len)
+}
+
+/// Like [`valid_action_sequence_strategy`], with a custom action mix.
+pub fn valid_action_sequence_strategy_weighted(
+    weights: ActionWeights,
+    len: Range<usize>,
+) -> impl Strategy<Value = Vec<NarrativeAction>> {
+    valid_action_sequence_strategy_from(chaos_cast_multiverse(), weights, len)
+}
+
+/// Like [`valid_action_sequence_strategy_weighted`], but resolved against a
+/// caller-supplied starting multiverse. Apply the generated actions to a clone
+/// of `initial`.
+pub fn valid_action_sequence_strategy_from(
+    initial: Multiverse,
+    weights: ActionWeights,
+    len: Range<usize>,
+) -> impl Strategy<Value = Vec<NarrativeAction>> {
+    prop::collection::vec(action_seed_strategy(weights), len).prop_map(move |seeds| {
+        let mut model = initial.clone();
+        let mut actions = Vec::new();
+        for seed in &seeds {
+            if let Some(action) = resolve_action_seed(&model, seed) {
+                apply_narrative_action(&mut model, &action);
+                actions.push(action);
+            }
+        }
+        actions
+    })
+}
+
+/// How deep chaos tests may nest branches. Each branch copies its parent's
+/// cast, so unbounded nesting in a long sequence is all memory and no insight.
+pub const CHAOS_MAX_BRANCH_DEPTH: usize = 4;
+
+/// The starting state for chaos testing: the thirteen protagonists by name, in
+/// the root timeline, with no abilities, goals, or anchors. Everything else has
+/// to come from generated actions. Branches nest at most
+/// [`CHAOS_MAX_BRANCH_DEPTH`] deep.
+pub fn chaos_cast_multiverse() -> Multiverse {
+    let mut multiverse = Multiverse::new();
+    multiverse.config.max_branch_depth = Some(CHAOS_MAX_BRANCH_DEPTH);
+    for action in chaos_cast_actions() {
+        apply_narrative_action(&mut multiverse, &action);
+    }
+    multiverse
+}
+
+/// The actions that build [`chaos_cast_multiverse`] from an empty multiverse.
+/// Prefix a chaos sequence with these to make it replayable from scratch.
+pub fn chaos_cast_actions() -> Vec<NarrativeAction> {
+    let root = Multiverse::new().root_timeline;
+    crate::protagonists::PROTAGONIST_NAMES
+        .iter()
+        .map(|name| NarrativeAction::CreateCharacter {
+            name: name.to_string(),
+            timeline: root,
+        })
+        .collect()
+}
+
+/// Raw random choices for one action, resolved against the model later.
+#[derive(Debug, Clone)]
+struct ActionSeed {
+    kind: usize,
+    picks: [Index; 3],
+    /// Which of the parent's residents follow a branch, one bit each
+    migration_mask: u64,
+    /// A memory degradation amount, or an anchor's starting stability
+    fraction: f32,
+    /// An anchor's era of origin
+    era: i64,
+    text: String,
+    state: RelationshipState,
+    ability: Ability,
+    violation: CausalityViolation,
+    goal: Goal,
+    belief: Belief,
+}
+
+/// Picks which kind of action a seed resolves to, in the order of the
+/// `resolve_action_seed` match arms.
+fn action_kind_strategy(weights: ActionWeights) -> impl Strategy<Value = usize> {
+    assert!(weights.total() > 0, "ActionWeights must have a non-zero weight");
+    let arms = [
+        weights.create_character,
+        weights.kill_character,
+        weights.resurrect_character,
+        weights.change_relationship,
+        weights.grant_knowledge,
+        weights.trade_memory,
+        weights.branch_timeline,
+        weights.create_witnessed_memory,
+        weights.violate_causality,
+        weights.grant_ability,
+        weights.trigger_appraisal,
+        weights.add_character_goal,
+        weights.share_knowledge,
+        weights.degrade_memories,
+        weights.wipe_memories,
+        weights.lose_ability,
+        weights.anchor_character,
+        weights.recall_memory,
+        weights.nudge_affinity,
+        weights.repair_causality,
+        weights.resolve_goal,
+        weights.propagate_emotions,
+        weights.rename_character,
+    ];
+    Union::new_weighted(
+        arms.iter()
+            .enumerate()
+            .filter(|(_, weight)| **weight > 0)
+            .map(|(kind, weight)| (*weight, Just(kind)))
+            .collect(),
+    )
+}
+
+fn action_seed_strategy(weights: ActionWeights) -> impl Strategy<Value = ActionSeed> {
+    (
+        action_kind_strategy(weights),
+        prop::array::uniform3(any::<Index>()),
+        (any::<u64>(), 0.1f32..1.0, -2000i64..-100),
+        "[A-Z][a-z]{5,12}",
+        relationship_state_strategy(),
+        ability_strategy(),
+        causality_violation_strategy(),
+        goal_strategy(),
+        generated_belief_strategy(),
+    )
+        .prop_map(
+            |(kind, picks, (migration_mask, fraction, era), text, state, ability, violation, goal, belief)| ActionSeed {
+                kind,
+                picks,
+                migration_mask,
+                fraction,
+                era,
+                text,
+                state,
+                ability,
+                violation,
+                goal,
+                belief,
+            },
+        )
+}
+
+/// Picks one element of a (sorted) candidate list, or `None` if it's empty.
+fn pick<T: Copy>(candidates: &[T], index: &Index) -> Option<T> {
+    if candidates.is_empty() {
+        None
+    } else {
+        Some(candidates[index.index(candidates.len())])
+    }
+}
+
+/// Turns raw choices into a concrete action whose preconditions hold in `model`.
+///
+/// Candidates are sorted by id so resolution is deterministic despite the
+/// multiverse's hash maps. Returns `None` when no valid target exists.
+fn resolve_action_seed(model: &Multiverse, seed: &ActionSeed) -> Option<NarrativeAction> {
+    let [first, second, third] = &seed.picks;
+
+    let mut timelines: Vec<TimelineId> = model.timelines.keys().copied().collect();
+    timelines.sort_by_key(|t| t.0);
+    let characters_where = |pred: &dyn Fn(&Character) -> bool| {
+        let mut ids: Vec<CharacterId> = model
+            .characters
+            .values()
+            .filter(|c| pred(c))
+            .map(|c| c.id)
+            .collect();
+        ids.sort_by_key(|c| c.0);
+        ids
+    };
+    let present = characters_where(&|c| c.is_present());
+    let timeline_of = |id: CharacterId| model.characters[&id].current_timeline;
+
+    match seed.kind {
+        0 => Some(NarrativeAction::CreateCharacter {
+            name: seed.text.clone(),
+            timeline: pick(&timelines, first)?,
+        }),
+        1 => {
+            let character = pick(&present, first)?;
+            Some(NarrativeAction::KillCharacter {
+                character,
+                timeline: timeline_of(character),
+            })
+        }
+        2 => {
+            let character = pick(&characters_where(&|c| !c.alive && !c.has_departed()), first)?;
+            Some(NarrativeAction::ResurrectCharacter {
+                character,
+                timeline: timeline_of(character),
+                mechanism: seed.text.clone(),
+            })
+        }
+        3 => {
+            let char1 = pick(&present, first)?;
+            let timeline = timeline_of(char1);
+            let others = characters_where(&|c| c.is_present() && c.current_timeline == timeline && c.id != char1);
+            Some(NarrativeAction::ChangeRelationship {
+                char1,
+                char2: pick(&others, second)?,
+                new_state: seed.state,
+                timeline,
+            })
+        }
+        4 => {
+            let character = pick(&present, first)?;
+            Some(NarrativeAction::GrantKnowledge {
+                character,
+                flag: seed.text.to_lowercase(),
+                timeline: timeline_of(character),
+            })
+        }
+        5 => {
+            let from = pick(&present, first)?;
+            let timeline = timeline_of(from);
+            // Face to face, or across timelines if either side can reach the other
+            let buyers = characters_where(&|c| {
+                c.is_present() && c.id != from && (c.current_timeline == timeline || model.can_trade_memory(from, c.id))
+            });
+            let to = pick(&buyers, second)?;
+
+            // Only memories the buyer could hold: from a timeline they know, or can
+            // see from anywhere. Sight of sibling branches is lost the moment they
+            // branch off again, and the memory with it.
+            let buyer = &model.characters[&to];
+            let sees_everywhere = matches!(
+                buyer.ability(AbilityKind::TimelinePerception),
+                Some(Ability::TimelinePerception { scope: PerceptionScope::AllTimelines })
+            );
+            let knows = |timeline: TimelineId| sees_everywhere || buyer.was_in_timeline(timeline);
+            let mut held: Vec<MemoryId> = model.characters[&from]
+                .memories
+                .iter()
+                .copied()
+                .filter(|m| model.memories.get(m).is_some_and(|m| knows(m.source_timeline)))
+                .collect();
+            held.sort_by_key(|m| m.0);
+            // Sellers with nothing to sell bootstrap a fresh memory, in an id range
+            // the multiverse's own counter won't reach in a test-sized sequence.
+            // It comes from the seller's timeline, so the buyer has to know that one.
+            let fresh = knows(timeline);
+            let memory = pick(&held, third)
+                .or_else(|| fresh.then(|| MemoryId(1_000_000 + model.memories.len() as u64)))?;
+
+            Some(NarrativeAction::TradeMemory {
+                memory,
+                from,
+                to,
+                mechanism: seed.text.to_lowercase(),
+            })
+        }
+        6 => {
+            let max_depth = model.config.max_branch_depth.unwrap_or(usize::MAX);
+            let with_events: Vec<TimelineId> = timelines
+                .iter()
+                .copied()
+                .filter(|t| !model.timelines[t].events.is_empty() && model.branch_depth(*t) < max_depth)
+                .collect();
+            let parent = pick(&with_events, first)?;
+            // A random subset of those living there follows the branch
+            let migrants = characters_where(&|c| c.is_present() && c.current_timeline == parent)
+                .into_iter()
+                .enumerate()
+                .filter(|(i, _)| seed.migration_mask & (1 << (i % 64)) != 0)
+                .map(|(_, id)| id)
+                .collect();
+            Some(NarrativeAction::BranchTimeline { parent, migrants })
+        }
+        7 => {
+            // An event with at least one living attendee still in its timeline,
+            // who hasn't had their memories wiped since
+            let mut last_wipe: HashMap<CharacterId, EventId> = HashMap::new();
+            let mut witnessable: Vec<(EventId, CharacterId)> = Vec::new();
+            let mut events: Vec<&Event> = model.events.values().collect();
+            events.sort_by_key(|e| e.id.0);
+            for event in &events {
+                for effect in &event.effects {
+                    if let EventEffect::MemoryWipe { character } = effect {
+                        last_wipe.insert(*character, event.id);
+                    }
+                }
+            }
+            let remembers = |c: &Character, event: EventId| {
+                c.has_ability(AbilityKind::MemoryImmunity)
+                    || last_wipe.get(&c.id).is_none_or(|wipe| event.0 > wipe.0)
+            };
+            for event in events {
+                let mut attendees: Vec<CharacterId> = event
+                    .participants
+                    .iter()
+                    .copied()
+                    .filter(|id| {
+                        model
+                            .characters
+                            .get(id)
+                            .map(|c| {
+                                c.is_present() && c.current_timeline == event.timeline && remembers(c, event.id)
+                            })
+                            .unwrap_or(false)
+                    })
+                    .collect();
+                attendees.sort_by_key(|c| c.0);
+                witnessable.extend(attendees.into_iter().map(|c| (event.id, c)));
+            }
+            let (event, character) = pick(&witnessable, first)?;
+            Some(NarrativeAction::CreateWitnessedMemory {
+                event,
+                character,
+                timeline: timeline_of(character),
+            })
+        }
+        8 => {
+            // A bootstrap needs someone there who remembers the loop
+            let usable = model.config.validation.min_usable_fidelity;
+            let looped: Vec<TimelineId> = timelines
+                .iter()
+                .copied()
+                .filter(|t| !characters_where(&|c| c.current_timeline == *t && loops(c, usable)).is_empty())
+                .collect();
+            let candidates = match seed.violation {
+                CausalityViolation::Bootstrap { .. } => &looped,
+                _ => &timelines,
+            };
+            Some(NarrativeAction::ViolateCausality {
+                timeline: pick(candidates, first)?,
+                violation_type: seed.violation.clone(),
+            })
+        }
+        9 => Some(NarrativeAction::GrantAbility {
+            character: pick(&present, first)?,
+            ability: seed.ability.clone(),
+            mechanism: seed.text.clone(),
+        }),
+        10 => {
+            // Appraisals only matter to someone with goals to appraise against
+            let with_goals = characters_where(&|c| c.is_present() && !c.emotional_state.goals.is_empty());
+            Some(NarrativeAction::TriggerAppraisal {
+                character: pick(&with_goals, first)?,
+                belief: seed.belief.clone(),
+            })
+        }
+        11 => Some(NarrativeAction::AddCharacterGoal {
+            character: pick(&present, first)?,
+            goal: seed.goal.clone(),
+        }),
+        12 => {
+            let knowing = characters_where(&|c| c.is_present() && !c.knowledge_flags.is_empty());
+            let from = pick(&knowing, first)?;
+            let timeline = timeline_of(from);
+            // Face to face, or over the Lattice if both are linked
+            let linked = |c: &Character| c.has_ability(AbilityKind::AnsibleLink);
+            let speaker_linked = linked(&model.characters[&from]);
+            let listeners = characters_where(&|c| {
+                c.is_present() && c.id != from && (c.current_timeline == timeline || (speaker_linked && linked(c)))
+            });
+            let mut flags: Vec<&String> = model.characters[&from].knowledge_flags.iter().collect();
+            flags.sort();
+            Some(NarrativeAction::ShareKnowledge {
+                from,
+                to: pick(&listeners, second)?,
+                flag: flags[third.index(flags.len())].clone(),
+            })
+        }
+        13 => Some(NarrativeAction::DegradeMemories {
+            character: pick(&present, first)?,
+            amount: seed.fraction,
+        }),
+        14 => Some(NarrativeAction::WipeMemories {
+            character: pick(&present, first)?,
+        }),
+        15 => {
+            // Only abilities nothing else depends on: losing perception, a Lattice
+            // link, or immunity would orphan what was gained through them
+            const LOSABLE: [AbilityKind; 3] = [
+                AbilityKind::Precognition,
+                AbilityKind::LoopMemory,
+                AbilityKind::CausalityHacking,
+            ];
+            let mut held: Vec<(CharacterId, AbilityKind)> = Vec::new();
+            for character in &present {
+                let c = &model.characters[character];
+                held.extend(LOSABLE.iter().filter(|kind| c.has_ability(**kind)).map(|kind| (*character, *kind)));
+            }
+            let (character, ability) = pick(&held, first)?;
+            Some(NarrativeAction::LoseAbility { character, ability })
+        }
+        16 => {
+            let unanchored = characters_where(&|c| c.is_present() && c.temporal_anchor.is_none());
+            Some(NarrativeAction::AnchorCharacter {
+                character: pick(&unanchored, first)?,
+                anchor: TemporalAnchor {
+                    origin_era: seed.era,
+                    stability: seed.fraction,
+                },
+            })
+        }
+        17 => {
+            // Only memories still clear enough to act on
+            let threshold = model.config.validation.min_usable_fidelity;
+            let mut clear: Vec<(CharacterId, MemoryId)> = Vec::new();
+            for character in &present {
+                let mut held: Vec<MemoryId> = model.characters[character]
+                    .memories
+                    .iter()
+                    .filter(|m| model.memories.get(m).is_some_and(|memory| memory.fidelity > threshold))
+                    .copied()
+                    .collect();
+                held.sort();
+                clear.extend(held.into_iter().map(|memory| (*character, memory)));
+            }
+            let (character, memory) = pick(&clear, first)?;
+            Some(NarrativeAction::RecallMemory { character, memory })
+        }
+        18 => {
+            let char1 = pick(&present, first)?;
+            let timeline = timeline_of(char1);
+            let others = characters_where(&|c| c.is_present() && c.current_timeline == timeline && c.id != char1);
+            // Warming or cooling, by up to most of a level
+            let delta = if seed.migration_mask & 1 == 0 { seed.fraction } else { -seed.fraction };
+            Some(NarrativeAction::NudgeAffinity {
+                char1,
+                char2: pick(&others, second)?,
+                delta,
+                timeline,
+            })
+        }
+        19 => {
+            let unstable: Vec<TimelineId> =
+                timelines.iter().copied().filter(|t| !model.timelines[t].causality_stable).collect();
+            Some(NarrativeAction::RepairCausality {
+                timeline: pick(&unstable, first)?,
+                mechanism: seed.text.clone(),
+            })
+        }
+        20 => {
+            let with_goals = characters_where(&|c| c.is_present() && !c.emotional_state.goals.is_empty());
+            let character = pick(&with_goals, first)?;
+            let mut goals: Vec<&String> = model.characters[&character].emotional_state.goals.keys().collect();
+            goals.sort();
+            Some(NarrativeAction::ResolveGoal {
+                character,
+                goal: goals[second.index(goals.len())].clone(),
+                success: seed.migration_mask & 1 == 0,
+            })
+        }
+        21 => {
+            let strength = seed.fraction as f64;
+            let stirring: Vec<TimelineId> =
+                timelines.iter().copied().filter(|t| !model.contagion(*t, strength).is_empty()).collect();
+            Some(NarrativeAction::PropagateEmotions {
+                timeline: pick(&stirring, first)?,
+                strength,
+            })
+        }
+        _ => {
+            let renamable = characters_where(&|c| c.is_present() && c.name != seed.text);
+            Some(NarrativeAction::RenameCharacter {
+                character: pick(&renamable, first)?,
+                new_name: seed.text.clone(),
+            })
+        }
+    }
+}
+
+/// ## Whole-Multiverse Generation
+///
+/// Some tests care about a *populated* multiverse rather than the actions that
+/// built it: query APIs, persistence round-trips, diffs. `arb_multiverse`
+/// produces one directly—characters, branches, events, relationships, and
+/// memories of every provenance—valid by construction.
+///
+/// Internally it plays out stateful action seeds (so every action's
+/// preconditions hold) and then installs forged and compound memories through
+/// explicit `MemoryTransfer` events.
+#[derive(Debug, Clone)]
+pub struct MultiverseGenConfig {
+    /// How many characters start in the root timeline
+    pub characters: Range<usize>,
+    /// How many narrative actions to play out; each records about one event
+    pub actions: Range<usize>,
+    /// Upper bound on timelines, including the root
+    pub max_timelines: usize,
+    /// How many forged or compound memories to install afterwards
+    pub installed_memories: Range<usize>,
+    /// Whether to include explicit causality violations
+    pub include_causality_violations: bool,
+    /// Whether to branch timelines at all
+    pub include_branches: bool,
+    /// If set, the action mix comes from [`ActionWeights::with_chaos`] and this
+    /// is the chance each installed memory is forged. Otherwise actions are
+    /// uniform and forgeries are a coin toss
+    pub chaos: Option<f64>,
+}
+
+impl Default for MultiverseGenConfig {
+    fn default() -> Self {
+        MultiverseGenConfig {
+            characters: 2..10,
+            actions: 0..40,
+            max_timelines: 4,
+            installed_memories: 0..4,
+            include_causality_violations: true,
+            include_branches: true,
+            chaos: None,
+        }
+    }
+}
+
+impl MultiverseGenConfig {
+    /// Whether the config allows `action` to be applied to `multiverse`.
+    fn permits(&self, multiverse: &Multiverse, action: &NarrativeAction) -> bool {
+        match action {
+            // The cast is fixed up front
+            NarrativeAction::CreateCharacter { .. } => false,
+            NarrativeAction::BranchTimeline { .. } => {
+                self.include_branches && multiverse.timelines.len() < self.max_timelines
+            }
+            // Anchors end in a departure, which is a causality violation of its own
+            NarrativeAction::ViolateCausality { .. } | NarrativeAction::AnchorCharacter { .. } => {
+                self.include_causality_violations
+            }
+            _ => true,
+        }
+    }
+}
+
+/// Strategy for a populated multiverse that satisfies `validate_all_properties`.
+pub fn arb_multiverse(config: MultiverseGenConfig) -> impl Strategy<Value = Multiverse> {
+    let base = config.chaos.map_or_else(ActionWeights::default, ActionWeights::with_chaos);
+    let weights = ActionWeights {
+        create_character: 0,
+        branch_timeline: base.branch_timeline * config.include_branches as u32,
+        violate_causality: base.violate_causality * config.include_causality_violations as u32,
+        anchor_character: base.anchor_character * config.include_causality_violations as u32,
+        ..base
+    };
+    let forgery_chance = config.chaos.map_or(0.5, |chaos| chaos.clamp(0.0, 1.0));
+    let installs = prop::collection::vec(
+        (prop::bool::weighted(forgery_chance), prop::array::uniform3(any::<Index>()), any::<Index>()),
+        config.installed_memories.clone(),
+    );
+
+    (
+        config.characters.clone(),
+        prop::collection::vec(action_seed_strategy(weights), config.actions.clone()),
+        installs,
+    )
+        .prop_map(move |(characters, seeds, installs)| {
+            let mut multiverse = Multiverse::new();
+            let root = multiverse.root_timeline;
+            for i in 0..characters {
+                multiverse.create_character(format!("Character {}", i), root);
+            }
+
+            for seed in &seeds {
+                if let Some(action) = resolve_action_seed(&multiverse, seed) {
+                    // Weights can't express the timeline cap, so the config has the last word
+                    if config.permits(&multiverse, &action) {
+                        apply_narrative_action(&mut multiverse, &action);
+                    }
+                }
+            }
+
+            for (forged, picks, forger) in &installs {
+                install_memory(&mut multiverse, *forged, picks, forger);
+            }
+            multiverse
+        })
+}
+
+/// Strategy for a valid multiverse of at most `max_chars` characters and
+/// roughly `max_events` events, for fixtures that just need a clean corpus to
+/// query or export.
+///
+/// Only justified operations make it in: memories are witnessed by actual
+/// participants or installed by explicit transfer, the dead re-participate
+/// only after a resurrection, and every causality violation carries its
+/// mechanism. The result passes `validate_all_properties`.
+pub fn valid_multiverse_strategy(max_chars: usize, max_events: usize) -> impl Strategy<Value = Multiverse> {
+    arb_multiverse(MultiverseGenConfig {
+        characters: max_chars.min(1)..max_chars + 1,
+        actions: 0..max_events + 1,
+        // Installed memories each cost a transfer event; keep them a small share
+        installed_memories: 0..max_events / 10 + 1,
+        ..MultiverseGenConfig::default()
+    })
+}
+
+/// Installs a forged memory (or a compound blended from memories the holder
+/// already has) into a living character via an explicit transfer event.
+/// Forgeries are the work of a registered forger, picked by `forger`.
+fn install_memory(multiverse: &mut Multiverse, forged: bool, picks: &[Index; 3], forger: &Index) {
+    let [first, second, third] = picks;
+
+    let mut alive: Vec<CharacterId> = multiverse
+        .characters
+        .values()
+        .filter(|c| c.is_present())
+        .map(|c| c.id)
+        .collect();
+    alive.sort_by_key(|c| c.0);
+    let holder = match pick(&alive, first) {
+        Some(holder) => holder,
+        None => return,
+    };
+    let timeline = multiverse.characters[&holder].current_timeline;
+
+    let mut held: Vec<MemoryId> = multiverse.characters[&holder].memories.iter().copied().collect();
+    held.sort_by_key(|m| m.0);
+
+    let (event, provenance) = if forged || held.is_empty() {
+        // A forgery has to be *of* something in the holder's timeline, by someone the story knows
+        let forgers: Vec<&String> = multiverse.forger_registry.iter().collect();
+        match (pick(&multiverse.timelines[&timeline].events, second), pick(&forgers, forger)) {
+            (Some(event), Some(forger)) => (
+                event,
+                MemoryProvenance::Forged {
+                    forger: forger.clone(),
+                },
+            ),
+            _ => return,
+        }
+    } else {
+        let mut sources = vec![pick(&held, second).expect("held is non-empty")];
+        let other = pick(&held, third).expect("held is non-empty");
+        if !sources.contains(&other) {
+            sources.push(other);
+        }
+        let event = multiverse.memories[&sources[0]].event;
+        (event, MemoryProvenance::Compound { sources })
+    };
+
+    let memory = multiverse.create_memory(event, timeline, provenance);
+    multiverse.record_event(Event {
+        id: EventId(0),
+        timeline,
+        description: format!("A memory is installed in {}", holder),
+        participants: vec![holder].into_iter().collect(),
+        effects: vec![EventEffect::MemoryTransfer {
+            memory,
+            from: None,
+            to: holder,
+            exclusive: false,
+        }],
+        causality_violation: None,
+    });
+}
+
+/// ## Seeded Scenarios
+///
+/// Demos and game tools want "a random but reproducible story" without running
+/// proptest. `generate_scenario` builds one from a `u64` seed: the same seed and
+/// config always produce an identical multiverse.
+///
+/// A `StdRng` seeded from the seed is the only source of randomness. It places
+/// the branches and seeds the generator for each action's details, which are
+/// resolved and applied exactly as in stateful chaos testing.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScenarioConfig {
+    /// How many characters start in the root timeline, named after the protagonists
+    pub characters: usize,
+    /// How many narrative actions to apply after the cast; each records about one event
+    pub events: usize,
+    /// How many times to branch a timeline along the way
+    pub branches: usize,
+    /// From 0.0 to 1.0: how often the story kills, resurrects, and breaks
+    /// causality. See [`ActionWeights::with_chaos`]
+    pub chaos: f64,
+}
+
+impl Default for ScenarioConfig {
+    fn default() -> Self {
+        ScenarioConfig {
+            characters: 5,
+            events: 30,
+            branches: 1,
+            chaos: 0.3,
+        }
+    }
+}
+
+impl ScenarioConfig {
+    /// The action mix for this chaos level. The cast is fixed and branches are
+    /// placed separately, so neither comes from the mix.
+    fn weights(&self) -> ActionWeights {
+        ActionWeights {
+            create_character: 0,
+            branch_timeline: 0,
+            ..ActionWeights::with_chaos(self.chaos)
+        }
+    }
+}
+
+/// Builds a reproducible multiverse from `seed`. See [`ScenarioConfig`].
+///
+/// Branches need a parent with history, so one that falls before any event is
+/// deferred until one exists. An action with no valid target is redrawn, up to
+/// a bound, so a very dead cast may end with fewer than `config.events`.
+pub fn generate_scenario(seed: u64, config: ScenarioConfig) -> Multiverse {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut runner = TestRunner::new_with_rng(
+        Config::default(),
+        TestRng::from_seed(RngAlgorithm::ChaCha, &rng.random::<[u8; 32]>()),
+    );
+    let seeds = action_seed_strategy(config.weights());
+
+    let mut multiverse = Multiverse::new();
+    let root = multiverse.root_timeline;
+    let names = crate::protagonists::PROTAGONIST_NAMES;
+    for i in 0..config.characters {
+        let name = match i / names.len() {
+            0 => names[i].to_string(),
+            generation => format!("{} {}", names[i % names.len()], generation + 1),
+        };
+        apply_narrative_action(
+            &mut multiverse,
+            &NarrativeAction::CreateCharacter { name, timeline: root },
+        );
+    }
+
+    let mut branch_steps: Vec<usize> = (0..config.branches)
+        .map(|_| rng.random_range(0..config.events.max(1)))
+        .collect();
+    branch_steps.sort_unstable();
+    let mut scheduled = branch_steps.iter().peekable();
+    let mut pending_branches = 0;
+    let mut applied = 0;
+    let mut attempts = 0;
+
+    while applied < config.events && attempts < config.events * 4 {
+        while scheduled.next_if(|&&step| step <= applied).is_some() {
+            pending_branches += 1;
+        }
+        if pending_branches > 0 && branch_scenario_timeline(&mut multiverse, &mut rng) {
+            pending_branches -= 1;
+        }
+
+        attempts += 1;
+        let seed = seeds
+            .new_tree(&mut runner)
+            .expect("action seeds never reject")
+            .current();
+        if let Some(action) = resolve_action_seed(&multiverse, &seed) {
+            if apply_narrative_action(&mut multiverse, &action).is_applied() {
+                applied += 1;
+            }
+        }
+    }
+
+    // Branches scheduled at the very end, or deferred past it
+    pending_branches += scheduled.count();
+    while pending_branches > 0 && branch_scenario_timeline(&mut multiverse, &mut rng) {
+        pending_branches -= 1;
+    }
+    multiverse
+}
+
+/// Branches a random timeline that has events. Returns `false` if none has.
+fn branch_scenario_timeline(multiverse: &mut Multiverse, rng: &mut StdRng) -> bool {
+    let mut parents: Vec<TimelineId> = multiverse
+        .timelines
+        .values()
+        .filter(|t| !t.events.is_empty())
+        .map(|t| t.id)
+        .collect();
+    if parents.is_empty() {
+        return false;
+    }
+    parents.sort_by_key(|t| t.0);
+    let parent = parents[rng.random_range(0..parents.len())];
+
+    let mut residents: Vec<CharacterId> = multiverse
+        .characters
+        .values()
+        .filter(|c| c.is_present() && c.current_timeline == parent)
+        .map(|c| c.id)
+        .collect();
+    residents.sort_by_key(|c| c.0);
+    let migrants = residents.into_iter().filter(|_| rng.random_bool(0.5)).collect();
+    apply_narrative_action(multiverse, &NarrativeAction::BranchTimeline { parent, migrants });
+    true
+}
+
+/// ## Saving and Replaying Traces
+///
+/// A shrunk failure is most useful outside proptest: stepped through in a
+/// debugger, or played in the demo binary. `save_action_trace` writes an action
+/// sequence as JSON; `replay_action_trace` reads it back and plays it against an
+/// empty multiverse, validating every property after every step.
+///
+/// Traces always start from `Multiverse::new()`, so any setup (such as
+/// [`chaos_cast_actions`]) belongs in the trace as ordinary actions.
+pub fn save_action_trace(path: impl AsRef<Path>, actions: &[NarrativeAction]) -> io::Result<()> {
+    let path = path.as_ref();
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    let json = serde_json::to_string_pretty(actions).map_err(io::Error::other)?;
+    std::fs::write(path, json)
+}
+
+/// Loads a trace saved by [`save_action_trace`] and replays it with
+/// [`replay_and_validate`].
+pub fn replay_action_trace(
+    path: impl AsRef<Path>,
+) -> io::Result<(Multiverse, Option<PropertyViolation>)> {
+    let json = std::fs::read_to_string(path)?;
+    let actions: Vec<NarrativeAction> =
+        serde_json::from_str(&json).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    Ok(replay_and_validate(&actions))
+}
+
+/// Applies `actions` to an empty multiverse, validating after every step.
+///
+/// Stops at the first violation and returns the state at that point along
+/// with the violation, or the final state and `None` if every step passed.
+pub fn replay_and_validate(actions: &[NarrativeAction]) -> (Multiverse, Option<PropertyViolation>) {
+    let mut multiverse = Multiverse::new();
+    for action in actions {
+        apply_narrative_action(&mut multiverse, action);
+        if let Err(violation) = validate_all_properties(&multiverse) {
+            return (multiverse, Some(violation));
+        }
+    }
+    (multiverse, None)
+}
+
+/// What became of an action handed to [`apply_narrative_action`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum ActionOutcome {
+    /// The action took effect. Carries the event it recorded, or for actions
+    /// that don't record one (branching, witnessing), the event they build on
+    Applied(EventId),
+    /// A character was created; no event is recorded for arrivals
+    Created(CharacterId),
+    /// The action is well-formed, but the world isn't in a state where it applies
+    Skipped(SkipReason),
+    /// The action refers to something that doesn't exist
+    Rejected(MultiverseError),
+}
+
+impl ActionOutcome {
+    /// Whether the action changed the multiverse.
+    pub fn is_applied(&self) -> bool {
+        matches!(self, ActionOutcome::Applied(_) | ActionOutcome::Created(_))
+    }
+}
+
+/// Why a well-formed action didn't apply.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SkipReason {
+    /// The character is dead, and only resurrection reaches the dead
+    CharacterDead(CharacterId),
+    /// The character has been pulled back to their era, and nothing reaches them
+    Departed(CharacterId),
+    /// The character isn't in the timeline the action names
+    WrongTimeline {
+        character: CharacterId,
+        timeline: TimelineId,
+    },
+    /// Neither party can reach the other's timeline to trade
+    OutOfReach { from: CharacterId, to: CharacterId },
+    /// The seller doesn't have the memory they're trying to sell
+    NotHeld {
+        character: CharacterId,
+        memory: MemoryId,
+    },
+    /// The timeline has no events to branch from
+    EmptyTimeline(TimelineId),
+    /// The character didn't attend the event in that timeline
+    NotWitnessed {
+        event: EventId,
+        character: CharacterId,
+    },
+    /// The character can't share knowledge they don't have
+    Unknowing { character: CharacterId, flag: String },
+    /// The character can't lose an ability they don't have
+    LacksAbility {
+        character: CharacterId,
+        ability: AbilityKind,
+    },
+    /// The character is already anchored to an era
+    AlreadyAnchored(CharacterId),
+    /// A bootstrap needs someone present with LoopMemory, and nobody is
+    NoLooper(TimelineId),
+    /// The memory has faded too far to act on
+    Faded {
+        character: CharacterId,
+        memory: MemoryId,
+    },
+    /// The timeline's causality is already sound, with nothing to repair
+    AlreadyStable(TimelineId),
+    /// Nobody in the timeline feels anything the others would catch
+    NothingToSpread(TimelineId),
+    /// The character can't resolve a goal they don't hold
+    NoSuchGoal { character: CharacterId, goal: String },
+    /// The character already goes by the name they'd take
+    AlreadyNamed(CharacterId),
+    /// The action would break a rule the character is held to
+    BreaksInvariant {
+        character: CharacterId,
+        invariant: CharacterInvariant,
+    },
+}
+
+/// ## Applying Actions to Multiverse
+///
+/// This function takes a narrative action and applies it to a Multiverse,
+/// creating appropriate events and updating state.
+///
+/// This is where the "action interpreter" lives—it translates abstract
+/// actions into concrete state changes. Actions whose preconditions fail leave
+/// the multiverse untouched, and the returned [`ActionOutcome`] says why, so a
+/// test can tell "the invariant held" apart from "nothing happened."
+pub fn apply_narrative_action(multiverse: &mut Multiverse, action: &NarrativeAction) -> ActionOutcome {
+    match try_apply_narrative_action(multiverse, action) {
+        Ok(outcome) => outcome,
+        Err(error) => ActionOutcome::Rejected(error),
+    }
+}
+
+/// Applies `action` like [`apply_narrative_action`], and returns the events
+/// it recorded along with the outcome, in the order they were recorded
+/// (forced departures included).
+pub fn apply_narrative_action_recorded(
+    multiverse: &mut Multiverse,
+    action: &NarrativeAction,
+) -> (ActionOutcome, Vec<EventId>) {
+    let before = multiverse.next_event_id();
+    let outcome = apply_narrative_action(multiverse, action);
+    let recorded = (before.0..multiverse.next_event_id().0)
+        .map(EventId)
+        .filter(|id| multiverse.events.contains_key(id))
+        .collect();
+    (outcome, recorded)
+}
+
+/// Looks up a character, or rejects the action if there's no such character.
+fn character_of(multiverse: &Multiverse, id: CharacterId) -> Result<&Character, MultiverseError> {
+    multiverse
+        .characters
+        .get(&id)
+        .ok_or(MultiverseError::UnknownCharacter(id))
+}
+
+/// Rejects the action if the timeline doesn't exist.
+fn require_timeline(multiverse: &Multiverse, id: TimelineId) -> Result<(), MultiverseError> {
+    if multiverse.timelines.contains_key(&id) {
+        Ok(())
+    } else {
+        Err(MultiverseError::UnknownTimeline(id))
+    }
+}
+
+/// Why `c` can't act at all, if it can't: it must be alive and not departed.
+fn unable(c: &Character) -> Option<SkipReason> {
+    if !c.alive {
+        Some(SkipReason::CharacterDead(c.id))
+    } else if c.has_departed() {
+        Some(SkipReason::Departed(c.id))
+    } else {
+        None
+    }
+}
+
+/// Whether `c` is around and remembers the loop clearly enough to act on, as
+/// a bootstrap needs.
+fn loops(c: &Character, usable: f32) -> bool {
+    c.is_present() && c.loop_retention() > usable
+}
+
+/// Why `c` can't act in `timeline`, if it can't: it must be present and there.
+fn unable_to_act(c: &Character, timeline: TimelineId) -> Option<SkipReason> {
+    if let Some(reason) = unable(c) {
+        Some(reason)
+    } else if c.current_timeline != timeline {
+        Some(SkipReason::WrongTimeline {
+            character: c.id,
+            timeline,
+        })
+    } else {
+        None
+    }
+}
+
+fn try_apply_narrative_action(
+    multiverse: &mut Multiverse,
+    action: &NarrativeAction,
+) -> Result<ActionOutcome, MultiverseError> {
+    let applied = |event: EventId| Ok(ActionOutcome::Applied(event));
+    let skipped = |reason: SkipReason| Ok(ActionOutcome::Skipped(reason));
+
+    match action {
+        NarrativeAction::CreateCharacter { name, timeline } => {
+            // Only create if timeline exists
+            require_timeline(multiverse, *timeline)?;
+            Ok(ActionOutcome::Created(multiverse.create_character(name.clone(), *timeline)))
+        }
+
+        NarrativeAction::KillCharacter {
+            character,
+            timeline,
+        } => {
+            let c = character_of(multiverse, *character)?;
+            require_timeline(multiverse, *timeline)?;
+            if let Some(reason) = unable_to_act(c, *timeline) {
+                return skipped(reason);
+            }
+
+            // Create death event
+            applied(multiverse.record_event(Event {
+                id: EventId(0), // Will be overwritten
+                timeline: *timeline,
+                description: format!("Character {} dies", character),
+                participants: vec![*character].into_iter().collect(),
+                effects: vec![EventEffect::CharacterDeath {
+                    character: *character,
+                }],
+                causality_violation: None,
+            }))
+        }
+
+        NarrativeAction::ResurrectCharacter {
+            character,
+            timeline,
+            mechanism,
+        } => {
+            let c = character_of(multiverse, *character)?;
+            require_timeline(multiverse, *timeline)?;
+            if c.has_departed() {
+                return skipped(SkipReason::Departed(*character));
+            }
+            if c.current_timeline != *timeline {
+                return skipped(SkipReason::WrongTimeline {
+                    character: *character,
+                    timeline: *timeline,
+                });
+            }
+
+            applied(multiverse.record_event(Event {
+                id: EventId(0),
+                timeline: *timeline,
+                description: format!("Character {} is resurrected", character),
+                participants: vec![*character].into_iter().collect(),
+                effects: vec![EventEffect::CharacterResurrection {
+                    character: *character,
+                    mechanism: mechanism.clone(),
+                }],
+                causality_violation: None,
+            }))
+        }
+
+        NarrativeAction::ChangeRelationship {
+            char1,
+            char2,
+            new_state,
             timeline,
         } => {
-            if let Some(c) = multiverse.characters.get(character) {
-                if c.alive && c.current_timeline == *timeline && multiverse.timelines.contains_key(timeline) {
-                    let memory_id = multiverse.create_witnessed_memory(*event, *timeline, *character);
-                    if let Some(c_mut) = multiverse.characters.get_mut(character) {
-                        c_mut.memories.insert(memory_id);
-                    }
+            let (c1, c2) = (character_of(multiverse, *char1)?, character_of(multiverse, *char2)?);
+            if let Some(reason) = unable_to_act(c1, *timeline).or_else(|| unable_to_act(c2, *timeline)) {
+                return skipped(reason);
+            }
+
+            applied(multiverse.record_event(Event {
+                id: EventId(0),
+                timeline: *timeline,
+                description: format!("Relationship changes between {} and {}", char1, char2),
+                participants: vec![*char1, *char2].into_iter().collect(),
+                effects: vec![EventEffect::RelationshipChange {
+                    character1: *char1,
+                    character2: *char2,
+                    new_state: *new_state,
+                }],
+                causality_violation: None,
+            }))
+        }
+
+        NarrativeAction::NudgeAffinity {
+            char1,
+            char2,
+            delta,
+            timeline,
+        } => {
+            let (c1, c2) = (character_of(multiverse, *char1)?, character_of(multiverse, *char2)?);
+            if let Some(reason) = unable_to_act(c1, *timeline).or_else(|| unable_to_act(c2, *timeline)) {
+                return skipped(reason);
+            }
+
+            applied(multiverse.record_event(Event {
+                id: EventId(0),
+                timeline: *timeline,
+                description: format!("{} and {} warm or cool to each other", char1, char2),
+                participants: vec![*char1, *char2].into_iter().collect(),
+                effects: vec![EventEffect::AffinityShift {
+                    character1: *char1,
+                    character2: *char2,
+                    delta: *delta,
+                }],
+                causality_violation: None,
+            }))
+        }
+
+        NarrativeAction::RepairCausality { timeline, mechanism } => {
+            let stable = multiverse
+                .timelines
+                .get(timeline)
+                .ok_or(MultiverseError::UnknownTimeline(*timeline))?
+                .causality_stable;
+            let unresolved = multiverse.unresolved_violations(*timeline);
+            if stable && unresolved.is_empty() {
+                return skipped(SkipReason::AlreadyStable(*timeline));
+            }
+
+            for violation in unresolved {
+                multiverse.repair_causality(violation, mechanism)?;
+            }
+            applied(multiverse.stabilize_timeline(*timeline, mechanism)?)
+        }
+
+        NarrativeAction::ResolveGoal {
+            character,
+            goal,
+            success,
+        } => {
+            let c = character_of(multiverse, *character)?;
+            if let Some(reason) = unable(c) {
+                return skipped(reason);
+            }
+            if !c.emotional_state.goals.contains_key(goal) {
+                return skipped(SkipReason::NoSuchGoal {
+                    character: *character,
+                    goal: goal.clone(),
+                });
+            }
+
+            let outcome = if *success { "achieves" } else { "gives up on" };
+            applied(multiverse.record_event(Event {
+                id: EventId(0),
+                timeline: c.current_timeline,
+                description: format!("{} {} the goal '{}'", character, outcome, goal),
+                participants: vec![*character].into_iter().collect(),
+                effects: vec![EventEffect::GoalResolved {
+                    character: *character,
+                    goal: goal.clone(),
+                    success: *success,
+                }],
+                causality_violation: None,
+            }))
+        }
+
+        NarrativeAction::PropagateEmotions { timeline, strength } => {
+            match multiverse.propagate_emotions(*timeline, *strength)? {
+                Some(event) => applied(event),
+                None => skipped(SkipReason::NothingToSpread(*timeline)),
+            }
+        }
+
+        NarrativeAction::RenameCharacter { character, new_name } => {
+            let c = character_of(multiverse, *character)?;
+            if let Some(reason) = unable(c) {
+                return skipped(reason);
+            }
+            if c.name == *new_name {
+                return skipped(SkipReason::AlreadyNamed(*character));
+            }
+
+            applied(multiverse.record_event(Event {
+                id: EventId(0),
+                timeline: c.current_timeline,
+                description: format!("{} ({}) becomes {}", character, c.name, new_name),
+                participants: vec![*character].into_iter().collect(),
+                effects: vec![EventEffect::CharacterRename {
+                    character: *character,
+                    new_name: new_name.clone(),
+                }],
+                causality_violation: None,
+            }))
+        }
+
+        NarrativeAction::GrantKnowledge {
+            character,
+            flag,
+            timeline,
+        } => {
+            if let Some(reason) = unable_to_act(character_of(multiverse, *character)?, *timeline) {
+                return skipped(reason);
+            }
+
+            applied(multiverse.record_event(Event {
+                id: EventId(0),
+                timeline: *timeline,
+                description: format!("Knowledge {} granted to {}", flag, character),
+                participants: vec![*character].into_iter().collect(),
+                effects: vec![EventEffect::KnowledgeGained {
+                    character: *character,
+                    flag: flag.clone(),
+                }],
+                causality_violation: None,
+            }))
+        }
+
+        NarrativeAction::TradeMemory {
+            memory,
+            from,
+            to,
+            mechanism,
+        } => {
+            let (f, t) = (character_of(multiverse, *from)?, character_of(multiverse, *to)?);
+            if let Some(reason) = unable(f).or_else(|| unable(t)) {
+                return skipped(reason);
+            }
+            if !multiverse.can_trade_memory(*from, *to) {
+                return skipped(SkipReason::OutOfReach { from: *from, to: *to });
+            }
+            // A sale gives the memory up, and some never give one up
+            if multiverse.is_held_to(*from, CharacterInvariant::NeverLosesMemories) {
+                return skipped(SkipReason::BreaksInvariant {
+                    character: *from,
+                    invariant: CharacterInvariant::NeverLosesMemories,
+                });
+            }
+
+            // The trade happens in the recipient's timeline
+            let timeline = t.current_timeline;
+            let seller_timeline = f.current_timeline;
+
+            if !multiverse.memories.contains_key(memory) {
+                // A memory new to the multiverse comes into the seller's hands
+                // first. It's hearsay picked up on the market, not a scene
+                // anyone lived through, so it's a memory of nothing recorded
+                // and surfaces in the acquisition itself.
+                let acquisition = multiverse.record_event(Event {
+                    id: EventId(0),
+                    timeline: seller_timeline,
+                    description: format!("{} acquires memory {} to sell", from, memory),
+                    participants: vec![*from].into_iter().collect(),
+                    effects: vec![EventEffect::MemoryTransfer {
+                        memory: *memory,
+                        from: None,
+                        to: *from,
+                        exclusive: false,
+                    }],
+                    causality_violation: None,
+                });
+                let traded_memory = Memory {
+                    id: *memory,
+                    event: acquisition,
+                    content: MemoryContent::Abstract {
+                        gist: format!("word going round the {}", mechanism),
+                    },
+                    source_timeline: seller_timeline,
+                    provenance: MemoryProvenance::Traded {
+                        original_owner: *from,
+                        acquired_via: mechanism.clone(),
+                    },
+                    fidelity: 0.9,
+                };
+                multiverse.memories.insert(*memory, traded_memory);
+                // The scene may have used up the last of an anchored seller's time here
+                if multiverse.characters[from].has_departed() {
+                    return applied(acquisition);
+                }
+            } else if !multiverse.characters[from].memories.contains(memory) {
+                return skipped(SkipReason::NotHeld {
+                    character: *from,
+                    memory: *memory,
+                });
+            }
+
+            // A sale, not a copy: the seller gives the memory up. A seller
+            // reaching in from another timeline isn't there to take part; the
+            // transfer records their side.
+            let mut participants: HashSet<CharacterId> = HashSet::from([*to]);
+            if seller_timeline == timeline {
+                participants.insert(*from);
+            }
+            applied(multiverse.record_event(Event {
+                id: EventId(0),
+                timeline,
+                description: format!("Memory traded from {} to {}", from, to),
+                participants,
+                effects: vec![EventEffect::MemoryTransfer {
+                    memory: *memory,
+                    from: Some(*from),
+                    to: *to,
+                    exclusive: true,
+                }],
+                causality_violation: None,
+            }))
+        }
+
+        NarrativeAction::BranchTimeline { parent, migrants } => {
+            let parent_timeline = multiverse
+                .timelines
+                .get(parent)
+                .ok_or(MultiverseError::UnknownTimeline(*parent))?;
+            let divergence_event = match parent_timeline.events.last() {
+                Some(&event) => event,
+                None => return skipped(SkipReason::EmptyTimeline(*parent)),
+            };
+            // Only the living can make the crossing, and only from the parent;
+            // someone who lives everywhere can't leave anywhere
+            for migrant in migrants {
+                if let Some(reason) = unable_to_act(character_of(multiverse, *migrant)?, *parent) {
+                    return skipped(reason);
+                }
+                if multiverse.is_held_to(*migrant, CharacterInvariant::Omnipresent) {
+                    return skipped(SkipReason::BreaksInvariant {
+                        character: *migrant,
+                        invariant: CharacterInvariant::Omnipresent,
+                    });
                 }
             }
+
+            multiverse.create_timeline_branch_with(*parent, divergence_event, migrants)?;
+            applied(divergence_event)
+        }
+
+        NarrativeAction::CreateWitnessedMemory {
+            event,
+            character,
+            timeline,
+        } => {
+            // Only memories of events the character actually attended, in this timeline
+            let e = multiverse
+                .events
+                .get(event)
+                .ok_or(MultiverseError::UnknownEvent(*event))?;
+            let witnessed = e.timeline == *timeline && e.participants.contains(character);
+
+            if let Some(reason) = unable_to_act(character_of(multiverse, *character)?, *timeline) {
+                return skipped(reason);
+            }
+            if !witnessed {
+                return skipped(SkipReason::NotWitnessed {
+                    event: *event,
+                    character: *character,
+                });
+            }
+            if multiverse.is_held_to(*character, CharacterInvariant::NeverWitnesses) {
+                return skipped(SkipReason::BreaksInvariant {
+                    character: *character,
+                    invariant: CharacterInvariant::NeverWitnesses,
+                });
+            }
+
+            let memory_id = multiverse.create_witnessed_memory(*event, *timeline, *character);
+            if let Some(c_mut) = multiverse.characters.get_mut(character) {
+                c_mut.memories.insert(memory_id);
+            }
+            // A collective remembers with every body at once
+            if multiverse.collective_of(*character).is_some() {
+                return applied(multiverse.link_collective(&[*character])?);
+            }
+            applied(*event)
         }
 
         NarrativeAction::ViolateCausality {
             timeline,
             violation_type,
         } => {
-            if let Some(timeline_data) = multiverse.timelines.get_mut(timeline) {
-                timeline_data.causality_stable = false;
+            let timeline_data = multiverse
+                .timelines
+                .get(timeline)
+                .ok_or(MultiverseError::UnknownTimeline(*timeline))?;
+            // Whoever remembers the loop is there when the item turns up
+            let usable = multiverse.config.validation.min_usable_fidelity;
+            let mut participants = HashSet::new();
+            if let CausalityViolation::Bootstrap { .. } = violation_type {
+                participants = timeline_data
+                    .characters
+                    .iter()
+                    .copied()
+                    .filter(|c| {
+                        let c = multiverse.characters.get(c);
+                        c.is_some_and(|c| c.current_timeline == *timeline && loops(c, usable))
+                    })
+                    .collect();
+                if participants.is_empty() {
+                    return skipped(SkipReason::NoLooper(*timeline));
+                }
+            }
+            multiverse.timelines.get_mut(timeline).unwrap().causality_stable = false;
+
+            applied(multiverse.record_event(Event {
+                id: EventId(0),
+                timeline: *timeline,
+                description: "Causality violation occurs".to_string(),
+                participants,
+                effects: vec![],
+                causality_violation: Some(violation_type.clone()),
+            }))
+        }
+
+        NarrativeAction::GrantAbility {
+            character,
+            ability,
+            mechanism,
+        } => {
+            let c = character_of(multiverse, *character)?;
+            if let Some(reason) = unable(c) {
+                return skipped(reason);
+            }
 
-                multiverse.record_event(Event {
-                    id: EventId(0),
-                    timeline: *timeline,
-                    description: "Causality violation occurs".to_string(),
-                    participants: std::collections::HashSet::new(),
-                    effects: vec![],
-                    causality_violation: Some(violation_type.clone()),
+            applied(multiverse.record_event(Event {
+                id: EventId(0),
+                timeline: c.current_timeline,
+                description: format!("{} gains {:?} via {}", character, ability, mechanism),
+                participants: vec![*character].into_iter().collect(),
+                effects: vec![EventEffect::AbilityGained {
+                    character: *character,
+                    ability: ability.clone(),
+                    mechanism: mechanism.clone(),
+                }],
+                causality_violation: None,
+            }))
+        }
+
+        NarrativeAction::TriggerAppraisal { character, belief } => {
+            let c = character_of(multiverse, *character)?;
+            if let Some(reason) = unable(c) {
+                return skipped(reason);
+            }
+            // Anyone there who cares about them, for better or worse, looks on
+            let timeline = c.current_timeline;
+            let onlookers = multiverse.characters.values().filter(|o| {
+                o.current_timeline == timeline
+                    && o.is_present()
+                    && multiverse.relationship_between(o.id, *character) != RelationshipState::Neutral
+            });
+            let participants = onlookers.map(|o| o.id).chain([*character]).collect();
+
+            applied(multiverse.record_event(Event {
+                id: EventId(0),
+                timeline,
+                description: format!("{} appraises a new belief", character),
+                participants,
+                effects: vec![EventEffect::AppraisalTrigger {
+                    character: *character,
+                    belief: belief.clone(),
+                }],
+                causality_violation: None,
+            }))
+        }
+
+        NarrativeAction::AddCharacterGoal { character, goal } => {
+            let c = character_of(multiverse, *character)?;
+            if let Some(reason) = unable(c) {
+                return skipped(reason);
+            }
+
+            applied(multiverse.record_event(Event {
+                id: EventId(0),
+                timeline: c.current_timeline,
+                description: format!("{} takes up the goal '{}'", character, goal.name),
+                participants: vec![*character].into_iter().collect(),
+                effects: vec![EventEffect::AddGoal {
+                    character: *character,
+                    goal: goal.clone(),
+                }],
+                causality_violation: None,
+            }))
+        }
+
+        NarrativeAction::ShareKnowledge { from, to, flag } => {
+            let (f, t) = (character_of(multiverse, *from)?, character_of(multiverse, *to)?);
+            if let Some(reason) = unable(f).or_else(|| unable(t)) {
+                return skipped(reason);
+            }
+            if !f.knowledge_flags.contains(flag) {
+                return skipped(SkipReason::Unknowing {
+                    character: *from,
+                    flag: flag.clone(),
                 });
             }
+            // Face to face, or over the Lattice if both are linked
+            let linked = f.has_ability(AbilityKind::AnsibleLink) && t.has_ability(AbilityKind::AnsibleLink);
+            if f.current_timeline != t.current_timeline && !linked {
+                return skipped(SkipReason::OutOfReach { from: *from, to: *to });
+            }
+
+            // Over the Lattice the speaker stays where they are; the share records them
+            let mut participants: HashSet<CharacterId> = HashSet::from([*to]);
+            if f.current_timeline == t.current_timeline {
+                participants.insert(*from);
+            }
+            applied(multiverse.record_event(Event {
+                id: EventId(0),
+                timeline: t.current_timeline,
+                description: format!("{} tells {} about {}", from, to, flag),
+                participants,
+                effects: vec![EventEffect::KnowledgeShared {
+                    from: *from,
+                    to: *to,
+                    flag: flag.clone(),
+                }],
+                causality_violation: None,
+            }))
         }
 
-        NarrativeAction::GrantAbility { character, ability } => {
-            if let Some(c) = multiverse.characters.get_mut(character) {
-                if c.alive {
-                    c.abilities.insert(ability.clone());
-                }
+        NarrativeAction::DegradeMemories { character, amount } => {
+            let c = character_of(multiverse, *character)?;
+            if let Some(reason) = unable(c) {
+                return skipped(reason);
+            }
+
+            applied(multiverse.record_event(Event {
+                id: EventId(0),
+                timeline: c.current_timeline,
+                description: format!("{}'s memories fade", character),
+                participants: vec![*character].into_iter().collect(),
+                effects: vec![EventEffect::MemoryDegrade {
+                    character: *character,
+                    amount: *amount,
+                }],
+                causality_violation: None,
+            }))
+        }
+
+        NarrativeAction::WipeMemories { character } => {
+            let c = character_of(multiverse, *character)?;
+            if let Some(reason) = unable(c) {
+                return skipped(reason);
+            }
+
+            applied(multiverse.record_event(Event {
+                id: EventId(0),
+                timeline: c.current_timeline,
+                description: format!("{}'s memories are wiped", character),
+                participants: vec![*character].into_iter().collect(),
+                effects: vec![EventEffect::MemoryWipe {
+                    character: *character,
+                }],
+                causality_violation: None,
+            }))
+        }
+
+        NarrativeAction::LoseAbility { character, ability } => {
+            let c = character_of(multiverse, *character)?;
+            if let Some(reason) = unable(c) {
+                return skipped(reason);
+            }
+            if !c.has_ability(*ability) {
+                return skipped(SkipReason::LacksAbility {
+                    character: *character,
+                    ability: *ability,
+                });
+            }
+
+            applied(multiverse.record_event(Event {
+                id: EventId(0),
+                timeline: c.current_timeline,
+                description: format!("{} loses {:?}", character, ability),
+                participants: vec![*character].into_iter().collect(),
+                effects: vec![EventEffect::AbilityLost {
+                    character: *character,
+                    ability: *ability,
+                }],
+                causality_violation: None,
+            }))
+        }
+
+        NarrativeAction::AnchorCharacter { character, anchor } => {
+            let c = character_of(multiverse, *character)?;
+            if let Some(reason) = unable(c) {
+                return skipped(reason);
+            }
+            if c.temporal_anchor.is_some() {
+                return skipped(SkipReason::AlreadyAnchored(*character));
+            }
+            // An anchor pulls its holder out of the present, and some are everywhere in it
+            if multiverse.is_held_to(*character, CharacterInvariant::Omnipresent) {
+                return skipped(SkipReason::BreaksInvariant {
+                    character: *character,
+                    invariant: CharacterInvariant::Omnipresent,
+                });
+            }
+
+            applied(multiverse.record_event(Event {
+                id: EventId(0),
+                timeline: c.current_timeline,
+                description: format!("{} is displaced from the year {}", character, anchor.origin_era),
+                participants: vec![*character].into_iter().collect(),
+                effects: vec![EventEffect::AnchorGained {
+                    character: *character,
+                    anchor: anchor.clone(),
+                }],
+                causality_violation: None,
+            }))
+        }
+
+        NarrativeAction::RecallMemory { character, memory } => {
+            let c = character_of(multiverse, *character)?;
+            if let Some(reason) = unable(c) {
+                return skipped(reason);
+            }
+            if !c.memories.contains(memory) {
+                return skipped(SkipReason::NotHeld {
+                    character: *character,
+                    memory: *memory,
+                });
             }
+            let fidelity = multiverse.memories.get(memory).map_or(0.0, |m| m.fidelity);
+            if fidelity <= multiverse.config.validation.min_usable_fidelity {
+                return skipped(SkipReason::Faded {
+                    character: *character,
+                    memory: *memory,
+                });
+            }
+
+            applied(multiverse.record_event(Event {
+                id: EventId(0),
+                timeline: c.current_timeline,
+                description: format!("{} acts on memory {}", character, memory),
+                participants: vec![*character].into_iter().collect(),
+                effects: vec![EventEffect::MemoryRecall {
+                    character: *character,
+                    memory: *memory,
+                }],
+                causality_violation: None,
+            }))
         }
     }
 }
@@ -490,7 +3125,774 @@ mod tests {
             }
 
             // Multiverse should still be valid
-            assert!(multiverse.timelines.len() > 0);
+            assert!(!multiverse.timelines.is_empty());
+        }
+
+        #[test]
+        fn test_relationship_web_pairs_are_unique(
+            (num_chars, web) in (0usize..8).prop_flat_map(|n| (Just(n), relationship_web_strategy(n)))
+        ) {
+            let mut seen = std::collections::HashSet::new();
+            for (a, b, _) in &web {
+                prop_assert!(a.0 < b.0, "pair ({}, {}) is not ordered", a, b);
+                prop_assert!((b.0 as usize) < num_chars, "{} is out of range", b);
+                prop_assert!(seen.insert((*a, *b)), "pair ({}, {}) appears twice", a, b);
+            }
+            prop_assert!(web.len() <= num_chars * num_chars.saturating_sub(1) / 2);
+        }
+
+        #[test]
+        fn test_belief_matches_goal_names(
+            belief in belief_strategy(vec!["Survive".to_string(), "Return to Past".to_string()])
+        ) {
+            prop_assert_eq!(belief.affected_goal_names.len(), belief.goal_congruences.len());
+            prop_assert_eq!(belief.validate(), Ok(()));
+            prop_assert!(belief
+                .affected_goal_names
+                .iter()
+                .all(|n| n == "Survive" || n == "Return to Past"));
+        }
+    }
+
+    /// Two characters who met once, with a memory of the meeting: enough for
+    /// the `arb_*` strategies to draw every kind of reference from.
+    fn smoke_multiverse() -> Multiverse {
+        let mut multiverse = Multiverse::new();
+        let root = multiverse.root_timeline;
+        let vera = multiverse.create_character("Vera Kandros".to_string(), root);
+        let khelis = multiverse.create_character("Khelis Tev".to_string(), root);
+        let meeting = multiverse.record_event(Event {
+            id: EventId(0),
+            timeline: root,
+            description: "Vera meets Khelis in the Dark Spoke".to_string(),
+            participants: HashSet::from([vera, khelis]),
+            effects: vec![],
+            causality_violation: None,
+        });
+        let memory = multiverse.create_witnessed_memory(meeting, root, vera);
+        multiverse.characters.get_mut(&vera).unwrap().memories.insert(memory);
+        multiverse
+    }
+
+    proptest! {
+        #[test]
+        fn test_arb_character_is_valid(character in arb_character()) {
+            let mut multiverse = Multiverse::new();
+            let root = multiverse.root_timeline;
+            multiverse.timelines.get_mut(&root).unwrap().characters.insert(character.id);
+            let abilities: Vec<(CharacterId, Ability)> =
+                character.abilities.values().map(|a| (character.id, a.clone())).collect();
+            multiverse.characters.insert(character.id, character);
+            multiverse.grant_abilities(root, crate::protagonists::STARTING_ABILITIES, abilities);
+            prop_assert_eq!(validate_all_properties(&multiverse), Ok(()));
+        }
+
+        #[test]
+        fn test_arb_event_is_valid(
+            event in arb_event(&ArbBounds::from_multiverse(&smoke_multiverse()))
+        ) {
+            let mut multiverse = smoke_multiverse();
+            if event.causality_violation.is_some() {
+                multiverse.timelines.get_mut(&event.timeline).unwrap().causality_stable = false;
+            }
+            multiverse.record_event(event);
+            prop_assert_eq!(validate_all_properties(&multiverse), Ok(()));
+        }
+
+        #[test]
+        fn test_arb_memory_is_valid(
+            memory in arb_memory(&ArbBounds::from_multiverse(&smoke_multiverse()))
+        ) {
+            let mut multiverse = smoke_multiverse();
+            let id = MemoryId(100);
+            // A witness may hold their own memory without any further justification
+            if let MemoryProvenance::Witnessed { character } = memory.provenance {
+                multiverse.characters.get_mut(&character).unwrap().memories.insert(id);
+            }
+            multiverse.memories.insert(id, Memory { id, ..memory });
+            prop_assert_eq!(validate_all_properties(&multiverse), Ok(()));
+        }
+
+        #[test]
+        fn test_arb_timeline_is_valid(
+            timeline in arb_timeline(&ArbBounds::from_multiverse(&smoke_multiverse()))
+        ) {
+            let mut multiverse = smoke_multiverse();
+            let id = TimelineId(100);
+            multiverse.timelines.insert(id, Timeline { id, ..timeline });
+            prop_assert_eq!(validate_all_properties(&multiverse), Ok(()));
+        }
+    }
+
+    #[test]
+    fn test_strategy_covers_every_action() {
+        use proptest::strategy::ValueTree;
+        use proptest::test_runner::TestRunner;
+        use std::collections::HashSet;
+
+        let strategy = narrative_action_strategy();
+        let mut runner = TestRunner::deterministic();
+        let seen: HashSet<&'static str> = (0..1000)
+            .map(|_| strategy.new_tree(&mut runner).unwrap().current().name())
+            .collect();
+
+        for name in [
+            "CreateCharacter",
+            "KillCharacter",
+            "ResurrectCharacter",
+            "ChangeRelationship",
+            "GrantKnowledge",
+            "TradeMemory",
+            "BranchTimeline",
+            "CreateWitnessedMemory",
+            "ViolateCausality",
+            "GrantAbility",
+            "TriggerAppraisal",
+            "AddCharacterGoal",
+            "ShareKnowledge",
+            "DegradeMemories",
+            "WipeMemories",
+            "LoseAbility",
+            "AnchorCharacter",
+            "RecallMemory",
+        ] {
+            assert!(seen.contains(name), "{} never generated in 1000 actions", name);
+        }
+    }
+
+    #[test]
+    fn test_arb_multiverse_mixes_provenance() {
+        use proptest::strategy::ValueTree;
+        use proptest::test_runner::TestRunner;
+        use std::collections::HashSet;
+
+        let strategy = arb_multiverse(MultiverseGenConfig::default());
+        let mut runner = TestRunner::deterministic();
+        let mut seen = HashSet::new();
+        for _ in 0..64 {
+            let multiverse = strategy.new_tree(&mut runner).unwrap().current();
+            for memory in multiverse.memories.values() {
+                seen.insert(std::mem::discriminant(&memory.provenance));
+            }
+        }
+        assert_eq!(seen.len(), 4, "expected witnessed, traded, forged and compound memories");
+    }
+
+    #[test]
+    fn test_weighted_strategy_follows_weights() {
+        use proptest::strategy::ValueTree;
+        use proptest::test_runner::TestRunner;
+
+        // Only kills, plus the odd resurrection
+        let weights = ActionWeights {
+            create_character: 0,
+            kill_character: 9,
+            resurrect_character: 1,
+            change_relationship: 0,
+            grant_knowledge: 0,
+            trade_memory: 0,
+            branch_timeline: 0,
+            create_witnessed_memory: 0,
+            violate_causality: 0,
+            grant_ability: 0,
+            trigger_appraisal: 0,
+            add_character_goal: 0,
+            share_knowledge: 0,
+            degrade_memories: 0,
+            wipe_memories: 0,
+            lose_ability: 0,
+            anchor_character: 0,
+            recall_memory: 0,
+            nudge_affinity: 0,
+            repair_causality: 0,
+            resolve_goal: 0,
+            propagate_emotions: 0,
+            rename_character: 0,
+        };
+        let strategy = narrative_action_strategy_weighted(weights);
+        let mut runner = TestRunner::deterministic();
+        let names: Vec<&'static str> = (0..1000)
+            .map(|_| strategy.new_tree(&mut runner).unwrap().current().name())
+            .collect();
+
+        assert!(names.iter().all(|n| *n == "KillCharacter" || *n == "ResurrectCharacter"));
+        let kills = names.iter().filter(|n| **n == "KillCharacter").count();
+        assert!(kills > 800, "expected ~900 kills, got {}", kills);
+    }
+
+    #[test]
+    fn test_witnessed_memory_requires_attendance() {
+        let mut multiverse = Multiverse::new();
+        let timeline = multiverse.root_timeline;
+        let present = multiverse.create_character("Present".to_string(), timeline);
+        let absent = multiverse.create_character("Absent".to_string(), timeline);
+        let event = multiverse.record_event(Event {
+            id: EventId(0),
+            timeline,
+            description: "Something happens".to_string(),
+            participants: vec![present].into_iter().collect(),
+            effects: vec![],
+            causality_violation: None,
+        });
+
+        for character in [present, absent] {
+            apply_narrative_action(
+                &mut multiverse,
+                &NarrativeAction::CreateWitnessedMemory {
+                    event,
+                    character,
+                    timeline,
+                },
+            );
+        }
+
+        assert_eq!(multiverse.characters[&present].memories.len(), 1);
+        assert!(multiverse.characters[&absent].memories.is_empty());
+    }
+
+    #[test]
+    fn test_action_trace_round_trip() {
+        let root = Multiverse::new().root_timeline;
+        let vera = CharacterId(0);
+        let trace = vec![
+            NarrativeAction::CreateCharacter {
+                name: "Vera Kandros".to_string(),
+                timeline: root,
+            },
+            NarrativeAction::KillCharacter {
+                character: vera,
+                timeline: root,
+            },
+            // No mechanism: death finality should catch this step
+            NarrativeAction::ResurrectCharacter {
+                character: vera,
+                timeline: root,
+                mechanism: String::new(),
+            },
+            NarrativeAction::GrantKnowledge {
+                character: vera,
+                flag: "never_reached".to_string(),
+                timeline: root,
+            },
+        ];
+
+        let path = std::env::temp_dir().join(format!("propyarn-trace-{}.json", std::process::id()));
+        save_action_trace(&path, &trace).unwrap();
+        let (multiverse, violation) = replay_action_trace(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(violation.map(|v| v.property), Some("death_finality".to_string()));
+        // Replay stopped at the resurrection, before the knowledge grant
+        assert_eq!(multiverse.events.len(), 2);
+
+        let (_, violation) = replay_and_validate(&trace[..2]);
+        assert!(violation.is_none());
+    }
+
+    #[test]
+    fn test_memory_trade_reach() {
+        let mut multiverse = Multiverse::new();
+        let root = multiverse.root_timeline;
+        let vera = multiverse.create_character("Vera Kandros".to_string(), root);
+        let khelis = multiverse.create_character("Khelis Tev".to_string(), root);
+        let conductor = multiverse.create_character("The Conductor".to_string(), root);
+        multiverse
+            .characters
+            .get_mut(&conductor)
+            .unwrap()
+            .insert_ability(Ability::timeline_perception());
+
+        let scene = multiverse.record_event(Event {
+            id: EventId(0),
+            timeline: root,
+            description: "The market opens".to_string(),
+            participants: vec![vera, khelis, conductor].into_iter().collect(),
+            effects: vec![],
+            causality_violation: None,
+        });
+        let branch = multiverse.create_timeline_branch(root, scene);
+        multiverse.characters.get_mut(&khelis).unwrap().current_timeline = branch;
+        multiverse.characters.get_mut(&conductor).unwrap().current_timeline = branch;
+
+        let trade = |memory: u64, from: CharacterId, to: CharacterId| NarrativeAction::TradeMemory {
+            memory: MemoryId(memory),
+            from,
+            to,
+            mechanism: "Memory Market".to_string(),
+        };
+
+        // Same timeline: Khelis and the Conductor are both in the branch
+        assert!(multiverse.can_trade_memory(khelis, conductor));
+        apply_narrative_action(&mut multiverse, &trade(100, khelis, conductor));
+        assert!(multiverse.characters[&conductor].memories.contains(&MemoryId(100)));
+
+        // Across timelines, ordinary characters can't reach each other
+        assert!(!multiverse.can_trade_memory(vera, khelis));
+        apply_narrative_action(&mut multiverse, &trade(101, vera, khelis));
+        assert!(!multiverse.characters[&khelis].memories.contains(&MemoryId(101)));
+
+        // ...but the Conductor exists in every timeline
+        assert!(multiverse.can_trade_memory(vera, conductor));
+        apply_narrative_action(&mut multiverse, &trade(102, vera, conductor));
+        assert!(multiverse.characters[&conductor].memories.contains(&MemoryId(102)));
+
+        // A seer who only perceives sibling branches reaches just those
+        let mara = multiverse.create_character("Mara Vex".to_string(), branch);
+        multiverse.characters.get_mut(&mara).unwrap().insert_ability(Ability::TimelinePerception {
+            scope: PerceptionScope::SiblingBranches,
+        });
+        let sibling = multiverse.create_timeline_branch(root, scene);
+        multiverse.characters.get_mut(&vera).unwrap().current_timeline = sibling;
+        assert!(multiverse.can_trade_memory(mara, vera));
+        assert!(multiverse.can_trade_memory(vera, mara));
+        multiverse.characters.get_mut(&vera).unwrap().current_timeline = root;
+        assert!(!multiverse.can_trade_memory(mara, vera));
+        assert!(!multiverse.can_trade_memory(vera, mara));
+    }
+
+    #[test]
+    fn test_action_outcomes() {
+        let mut multiverse = Multiverse::new();
+        let root = multiverse.root_timeline;
+        let riven = match apply_narrative_action(
+            &mut multiverse,
+            &NarrativeAction::CreateCharacter {
+                name: "Riven Blackwood".to_string(),
+                timeline: root,
+            },
+        ) {
+            ActionOutcome::Created(id) => id,
+            other => panic!("expected a new character, got {:?}", other),
+        };
+        let kill = NarrativeAction::KillCharacter {
+            character: riven,
+            timeline: root,
+        };
+
+        // Nothing to branch from yet
+        assert_eq!(
+            apply_narrative_action(
+                &mut multiverse,
+                &NarrativeAction::BranchTimeline {
+                    parent: root,
+                    migrants: vec![],
+                },
+            ),
+            ActionOutcome::Skipped(SkipReason::EmptyTimeline(root))
+        );
+
+        let death = match apply_narrative_action(&mut multiverse, &kill) {
+            ActionOutcome::Applied(event) => event,
+            other => panic!("expected the death to apply, got {:?}", other),
+        };
+        assert!(multiverse.events.contains_key(&death));
+
+        // Dying twice is a no-op, not an error
+        let events = multiverse.events.len();
+        assert_eq!(
+            apply_narrative_action(&mut multiverse, &kill),
+            ActionOutcome::Skipped(SkipReason::CharacterDead(riven))
+        );
+        assert_eq!(multiverse.events.len(), events);
+
+        // Someone who never existed can't die at all
+        let ghost = CharacterId(99);
+        assert_eq!(
+            apply_narrative_action(
+                &mut multiverse,
+                &NarrativeAction::KillCharacter {
+                    character: ghost,
+                    timeline: root,
+                },
+            ),
+            ActionOutcome::Rejected(MultiverseError::UnknownCharacter(ghost))
+        );
+    }
+
+    #[test]
+    fn test_every_failed_precondition_is_named() {
+        let mut multiverse = Multiverse::new();
+        let root = multiverse.root_timeline;
+        let vera = multiverse.create_character("Vera Kandros".to_string(), root);
+        let kael = multiverse.create_character("Kael Thornwick".to_string(), root);
+        let nameless = multiverse.create_character("Nameless".to_string(), root);
+        let scene = multiverse.record_event(Event {
+            id: EventId(0),
+            timeline: root,
+            description: "Vera folds the Errant Promise out of the Dark Spoke".to_string(),
+            participants: HashSet::from([vera]),
+            effects: vec![],
+            causality_violation: None,
+        });
+        let branch = multiverse.create_timeline_branch_with(root, scene, &[kael]).unwrap();
+        let faded = multiverse.create_witnessed_memory(scene, root, vera);
+        multiverse.characters.get_mut(&vera).unwrap().memories.insert(faded);
+        multiverse.memories.get_mut(&faded).unwrap().fidelity = 0.0;
+        let anchor = TemporalAnchor {
+            origin_era: -1000,
+            stability: 1.0,
+        };
+        multiverse.characters.get_mut(&nameless).unwrap().temporal_anchor = Some(anchor.clone());
+
+        let events = multiverse.events.len();
+        let mut outcome = |action: NarrativeAction| apply_narrative_action(&mut multiverse, &action);
+        let skipped = ActionOutcome::Skipped;
+        assert_eq!(
+            outcome(NarrativeAction::KillCharacter {
+                character: kael,
+                timeline: root,
+            }),
+            skipped(SkipReason::WrongTimeline {
+                character: kael,
+                timeline: root,
+            })
+        );
+        assert_eq!(
+            outcome(NarrativeAction::TradeMemory {
+                memory: MemoryId(500),
+                from: vera,
+                to: kael,
+                mechanism: "Memory Cartels".to_string(),
+            }),
+            skipped(SkipReason::OutOfReach { from: vera, to: kael })
+        );
+        assert_eq!(
+            outcome(NarrativeAction::RecallMemory {
+                character: vera,
+                memory: MemoryId(77),
+            }),
+            skipped(SkipReason::NotHeld {
+                character: vera,
+                memory: MemoryId(77),
+            })
+        );
+        assert_eq!(
+            outcome(NarrativeAction::RecallMemory {
+                character: vera,
+                memory: faded,
+            }),
+            skipped(SkipReason::Faded {
+                character: vera,
+                memory: faded,
+            })
+        );
+        assert_eq!(
+            outcome(NarrativeAction::CreateWitnessedMemory {
+                event: scene,
+                character: nameless,
+                timeline: root,
+            }),
+            skipped(SkipReason::NotWitnessed {
+                event: scene,
+                character: nameless,
+            })
+        );
+        assert_eq!(
+            outcome(NarrativeAction::ShareKnowledge {
+                from: vera,
+                to: nameless,
+                flag: "precursor_map".to_string(),
+            }),
+            skipped(SkipReason::Unknowing {
+                character: vera,
+                flag: "precursor_map".to_string(),
+            })
+        );
+        assert_eq!(
+            outcome(NarrativeAction::LoseAbility {
+                character: vera,
+                ability: AbilityKind::LoopMemory,
+            }),
+            skipped(SkipReason::LacksAbility {
+                character: vera,
+                ability: AbilityKind::LoopMemory,
+            })
+        );
+        assert_eq!(
+            outcome(NarrativeAction::ViolateCausality {
+                timeline: branch,
+                violation_type: CausalityViolation::Bootstrap {
+                    mechanism: "Time-gun".to_string(),
+                    looped_item: "Time-gun schematics".to_string(),
+                },
+            }),
+            skipped(SkipReason::NoLooper(branch))
+        );
+        assert_eq!(
+            outcome(NarrativeAction::AnchorCharacter {
+                character: nameless,
+                anchor: anchor.clone(),
+            }),
+            skipped(SkipReason::AlreadyAnchored(nameless))
+        );
+        assert_eq!(
+            outcome(NarrativeAction::CreateCharacter {
+                name: "Kor-Valeth".to_string(),
+                timeline: TimelineId(99),
+            }),
+            ActionOutcome::Rejected(MultiverseError::UnknownTimeline(TimelineId(99)))
+        );
+        assert_eq!(
+            outcome(NarrativeAction::CreateWitnessedMemory {
+                event: EventId(999),
+                character: vera,
+                timeline: root,
+            }),
+            ActionOutcome::Rejected(MultiverseError::UnknownEvent(EventId(999)))
+        );
+        assert_eq!(
+            outcome(NarrativeAction::RepairCausality {
+                timeline: root,
+                mechanism: "Conductor's intervention".to_string(),
+            }),
+            skipped(SkipReason::AlreadyStable(root))
+        );
+        assert_eq!(
+            outcome(NarrativeAction::ResolveGoal {
+                character: vera,
+                goal: "Ring Survives".to_string(),
+                success: true,
+            }),
+            skipped(SkipReason::NoSuchGoal {
+                character: vera,
+                goal: "Ring Survives".to_string(),
+            })
+        );
+        assert_eq!(
+            outcome(NarrativeAction::PropagateEmotions { timeline: root, strength: 0.5 }),
+            skipped(SkipReason::NothingToSpread(root))
+        );
+        assert_eq!(
+            outcome(NarrativeAction::RenameCharacter {
+                character: vera,
+                new_name: "Vera Kandros".to_string(),
+            }),
+            skipped(SkipReason::AlreadyNamed(vera))
+        );
+
+        // Nameless's time here runs out, and nothing reaches them after
+        multiverse.characters.get_mut(&nameless).unwrap().temporal_anchor = Some(TemporalAnchor {
+            stability: 0.0,
+            ..anchor
+        });
+        assert_eq!(
+            apply_narrative_action(
+                &mut multiverse,
+                &NarrativeAction::KillCharacter {
+                    character: nameless,
+                    timeline: root,
+                },
+            ),
+            skipped(SkipReason::Departed(nameless))
+        );
+        assert_eq!(multiverse.events.len(), events);
+    }
+
+    #[test]
+    fn test_registered_invariants_turn_actions_down() {
+        let mut multiverse = Multiverse::new();
+        let root = multiverse.root_timeline;
+        let khelis = multiverse.create_character("Khelis Tev".to_string(), root);
+        let cartographer = multiverse.create_character("The Cartographer".to_string(), root);
+        let conductor = multiverse.create_character("The Conductor".to_string(), root);
+        multiverse.register_character_invariant(khelis, CharacterInvariant::NeverWitnesses);
+        multiverse.register_character_invariant(cartographer, CharacterInvariant::NeverLosesMemories);
+        multiverse.register_character_invariant(conductor, CharacterInvariant::Omnipresent);
+        let survey = multiverse.record_event(Event {
+            id: EventId(0),
+            timeline: root,
+            description: "The Cartographer charts the Ring with Khelis in tow".to_string(),
+            participants: HashSet::from([khelis, cartographer, conductor]),
+            effects: vec![],
+            causality_violation: None,
+        });
+        let chart = multiverse.create_witnessed_memory(survey, root, cartographer);
+        multiverse.characters.get_mut(&cartographer).unwrap().memories.insert(chart);
+
+        let events = multiverse.events.len();
+        let mut outcome = |action: NarrativeAction| apply_narrative_action(&mut multiverse, &action);
+        let breaks = |character, invariant| ActionOutcome::Skipped(SkipReason::BreaksInvariant { character, invariant });
+        assert_eq!(
+            outcome(NarrativeAction::CreateWitnessedMemory {
+                event: survey,
+                character: khelis,
+                timeline: root,
+            }),
+            breaks(khelis, CharacterInvariant::NeverWitnesses)
+        );
+        assert_eq!(
+            outcome(NarrativeAction::TradeMemory {
+                memory: chart,
+                from: cartographer,
+                to: khelis,
+                mechanism: "Memory Market".to_string(),
+            }),
+            breaks(cartographer, CharacterInvariant::NeverLosesMemories)
+        );
+        assert_eq!(
+            outcome(NarrativeAction::BranchTimeline {
+                parent: root,
+                migrants: vec![khelis, conductor],
+            }),
+            breaks(conductor, CharacterInvariant::Omnipresent)
+        );
+        assert_eq!(
+            outcome(NarrativeAction::AnchorCharacter {
+                character: conductor,
+                anchor: TemporalAnchor {
+                    origin_era: -1000,
+                    stability: 1.0,
+                },
+            }),
+            breaks(conductor, CharacterInvariant::Omnipresent)
+        );
+        assert_eq!(multiverse.events.len(), events);
+        assert_eq!(validate_all_properties(&multiverse), Ok(()));
+    }
+
+    #[test]
+    fn test_generate_scenario_is_reproducible() {
+        let config = ScenarioConfig {
+            characters: 15,
+            events: 40,
+            branches: 3,
+            chaos: 0.5,
+        };
+        // Hash sets serialize in arbitrary order, so compare after a round trip
+        let through_serde = |multiverse: &Multiverse| -> Multiverse {
+            Multiverse::load_from_json(&multiverse.to_json().unwrap()).unwrap()
+        };
+
+        for seed in 0..8 {
+            let scenario = generate_scenario(seed, config.clone());
+            assert_eq!(through_serde(&scenario), generate_scenario(seed, config.clone()));
+
+            assert_eq!(scenario.characters.len(), 15);
+            assert!(scenario.characters.values().any(|c| c.name == "Vera Kandros 2"));
+            assert_eq!(scenario.timelines.len(), 4);
+            assert_eq!(validate_all_properties(&scenario), Ok(()));
+        }
+        assert_ne!(generate_scenario(1, config.clone()), generate_scenario(2, config));
+
+        // Without chaos, nobody dies and causality holds
+        let calm = generate_scenario(7, ScenarioConfig { chaos: 0.0, ..ScenarioConfig::default() });
+        assert!(calm.characters.values().all(|c| c.alive));
+        assert!(calm.events.values().all(|e| e.causality_violation.is_none()));
+    }
+
+    #[test]
+    fn test_action_one_liners() {
+        use NarrativeAction::*;
+        let (t, vera, riven) = (TimelineId(0), CharacterId(0), CharacterId(3));
+        let belief = Belief::new(1.0, None, vec!["Survive".to_string()], vec![-0.5], true).unwrap();
+        let cases = [
+            (CreateCharacter { name: "Vera".to_string(), timeline: t }, r#"Create "Vera" @ Timeline#0"#),
+            (KillCharacter { character: riven, timeline: t }, "Kill Char#3 @ Timeline#0"),
+            (
+                ResurrectCharacter { character: riven, timeline: t, mechanism: "Living Gate".to_string() },
+                "Resurrect Char#3 @ Timeline#0 via Living Gate",
+            ),
+            (
+                ChangeRelationship { char1: vera, char2: riven, new_state: RelationshipState::Allied, timeline: t },
+                "Make Char#0 and Char#3 Allied @ Timeline#0",
+            ),
+            (
+                GrantKnowledge { character: vera, flag: "gate_location".to_string(), timeline: t },
+                r#"Teach Char#0 "gate_location" @ Timeline#0"#,
+            ),
+            (
+                TradeMemory { memory: MemoryId(2), from: vera, to: riven, mechanism: "Cartel".to_string() },
+                "Trade Memory#2 from Char#0 to Char#3 via Cartel",
+            ),
+            (BranchTimeline { parent: t, migrants: vec![] }, "Branch Timeline#0"),
+            (BranchTimeline { parent: t, migrants: vec![vera, riven] }, "Branch Timeline#0 taking Char#0, Char#3"),
+            (
+                CreateWitnessedMemory { event: EventId(4), character: vera, timeline: t },
+                "Witness Event#4 as Char#0 @ Timeline#0",
+            ),
+            (
+                ViolateCausality {
+                    timeline: t,
+                    violation_type: CausalityViolation::EffectBeforeCause { mechanism: "Time-gun".to_string() },
+                },
+                "Violate causality @ Timeline#0: EffectBeforeCause via Time-gun",
+            ),
+            (
+                GrantAbility { character: riven, ability: Ability::causality_hacking(), mechanism: "Lux".to_string() },
+                "Grant CausalityHacking to Char#3 via Lux",
+            ),
+            (TriggerAppraisal { character: vera, belief }, r#"Appraise for Char#0: "Survive" -0.50"#),
+            (
+                AddCharacterGoal { character: vera, goal: Goal::new("Survive".to_string(), 0.8, true) },
+                r#"Give Char#0 goal "Survive" (+0.80)"#,
+            ),
+            (
+                ShareKnowledge { from: vera, to: riven, flag: "gate_location".to_string() },
+                r#"Share "gate_location" from Char#0 to Char#3"#,
+            ),
+            (DegradeMemories { character: vera, amount: 0.25 }, "Degrade memories of Char#0 by 0.25"),
+            (WipeMemories { character: vera }, "Wipe memories of Char#0"),
+            (
+                LoseAbility { character: riven, ability: AbilityKind::CausalityHacking },
+                "Strip CausalityHacking from Char#3",
+            ),
+            (
+                AnchorCharacter { character: vera, anchor: TemporalAnchor { origin_era: -1000, stability: 0.5 } },
+                "Anchor Char#0 to era -1000 (0.50)",
+            ),
+            (RecallMemory { character: vera, memory: MemoryId(2) }, "Recall Memory#2 as Char#0"),
+            (
+                NudgeAffinity { char1: vera, char2: riven, delta: -0.5, timeline: t },
+                "Nudge Char#0 and Char#3 by -0.50 @ Timeline#0",
+            ),
+            (RepairCausality { timeline: t, mechanism: "Conductor".to_string() }, "Repair Timeline#0 via Conductor"),
+            (
+                ResolveGoal { character: vera, goal: "Survive".to_string(), success: false },
+                r#"Abandon "Survive" for Char#0"#,
+            ),
+            (PropagateEmotions { timeline: t, strength: 0.5 }, "Spread feelings @ Timeline#0 (0.50)"),
+            (RenameCharacter { character: riven, new_name: "Ash".to_string() }, r#"Rename Char#3 to "Ash""#),
+        ];
+        for (action, expected) in &cases {
+            assert_eq!(action.to_string(), *expected);
         }
+        let covered: HashSet<&str> = cases.iter().map(|(action, _)| action.name()).collect();
+        assert_eq!(covered, NarrativeAction::NAMES.iter().copied().collect());
+
+        // With a multiverse to hand, characters go by name; strangers keep their ids
+        let mut multiverse = Multiverse::new();
+        let vera = multiverse.create_character("Vera Kandros".to_string(), t);
+        let kill = KillCharacter { character: vera, timeline: t };
+        assert_eq!(multiverse.describe_action(&kill), "Kill Vera Kandros @ Timeline#0");
+        let share = ShareKnowledge { from: vera, to: riven, flag: "gate_location".to_string() };
+        assert_eq!(multiverse.describe_action(&share), r#"Share "gate_location" from Vera Kandros to Char#3"#);
+    }
+
+    // `cargo test --features bincode bincode -- --nocapture` for the sizes
+    #[cfg(feature = "bincode")]
+    #[test]
+    fn test_bincode_round_trip() {
+        let config = ScenarioConfig {
+            characters: 13,
+            events: 1000,
+            branches: 5,
+            chaos: 0.5,
+        };
+        let mut scenario = generate_scenario(3, config);
+        let bytes = scenario.to_bincode().unwrap();
+        let mut loaded = Multiverse::from_bincode(&bytes).unwrap();
+        assert_eq!(loaded, scenario);
+        assert_eq!(validate_all_properties(&loaded), Ok(()));
+
+        // The id counters came along: both hand out the same next ids
+        let root = scenario.root_timeline;
+        assert_eq!(
+            loaded.create_character("Nameless".to_string(), root),
+            scenario.create_character("Nameless".to_string(), root)
+        );
+
+        let json = scenario.to_json().unwrap();
+        assert!(bytes.len() < json.len());
+        assert!(matches!(Multiverse::from_bincode(&bytes[..bytes.len() / 2]), Err(LoadError::Bincode(_))));
     }
 }
@@ -0,0 +1,173 @@
+//! # Inference: Putting Two and Two Together
+//!
+//! Some knowledge follows from other knowledge. Anyone who knows what the Ring
+//! is *for* starts to suspect the Gate Cult; anyone who suspects the Cult and
+//! has watched a Gate rewrite someone knows what the Cult does with memories.
+//! Writing every one of those conclusions into every scene by hand is how a
+//! character ends up knowing the premises and never the conclusion.
+//!
+//! [`KnowledgeRules`] holds rules of the form "these flags imply that one", and
+//! [`Multiverse::apply_inference`] grants each character whatever follows from
+//! what they know. The conclusions arrive as ordinary
+//! [`EventEffect::KnowledgeGained`] effects, so knowledge flag propagation holds
+//! for them like any other flag, and the story records when each one was drawn.
+//!
+//! Rules can feed each other, in any order and even in cycles: derivation runs
+//! until nothing new follows, and a flag is only ever derived once.
+
+use crate::narrative_core::*;
+use std::collections::HashSet;
+
+/// One rule: knowing every flag in `if_flags` implies knowing `then_flag`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct KnowledgeRule {
+    pub if_flags: Vec<String>,
+    pub then_flag: String,
+}
+
+/// A set of [`KnowledgeRule`]s; see the module docs.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct KnowledgeRules {
+    rules: Vec<KnowledgeRule>,
+}
+
+impl KnowledgeRules {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the rule that knowing all of `if_flags` implies `then_flag`.
+    pub fn rule(mut self, if_flags: &[&str], then_flag: &str) -> Self {
+        self.rules.push(KnowledgeRule {
+            if_flags: if_flags.iter().map(|f| f.to_string()).collect(),
+            then_flag: then_flag.to_string(),
+        });
+        self
+    }
+
+    pub fn rules(&self) -> &[KnowledgeRule] {
+        &self.rules
+    }
+
+    /// Everything that follows from `known` and isn't already in it, in the
+    /// order it was derived. Runs the rules to a fixpoint, so conclusions can
+    /// be premises of other rules.
+    pub fn derive(&self, known: &HashSet<String>) -> Vec<String> {
+        let mut known = known.clone();
+        let mut derived = Vec::new();
+        loop {
+            let before = derived.len();
+            for rule in &self.rules {
+                if !known.contains(&rule.then_flag) && rule.if_flags.iter().all(|f| known.contains(f)) {
+                    known.insert(rule.then_flag.clone());
+                    derived.push(rule.then_flag.clone());
+                }
+            }
+            // Every pass adds a new flag or ends it, and there are only so many
+            if derived.len() == before {
+                return derived;
+            }
+        }
+    }
+}
+
+impl Multiverse {
+    /// Grants every character present (see [`Character::is_present`]) what
+    /// follows from their knowledge under `rules`, recording one event per
+    /// character who learns anything, in their current timeline. Returns those
+    /// events in character order; applying the same rules again finds nothing.
+    ///
+    /// Conclusions aren't checked against what the character already knows:
+    /// rules that derive one of a pair of mutually exclusive flags are caught
+    /// like any other contradiction.
+    pub fn apply_inference(&mut self, rules: &KnowledgeRules) -> Vec<EventId> {
+        let mut present: Vec<&Character> = self.characters.values().filter(|c| c.is_present()).collect();
+        present.sort_by_key(|c| c.id);
+        let conclusions: Vec<(CharacterId, TimelineId, Vec<String>)> = present
+            .into_iter()
+            .map(|c| (c.id, c.current_timeline, rules.derive(&c.knowledge_flags)))
+            .filter(|(_, _, derived)| !derived.is_empty())
+            .collect();
+
+        conclusions
+            .into_iter()
+            .map(|(character, timeline, derived)| {
+                self.record_event(Event {
+                    id: EventId(0),
+                    timeline,
+                    description: format!("{} puts two and two together", character),
+                    participants: HashSet::from([character]),
+                    effects: derived
+                        .into_iter()
+                        .map(|flag| EventEffect::KnowledgeGained { character, flag })
+                        .collect(),
+                    causality_violation: None,
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::properties::validate_all_properties;
+
+    #[test]
+    fn test_two_rules_chain_to_a_third_flag() {
+        let mut multiverse = Multiverse::new();
+        let root = multiverse.root_timeline;
+        let [vera, khelis] =
+            ["Vera Kandros", "Khelis Tev"].map(|name| multiverse.create_character(name.to_string(), root));
+        let learns = |multiverse: &mut Multiverse, character, flags: &[&str]| {
+            multiverse.record_event(Event {
+                id: EventId(0),
+                timeline: root,
+                description: "A long night in the Dark Spoke".to_string(),
+                participants: HashSet::from([character]),
+                effects: flags
+                    .iter()
+                    .map(|flag| EventEffect::KnowledgeGained {
+                        character,
+                        flag: flag.to_string(),
+                    })
+                    .collect(),
+                causality_violation: None,
+            });
+        };
+        learns(&mut multiverse, vera, &["knows_ring_purpose", "saw_gate_rewrite"]);
+        learns(&mut multiverse, khelis, &["saw_gate_rewrite"]);
+
+        // Listed backwards, so the chain needs a second pass
+        let rules = KnowledgeRules::new()
+            .rule(&["suspects_gate_cult", "saw_gate_rewrite"], "knows_cult_rewrites_memories")
+            .rule(&["knows_ring_purpose"], "suspects_gate_cult");
+        let events = multiverse.apply_inference(&rules);
+
+        assert_eq!(events.len(), 1);
+        let effects = &multiverse.events[&events[0]].effects;
+        let derived: Vec<&str> = effects
+            .iter()
+            .filter_map(|e| match e {
+                EventEffect::KnowledgeGained { character, flag } if *character == vera => Some(flag.as_str()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(derived, vec!["suspects_gate_cult", "knows_cult_rewrites_memories"]);
+        assert!(multiverse.characters[&vera].knowledge_flags.contains("knows_cult_rewrites_memories"));
+        // Khelis has seen a rewrite, but doesn't know what the Ring is for
+        assert_eq!(multiverse.characters[&khelis].knowledge_flags.len(), 1);
+        assert_eq!(validate_all_properties(&multiverse), Ok(()));
+
+        // Nothing more follows
+        assert!(multiverse.apply_inference(&rules).is_empty());
+    }
+
+    #[test]
+    fn test_cyclic_rules_reach_a_fixpoint() {
+        let rules = KnowledgeRules::new().rule(&["a"], "b").rule(&["b"], "a").rule(&["a", "b"], "c");
+        let known = HashSet::from(["a".to_string()]);
+        assert_eq!(rules.derive(&known), vec!["b", "c"]);
+        assert!(rules.derive(&HashSet::new()).is_empty());
+    }
+}
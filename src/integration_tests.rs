@@ -22,6 +22,7 @@
 //!
 //! And verify that narrative invariants hold across ALL randomly-generated scenarios.
 
+use crate::chaos::format_shrink_trace;
 use crate::generators::*;
 use crate::narrative_core::*;
 use crate::properties::*;
@@ -32,7 +33,8 @@ proptest! {
     // ## Test: Memory Trading with the Memory Cartels
     //
     // Scenario: Khelis Tev trades memories in the Dark Spoke.
-    // Property: All traded memories must have explicit provenance.
+    // Property: All traded memories must have explicit provenance, and each is
+    // sold once: afterwards the customer has it and Khelis doesn't.
     #[test]
     fn test_memory_cartel_trading(
         num_trades in 1usize..20,
@@ -46,11 +48,11 @@ proptest! {
         let customer = multiverse.create_character("Customer".to_string(), timeline);
 
         // Perform memory trades
-        for i in 0..num_trades.min(memory_ids.len()) {
+        for memory in memory_ids.iter().take(num_trades) {
             apply_narrative_action(
                 &mut multiverse,
                 &NarrativeAction::TradeMemory {
-                    memory: memory_ids[i],
+                    memory: *memory,
                     from: khelis,
                     to: customer,
                     mechanism: "Memory Market".to_string(),
@@ -60,6 +62,12 @@ proptest! {
 
         // Validate: all memory trades must be justified
         prop_assert!(validate_all_properties(&multiverse).is_ok());
+
+        // A repeated id is a memory already sold, so Khelis has nothing to sell the second time
+        let sold: std::collections::HashSet<MemoryId> =
+            memory_ids.iter().take(num_trades).copied().collect();
+        prop_assert_eq!(&multiverse.characters[&customer].memories, &sold);
+        prop_assert!(multiverse.characters[&khelis].memories.is_empty());
     }
 }
 
@@ -77,10 +85,19 @@ proptest! {
         // Create Vera in root timeline
         let vera = multiverse.create_character("Vera Kandros".to_string(), root);
 
-        // Grant Vera timeline perception ability
-        if let Some(v) = multiverse.characters.get_mut(&vera) {
-            v.abilities.insert(Ability::TimelinePerception);
-        }
+        // Fold Drive exposure grants Vera timeline perception
+        multiverse.record_event(Event {
+            id: EventId(0),
+            timeline: root,
+            description: "Vera is caught in a Fold Drive causality bubble".to_string(),
+            participants: vec![vera].into_iter().collect(),
+            effects: vec![EventEffect::AbilityGained {
+                character: vera,
+                ability: Ability::timeline_perception(),
+                mechanism: "Fold Drive exposure".to_string(),
+            }],
+            causality_violation: None,
+        });
 
         // Vera makes decisions that branch timelines
         let mut current_timeline = root;
@@ -95,13 +112,10 @@ proptest! {
                 causality_violation: None,
             });
 
-            // Branch timeline
-            let new_timeline = multiverse.create_timeline_branch(current_timeline, event_id);
-
-            // Update Vera's timeline
-            if let Some(v) = multiverse.characters.get_mut(&vera) {
-                v.current_timeline = new_timeline;
-            }
+            // Branch timeline, taking Vera across
+            let new_timeline = multiverse
+                .create_timeline_branch_with(current_timeline, event_id, &[vera])
+                .unwrap();
 
             current_timeline = new_timeline;
         }
@@ -192,12 +206,23 @@ proptest! {
         // Create Riven
         let riven = multiverse.create_character("Riven Blackwood".to_string(), timeline);
 
-        // Grant causality hacking ability
-        if let Some(r) = multiverse.characters.get_mut(&riven) {
-            r.abilities.insert(Ability::CausalityHacking);
-        }
+        // Riven bonds with the Precursor revolver
+        multiverse.record_event(Event {
+            id: EventId(0),
+            timeline,
+            description: "Riven takes up the Precursor revolver".to_string(),
+            participants: vec![riven].into_iter().collect(),
+            effects: vec![EventEffect::AbilityGained {
+                character: riven,
+                ability: Ability::causality_hacking(),
+                mechanism: "Precursor Time-Weapon".to_string(),
+            }],
+            causality_violation: None,
+        });
 
-        // Riven fires time-gun multiple times
+        // Riven fires time-gun multiple times, and each shot strains the Ring further
+        let mut incoherence = multiverse.incoherence_level();
+        prop_assert_eq!(incoherence, 0.0);
         for i in 0..num_shots {
             // Mark timeline as unstable
             if let Some(t) = multiverse.timelines.get_mut(&timeline) {
@@ -214,7 +239,11 @@ proptest! {
                     mechanism: "Precursor Time-Weapon".to_string(),
                 }),
             });
+            prop_assert!(multiverse.incoherence_level() > incoherence);
+            incoherence = multiverse.incoherence_level();
         }
+        prop_assert!(multiverse.is_ring_collapsing(num_shots as f64));
+        prop_assert!(!multiverse.is_ring_collapsing(num_shots as f64 + 1.0));
 
         // Validate: causality violations are justified
         prop_assert!(validate_all_properties(&multiverse).is_ok());
@@ -280,6 +309,99 @@ proptest! {
     }
 }
 
+#[cfg(test)]
+proptest! {
+    // Fifty-odd characters means over a thousand relationship events per case
+    #![proptest_config(ProptestConfig::with_cases(32))]
+
+    // ## Test: Social Networks at Scale
+    //
+    // Scenario: A crowd of fifty or more, related at random, installed through
+    // ordinary relationship changes.
+    // Property: Both sides of every relationship agree with the event that set
+    // it, and the ally and enemy queries see exactly the generated topology.
+    #[test]
+    fn test_random_network_is_consistent(
+        (num_characters, network) in (50usize..64, 0.0f64..=1.0).prop_flat_map(|(n, density)| {
+            (Just(n), relationship_network_strategy(n, density))
+        })
+    ) {
+        let mut multiverse = Multiverse::new();
+        let timeline = multiverse.root_timeline;
+        for i in 0..num_characters {
+            multiverse.create_character(format!("Crowd #{}", i), timeline);
+        }
+
+        let outcomes = install_relationship_network(&mut multiverse, timeline, &network);
+        prop_assert!(outcomes.iter().all(ActionOutcome::is_applied));
+        prop_assert_eq!(prop_relationship_consistency(&multiverse), Ok(()));
+
+        for (a, b, state) in &network {
+            prop_assert_eq!(multiverse.characters[a].relationships.get(b), Some(state));
+            prop_assert_eq!(multiverse.characters[b].relationships.get(a), Some(state));
+        }
+        let allies: usize = multiverse.characters.keys().map(|c| multiverse.allies_of(*c).len()).sum();
+        let enemies: usize = multiverse.characters.keys().map(|c| multiverse.enemies_of(*c).len()).sum();
+        prop_assert_eq!(allies, 2 * network.iter().filter(|(_, _, s)| *s > RelationshipState::Neutral).count());
+        prop_assert_eq!(enemies, 2 * network.iter().filter(|(_, _, s)| *s < RelationshipState::Neutral).count());
+    }
+
+    // With no chance relationships, allied cliques are all there is: being
+    // allies is an equivalence, and nobody has an enemy
+    #[test]
+    fn test_allied_cliques_partition_the_cast(
+        network in (1usize..6).prop_flat_map(|cliques| {
+            relationship_network_strategy_with(50, 0.0, NetworkClustering::AlliedCliques(cliques))
+        })
+    ) {
+        let mut multiverse = Multiverse::new();
+        let timeline = multiverse.root_timeline;
+        let crowd: Vec<CharacterId> = (0..50)
+            .map(|i| multiverse.create_character(format!("Crowd #{}", i), timeline))
+            .collect();
+        install_relationship_network(&mut multiverse, timeline, &network);
+        prop_assert_eq!(validate_all_properties(&multiverse), Ok(()));
+
+        let circle = |c: CharacterId| {
+            let mut circle = multiverse.allies_of(c);
+            circle.push(c);
+            circle.sort();
+            circle
+        };
+        for member in &crowd {
+            prop_assert!(multiverse.enemies_of(*member).is_empty());
+            for ally in multiverse.allies_of(*member) {
+                prop_assert_eq!(circle(ally), circle(*member));
+            }
+        }
+    }
+
+    // Two camps, hostile across the divide: an enemy's enemy is never an enemy
+    #[test]
+    fn test_hostile_bipartition_has_no_enemy_triangles(
+        network in (0.2f64..=1.0).prop_flat_map(|density| {
+            relationship_network_strategy_with(50, density, NetworkClustering::HostileBipartition)
+        })
+    ) {
+        let mut multiverse = Multiverse::new();
+        let timeline = multiverse.root_timeline;
+        let crowd: Vec<CharacterId> = (0..50)
+            .map(|i| multiverse.create_character(format!("Crowd #{}", i), timeline))
+            .collect();
+        install_relationship_network(&mut multiverse, timeline, &network);
+        prop_assert_eq!(validate_all_properties(&multiverse), Ok(()));
+
+        for member in &crowd {
+            let enemies = multiverse.enemies_of(*member);
+            for enemy in &enemies {
+                let shared: Vec<CharacterId> =
+                    multiverse.enemies_of(*enemy).into_iter().filter(|e| enemies.contains(e)).collect();
+                prop_assert!(shared.is_empty(), "{}, {}, and {:?} are mutual enemies", member, enemy, shared);
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 proptest! {
     // ## Test: Knowledge Propagation Through the Ansible Lattice
@@ -317,8 +439,9 @@ proptest! {
                 timeline,
                 description: format!("Corvus shares {} with recipient", flag),
                 participants: vec![corvus, recipient].into_iter().collect(),
-                effects: vec![EventEffect::KnowledgeGained {
-                    character: recipient,
+                effects: vec![EventEffect::KnowledgeShared {
+                    from: corvus,
+                    to: recipient,
                     flag: flag.clone(),
                 }],
                 causality_violation: None,
@@ -339,52 +462,571 @@ proptest! {
     }
 }
 
+#[cfg(test)]
+proptest! {
+    // ## Test: The Living Gate Takes Its Payment
+    //
+    // Scenario: Yash-Tel travels with Vera, then passes through the Living Gate,
+    // which takes every memory Yash-Tel carries as its toll.
+    // Property: Afterwards Yash-Tel holds no memories from before the Gate, and
+    // smuggling one back in without a transfer is caught.
+    #[test]
+    fn test_living_gate_erases_memories(journeys in 1usize..6) {
+        let mut multiverse = Multiverse::new();
+        let timeline = multiverse.root_timeline;
+        let char_ids = crate::protagonists::create_thirteen_protagonists(&mut multiverse);
+        let vera = char_ids[0];
+        let yash = char_ids[5];
+
+        let mut before_gate = Vec::new();
+        for i in 0..journeys {
+            let event = multiverse.record_event(Event {
+                id: EventId(0),
+                timeline,
+                description: format!("Yash-Tel and Vera cross the Ring, leg #{}", i),
+                participants: vec![yash, vera].into_iter().collect(),
+                effects: vec![],
+                causality_violation: None,
+            });
+            let memory = multiverse.create_witnessed_memory(event, timeline, yash);
+            multiverse.characters.get_mut(&yash).unwrap().memories.insert(memory);
+            before_gate.push(memory);
+        }
+
+        multiverse.record_event(Event {
+            id: EventId(0),
+            timeline,
+            description: "The Living Gate takes its payment from Yash-Tel".to_string(),
+            participants: vec![yash].into_iter().collect(),
+            effects: vec![EventEffect::MemoryWipe { character: yash }],
+            causality_violation: None,
+        });
+
+        prop_assert!(multiverse.characters[&yash].memories.is_empty());
+        prop_assert_eq!(validate_all_properties(&multiverse), Ok(()));
+
+        // A memory slipped back in with no transfer event violates the wipe
+        multiverse.characters.get_mut(&yash).unwrap().memories.insert(before_gate[0]);
+        prop_assert!(prop_memory_wipe_final(&multiverse).is_err());
+    }
+}
+
+#[cfg(test)]
+proptest! {
+    // ## Test: Kor-Valeth's Temporal Anchor Gives Way
+    //
+    // Scenario: Kor-Valeth, exiled from 1,000 years in the past, fights alongside the
+    // other protagonists until the past reclaims them.
+    // Property: Once pulled back, Kor-Valeth never appears in the timeline again.
+    #[test]
+    fn test_kor_valeth_pulled_back(extra_scenes in 0usize..5) {
+        let mut multiverse = Multiverse::new();
+        let timeline = multiverse.root_timeline;
+        let char_ids = crate::protagonists::create_thirteen_protagonists(&mut multiverse);
+        let kor = char_ids[10];
+        let vera = char_ids[0];
+
+        // Kor-Valeth fights until their anchor gives out
+        let mut scenes = 0;
+        while multiverse.remaining_stability(kor).unwrap() > 0.0 {
+            multiverse.record_event(Event {
+                id: EventId(0),
+                timeline,
+                description: format!("Kor-Valeth fights beside Vera, battle #{}", scenes),
+                participants: vec![kor, vera].into_iter().collect(),
+                effects: vec![],
+                causality_violation: None,
+            });
+            scenes += 1;
+            prop_assert!(scenes <= 20, "anchor never gave way");
+        }
+
+        prop_assert!(!multiverse.timelines[&timeline].characters.contains(&kor));
+        let departed = multiverse.events.values().any(|e| {
+            e.effects.iter().any(|effect| {
+                matches!(effect, EventEffect::TemporalDeparture { character } if *character == kor)
+            })
+        });
+        prop_assert!(departed);
+
+        // The story goes on without them
+        for i in 0..extra_scenes {
+            multiverse.record_event(Event {
+                id: EventId(0),
+                timeline,
+                description: format!("Vera mourns the lost warrior #{}", i),
+                participants: vec![vera].into_iter().collect(),
+                effects: vec![],
+                causality_violation: None,
+            });
+        }
+
+        prop_assert!(validate_all_properties(&multiverse).is_ok());
+    }
+}
+
 #[cfg(test)]
 proptest! {
     // ## Comprehensive Chaos Test: Random Action Sequences
     //
-    // This test generates completely random sequences of narrative actions
-    // and verifies that ALL properties hold throughout.
+    // This test generates random sequences of narrative actions and verifies
+    // that ALL properties hold throughout. Actions come from the stateful
+    // generator, so they target characters and timelines that actually exist in
+    // a state where the action makes sense—nothing is silently skipped.
     //
     // This is the ultimate stress test—if properties hold here, they hold everywhere.
     #[test]
     fn test_random_narrative_sequences(
-        actions in prop::collection::vec(narrative_action_strategy(), 10..50)
+        actions in valid_action_sequence_strategy(10..50)
     ) {
-        let mut multiverse = Multiverse::new();
-
-        // Create the 13 protagonists
-        let _vera = multiverse.create_character("Vera Kandros".to_string(), multiverse.root_timeline);
-        let _khelis = multiverse.create_character("Khelis Tev".to_string(), multiverse.root_timeline);
-        let _saros = multiverse.create_character("Dr. Elian Saros".to_string(), multiverse.root_timeline);
-        let _nameless = multiverse.create_character("Nameless".to_string(), multiverse.root_timeline);
-        let _corvus = multiverse.create_character("Corvus Shal".to_string(), multiverse.root_timeline);
-        let _yash = multiverse.create_character("Yash-Tel".to_string(), multiverse.root_timeline);
-        let _riven = multiverse.create_character("Riven Blackwood".to_string(), multiverse.root_timeline);
-        let _cartographer = multiverse.create_character("The Cartographer".to_string(), multiverse.root_timeline);
-        let _synthesis = multiverse.create_character("Synthesis".to_string(), multiverse.root_timeline);
-        let _mara = multiverse.create_character("Mara Vex".to_string(), multiverse.root_timeline);
-        let _kor = multiverse.create_character("Kor-Valeth".to_string(), multiverse.root_timeline);
-        let _lux = multiverse.create_character("Dr. Theo Lux".to_string(), multiverse.root_timeline);
-        let _conductor = multiverse.create_character("The Conductor".to_string(), multiverse.root_timeline);
+        let mut multiverse = chaos_cast_multiverse();
+        let (mut applied, mut skipped) = (0, 0);
 
         // Apply random actions
-        for action in &actions {
-            apply_narrative_action(&mut multiverse, action);
+        for (i, action) in actions.iter().enumerate() {
+            if apply_narrative_action(&mut multiverse, action).is_applied() {
+                applied += 1;
+            } else {
+                skipped += 1;
+            }
 
             // After EVERY action, properties must hold
             // This is the key insight: invariants are ALWAYS true, not just at endpoints
             if let Err(e) = validate_all_properties(&multiverse) {
-                // If a property fails, proptest will shrink to minimal failing case
-                panic!("Property violation after action {:?}: {}", action, e);
+                // If a property fails, proptest will shrink to minimal failing case.
+                // Each shrink step overwrites the saved trace, so it ends up minimal.
+                let trace = save_failure_trace("random_narrative_sequences", &actions);
+                let steps: Vec<NarrativeAction> =
+                    chaos_cast_actions().into_iter().chain(actions[..=i].iter().cloned()).collect();
+                panic!(
+                    "Property violation after action {}: {}\n{}\n(trace saved to {})",
+                    multiverse.describe_action(action),
+                    e.narrate(&multiverse),
+                    format_shrink_trace(&steps),
+                    trace.display()
+                );
             }
         }
 
         // Final validation
         prop_assert!(validate_all_properties(&multiverse).is_ok());
+        prop_assert_eq!(skipped, 0, "stateful generation produced skipped actions");
+        prop_assert!(
+            applied as f64 >= MIN_APPLIED_FRACTION * actions.len() as f64,
+            "only {} of {} actions applied",
+            applied,
+            actions.len()
+        );
+    }
+}
+
+#[cfg(test)]
+proptest! {
+    // Each case is a thousand actions, so a handful of cases is plenty
+    #![proptest_config(ProptestConfig::with_cases(4))]
+
+    // ## Stress Test: A Thousand Actions on a Budget
+    //
+    // Validating after every action is quadratic over a long story, so this
+    // validates at checkpoints instead, timing every property as it goes. A run
+    // over budget fails with the per-property breakdown, which names the
+    // property to optimize. Ignored by default; run it with
+    // `cargo test stress -- --ignored`, ideally with `--release`.
+    #[test]
+    #[ignore]
+    fn test_stress_long_sequences(
+        actions in valid_action_sequence_strategy(1000..1200)
+    ) {
+        let mut multiverse = chaos_cast_multiverse();
+        let mut timings = PropertyTimings::new();
+        let start = std::time::Instant::now();
+
+        for (i, action) in actions.iter().enumerate() {
+            apply_narrative_action(&mut multiverse, action);
+            if (i + 1) % STRESS_CHECKPOINT == 0 || i + 1 == actions.len() {
+                let report = validate_all_properties_timed(&multiverse);
+                timings.record(&report);
+                if let Some(e) = report.first_violation() {
+                    let trace = save_failure_trace("stress_long_sequences", &actions);
+                    panic!(
+                        "Property violation by action {} of {}: {} (trace saved to {})",
+                        i + 1,
+                        actions.len(),
+                        e,
+                        trace.display()
+                    );
+                }
+            }
+        }
+
+        let elapsed = start.elapsed();
+        prop_assert!(
+            elapsed <= STRESS_BUDGET,
+            "{} actions took {:?}, over the {:?} budget. Time per property:\n{}\nOn the final multiverse:\n{}",
+            actions.len(),
+            elapsed,
+            STRESS_BUDGET,
+            timings,
+            validate_all_properties_timed(&multiverse)
+        );
+    }
+}
+
+/// A Dark Spoke house rule, not one of the built-ins: Khelis Tev never
+/// carries more than `limit` memories at once.
+#[cfg(test)]
+fn khelis_memory_limit(limit: usize) -> impl Fn(&Multiverse) -> Result<(), PropertyViolation> + Send + Sync {
+    move |multiverse| {
+        let held = multiverse
+            .characters
+            .values()
+            .filter(|c| c.name == "Khelis Tev")
+            .map(|c| c.memories.len())
+            .max()
+            .unwrap_or(0);
+        if held > limit {
+            return Err(PropertyViolation::new(
+                "khelis_memory_limit",
+                format!("Khelis Tev holds {} memories, over the limit of {}", held, limit),
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+proptest! {
+    // ## Custom Invariants: House Rules in the Chaos Test
+    //
+    // A game registers rules of its own next to the built-ins, and the chaos
+    // test checks them after every action like any other property.
+    #[test]
+    fn test_registered_house_rules_hold(
+        actions in valid_action_sequence_strategy_weighted(ActionWeights::memory_heavy(), 10..50)
+    ) {
+        let mut registry = PropertyRegistry::new();
+        registry.register("khelis_memory_limit", khelis_memory_limit(20));
+
+        let mut multiverse = chaos_cast_multiverse();
+        for action in &actions {
+            apply_narrative_action(&mut multiverse, action);
+            prop_assert_eq!(registry.validate(&multiverse), Ok(()));
+        }
     }
 }
 
+/// The thirteen, built from their profiles, invariants and all.
+#[cfg(test)]
+fn profiled_cast() -> Multiverse {
+    let mut multiverse = Multiverse::new();
+    crate::protagonists::CastBuilder::thirteen().build(&mut multiverse).expect("the default profiles are valid");
+    multiverse
+}
+
+#[cfg(test)]
+proptest! {
+    // ## Weighted Chaos Presets
+    //
+    // Uniform action mixes spread effort thinly across every property. Each
+    // preset below skews generation toward the actions that stress one class of
+    // invariant, and checks that class after every step on top of the full suite.
+
+    // Lots of trades and witnessed memories: memory provenance and knowledge
+    #[test]
+    fn test_memory_heavy_sequences(
+        actions in valid_action_sequence_strategy_weighted(ActionWeights::memory_heavy(), 10..50)
+    ) {
+        let mut multiverse = chaos_cast_multiverse();
+        for action in &actions {
+            apply_narrative_action(&mut multiverse, action);
+            prop_assert_eq!(prop_memory_consistency(&multiverse), Ok(()), "after {:?}", action);
+            prop_assert_eq!(validate_all_properties(&multiverse), Ok(()), "after {:?}", action);
+        }
+    }
+
+    // Lots of kills and resurrections: death finality
+    #[test]
+    fn test_lethal_sequences(
+        actions in valid_action_sequence_strategy_weighted(ActionWeights::lethal(), 10..50)
+    ) {
+        let mut multiverse = chaos_cast_multiverse();
+        for action in &actions {
+            apply_narrative_action(&mut multiverse, action);
+            prop_assert_eq!(prop_death_finality(&multiverse), Ok(()), "after {:?}", action);
+            prop_assert_eq!(validate_all_properties(&multiverse), Ok(()), "after {:?}", action);
+        }
+    }
+
+    // Lots of branching and causality violations: timeline structure
+    #[test]
+    fn test_timeline_chaos_sequences(
+        actions in valid_action_sequence_strategy_weighted(ActionWeights::timeline_chaos(), 10..50)
+    ) {
+        let mut multiverse = chaos_cast_multiverse();
+        for action in &actions {
+            apply_narrative_action(&mut multiverse, action);
+            prop_assert_eq!(prop_causality_justification(&multiverse), Ok(()), "after {:?}", action);
+            prop_assert_eq!(validate_all_properties(&multiverse), Ok(()), "after {:?}", action);
+        }
+        // However hard generation leans on branching, nesting stays bounded
+        for timeline in multiverse.timelines.keys() {
+            prop_assert!(multiverse.branch_depth(*timeline) <= CHAOS_MAX_BRANCH_DEPTH);
+        }
+    }
+
+    // Any branch can be cut out and shared: whatever happened to its characters
+    // outside it is undone, so the copy validates, and it loads back unchanged
+    #[test]
+    fn test_every_subtree_stands_alone(
+        actions in valid_action_sequence_strategy_weighted(ActionWeights::timeline_chaos(), 10..50)
+    ) {
+        let mut multiverse = chaos_cast_multiverse();
+        for action in &actions {
+            apply_narrative_action(&mut multiverse, action);
+        }
+        for timeline in multiverse.timelines.keys() {
+            let subtree = multiverse.extract_subtree(*timeline);
+            prop_assert_eq!(validate_all_properties(&subtree), Ok(()), "cut at {}", timeline);
+            let loaded = Multiverse::load_from_json(&subtree.to_json().unwrap());
+            prop_assert_eq!(loaded.as_ref().ok(), Some(&subtree), "cut at {}", timeline);
+        }
+    }
+
+    // Anyone can split off a parallel self, travellers included: the copy
+    // only takes along what it can perceive from where it starts
+    #[test]
+    fn test_clones_of_travellers_validate(
+        actions in valid_action_sequence_strategy_weighted(ActionWeights::timeline_chaos(), 10..50)
+    ) {
+        let mut multiverse = chaos_cast_multiverse();
+        for action in &actions {
+            apply_narrative_action(&mut multiverse, action);
+        }
+        let mut travellers: Vec<CharacterId> = multiverse
+            .characters
+            .values()
+            .filter(|c| c.is_present() && !c.timeline_history.is_empty())
+            .map(|c| c.id)
+            .collect();
+        travellers.sort();
+        for traveller in travellers {
+            multiverse.clone_character(traveller, "(Future)").unwrap();
+            multiverse.clone_character_with_relationships(traveller, "(Parallel)").unwrap();
+            prop_assert_eq!(validate_all_properties(&multiverse), Ok(()), "after cloning {}", traveller);
+        }
+    }
+
+    // A cast built from the profiles is held to each protagonist's own rules,
+    // and the engine turns down whatever would break them
+    #[test]
+    fn test_profiled_cast_keeps_its_invariants(
+        actions in valid_action_sequence_strategy_from(profiled_cast(), ActionWeights::default(), 10..50)
+    ) {
+        let mut multiverse = profiled_cast();
+        for action in &actions {
+            apply_narrative_action(&mut multiverse, action);
+            prop_assert_eq!(prop_character_invariants(&multiverse), Ok(()), "after {:?}", action);
+            prop_assert_eq!(validate_all_properties(&multiverse), Ok(()), "after {:?}", action);
+        }
+    }
+
+    // Chaos 0.0: relationships, knowledge, and memories only. Nothing may
+    // leave a timeline causality-unstable
+    #[test]
+    fn test_low_chaos_sequences(
+        actions in valid_action_sequence_strategy_weighted(ActionWeights::cozy(), 10..50)
+    ) {
+        let mut multiverse = chaos_cast_multiverse();
+        for action in &actions {
+            apply_narrative_action(&mut multiverse, action);
+            prop_assert_eq!(validate_all_properties(&multiverse), Ok(()), "after {:?}", action);
+        }
+        for timeline in multiverse.timelines.values() {
+            prop_assert!(timeline.causality_stable, "{} became unstable", timeline.id);
+        }
+    }
+
+    // Chaos 1.0: kills, violations, and branches dominate, and every property
+    // still has to hold
+    #[test]
+    fn test_max_chaos_sequences(
+        actions in valid_action_sequence_strategy_weighted(ActionWeights::incoherence(), 10..50)
+    ) {
+        let mut multiverse = chaos_cast_multiverse();
+        for action in &actions {
+            apply_narrative_action(&mut multiverse, action);
+            prop_assert_eq!(validate_all_properties(&multiverse), Ok(()), "after {:?}", action);
+        }
+    }
+
+    // Lots of goals and appraisals: emotional state validity under saturation
+    #[test]
+    fn test_emotional_sequences(
+        actions in valid_action_sequence_strategy_weighted(ActionWeights::emotional(), 10..50)
+    ) {
+        let mut multiverse = chaos_cast_multiverse();
+        for action in &actions {
+            apply_narrative_action(&mut multiverse, action);
+            prop_assert_eq!(prop_emotional_state_validity(&multiverse), Ok(()), "after {:?}", action);
+            prop_assert_eq!(validate_all_properties(&multiverse), Ok(()), "after {:?}", action);
+        }
+    }
+}
+
+#[cfg(test)]
+proptest! {
+    // ## Sanity Check: Generated Multiverses Are Valid
+    //
+    // `arb_multiverse` promises a multiverse that passes the full property suite
+    // by construction, within the configured bounds. Everything built on top of
+    // it relies on that promise.
+    #[test]
+    fn test_arb_multiverse_is_valid(
+        multiverse in arb_multiverse(MultiverseGenConfig::default())
+    ) {
+        let config = MultiverseGenConfig::default();
+        prop_assert_eq!(validate_all_properties(&multiverse), Ok(()));
+        prop_assert!(config.characters.contains(&multiverse.characters.len()));
+        prop_assert!(multiverse.timelines.len() <= config.max_timelines);
+    }
+
+    // With branches and causality violations switched off, there are none
+    #[test]
+    fn test_arb_multiverse_respects_toggles(
+        multiverse in arb_multiverse(MultiverseGenConfig {
+            include_branches: false,
+            include_causality_violations: false,
+            ..MultiverseGenConfig::default()
+        })
+    ) {
+        prop_assert_eq!(validate_all_properties(&multiverse), Ok(()));
+        prop_assert_eq!(multiverse.timelines.len(), 1);
+        prop_assert!(multiverse.events.values().all(|e| e.causality_violation.is_none()));
+    }
+
+    // `valid_multiverse_strategy` is the fixture corpus; every sample must pass
+    #[test]
+    fn test_valid_multiverse_strategy_passes_all_properties(
+        multiverse in valid_multiverse_strategy(6, 30)
+    ) {
+        prop_assert_eq!(validate_all_properties(&multiverse), Ok(()));
+        prop_assert!(multiverse.characters.len() <= 6);
+    }
+}
+
+#[cfg(test)]
+proptest! {
+    // ## Test: Compound Memories Blend Real Sources
+    //
+    // A memory dealer blends fragments—some witnessed, some bought, some
+    // forged, some already blends themselves—into one compound memory and
+    // sells it to Vera. Every source in the tree must exist, and the result
+    // must pass the full property suite.
+    #[test]
+    fn test_compound_memory_trees(tree in memory_tree_strategy(3)) {
+        // The whole cast is at the market, so any witnessed fragment was really seen
+        let mut multiverse = chaos_cast_multiverse();
+        let timeline = multiverse.root_timeline;
+        let vera = CharacterId(0);
+        let market = multiverse.record_event(Event {
+            id: EventId(0),
+            timeline,
+            description: "The cast browses the Memory Market".to_string(),
+            participants: multiverse.characters.keys().copied().collect(),
+            effects: vec![],
+            causality_violation: None,
+        });
+
+        let blend = create_memory_tree(&mut multiverse, &tree, market, timeline);
+        multiverse.record_event(Event {
+            id: EventId(0),
+            timeline,
+            description: "Vera buys a blended memory".to_string(),
+            participants: vec![vera].into_iter().collect(),
+            effects: vec![EventEffect::MemoryTransfer {
+                memory: blend,
+                from: None,
+                to: vera,
+                exclusive: false,
+            }],
+            causality_violation: None,
+        });
+
+        prop_assert_eq!(multiverse.memories.len(), tree.size());
+        for memory in multiverse.memories.values() {
+            if let MemoryProvenance::Compound { sources } = &memory.provenance {
+                prop_assert!(sources.iter().all(|s| multiverse.memories.contains_key(s)));
+            }
+        }
+        prop_assert_eq!(validate_all_properties(&multiverse), Ok(()));
+    }
+}
+
+/// Plays `actions` against [`chaos_cast_multiverse`], the state every chaos
+/// proptest starts from, validating after each one. A minimal case proptest
+/// shrank to (the `actions = [...]` in `proptest-regressions/`) can be pasted
+/// in as it is and pinned as an ordinary `#[test]`.
+///
+/// On a violation the error names the step and the action, narrates the
+/// violation, and gives the whole trace annotated as `format_shrink_trace` does.
+#[cfg(test)]
+fn replay_action_sequence(actions: &[NarrativeAction]) -> Result<(), String> {
+    let mut multiverse = chaos_cast_multiverse();
+    for (i, action) in actions.iter().enumerate() {
+        apply_narrative_action(&mut multiverse, action);
+        if let Err(violation) = validate_all_properties(&multiverse) {
+            let steps: Vec<NarrativeAction> =
+                chaos_cast_actions().into_iter().chain(actions[..=i].iter().cloned()).collect();
+            return Err(format!(
+                "step {} ({}): {}\n{}",
+                i,
+                multiverse.describe_action(action),
+                violation.narrate(&multiverse),
+                format_shrink_trace(&steps)
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Saves a failing chaos sequence under `target/propyarn-failures/`, prefixed
+/// with the cast setup so `replay_action_trace` can play it from scratch.
+///
+/// The file name is timestamped once per test run, so every failure during
+/// shrinking overwrites the same file and it ends up holding the minimal case.
+#[cfg(test)]
+fn save_failure_trace(test_name: &str, actions: &[NarrativeAction]) -> std::path::PathBuf {
+    use std::sync::OnceLock;
+
+    static RUN_STAMP: OnceLock<String> = OnceLock::new();
+    let stamp = RUN_STAMP.get_or_init(|| chrono::Local::now().format("%Y%m%d-%H%M%S").to_string());
+
+    let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("target")
+        .join("propyarn-failures")
+        .join(format!("{}-{}.json", test_name, stamp));
+
+    let mut trace = chaos_cast_actions();
+    trace.extend_from_slice(actions);
+    if let Err(e) = save_action_trace(&path, &trace) {
+        eprintln!("could not save failure trace to {}: {}", path.display(), e);
+    }
+    path
+}
+
+/// The least fraction of a generated sequence that must actually apply. Below
+/// this, a chaos test mostly checks that skipped actions leave things alone.
+#[cfg(test)]
+const MIN_APPLIED_FRACTION: f64 = 0.9;
+
+/// Actions between full validations in the stress test.
+#[cfg(test)]
+const STRESS_CHECKPOINT: usize = 50;
+
+/// Wall-clock allowance for validating one stress sequence, in a debug build.
+#[cfg(test)]
+const STRESS_BUDGET: std::time::Duration = std::time::Duration::from_secs(20);
+
 #[cfg(test)]
 mod unit_tests {
     use super::*;
@@ -418,4 +1060,323 @@ mod unit_tests {
         assert_eq!(multiverse.characters.len(), 13);
         assert!(validate_all_properties(&multiverse).is_ok());
     }
+    #[test]
+    fn test_thread_beta_wears_riven_down() {
+        use crate::protagonists::CastBuilder;
+        use crate::story_scenarios::thread_beta_gunslinger_paradox;
+
+        let mut multiverse = Multiverse::new();
+        let cast = CastBuilder::thirteen().build(&mut multiverse).unwrap();
+        thread_beta_gunslinger_paradox(&mut multiverse, &cast).unwrap();
+        let riven = &multiverse.characters[&cast.require("Gunslinger").unwrap()].emotional_state;
+        assert!(riven.feels(&crate::emotional_system::EmotionType::Fear) > 0.0);
+        assert!(riven.get_mood()[0] < 0.0);
+
+        // Long after the fear has faded, Riven is still ground down
+        multiverse.decay_emotions(200);
+        let riven = &multiverse.characters[&cast.require("Gunslinger").unwrap()].emotional_state;
+        assert!(riven.emotions.is_empty());
+        assert_eq!(riven.get_pad()[0], 0.0);
+        assert!(riven.get_mood()[0] < 0.0, "mood {:?}", riven.get_mood());
+        assert!(riven.get_affect()[0] < 0.0);
+        assert_eq!(validate_all_properties(&multiverse), Ok(()));
+    }
+
+    #[test]
+    fn test_riven_flees_when_fear_passes_seven_tenths() {
+        use crate::emotional_system::EmotionType;
+        use crate::protagonists::CastBuilder;
+        use crate::story_scenarios::thread_beta_gunslinger_paradox;
+        use crate::triggers::TriggerAction;
+
+        let mut multiverse = Multiverse::new();
+        let cast = CastBuilder::thirteen().build(&mut multiverse).unwrap();
+        let riven = cast.require("Gunslinger").unwrap();
+        let flees = multiverse.register_trigger(
+            Some(riven),
+            EmotionType::Fear,
+            0.7,
+            TriggerAction::Event {
+                description: "{name} flees the Singing Gardens".to_string(),
+                effects: vec![EventEffect::KnowledgeGained {
+                    character: riven,
+                    flag: "fled_the_gardens".to_string(),
+                }],
+            },
+        );
+        thread_beta_gunslinger_paradox(&mut multiverse, &cast).unwrap();
+
+        // The ambush does it, once; the later scares find Riven already past the line
+        let firings = multiverse.emotion_triggers().firings();
+        assert_eq!(firings.len(), 1);
+        let firing = firings[0];
+        assert_eq!((firing.trigger, firing.character), (flees, riven));
+        assert!(multiverse.events[&firing.cause].effects.iter().any(
+            |e| matches!(e, EventEffect::AppraisalTrigger { character, .. } if *character == riven)
+        ));
+        assert!(firing.event > firing.cause);
+        let fled = &multiverse.events[&firing.event];
+        assert_eq!(fled.description, "Riven Blackwood flees the Singing Gardens");
+        assert!(multiverse.characters[&riven].knowledge_flags.contains("fled_the_gardens"));
+        assert!(multiverse.characters[&riven].emotional_state.feels(&EmotionType::Fear) > 0.7);
+        assert_eq!(validate_all_properties(&multiverse), Ok(()));
+    }
+
+    #[test]
+    fn test_living_gate_makes_yash_tel_someone_else() {
+        let mut multiverse = Multiverse::new();
+        let timeline = multiverse.root_timeline;
+        let char_ids = crate::protagonists::create_thirteen_protagonists(&mut multiverse);
+        let yash = char_ids[5];
+        assert_eq!(multiverse.character_named("Yash-Tel"), Some(yash));
+
+        multiverse.record_event(Event {
+            id: EventId(0),
+            timeline,
+            description: "Yash-Tel walks through the Living Gate and out as someone else".to_string(),
+            participants: vec![yash].into_iter().collect(),
+            effects: vec![
+                EventEffect::MemoryWipe { character: yash },
+                EventEffect::CharacterRename {
+                    character: yash,
+                    new_name: "Tel-Anuresh".to_string(),
+                },
+            ],
+            causality_violation: None,
+        });
+
+        let renamed = &multiverse.characters[&yash];
+        assert_eq!(renamed.name, "Tel-Anuresh");
+        assert_eq!(renamed.former_names, vec!["Yash-Tel".to_string()]);
+        assert_eq!(renamed.original_name(), "Yash-Tel");
+        assert_eq!(multiverse.character_named("Tel-Anuresh"), Some(yash));
+        assert_eq!(multiverse.character_named("Yash-Tel"), None);
+        // The sibling perception they started with is still theirs under the new name
+        assert_eq!(validate_all_properties(&multiverse), Ok(()));
+    }
+
+    #[test]
+    fn test_lux_regrets_the_great_incoherence() {
+        use crate::emotional_system::{Belief, EmotionType, Goal};
+        let mut multiverse = Multiverse::new();
+        let timeline = multiverse.root_timeline;
+        let char_ids = crate::protagonists::create_thirteen_protagonists(&mut multiverse);
+        let lux = char_ids[11];
+        let state = &mut multiverse.characters.get_mut(&lux).unwrap().emotional_state;
+        state.add_goal(Goal::new(PREVENT_RING_COLLAPSE.to_string(), 1.0, true));
+
+        // Alone with the old equations, Lux finally admits whose hack it was
+        let belief = Belief::new(1.0, None, vec![PREVENT_RING_COLLAPSE.to_string()], vec![-0.9], false)
+            .and_then(|b| b.caused_by_self(Some(-0.8)))
+            .unwrap();
+        let event = multiverse.record_event(Event {
+            id: EventId(0),
+            timeline,
+            description: "Dr. Lux rereads the equations that started the Great Incoherence".to_string(),
+            participants: vec![lux].into_iter().collect(),
+            effects: vec![EventEffect::AppraisalTrigger { character: lux, belief }],
+            causality_violation: None,
+        });
+
+        let felt = multiverse.emotional_outcomes(event);
+        assert!(felt.iter().any(|(c, e)| *c == lux && e.emotion_type == EmotionType::Remorse && e.intensity > 0.0));
+        let state = &multiverse.characters[&lux].emotional_state;
+        assert!(state.feels(&EmotionType::Remorse) > 0.0);
+        assert_eq!(state.feels(&EmotionType::Gratification), 0.0);
+        // Lux blames no one else
+        assert!(state.relations.is_empty());
+        assert_eq!(prop_emotional_state_validity(&multiverse), Ok(()));
+        assert_eq!(validate_all_properties(&multiverse), Ok(()));
+    }
+
+    // A chaos run once shrank a failure down to this: Char#1 is anchored to
+    // their era, dies, comes back, and dies again, the anchor wearing down
+    // through all of it, and every property has to hold throughout
+    #[test]
+    fn test_regression_anchored_character_dies_twice() {
+        use NarrativeAction::*;
+        let root = TimelineId(0);
+        let anchor = |origin_era, stability| TemporalAnchor { origin_era, stability };
+        let actions = [
+            AnchorCharacter { character: CharacterId(2), anchor: anchor(-102, 0.1) },
+            AnchorCharacter { character: CharacterId(3), anchor: anchor(-102, 0.66209835) },
+            CreateCharacter { name: "Vsgxniiqwi".to_string(), timeline: root },
+            AnchorCharacter { character: CharacterId(9), anchor: anchor(-1381, 0.9476912) },
+            AnchorCharacter { character: CharacterId(1), anchor: anchor(-1067, 0.30698663) },
+            KillCharacter { character: CharacterId(1), timeline: root },
+            ResurrectCharacter { character: CharacterId(1), timeline: root, mechanism: "Vgretaru".to_string() },
+            CreateCharacter { name: "Rsgluijrbwexx".to_string(), timeline: root },
+            CreateCharacter { name: "Mpvotm".to_string(), timeline: root },
+            KillCharacter { character: CharacterId(1), timeline: root },
+        ];
+        assert_eq!(replay_action_sequence(&actions), Ok(()));
+    }
+
+    #[test]
+    fn test_regression_exchanges_across_timelines_have_both_sides_present() {
+        use crate::market::MemoryMarket;
+        use NarrativeAction::*;
+
+        let mut multiverse = Multiverse::new();
+        let root = multiverse.root_timeline;
+        let corvus = multiverse.create_character("Corvus Shal".to_string(), root);
+        let khelis = multiverse.create_character("Khelis Tev".to_string(), root);
+        let conductor = multiverse.create_character("The Conductor".to_string(), root);
+        multiverse.grant_ability(corvus, Ability::AnsibleLink);
+        multiverse.grant_ability(khelis, Ability::AnsibleLink);
+        multiverse.grant_ability(conductor, Ability::timeline_perception());
+        let fold = multiverse.record_event(Event {
+            id: EventId(0),
+            timeline: root,
+            description: "The Fold opens".to_string(),
+            participants: [corvus, khelis].into_iter().collect(),
+            effects: vec![],
+            causality_violation: None,
+        });
+        multiverse.create_timeline_branch_with(root, fold, &[khelis]).unwrap();
+
+        // Corvus sells and talks to Khelis over the Lattice, from the other side of the Fold
+        let actions = [
+            TradeMemory { memory: MemoryId(1_000_000), from: corvus, to: khelis, mechanism: "lattice".to_string() },
+            GrantKnowledge { character: corvus, flag: "gate_location".to_string(), timeline: root },
+            ShareKnowledge { from: corvus, to: khelis, flag: "gate_location".to_string() },
+        ];
+        for action in &actions {
+            assert!(apply_narrative_action(&mut multiverse, action).is_applied(), "{:?}", action);
+        }
+
+        // ...and the Conductor buys it back from Khelis, seeing into her branch
+        let mut market = MemoryMarket::new();
+        market.list_memory(&multiverse, khelis, MemoryId(1_000_000), 5.0).unwrap();
+        market.purchase_memory(&mut multiverse, conductor, MemoryId(1_000_000)).unwrap();
+
+        assert_eq!(prop_participants_in_timeline(&multiverse), Ok(()));
+        assert_eq!(validate_all_properties(&multiverse), Ok(()));
+    }
+
+    #[test]
+    fn test_stateful_generation_applies_more_actions() {
+        use proptest::strategy::ValueTree;
+        use proptest::test_runner::TestRunner;
+
+        // Tally applied vs skipped over the same number of sequences from each generator
+        fn tally<S: Strategy<Value = Vec<NarrativeAction>>>(strategy: S) -> (usize, usize) {
+            let mut runner = TestRunner::deterministic();
+            let (mut applied, mut skipped) = (0, 0);
+            for _ in 0..64 {
+                let actions = strategy.new_tree(&mut runner).unwrap().current();
+                let mut multiverse = chaos_cast_multiverse();
+                for action in &actions {
+                    if apply_narrative_action(&mut multiverse, action).is_applied() {
+                        applied += 1;
+                    } else {
+                        skipped += 1;
+                    }
+                }
+            }
+            (applied, skipped)
+        }
+
+        let blind = tally(prop::collection::vec(narrative_action_strategy(), 10..50));
+        let stateful = tally(valid_action_sequence_strategy(10..50));
+        assert_eq!(
+            stateful.1, 0,
+            "stateful generation skipped {} actions ({} applied)",
+            stateful.1, stateful.0
+        );
+        let ratio = |(applied, skipped): (usize, usize)| applied as f64 / (applied + skipped) as f64;
+        assert!(
+            ratio(stateful) > ratio(blind),
+            "stateful generation applied {} and skipped {}, blind generation applied {} and skipped {}",
+            stateful.0,
+            stateful.1,
+            blind.0,
+            blind.1
+        );
+    }
+
+    #[test]
+    fn test_branches_see_activity() {
+        use proptest::strategy::ValueTree;
+        use proptest::test_runner::TestRunner;
+
+        // Branches used to be empty copies nobody lived in; now migrants carry
+        // the story on there. Count sequences where some branch gets more than
+        // its crossing event.
+        let mut runner = TestRunner::deterministic();
+        let strategy = valid_action_sequence_strategy(40..60);
+        let mut active = 0;
+        for _ in 0..32 {
+            let actions = strategy.new_tree(&mut runner).unwrap().current();
+            let mut multiverse = chaos_cast_multiverse();
+            for action in &actions {
+                apply_narrative_action(&mut multiverse, action);
+            }
+            let root = multiverse.root_timeline;
+            if multiverse.timelines.values().any(|t| t.id != root && t.events.len() > 1) {
+                active += 1;
+            }
+        }
+        assert!(active >= 16, "only {} of 32 sequences had activity in a branch", active);
+    }
+
+    #[test]
+    fn test_registered_house_rules_are_enforced() {
+        use proptest::strategy::ValueTree;
+        use proptest::test_runner::TestRunner;
+
+        // The same chaos run, with a limit tight enough that a trade breaks it
+        let mut registry = PropertyRegistry::new();
+        registry.register("khelis_memory_limit", khelis_memory_limit(0));
+        let mut runner = TestRunner::deterministic();
+        let strategy = valid_action_sequence_strategy_weighted(ActionWeights::memory_heavy(), 10..50);
+        let (multiverse, violation) = (0..64)
+            .find_map(|_| {
+                let actions = strategy.new_tree(&mut runner).unwrap().current();
+                let mut multiverse = chaos_cast_multiverse();
+                for action in &actions {
+                    apply_narrative_action(&mut multiverse, action);
+                    if let Err(violation) = registry.validate(&multiverse) {
+                        return Some((multiverse, violation));
+                    }
+                }
+                None
+            })
+            .expect("no sequence ever handed Khelis a memory");
+        assert_eq!(violation.property, "khelis_memory_limit");
+
+        // Disabled, the same story passes
+        registry.disable("khelis_memory_limit");
+        assert_eq!(registry.validate(&multiverse), Ok(()));
+    }
+
+    #[test]
+    fn test_generators_cover_every_effect() {
+        use crate::coverage::{apply_tracked, CoverageTracker};
+        use proptest::strategy::ValueTree;
+        use proptest::test_runner::TestRunner;
+
+        // One tracker per sequence, merged into the total as a proptest case would
+        let mut runner = TestRunner::deterministic();
+        let strategy = valid_action_sequence_strategy(10..50);
+        let mut total = CoverageTracker::new();
+        for _ in 0..200 {
+            let actions = strategy.new_tree(&mut runner).unwrap().current();
+            let mut multiverse = chaos_cast_multiverse();
+            let mut tracker = CoverageTracker::new();
+            for action in &actions {
+                apply_tracked(&mut multiverse, &mut tracker, action);
+            }
+            tracker.record_validation(&multiverse);
+            total.merge(&tracker);
+        }
+
+        let report = total.report();
+        assert!(
+            report.missing_effects.is_empty(),
+            "generators never produced these effects in 200 sequences: {}\n{}",
+            report.missing_effects.join(", "),
+            report
+        );
+    }
 }
@@ -64,7 +64,23 @@
 //!
 //! - **`narrative_core`**: Core data structures (Timeline, Character, Memory, Event)
 //! - **`properties`**: Property tests that validate narrative invariants
+//! - **`combinators`**: Building properties from `for_all_*`, `implies`, `and`, and `or`
+//! - **`temporal`**: Obligations a finished story must have resolved, checked by `validate_final`
 //! - **`generators`**: Proptest strategies for generating random scenarios
+//! - **`chaos`**: Time-budgeted random testing outside the proptest runner
+//! - **`action_log`**: Recording applied actions and replaying them as a save format
+//! - **`causal_graph`**: Events as a causal DAG, for ordering and paradox detection
+//! - **`ancestry`**: Every timeline's ancestors, computed once per validation
+//! - **`coverage`**: Counting which actions, effects, and properties tests exercised
+//! - **`mutations`**: Deliberately broken engines, to check the properties catch them
+//! - **`observers`**: Callbacks notified as events are recorded
+//! - **`pad_recorder`**: Sampling everyone's PAD mood after each event, for charting
+//! - **`diff`**: What changed between two snapshots of a multiverse
+//! - **`market`**: The Memory Market, listing memories and recording their sale
+//! - **`view`**: Read-only views of a multiverse, for code that only looks
+//! - **`inference`**: Rules deriving knowledge flags from other knowledge flags
+//! - **`subtree`**: Extracting one branch and its descendants as a standalone multiverse
+//! - **`triggers`**: Emotion thresholds that, once crossed, record events of their own
 //! - **`integration_tests`**: Tests applying properties to "The Thirteen Suns"
 //!
 //! ## Example: Validating Memory Consistency
@@ -177,7 +193,23 @@
 pub mod narrative_core;
 pub mod emotional_system;
 pub mod properties;
+pub mod combinators;
+pub mod temporal;
 pub mod generators;
+pub mod chaos;
+pub mod action_log;
+pub mod causal_graph;
+pub mod ancestry;
+pub mod coverage;
+pub mod mutations;
+pub mod observers;
+pub mod pad_recorder;
+pub mod diff;
+pub mod market;
+pub mod view;
+pub mod inference;
+pub mod subtree;
+pub mod triggers;
 #[cfg(test)]
 pub mod integration_tests;
 pub mod protagonists;
@@ -186,6 +218,21 @@ pub mod story_scenarios;
 pub use narrative_core::*;
 pub use emotional_system::*;
 pub use properties::*;
+pub use combinators::*;
+pub use temporal::*;
 pub use generators::*;
+pub use chaos::*;
+pub use action_log::*;
+pub use causal_graph::*;
+pub use ancestry::*;
+pub use coverage::*;
+pub use mutations::*;
+pub use observers::*;
+pub use pad_recorder::*;
+pub use diff::*;
+pub use market::*;
+pub use view::*;
+pub use inference::*;
+pub use triggers::*;
 pub use protagonists::*;
 pub use story_scenarios::*;
@@ -6,14 +6,46 @@
 use propyarn::narrative_core::*;
 // use propyarn::protagonists::*;
 use propyarn::story_scenarios::*;
-// use propyarn::properties::*;
+use propyarn::generators::{generate_scenario, replay_action_trace, ScenarioConfig};
+use propyarn::properties::{validate_with_warnings, PROPERTIES};
+use propyarn::PadRecorder;
 
 fn main() {
+    // `propyarn --replay <trace.json>` replays a saved failure trace instead of the demo,
+    // `propyarn --seed <n>` prints the scenario generated from that seed, and
+    // `propyarn --record-pad` runs the demo and charts everyone's mood in pad_trajectories.csv
+    let args: Vec<String> = std::env::args().collect();
+    if let [_, flag, value] = args.as_slice() {
+        if flag == "--replay" {
+            replay_trace(value);
+            return;
+        }
+        if flag == "--seed" {
+            match value.parse() {
+                Ok(seed) => print_scenario(seed),
+                Err(e) => eprintln!("Invalid seed {}: {}", value, e),
+            }
+            return;
+        }
+    }
+
     let mut multiverse = Multiverse::new();
+    let recorder = (args.get(1).map(String::as_str) == Some("--record-pad")).then(PadRecorder::new);
+    if let Some(recorder) = &recorder {
+        multiverse.add_observer(recorder.clone());
+    }
 
     // Run the full narrative demo
     run_full_demo(&mut multiverse);
 
+    if let Some(recorder) = &recorder {
+        let written = std::fs::File::create("pad_trajectories.csv").and_then(|file| recorder.to_csv(file));
+        match written {
+            Ok(()) => println!("\nRecorded {} mood samples to pad_trajectories.csv", recorder.samples().len()),
+            Err(e) => eprintln!("Could not write pad_trajectories.csv: {}", e),
+        }
+    }
+
     // Additional exploration menu
     println!("\n═══ INTERACTIVE EXPLORATION ═══\n");
     println!("The demo has completed. The multiverse contains:");
@@ -25,7 +57,16 @@ fn main() {
         println!("  Alive: {}", character.alive);
         println!("  Memories: {}", character.memories.len());
         println!("  Knowledge flags: {}", character.knowledge_flags.len());
-        println!("  Abilities: {:?}", character.abilities);
+        println!("  Abilities: {:?}", character.abilities.values().collect::<Vec<_>>());
+        match character.emotional_state.dominant_emotion() {
+            Some(emotion) => println!(
+                "  Feeling: {} ({:.2}), speaks {}",
+                emotion.emotion_type.as_str(),
+                emotion.intensity,
+                dialogue_flavor(&character.emotional_state)
+            ),
+            None => println!("  Feeling: nothing in particular"),
+        }
 
         if !character.knowledge_flags.is_empty() {
             println!("  Knows:");
@@ -86,6 +127,12 @@ fn main() {
         println!();
     }
 
+    println!("\n═══ PROPERTY VALIDATION ═══\n");
+    let mut report = validate_with_warnings(&multiverse);
+    // The warnings get their own say at the end
+    let warnings = std::mem::take(&mut report.warnings);
+    print!("{}", report);
+
     println!("\n═══ NARRATIVE INSIGHTS ═══\n");
     println!("This demonstration showcases:");
     println!();
@@ -96,7 +143,7 @@ fn main() {
     println!("   - Causality violations with justifications");
     println!();
     println!("2. PROPERTY-BASED VALIDATION");
-    println!("   - Every action is validated against 6 invariants");
+    println!("   - Every action is validated against {} invariants", PROPERTIES.len());
     println!("   - Violations are caught immediately");
     println!("   - Proptest shrinks failures to minimal cases");
     println!();
@@ -125,8 +172,64 @@ fn main() {
     println!("Each test generates thousands of random scenarios and validates");
     println!("that ALL narrative properties hold.");
 
+    if !warnings.is_empty() {
+        println!("\n═══ THINGS THAT DON'T QUITE SIT RIGHT ═══\n");
+        for warning in &warnings {
+            println!("\x1b[33m• {}\x1b[0m", warning.message);
+        }
+        println!("\nNone of these break the story, but a careful reader might notice.");
+    }
+
     println!("\n╔════════════════════════════════════════════════════════════════╗");
     println!("║         'Thirteen must become One, or all become None.'       ║");
     println!("║                  — The Ansible Lattice                         ║");
     println!("╚════════════════════════════════════════════════════════════════╝\n");
 }
+
+/// Replays an action trace step by step, reporting the first property violation.
+fn replay_trace(path: &str) {
+    println!("═══ REPLAYING {} ═══\n", path);
+    match replay_action_trace(path) {
+        Ok((multiverse, violation)) => {
+            println!(
+                "Reached {} characters, {} timelines, {} events.",
+                multiverse.characters.len(),
+                multiverse.timelines.len(),
+                multiverse.events.len()
+            );
+            match violation {
+                Some(violation) => println!("✗ Stopped at violation: {}", violation),
+                None => println!("✓ Every property held after every step."),
+            }
+        }
+        Err(e) => eprintln!("Could not replay {}: {}", path, e),
+    }
+}
+
+/// Generates the scenario for `seed` with the default config and prints its story.
+fn print_scenario(seed: u64) {
+    let multiverse = generate_scenario(seed, ScenarioConfig::default());
+    println!("═══ SCENARIO FROM SEED {} ═══\n", seed);
+
+    let mut characters: Vec<_> = multiverse.characters.values().collect();
+    characters.sort_by_key(|c| c.id.0);
+    for character in characters {
+        println!(
+            "• {} ({}) in {}{}",
+            character.name,
+            character.id,
+            character.current_timeline,
+            if character.alive { "" } else { " — dead" }
+        );
+    }
+
+    println!("\n═══ EVENTS ═══\n");
+    let mut events: Vec<_> = multiverse.events.values().collect();
+    events.sort_by_key(|e| e.id.0);
+    for event in events {
+        println!("{} in {}: {}", event.id, event.timeline, event.description);
+        if let Some(violation) = &event.causality_violation {
+            println!("  ⚠ Causality Violation: {:?}", violation);
+        }
+    }
+}
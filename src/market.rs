@@ -0,0 +1,324 @@
+//! # The Memory Market: Khelis's Exchange
+//!
+//! Khelis Tev deals in memories, and until now a sale was whatever event a
+//! caller chose to record with "Memory Market" written on it. A
+//! [`MemoryMarket`] keeps the stall itself: what is on offer, who is selling,
+//! and at what asking price. Buying goes through the market, which records the
+//! sale as an ordinary exclusive `MemoryTransfer` event, so the properties see
+//! it like any other trade and the ledger says who bought what from whom.
+//!
+//! Prices are abstract. The market doesn't hold anyone's money; it only
+//! remembers what was asked and what was paid.
+
+use crate::narrative_core::*;
+use crate::properties::CharacterInvariant;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet};
+use thiserror::Error;
+
+/// What sales through the market record as their `acquired_via`.
+pub const MEMORY_MARKET: &str = "Memory Market";
+
+/// A memory on offer.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Listing {
+    pub seller: CharacterId,
+    /// The asking price, in whatever currency the story trades in
+    pub price: f32,
+}
+
+/// A completed sale.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Sale {
+    pub memory: MemoryId,
+    pub seller: CharacterId,
+    pub buyer: CharacterId,
+    pub price: f32,
+    /// The event that recorded the transfer
+    pub event: EventId,
+}
+
+/// Why a listing or purchase didn't go through.
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum MarketError {
+    #[error("no such character: {0}")]
+    UnknownCharacter(CharacterId),
+    #[error("{0} is not listed on the market")]
+    NotListed(MemoryId),
+    #[error("{character} does not hold {memory}")]
+    NotHeld {
+        character: CharacterId,
+        memory: MemoryId,
+    },
+    #[error("{0} is dead or departed and can't trade")]
+    Absent(CharacterId),
+    #[error("{seller} can't reach {buyer} to trade")]
+    OutOfReach {
+        seller: CharacterId,
+        buyer: CharacterId,
+    },
+    /// The seller is held to [`CharacterInvariant::NeverLosesMemories`], and a
+    /// sale gives the memory up
+    #[error("{0} never loses a memory, so can't sell one")]
+    NeverSells(CharacterId),
+}
+
+/// The memories on offer, and every sale made.
+///
+/// **Invariant**: every listed memory was held by its seller when it was
+/// listed. A seller who loses it before the sale (to a wipe, say) loses the
+/// listing too, at the next attempt to buy it.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MemoryMarket {
+    listings: BTreeMap<MemoryId, Listing>,
+    sales: Vec<Sale>,
+}
+
+impl MemoryMarket {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Puts a memory the seller holds up for sale, replacing any earlier
+    /// listing of it.
+    pub fn list_memory(
+        &mut self,
+        multiverse: &Multiverse,
+        seller: CharacterId,
+        memory: MemoryId,
+        price: f32,
+    ) -> Result<(), MarketError> {
+        let character = present(multiverse, seller)?;
+        if !character.memories.contains(&memory) {
+            return Err(MarketError::NotHeld {
+                character: seller,
+                memory,
+            });
+        }
+        if multiverse.is_held_to(seller, CharacterInvariant::NeverLosesMemories) {
+            return Err(MarketError::NeverSells(seller));
+        }
+        self.listings.insert(memory, Listing { seller, price });
+        Ok(())
+    }
+
+    /// Takes a memory off the market. Returns the listing, if there was one.
+    pub fn delist_memory(&mut self, memory: MemoryId) -> Option<Listing> {
+        self.listings.remove(&memory)
+    }
+
+    /// Sells a listed memory to `buyer`, at the asking price.
+    ///
+    /// The sale is recorded in the buyer's timeline as an exclusive
+    /// `MemoryTransfer`, and the memory's provenance becomes `Traded` via the
+    /// Memory Market. A memory that was already traded keeps its original
+    /// owner; otherwise the original owner is the witness, or the seller.
+    pub fn purchase_memory(
+        &mut self,
+        multiverse: &mut Multiverse,
+        buyer: CharacterId,
+        memory: MemoryId,
+    ) -> Result<Sale, MarketError> {
+        let listing = *self.listings.get(&memory).ok_or(MarketError::NotListed(memory))?;
+        let seller = listing.seller;
+        let timeline = present(multiverse, buyer)?.current_timeline;
+        let held = present(multiverse, seller)?.memories.contains(&memory);
+        if !held || !multiverse.memories.contains_key(&memory) {
+            self.listings.remove(&memory);
+            return Err(MarketError::NotHeld {
+                character: seller,
+                memory,
+            });
+        }
+        if !multiverse.can_trade_memory(seller, buyer) {
+            return Err(MarketError::OutOfReach { seller, buyer });
+        }
+        if multiverse.is_held_to(seller, CharacterInvariant::NeverLosesMemories) {
+            return Err(MarketError::NeverSells(seller));
+        }
+
+        let bought = multiverse.memories.get_mut(&memory).expect("checked above");
+        let original_owner = match &bought.provenance {
+            MemoryProvenance::Traded { original_owner, .. } => *original_owner,
+            MemoryProvenance::Witnessed { character } => *character,
+            MemoryProvenance::Forged { .. } | MemoryProvenance::Compound { .. } => seller,
+        };
+        bought.provenance = MemoryProvenance::Traded {
+            original_owner,
+            acquired_via: MEMORY_MARKET.to_string(),
+        };
+        // A seller reaching in from another timeline isn't there to take part;
+        // the transfer records their side
+        let mut participants = HashSet::from([buyer]);
+        if multiverse.characters[&seller].current_timeline == timeline {
+            participants.insert(seller);
+        }
+        let event = multiverse.record_event(Event {
+            id: EventId(0),
+            timeline,
+            description: format!("{} buys {} from {} at the Memory Market", buyer, memory, seller),
+            participants,
+            effects: vec![EventEffect::MemoryTransfer {
+                memory,
+                from: Some(seller),
+                to: buyer,
+                exclusive: true,
+            }],
+            causality_violation: None,
+        });
+
+        self.listings.remove(&memory);
+        let sale = Sale {
+            memory,
+            seller,
+            buyer,
+            price: listing.price,
+            event,
+        };
+        self.sales.push(sale);
+        Ok(sale)
+    }
+
+    /// What is on offer, by memory.
+    pub fn listings(&self) -> &BTreeMap<MemoryId, Listing> {
+        &self.listings
+    }
+
+    /// The asking price of a listed memory.
+    pub fn price_of(&self, memory: MemoryId) -> Option<f32> {
+        self.listings.get(&memory).map(|listing| listing.price)
+    }
+
+    /// Every sale made, oldest first.
+    pub fn sales(&self) -> &[Sale] {
+        &self.sales
+    }
+}
+
+/// The character, if they exist and are around to trade.
+fn present(multiverse: &Multiverse, id: CharacterId) -> Result<&Character, MarketError> {
+    let character = multiverse
+        .characters
+        .get(&id)
+        .ok_or(MarketError::UnknownCharacter(id))?;
+    if !character.is_present() {
+        return Err(MarketError::Absent(id));
+    }
+    Ok(character)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::properties::validate_all_properties;
+
+    /// Khelis, holding one memory they witnessed, and a customer.
+    fn stall() -> (Multiverse, CharacterId, CharacterId, MemoryId) {
+        let mut multiverse = Multiverse::new();
+        let root = multiverse.root_timeline;
+        let khelis = multiverse.create_character("Khelis Tev".to_string(), root);
+        let vera = multiverse.create_character("Vera Kandros".to_string(), root);
+        let sunrise = multiverse.record_event(Event {
+            id: EventId(0),
+            timeline: root,
+            description: "Khelis watches the last sunrise over the Ring".to_string(),
+            participants: [khelis].into_iter().collect(),
+            effects: vec![],
+            causality_violation: None,
+        });
+        let memory = multiverse.create_witnessed_memory(sunrise, root, khelis);
+        multiverse.characters.get_mut(&khelis).unwrap().memories.insert(memory);
+        (multiverse, khelis, vera, memory)
+    }
+
+    #[test]
+    fn test_listing_needs_the_memory() {
+        let (multiverse, khelis, vera, memory) = stall();
+        let mut market = MemoryMarket::new();
+        assert_eq!(
+            market.list_memory(&multiverse, vera, memory, 10.0),
+            Err(MarketError::NotHeld {
+                character: vera,
+                memory
+            })
+        );
+        market.list_memory(&multiverse, khelis, memory, 10.0).unwrap();
+        assert_eq!(market.price_of(memory), Some(10.0));
+        assert_eq!(market.listings()[&memory].seller, khelis);
+    }
+
+    #[test]
+    fn test_who_never_forgets_never_sells() {
+        let (mut multiverse, khelis, vera, memory) = stall();
+        let mut market = MemoryMarket::new();
+        market.list_memory(&multiverse, khelis, memory, 10.0).unwrap();
+
+        // Held to keeping every memory once listed, Khelis can't go through with it
+        multiverse.register_character_invariant(khelis, CharacterInvariant::NeverLosesMemories);
+        let events = multiverse.events.len();
+        assert_eq!(
+            market.purchase_memory(&mut multiverse, vera, memory),
+            Err(MarketError::NeverSells(khelis))
+        );
+        assert_eq!(multiverse.events.len(), events);
+        assert_eq!(
+            market.list_memory(&multiverse, khelis, memory, 10.0),
+            Err(MarketError::NeverSells(khelis))
+        );
+    }
+
+    #[test]
+    fn test_buying_a_listed_memory() {
+        let (mut multiverse, khelis, vera, memory) = stall();
+        let mut market = MemoryMarket::new();
+        market.list_memory(&multiverse, khelis, memory, 12.5).unwrap();
+
+        let sale = market.purchase_memory(&mut multiverse, vera, memory).unwrap();
+        assert_eq!((sale.seller, sale.buyer, sale.price), (khelis, vera, 12.5));
+        assert_eq!(market.sales(), &[sale]);
+        assert!(market.listings().is_empty());
+
+        assert!(multiverse.characters[&vera].memories.contains(&memory));
+        assert!(!multiverse.characters[&khelis].memories.contains(&memory));
+        assert_eq!(
+            multiverse.memories[&memory].provenance,
+            MemoryProvenance::Traded {
+                original_owner: khelis,
+                acquired_via: MEMORY_MARKET.to_string(),
+            }
+        );
+        assert!(matches!(
+            multiverse.events[&sale.event].effects[..],
+            [EventEffect::MemoryTransfer { exclusive: true, .. }]
+        ));
+        assert_eq!(validate_all_properties(&multiverse), Ok(()));
+
+        // Sold once, it's gone from the stall
+        assert_eq!(
+            market.purchase_memory(&mut multiverse, khelis, memory),
+            Err(MarketError::NotListed(memory))
+        );
+    }
+
+    #[test]
+    fn test_buying_an_unlisted_memory_fails() {
+        let (mut multiverse, khelis, vera, memory) = stall();
+        let mut market = MemoryMarket::new();
+        let events = multiverse.events.len();
+        assert_eq!(
+            market.purchase_memory(&mut multiverse, vera, memory),
+            Err(MarketError::NotListed(memory))
+        );
+        assert_eq!(multiverse.events.len(), events);
+        assert!(multiverse.characters[&khelis].memories.contains(&memory));
+
+        // A listing doesn't outlive the memory: wiped, Khelis has nothing to sell
+        market.list_memory(&multiverse, khelis, memory, 3.0).unwrap();
+        multiverse.characters.get_mut(&khelis).unwrap().memories.clear();
+        assert!(matches!(
+            market.purchase_memory(&mut multiverse, vera, memory),
+            Err(MarketError::NotHeld { .. })
+        ));
+        assert!(market.listings().is_empty());
+    }
+}
@@ -0,0 +1,271 @@
+//! # Mutation Testing: Do the Properties Have Teeth?
+//!
+//! A property that never fails might be checking the right thing, or nothing
+//! at all. Mutation testing tells the two apart: break the engine on purpose,
+//! run the usual generated scenarios, and see whether some property notices.
+//!
+//! Each [`Mutant`] is one deliberate fault. A [`MutatedMultiverse`] applies
+//! actions as usual, then tampers with whatever the new events just did: the
+//! dead get back up, sold memories stay with the seller, and so on. A mutant
+//! the properties never catch is a *survivor*, and names an invariant that is
+//! under-specified.
+
+use crate::generators::{apply_narrative_action_recorded, ActionOutcome, NarrativeAction};
+use crate::narrative_core::*;
+use crate::properties::{validate_all_properties, PropertyViolation};
+use std::collections::{HashMap, HashSet};
+
+/// A deliberate fault in how events change the multiverse.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Mutant {
+    /// Death events are recorded, but the character stays alive
+    SkipDeathEffects,
+    /// Every new memory also turns up with someone in another timeline
+    AllowCrossTimelineMemories,
+    /// Causality violations lose their in-world mechanism
+    DropCausalityMechanisms,
+    /// Memory wipes are recorded, but nothing is forgotten
+    IgnoreMemoryWipes,
+    /// Sellers keep a copy of every memory they sell
+    KeepSoldMemories,
+}
+
+impl Mutant {
+    /// Every mutant, for building a test matrix.
+    pub const ALL: [Mutant; 5] = [
+        Mutant::SkipDeathEffects,
+        Mutant::AllowCrossTimelineMemories,
+        Mutant::DropCausalityMechanisms,
+        Mutant::IgnoreMemoryWipes,
+        Mutant::KeepSoldMemories,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Mutant::SkipDeathEffects => "skip_death_effects",
+            Mutant::AllowCrossTimelineMemories => "allow_cross_timeline_memories",
+            Mutant::DropCausalityMechanisms => "drop_causality_mechanisms",
+            Mutant::IgnoreMemoryWipes => "ignore_memory_wipes",
+            Mutant::KeepSoldMemories => "keep_sold_memories",
+        }
+    }
+}
+
+/// A multiverse whose event handling has been sabotaged by a [`Mutant`].
+#[derive(Debug, Clone)]
+pub struct MutatedMultiverse {
+    pub multiverse: Multiverse,
+    pub mutant: Mutant,
+}
+
+impl MutatedMultiverse {
+    pub fn new(multiverse: Multiverse, mutant: Mutant) -> Self {
+        MutatedMultiverse { multiverse, mutant }
+    }
+
+    pub fn skip_death_effects(multiverse: Multiverse) -> Self {
+        Self::new(multiverse, Mutant::SkipDeathEffects)
+    }
+
+    pub fn allow_cross_timeline_memories(multiverse: Multiverse) -> Self {
+        Self::new(multiverse, Mutant::AllowCrossTimelineMemories)
+    }
+
+    pub fn drop_causality_mechanisms(multiverse: Multiverse) -> Self {
+        Self::new(multiverse, Mutant::DropCausalityMechanisms)
+    }
+
+    pub fn ignore_memory_wipes(multiverse: Multiverse) -> Self {
+        Self::new(multiverse, Mutant::IgnoreMemoryWipes)
+    }
+
+    pub fn keep_sold_memories(multiverse: Multiverse) -> Self {
+        Self::new(multiverse, Mutant::KeepSoldMemories)
+    }
+
+    /// Applies `action` as [`apply_narrative_action_recorded`] would, then
+    /// injects the fault into whatever the action's events did.
+    pub fn apply(&mut self, action: &NarrativeAction) -> ActionOutcome {
+        let held_before: HashMap<CharacterId, HashSet<MemoryId>> = self
+            .multiverse
+            .characters
+            .values()
+            .map(|c| (c.id, c.memories.clone()))
+            .collect();
+        let memories_before: HashSet<MemoryId> = self.multiverse.memories.keys().copied().collect();
+
+        let (outcome, new_events) = apply_narrative_action_recorded(&mut self.multiverse, action);
+        let multiverse = &mut self.multiverse;
+        match self.mutant {
+            Mutant::SkipDeathEffects => {
+                for effect in new_effects(multiverse, &new_events) {
+                    if let EventEffect::CharacterDeath { character } = effect {
+                        if let Some(c) = multiverse.characters.get_mut(&character) {
+                            c.alive = true;
+                        }
+                    }
+                }
+            }
+            Mutant::AllowCrossTimelineMemories => {
+                let mut fresh: Vec<&Memory> = multiverse
+                    .memories
+                    .values()
+                    .filter(|m| !memories_before.contains(&m.id))
+                    .collect();
+                fresh.sort_by_key(|m| m.id);
+                let mut leaks = Vec::new();
+                for memory in fresh {
+                    let outsider = multiverse
+                        .characters
+                        .values()
+                        .filter(|c| c.current_timeline != memory.source_timeline)
+                        .map(|c| c.id)
+                        .min();
+                    if let Some(outsider) = outsider {
+                        leaks.push((outsider, memory.id));
+                    }
+                }
+                for (outsider, memory) in leaks {
+                    multiverse.characters.get_mut(&outsider).unwrap().memories.insert(memory);
+                }
+            }
+            Mutant::DropCausalityMechanisms => {
+                for id in &new_events {
+                    let event = multiverse.events.get_mut(id).unwrap();
+                    match &mut event.causality_violation {
+                        Some(CausalityViolation::EffectBeforeCause { mechanism })
+                        | Some(CausalityViolation::RetroactiveChange { mechanism })
+                        | Some(CausalityViolation::Superposition { mechanism })
+                        | Some(CausalityViolation::Bootstrap { mechanism, .. }) => mechanism.clear(),
+                        None => {}
+                    }
+                }
+            }
+            Mutant::IgnoreMemoryWipes => {
+                for effect in new_effects(multiverse, &new_events) {
+                    if let EventEffect::MemoryWipe { character } = effect {
+                        if let (Some(c), Some(held)) =
+                            (multiverse.characters.get_mut(&character), held_before.get(&character))
+                        {
+                            c.memories.extend(held);
+                        }
+                    }
+                }
+            }
+            Mutant::KeepSoldMemories => {
+                for effect in new_effects(multiverse, &new_events) {
+                    if let EventEffect::MemoryTransfer {
+                        memory,
+                        from: Some(seller),
+                        exclusive: true,
+                        ..
+                    } = effect
+                    {
+                        if let Some(c) = multiverse.characters.get_mut(&seller) {
+                            c.memories.insert(memory);
+                        }
+                    }
+                }
+            }
+        }
+        outcome
+    }
+}
+
+/// The effects of the given events, cloned so the multiverse can be changed
+/// while walking them.
+fn new_effects(multiverse: &Multiverse, events: &[EventId]) -> Vec<EventEffect> {
+    events
+        .iter()
+        .filter_map(|id| multiverse.events.get(id))
+        .flat_map(|event| event.effects.iter().cloned())
+        .collect()
+}
+
+/// Plays `actions` against `initial` under `mutant`, validating every property
+/// after each action. Returns the first violation: the property that caught
+/// the mutant, or `None` if it survived.
+pub fn first_violation(
+    mutant: Mutant,
+    initial: Multiverse,
+    actions: &[NarrativeAction],
+) -> Option<PropertyViolation> {
+    let mut mutated = MutatedMultiverse::new(initial, mutant);
+    for action in actions {
+        mutated.apply(action);
+        if let Err(violation) = validate_all_properties(&mutated.multiverse) {
+            return Some(violation);
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::{apply_narrative_action, chaos_cast_multiverse, valid_action_sequence_strategy};
+    use proptest::strategy::{Strategy, ValueTree};
+    use proptest::test_runner::TestRunner;
+
+    #[test]
+    fn test_every_mutant_is_caught() {
+        let mut runner = TestRunner::deterministic();
+        let strategy = valid_action_sequence_strategy(20..60);
+        let sequences: Vec<Vec<NarrativeAction>> = (0..64)
+            .map(|_| strategy.new_tree(&mut runner).unwrap().current())
+            .collect();
+
+        let mut caught = Vec::new();
+        let mut survivors = Vec::new();
+        for mutant in Mutant::ALL {
+            let killer = sequences.iter().find_map(|actions| {
+                first_violation(mutant, chaos_cast_multiverse(), actions).map(|violation| (actions, violation))
+            });
+            match killer {
+                Some((actions, violation)) => {
+                    // A kill only counts if the sequence passes without the mutant
+                    assert_eq!(
+                        unmutated_violation(actions),
+                        None,
+                        "{} was caught by {} on a sequence that fails anyway",
+                        mutant.name(),
+                        violation.property
+                    );
+                    caught.push(format!("{} by {}", mutant.name(), violation.property));
+                }
+                None => survivors.push(mutant.name()),
+            }
+        }
+        assert!(
+            survivors.is_empty(),
+            "no property caught these mutants in {} sequences: {} (caught: {})",
+            sequences.len(),
+            survivors.join(", "),
+            caught.join(", ")
+        );
+    }
+
+    /// The first violation playing `actions` unsabotaged, validating after each.
+    fn unmutated_violation(actions: &[NarrativeAction]) -> Option<PropertyViolation> {
+        let mut multiverse = chaos_cast_multiverse();
+        actions.iter().find_map(|action| {
+            apply_narrative_action(&mut multiverse, action);
+            validate_all_properties(&multiverse).err()
+        })
+    }
+
+    #[test]
+    fn test_unmutated_sequences_pass() {
+        // The matrix only means something if the same sequences pass unsabotaged
+        let mut runner = TestRunner::deterministic();
+        let strategy = valid_action_sequence_strategy(20..60);
+        for _ in 0..8 {
+            let actions = strategy.new_tree(&mut runner).unwrap().current();
+            let mut multiverse = chaos_cast_multiverse();
+            for action in &actions {
+                apply_narrative_action(&mut multiverse, action);
+            }
+            assert_eq!(validate_all_properties(&multiverse), Ok(()));
+        }
+    }
+}
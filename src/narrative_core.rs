@@ -29,15 +29,41 @@
 //!
 //! Each timeline has a unique identifier and tracks its divergence point from parents.
 
+use crate::observers::{MultiverseObserver, Observers};
+use crate::temporal::{Obligation, Obligations};
+use crate::triggers::EmotionTriggerRegistry;
 use serde::{Deserialize, Serialize};
-use std::collections::{HashMap, HashSet};
+use std::borrow::Borrow;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::fmt;
+use thiserror::Error;
+
+/// Gives an enum `name()`, its variant's name for reporting and coverage
+/// bookkeeping, and `NAMES`, every variant's name in order. The match behind
+/// `name()` is exhaustive, so a variant left off the list won't compile, and
+/// can't go missing from anything built on `NAMES`.
+macro_rules! named_variants {
+    ($enum:ident { $($variant:ident),* $(,)? }) => {
+        impl $enum {
+            /// Every variant's name, in declaration order.
+            pub const NAMES: &'static [&'static str] = &[$(stringify!($variant)),*];
+
+            /// The variant name, for reporting and coverage bookkeeping.
+            pub fn name(&self) -> &'static str {
+                match self {
+                    $($enum::$variant { .. } => stringify!($variant),)*
+                }
+            }
+        }
+    };
+}
+pub(crate) use named_variants;
 
 /// A unique identifier for a timeline.
 ///
 /// Timelines are the fundamental unit of branching narrative. Each represents
 /// a causally-consistent sequence of events.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub struct TimelineId(pub u64);
 
 impl fmt::Display for TimelineId {
@@ -47,7 +73,7 @@ impl fmt::Display for TimelineId {
 }
 
 /// A unique identifier for a character.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub struct CharacterId(pub u64);
 
 impl fmt::Display for CharacterId {
@@ -60,7 +86,7 @@ impl fmt::Display for CharacterId {
 ///
 /// Memories can be extracted, traded, forged, and installed. Each memory
 /// has a provenance that tracks its origin and any modifications.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub struct MemoryId(pub u64);
 
 impl fmt::Display for MemoryId {
@@ -70,7 +96,7 @@ impl fmt::Display for MemoryId {
 }
 
 /// A unique identifier for an event in the narrative.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub struct EventId(pub u64);
 
 impl fmt::Display for EventId {
@@ -79,6 +105,47 @@ impl fmt::Display for EventId {
     }
 }
 
+/// The name of a piece of knowledge, e.g. `"conductor_summons"`.
+///
+/// Characters and effects carry flags as plain strings, so a typo quietly
+/// becomes a new flag that nothing else ever mentions. Registering a story's
+/// flags with [`Multiverse::register_flag`] and turning on
+/// [`MultiverseConfig::strict_flags`] makes `prop_knowledge_flags` reject any
+/// flag outside the registry.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct KnowledgeFlag(String);
+
+impl KnowledgeFlag {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<String> for KnowledgeFlag {
+    fn from(name: String) -> Self {
+        KnowledgeFlag(name)
+    }
+}
+
+impl From<&str> for KnowledgeFlag {
+    fn from(name: &str) -> Self {
+        KnowledgeFlag(name.to_string())
+    }
+}
+
+impl Borrow<str> for KnowledgeFlag {
+    fn borrow(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for KnowledgeFlag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
 /// ## Memory System
 ///
 /// Memories are first-class entities in this narrative system. They can be:
@@ -92,10 +159,16 @@ impl fmt::Display for EventId {
 /// Each memory tracks its **provenance** to enable validation properties like:
 /// - "Characters can only remember events they witnessed OR acquired via memory trade"
 /// - "Forged memories must have an in-universe justification (Memory Cartel, etc.)"
-#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// The justification is a forger the story knows about: see
+/// [`Multiverse::register_forger`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Memory {
     pub id: MemoryId,
     pub event: EventId,
+    /// Whether this is a memory of `event` at all, or hearsay with no scene behind it
+    #[serde(default)]
+    pub content: MemoryContent,
     /// The timeline this memory is "from" (may differ from character's current timeline)
     pub source_timeline: TimelineId,
     /// How this memory came to exist
@@ -104,11 +177,23 @@ pub struct Memory {
     pub fidelity: f32,
 }
 
+/// What a memory is a memory *of*.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub enum MemoryContent {
+    /// The recorded event in `Memory::event`
+    #[default]
+    Event,
+    /// Hearsay with no scene behind it: a rumour, a price, a name overheard in
+    /// the Dark Spoke. `Memory::event` needn't be recorded; when it is, it's
+    /// where the memory surfaced rather than what it's about.
+    Abstract { gist: String },
+}
+
 /// Tracks how a memory was created.
 ///
 /// This is crucial for property testing—we can verify that characters only have
 /// memories that are justified by game events.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum MemoryProvenance {
     /// Directly witnessed by the original character
     Witnessed { character: CharacterId },
@@ -131,12 +216,19 @@ pub enum MemoryProvenance {
 /// - **Relationship state**: How they feel about other characters
 /// - **Alive status**: Dead characters can't act (unless resurrected)
 /// - **Abilities**: Special powers like timeline-perception, precognition
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Character {
     pub id: CharacterId,
     pub name: String,
+    /// Names they went by before, oldest first; see [`EventEffect::CharacterRename`]
+    #[serde(default)]
+    pub former_names: Vec<String>,
     /// The timeline this character currently inhabits
     pub current_timeline: TimelineId,
+    /// Every move between timelines, as the event that moved them and where they
+    /// went. Together with `native_timeline` this is everywhere they have lived
+    #[serde(default)]
+    pub timeline_history: Vec<(EventId, TimelineId)>,
     /// Their native timeline (where they originated)
     pub native_timeline: TimelineId,
     /// All memories this character possesses
@@ -145,30 +237,281 @@ pub struct Character {
     pub knowledge_flags: HashSet<String>,
     /// Is this character alive in their current timeline?
     pub alive: bool,
-    /// Special abilities that affect property validation
-    pub abilities: HashSet<Ability>,
+    /// Special abilities that affect property validation, keyed by kind
+    pub abilities: HashMap<AbilityKind, Ability>,
     /// Relationship values with other characters (in current timeline)
     pub relationships: HashMap<CharacterId, RelationshipState>,
+    /// How far a relationship has drifted within its level, for those that
+    /// have: the state is always [`RelationshipState::from_affinity`] of the
+    /// affinity here. See [`Character::affinity`]
+    #[serde(default)]
+    pub affinities: HashMap<CharacterId, f32>,
     /// Emotional state and goals (Gamygdala/PAD system)
     pub emotional_state: crate::emotional_system::EmotionalState,
+    /// Tether to the character's era of origin, if they are displaced in time
+    pub temporal_anchor: Option<TemporalAnchor>,
+    /// The timeline the past keeps pulling them back to, if any; see
+    /// [`Multiverse::advance_temporal_pull`]
+    #[serde(default)]
+    pub anchor_timeline: Option<TimelineId>,
+    /// How hard that pull has grown while they've been away from it. At 1.0
+    /// it drags them home
+    #[serde(default)]
+    pub pull_strength: f32,
+}
+
+/// ## Temporal Anchors
+///
+/// Some characters don't belong to the present. Kor-Valeth is a warrior from
+/// 1,000 years in the Ring's past, anchored to their original time and slowly
+/// being pulled back.
+///
+/// An anchor is a consumable resource: every event the character participates in
+/// erodes its `stability`. When stability reaches zero the past reclaims them via
+/// a forced-departure event, and they may never appear in that timeline again.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TemporalAnchor {
+    /// The era the character was displaced from (negative = years before present)
+    pub origin_era: i64,
+    /// Remaining hold on the present: 1.0 = firmly here, 0.0 = pulled back
+    pub stability: f32,
+}
+
+/// How much anchor stability a displaced character loses per event they take part in.
+pub const ANCHOR_DECAY_PER_EVENT: f32 = 0.1;
+
+/// How much stronger the pull of a character's anchor timeline grows with
+/// each [`Multiverse::advance_temporal_pull`] they spend away from it.
+pub const TEMPORAL_PULL_PER_ADVANCE: f32 = 0.25;
+
+/// The forgers every multiverse starts out knowing: the factions of the Ring
+/// who are in the business of fabricating memories.
+pub const KNOWN_FORGERS: [&str; 3] = ["Gate Cult", "Memory Cartels", "Dr. Lux"];
+
+fn known_forgers() -> BTreeSet<String> {
+    KNOWN_FORGERS.iter().map(|f| f.to_string()).collect()
 }
 
+/// The goal a stabilized timeline furthers: the Conductor's, and anyone else's
+/// who has taken it up.
+pub const PREVENT_RING_COLLAPSE: &str = "Prevent Ring Collapse";
+
+/// Memories degraded below this fidelity are lost to the character holding them.
+pub const MEMORY_FIDELITY_FLOOR: f32 = 0.1;
+
+/// The default [`ValidationConfig::min_usable_fidelity`]: a memory this faint is
+/// still held, but too far gone to act on.
+pub const MIN_USABLE_FIDELITY: f32 = 0.25;
+
 /// Special abilities that grant exceptions to normal narrative rules.
 ///
 /// For example, a character with `TimelinePerception` can reference events
 /// from alternate timelines without violating the consistency property.
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+///
+/// Abilities carry parameters (how far a precognitive sees, how widely a
+/// perceiver reaches), so they aren't suitable as set members on their own.
+/// Characters key their abilities by [`AbilityKind`]: a character holds at most
+/// one instance of each kind. Prefer the constructors (`Ability::precognition()`,
+/// etc.) over spelling out the variants, so parameters get sensible defaults.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum Ability {
-    /// Can perceive multiple timelines simultaneously
+    /// Can perceive other timelines simultaneously
+    TimelinePerception { scope: PerceptionScope },
+    /// Can see possible futures (may be contradictory), up to a horizon of events
+    Precognition { horizon_events: Option<u32> },
+    /// Immune to memory manipulation
+    MemoryImmunity,
+    /// Remembers across time loops, at the given fidelity (1.0 = perfect retention)
+    LoopMemory { retention: f32 },
+    /// Can manipulate causality directly
+    CausalityHacking,
+    /// Linked into the Ansible Lattice: can share knowledge with other linked
+    /// minds instantly, even across timelines
+    AnsibleLink,
+}
+
+/// How far a character with `TimelinePerception` can see.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum PerceptionScope {
+    /// Only timelines branching from the same parent as the current one
+    SiblingBranches,
+    /// Every timeline in the multiverse
+    AllTimelines,
+}
+
+/// The parameter-free identity of an [`Ability`], used for set membership.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum AbilityKind {
     TimelinePerception,
-    /// Can see possible futures (may be contradictory)
     Precognition,
-    /// Immune to memory manipulation
     MemoryImmunity,
-    /// Remembers across time loops
     LoopMemory,
-    /// Can manipulate causality directly
     CausalityHacking,
+    AnsibleLink,
+}
+
+impl Ability {
+    /// Perception of every timeline (the Conductor, Nameless).
+    pub fn timeline_perception() -> Self {
+        Ability::TimelinePerception {
+            scope: PerceptionScope::AllTimelines,
+        }
+    }
+
+    /// Perception limited to sibling branches (Vera's Fold Drive superposition).
+    pub fn sibling_perception() -> Self {
+        Ability::TimelinePerception {
+            scope: PerceptionScope::SiblingBranches,
+        }
+    }
+
+    /// Precognition with no fixed horizon.
+    pub fn precognition() -> Self {
+        Ability::Precognition {
+            horizon_events: None,
+        }
+    }
+
+    /// Precognition reaching at most `horizon_events` events ahead.
+    pub fn precognition_within(horizon_events: u32) -> Self {
+        Ability::Precognition {
+            horizon_events: Some(horizon_events),
+        }
+    }
+
+    pub fn memory_immunity() -> Self {
+        Ability::MemoryImmunity
+    }
+
+    /// Loop memory with perfect retention.
+    pub fn loop_memory() -> Self {
+        Ability::LoopMemory { retention: 1.0 }
+    }
+
+    /// Loop memory that retains only `retention` fidelity across loops.
+    pub fn loop_memory_with(retention: f32) -> Self {
+        Ability::LoopMemory { retention }
+    }
+
+    pub fn causality_hacking() -> Self {
+        Ability::CausalityHacking
+    }
+
+    pub fn ansible_link() -> Self {
+        Ability::AnsibleLink
+    }
+
+    pub fn kind(&self) -> AbilityKind {
+        match self {
+            Ability::TimelinePerception { .. } => AbilityKind::TimelinePerception,
+            Ability::Precognition { .. } => AbilityKind::Precognition,
+            Ability::MemoryImmunity => AbilityKind::MemoryImmunity,
+            Ability::LoopMemory { .. } => AbilityKind::LoopMemory,
+            Ability::CausalityHacking => AbilityKind::CausalityHacking,
+            Ability::AnsibleLink => AbilityKind::AnsibleLink,
+        }
+    }
+}
+
+impl Character {
+    /// Does this character hold an ability of the given kind (with any parameters)?
+    pub fn has_ability(&self, kind: AbilityKind) -> bool {
+        self.abilities.contains_key(&kind)
+    }
+
+    /// The character's ability of the given kind, with its parameters.
+    pub fn ability(&self, kind: AbilityKind) -> Option<&Ability> {
+        self.abilities.get(&kind)
+    }
+
+    /// How much of a time loop this character carries into the next: the
+    /// retention of their `LoopMemory`, or nothing without one.
+    pub fn loop_retention(&self) -> f32 {
+        match self.ability(AbilityKind::LoopMemory) {
+            Some(Ability::LoopMemory { retention }) => *retention,
+            _ => 0.0,
+        }
+    }
+
+    /// Grants an ability, replacing any existing ability of the same kind.
+    pub fn insert_ability(&mut self, ability: Ability) {
+        self.abilities.insert(ability.kind(), ability);
+    }
+
+    /// The name they entered the story with, before any renaming.
+    pub fn original_name(&self) -> &str {
+        self.former_names.first().unwrap_or(&self.name)
+    }
+
+    /// Whether the past has reclaimed this character: their temporal anchor is
+    /// exhausted, and they can take no further part in the story.
+    pub fn has_departed(&self) -> bool {
+        self.temporal_anchor
+            .as_ref()
+            .is_some_and(|anchor| anchor.stability <= 0.0)
+    }
+
+    /// Whether this character can take part in the story: alive, and not
+    /// reclaimed by the past.
+    pub fn is_present(&self) -> bool {
+        self.alive && !self.has_departed()
+    }
+
+    /// Whether this character has ever lived in `timeline`: born there, moved
+    /// there, or there now.
+    pub fn was_in_timeline(&self, timeline: TimelineId) -> bool {
+        self.native_timeline == timeline
+            || self.current_timeline == timeline
+            || self.timeline_history.iter().any(|(_, t)| *t == timeline)
+    }
+
+    /// The timeline this character lived in when `event` happened, going by
+    /// their recorded moves. The event that moves them already finds them in
+    /// their new timeline; see [`Character::timeline_before`] for the old one.
+    pub fn timeline_at(&self, event: EventId) -> TimelineId {
+        self.timeline_history
+            .iter()
+            .take_while(|(moved_at, _)| moved_at.0 <= event.0)
+            .last()
+            .map_or(self.native_timeline, |&(_, to)| to)
+    }
+
+    /// How this character feels about `other`, from -2.0 (Hostile) to 2.0
+    /// (Allied): the drifted affinity if there is one, otherwise the middle of
+    /// their relationship's level, or 0.0 for strangers.
+    pub fn affinity(&self, other: CharacterId) -> f32 {
+        self.affinities
+            .get(&other)
+            .copied()
+            .or_else(|| self.relationships.get(&other).map(|r| r.affinity()))
+            .unwrap_or(0.0)
+    }
+
+    /// The timeline this character lived in just before `event` happened.
+    pub fn timeline_before(&self, event: EventId) -> TimelineId {
+        self.timeline_history
+            .iter()
+            .take_while(|(moved_at, _)| moved_at.0 < event.0)
+            .last()
+            .map_or(self.native_timeline, |&(_, to)| to)
+    }
+
+    /// Whether the recorded history shows this character living in `timeline`
+    /// when `event` happened, and moving away since. Whatever they picked up
+    /// there, they brought with them.
+    pub fn lived_in_timeline_at(&self, timeline: TimelineId, event: EventId) -> bool {
+        let mut residence = self.native_timeline;
+        let mut arrived: Option<EventId> = None; // None: since they were created
+        for &(moved_at, to) in &self.timeline_history {
+            let there_by_then = arrived.map(|a| a.0 <= event.0).unwrap_or(true);
+            if residence == timeline && there_by_then && event.0 < moved_at.0 {
+                return true;
+            }
+            residence = to;
+            arrived = Some(moved_at);
+        }
+        false
+    }
 }
 
 /// Relationship states between characters.
@@ -183,6 +526,40 @@ pub enum RelationshipState {
     Allied = 2,
 }
 
+/// The strongest a relationship can be, either way: Allied is `MAX_AFFINITY`
+/// and Hostile its negative.
+pub const MAX_AFFINITY: f32 = 2.0;
+
+/// How strongly someone has to feel about another, all told, before it moves
+/// where they stand; see [`Multiverse::apply_emotional_relationship_drift`].
+pub const RELATIONSHIP_DRIFT_THRESHOLD: f64 = 0.25;
+
+impl RelationshipState {
+    /// How well one character wishes another, from -1 (Hostile) to 1 (Allied).
+    pub fn regard(self) -> f64 {
+        self as i8 as f64 / 2.0
+    }
+
+    /// The middle of this level on the continuous scale: Friendly is 1.0.
+    pub fn affinity(self) -> f32 {
+        self as i8 as f32
+    }
+
+    /// The level a continuous affinity falls in: the nearest one, with a tie
+    /// going to the stronger feeling, so Friendly runs from 0.5 up to but not
+    /// including 1.5. Out of range affinities count as the extremes.
+    pub fn from_affinity(affinity: f32) -> Self {
+        match affinity.clamp(-MAX_AFFINITY, MAX_AFFINITY).round() as i8 {
+            -2 => RelationshipState::Hostile,
+            -1 => RelationshipState::Distrustful,
+            1 => RelationshipState::Friendly,
+            2 => RelationshipState::Allied,
+            // NaN rounds to zero too
+            _ => RelationshipState::Neutral,
+        }
+    }
+}
+
 /// ## Timeline Structure
 ///
 /// A timeline is a branching point in the narrative. It tracks:
@@ -190,12 +567,14 @@ pub enum RelationshipState {
 /// - What characters exist in this timeline
 /// - What events have occurred
 /// - Whether causality is stable or violated
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Timeline {
     pub id: TimelineId,
     /// The timeline this branched from (None for the original timeline)
     pub parent: Option<TimelineId>,
-    /// The event that caused this timeline to diverge
+    /// The event that caused this timeline to diverge. The branch inherits the
+    /// parent's history up to and including this event; anything the parent
+    /// records afterwards belongs to the parent alone.
     pub divergence_event: Option<EventId>,
     /// All events that have occurred in this timeline (in order)
     pub events: Vec<EventId>,
@@ -212,7 +591,7 @@ pub struct Timeline {
 /// - Involves one or more characters
 /// - May affect character state, relationships, or memories
 /// - May have causality-violating properties (precedes its cause, etc.)
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Event {
     pub id: EventId,
     pub timeline: TimelineId,
@@ -225,11 +604,54 @@ pub struct Event {
     pub causality_violation: Option<CausalityViolation>,
 }
 
+impl Event {
+    /// Whether `character` took part in this event or is touched by its effects.
+    pub fn involves(&self, character: CharacterId) -> bool {
+        self.participants.contains(&character) || self.effects.iter().any(|e| e.concerns(character))
+    }
+
+    /// Effects apply in order, so an effect that needs a character alive
+    /// can't come after one that killed them in the same event, unless a
+    /// resurrection comes between.
+    pub fn validate_effect_order(&self) -> Result<(), String> {
+        match self.first_posthumous_effect() {
+            None => Ok(()),
+            Some((index, character)) => Err(format!(
+                "effect {} ({}) needs {} alive, but an earlier effect in the event killed them",
+                index,
+                self.effects[index].name(),
+                character
+            )),
+        }
+    }
+
+    /// The position of the first effect that needs someone alive after an
+    /// earlier effect of this event killed them, and who.
+    fn first_posthumous_effect(&self) -> Option<(usize, CharacterId)> {
+        let mut dead = HashSet::new();
+        for (index, effect) in self.effects.iter().enumerate() {
+            if let Some(&character) = dead.iter().find(|&&c| effect.needs_alive(c)) {
+                return Some((index, character));
+            }
+            match effect {
+                EventEffect::CharacterDeath { character } => {
+                    dead.insert(*character);
+                }
+                EventEffect::CharacterResurrection { character, .. } => {
+                    dead.remove(character);
+                }
+                _ => {}
+            }
+        }
+        None
+    }
+}
+
 /// Effects that events can have on the game state.
 ///
 /// These are tracked explicitly so property tests can verify state changes
 /// are properly propagated.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum EventEffect {
     /// Character dies in this timeline
     CharacterDeath { character: CharacterId },
@@ -238,41 +660,210 @@ pub enum EventEffect {
         character: CharacterId,
         mechanism: String,
     },
-    /// Relationship change between two characters
+    /// Character becomes someone else, as Yash-Tel does at the Living Gate.
+    /// The old name is kept in [`Character::former_names`]
+    CharacterRename {
+        character: CharacterId,
+        new_name: String,
+    },
+    /// Relationship change between two characters. Sets the level outright,
+    /// and any drift within the old one is forgotten
     RelationshipChange {
         character1: CharacterId,
         character2: CharacterId,
         new_state: RelationshipState,
     },
+    /// Two characters' feelings for each other shift by `delta` on the
+    /// continuous scale, within [-`MAX_AFFINITY`, `MAX_AFFINITY`]. The level only
+    /// changes if the shift carries it over a threshold
+    AffinityShift {
+        character1: CharacterId,
+        character2: CharacterId,
+        delta: f32,
+    },
     /// Knowledge flag is set
     KnowledgeGained {
         character: CharacterId,
         flag: String,
     },
-    /// Memory is traded or installed
+    /// One character passes a knowledge flag to another. Within a timeline this
+    /// is just conversation; across timelines it requires both to be linked into
+    /// the Ansible Lattice.
+    KnowledgeShared {
+        from: CharacterId,
+        to: CharacterId,
+        flag: String,
+    },
+    /// Memory is traded or installed. An `exclusive` transfer is a sale: `from`
+    /// gives the memory up. Otherwise it's a copy, and `from` keeps theirs
     MemoryTransfer {
         memory: MemoryId,
         from: Option<CharacterId>,
         to: CharacterId,
+        #[serde(default)]
+        exclusive: bool,
     },
+    /// A character's memories fade: every memory they hold loses `amount` of its
+    /// fidelity, and any that fall below [`MEMORY_FIDELITY_FLOOR`] are lost
+    MemoryDegrade { character: CharacterId, amount: f32 },
+    /// A character's memories are erased entirely (a Living Gate's price)
+    MemoryWipe { character: CharacterId },
+    /// A character acts on a memory they hold. Only valid while the memory's
+    /// fidelity is above [`ValidationConfig::min_usable_fidelity`]
+    MemoryRecall { character: CharacterId, memory: MemoryId },
     /// Timeline branches at this point
     TimelineBranch { new_timeline: TimelineId },
+    /// The causality violation in `violation`, an earlier event of the same
+    /// timeline, is mended. A timeline can only be stabilized once every
+    /// violation in it has been
+    CausalityRepaired { violation: EventId, mechanism: String },
+    /// The event's timeline is causally sound again
+    TimelineStabilized { mechanism: String },
+    /// A character crosses into another timeline (a Fold jump, a Gate transit)
+    /// and takes up residence there
+    TimelineMove { character: CharacterId, to: TimelineId },
     /// An emotional appraisal event (Gamygdala belief)
     AppraisalTrigger {
         character: CharacterId,
         belief: crate::emotional_system::Belief,
     },
+    /// An onlooker's feelings about what happened to someone they have a
+    /// relationship with: HappyFor, Pity, Resentment, or Gloating. Only for
+    /// someone who took part in the event or perceives its timeline, and only
+    /// alongside the `subject`'s own appraisal
+    SocialAppraisal {
+        observer: CharacterId,
+        subject: CharacterId,
+        emotion: crate::emotional_system::Emotion,
+    },
+    /// A goal comes true (`success`) or is given up on. Either way it's
+    /// appraised and, unless it's a maintenance goal come true, dropped; see
+    /// [`EmotionalState::complete_goal`] and [`EmotionalState::abandon_goal`]
+    GoalResolved {
+        character: CharacterId,
+        goal: String,
+        success: bool,
+    },
+    /// Some of what `from` feels rubs off on `to`, who shares scenes with
+    /// them: `to` feels `emotion`. See [`Multiverse::propagate_emotions`]
+    EmotionSpread {
+        from: CharacterId,
+        to: CharacterId,
+        emotion: crate::emotional_system::Emotion,
+    },
     /// Add a goal to a character
     AddGoal {
         character: CharacterId,
         goal: crate::emotional_system::Goal,
     },
+    /// A character is displaced from their era and anchored to it from now on
+    AnchorGained {
+        character: CharacterId,
+        anchor: TemporalAnchor,
+    },
+    /// An anchored character is pulled back to their era of origin
+    TemporalDeparture { character: CharacterId },
+    /// Character acquires a special ability
+    AbilityGained {
+        character: CharacterId,
+        ability: Ability,
+        mechanism: String, // "Fold Drive exposure", "Precursor relic", etc.
+    },
+    /// Character loses a special ability
+    AbilityLost {
+        character: CharacterId,
+        ability: AbilityKind,
+    },
+}
+
+named_variants!(EventEffect {
+    CharacterDeath,
+    CharacterResurrection,
+    CharacterRename,
+    RelationshipChange,
+    AffinityShift,
+    KnowledgeGained,
+    KnowledgeShared,
+    MemoryTransfer,
+    MemoryDegrade,
+    MemoryWipe,
+    MemoryRecall,
+    TimelineBranch,
+    CausalityRepaired,
+    TimelineStabilized,
+    TimelineMove,
+    AppraisalTrigger,
+    SocialAppraisal,
+    EmotionSpread,
+    AddGoal,
+    GoalResolved,
+    AnchorGained,
+    TemporalDeparture,
+    AbilityGained,
+    AbilityLost,
+});
+
+impl EventEffect {
+    /// Whether `character` has to be alive for this effect: they act, learn,
+    /// feel, or move in it. The dead can still die, come back, lose memories
+    /// and abilities, hand over memories, and be felt about.
+    pub fn needs_alive(&self, character: CharacterId) -> bool {
+        match self {
+            EventEffect::CharacterDeath { .. }
+            | EventEffect::CharacterResurrection { .. }
+            | EventEffect::MemoryDegrade { .. }
+            | EventEffect::MemoryWipe { .. }
+            | EventEffect::AbilityLost { .. } => false,
+            EventEffect::MemoryTransfer { to, .. } => *to == character,
+            EventEffect::SocialAppraisal { observer, .. } => *observer == character,
+            _ => self.concerns(character),
+        }
+    }
+
+    /// Whether this effect names `character`, on either side.
+    pub fn concerns(&self, character: CharacterId) -> bool {
+        match self {
+            EventEffect::RelationshipChange {
+                character1,
+                character2,
+                ..
+            }
+            | EventEffect::AffinityShift {
+                character1,
+                character2,
+                ..
+            } => *character1 == character || *character2 == character,
+            EventEffect::KnowledgeShared { from, to, .. } | EventEffect::EmotionSpread { from, to, .. } => {
+                *from == character || *to == character
+            }
+            EventEffect::SocialAppraisal { observer, subject, .. } => *observer == character || *subject == character,
+            EventEffect::MemoryTransfer { from, to, .. } => *from == Some(character) || *to == character,
+            EventEffect::TimelineBranch { .. }
+            | EventEffect::CausalityRepaired { .. }
+            | EventEffect::TimelineStabilized { .. } => false,
+            EventEffect::CharacterDeath { character: c }
+            | EventEffect::CharacterResurrection { character: c, .. }
+            | EventEffect::CharacterRename { character: c, .. }
+            | EventEffect::KnowledgeGained { character: c, .. }
+            | EventEffect::MemoryDegrade { character: c, .. }
+            | EventEffect::MemoryWipe { character: c }
+            | EventEffect::MemoryRecall { character: c, .. }
+            | EventEffect::TimelineMove { character: c, .. }
+            | EventEffect::AppraisalTrigger { character: c, .. }
+            | EventEffect::AddGoal { character: c, .. }
+            | EventEffect::GoalResolved { character: c, .. }
+            | EventEffect::AnchorGained { character: c, .. }
+            | EventEffect::TemporalDeparture { character: c }
+            | EventEffect::AbilityGained { character: c, .. }
+            | EventEffect::AbilityLost { character: c, .. } => *c == character,
+        }
+    }
 }
 
 /// Types of causality violations that can occur.
 ///
 /// These must have in-universe justifications (Gates, time weapons, etc.)
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum CausalityViolation {
     /// Effect precedes cause (e.g., Riven's time-gun)
     EffectBeforeCause {
@@ -282,6 +873,129 @@ pub enum CausalityViolation {
     RetroactiveChange { mechanism: String },
     /// Event exists in quantum superposition
     Superposition { mechanism: String },
+    /// Something with no origin: the manual that teaches how to build the gun
+    /// it was written with. It exists because it always existed in the loop
+    Bootstrap {
+        mechanism: String,
+        /// What came from nowhere, e.g. "Time-gun schematics"
+        looped_item: String,
+    },
+}
+
+impl CausalityViolation {
+    /// The variant name, for reporting and coverage bookkeeping.
+    pub fn name(&self) -> &'static str {
+        match self {
+            CausalityViolation::EffectBeforeCause { .. } => "EffectBeforeCause",
+            CausalityViolation::RetroactiveChange { .. } => "RetroactiveChange",
+            CausalityViolation::Superposition { .. } => "Superposition",
+            CausalityViolation::Bootstrap { .. } => "Bootstrap",
+        }
+    }
+
+    /// How much this violation adds to [`Multiverse::incoherence_level`]. A
+    /// superposition hasn't collapsed into anything yet; an effect before its
+    /// cause is the baseline; rewriting the past costs more, and a loop with no
+    /// origin at all costs most.
+    pub fn incoherence_cost(&self) -> f64 {
+        match self {
+            CausalityViolation::Superposition { .. } => 0.5,
+            CausalityViolation::EffectBeforeCause { .. } => 1.0,
+            CausalityViolation::RetroactiveChange { .. } => 2.0,
+            CausalityViolation::Bootstrap { .. } => 3.0,
+        }
+    }
+}
+
+/// A request the multiverse refuses: a reference to something it has never
+/// heard of, or a change that would break one of its configured limits.
+///
+/// This is a mistake in whoever built the request, as opposed to a world
+/// state in which the request simply doesn't apply (a dead character can't be
+/// killed again, but that's not an error).
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum MultiverseError {
+    #[error("no such character: {0}")]
+    UnknownCharacter(CharacterId),
+    #[error("no such timeline: {0}")]
+    UnknownTimeline(TimelineId),
+    #[error("no such event: {0}")]
+    UnknownEvent(EventId),
+    #[error("a collective needs at least one member")]
+    EmptyCollective,
+    #[error("branching {parent} would exceed the maximum branch depth of {max_depth}")]
+    BranchTooDeep {
+        parent: TimelineId,
+        max_depth: usize,
+    },
+    #[error("{0} doesn't violate causality, so there's nothing to repair")]
+    NotAViolation(EventId),
+    #[error("{timeline} can't be stabilized while the violation in {event} is unrepaired")]
+    UnresolvedViolation { timeline: TimelineId, event: EventId },
+}
+
+/// Why a saved multiverse couldn't be loaded.
+#[derive(Debug, Error)]
+pub enum LoadError {
+    #[error("malformed multiverse JSON: {0}")]
+    Json(#[from] serde_json::Error),
+    #[cfg(feature = "bincode")]
+    #[error("malformed multiverse bincode: {0}")]
+    Bincode(#[from] bincode::Error),
+    /// The JSON parsed, but refers to things that aren't in it
+    #[error("{} dangling references: {}", .0.len(), .0.join("; "))]
+    Integrity(Vec<String>),
+}
+
+/// Limits a multiverse enforces on itself.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MultiverseConfig {
+    /// How many branches deep a timeline may be (the root is depth 0). `None`
+    /// means unbounded; random tests should set one, since every branch copies
+    /// its parent's cast
+    pub max_branch_depth: Option<usize>,
+    /// Whether every knowledge flag must be registered with
+    /// [`Multiverse::register_flag`] before it is granted
+    #[serde(default)]
+    pub strict_flags: bool,
+    /// Thresholds the properties check against
+    #[serde(default)]
+    pub validation: ValidationConfig,
+}
+
+/// Thresholds for property checks that are a matter of degree.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ValidationConfig {
+    /// Memories at or below this fidelity can't be recalled
+    pub min_usable_fidelity: f32,
+}
+
+impl Default for ValidationConfig {
+    fn default() -> Self {
+        ValidationConfig {
+            min_usable_fidelity: MIN_USABLE_FIDELITY,
+        }
+    }
+}
+
+/// ## Collective Characters
+///
+/// Synthesis is seven bodies and one mind. Each body is a character of its own,
+/// able to be in a different timeline from the rest, but memories belong to the
+/// mind: whatever one body is handed, every living body is handed too, and what
+/// one body sells, none of them keeps.
+///
+/// Losses are the exception. A body that is wiped, fades, or dies loses its
+/// memories alone, and gets them back when the collective next syncs.
+///
+/// Feelings are each body's own between syncs, but bodies that were apart
+/// come back one mind: see [`Multiverse::link_collective`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CollectiveCharacter {
+    pub members: BTreeSet<CharacterId>,
+    /// The event at which every member last held the same memories
+    pub last_sync: EventId,
 }
 
 /// ## The Multiverse
@@ -293,7 +1007,7 @@ pub enum CausalityViolation {
 /// - All events
 ///
 /// This is the structure that property tests will generate and validate.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Multiverse {
     pub timelines: HashMap<TimelineId, Timeline>,
     pub characters: HashMap<CharacterId, Character>,
@@ -301,6 +1015,48 @@ pub struct Multiverse {
     pub events: HashMap<EventId, Event>,
     /// The "canonical" timeline (usually Timeline#0)
     pub root_timeline: TimelineId,
+    /// Limits enforced on changes, such as how deep branches may nest
+    #[serde(default)]
+    pub config: MultiverseConfig,
+    /// Knowledge flags the story declares; see [`KnowledgeFlag`]
+    #[serde(default)]
+    pub flag_registry: BTreeSet<KnowledgeFlag>,
+    /// Who may forge memories; starts with [`KNOWN_FORGERS`]
+    #[serde(default = "known_forgers")]
+    pub forger_registry: BTreeSet<String>,
+    /// Pairs of knowledge flags that can't both be true, such as two futures
+    /// only one of which can happen; see [`Multiverse::declare_mutually_exclusive`]
+    #[serde(default)]
+    pub mutually_exclusive: Vec<(String, String)>,
+    /// Characters sharing one memory pool; see [`CollectiveCharacter`]
+    #[serde(default)]
+    pub collectives: Vec<CollectiveCharacter>,
+    /// Rules that hold for one character alone, such as the Cartographer never
+    /// losing a memory; see [`Multiverse::register_character_invariant`]
+    #[serde(default)]
+    pub character_invariants: Vec<(CharacterId, crate::properties::CharacterInvariant)>,
+    /// Rules turning feelings into events, and their firings; see
+    /// [`Multiverse::register_trigger`]
+    #[serde(default)]
+    pub(crate) emotion_triggers: EmotionTriggerRegistry,
+    /// What each event's appraisals made each character feel; see
+    /// [`Multiverse::emotional_outcomes`]
+    #[serde(default)]
+    pub(crate) emotion_log: HashMap<EventId, Vec<(CharacterId, crate::emotional_system::Emotion)>>,
+    /// Effects dropped from recorded events for coming after their
+    /// character's death; see [`Multiverse::posthumous_effects`]
+    #[serde(default)]
+    pub(crate) posthumous_log: HashMap<EventId, Vec<EventEffect>>,
+    /// Strain the recorded causality violations have put on the Ring; see
+    /// [`Multiverse::incoherence_level`]
+    #[serde(default)]
+    pub(crate) incoherence: f64,
+    /// Notified of every recorded event; see [`MultiverseObserver`]
+    #[serde(skip)]
+    observers: Observers,
+    /// Checked once the story is over; see [`Obligation`]
+    #[serde(skip)]
+    obligations: Obligations,
     /// Counter for generating unique IDs
     next_timeline_id: u64,
     next_character_id: u64,
@@ -331,6 +1087,18 @@ impl Multiverse {
             memories: HashMap::new(),
             events: HashMap::new(),
             root_timeline,
+            config: MultiverseConfig::default(),
+            flag_registry: BTreeSet::new(),
+            forger_registry: known_forgers(),
+            mutually_exclusive: Vec::new(),
+            collectives: Vec::new(),
+            character_invariants: Vec::new(),
+            emotion_triggers: EmotionTriggerRegistry::default(),
+            emotion_log: HashMap::new(),
+            posthumous_log: HashMap::new(),
+            incoherence: 0.0,
+            observers: Observers::default(),
+            obligations: Obligations::default(),
             next_timeline_id: 1,
             next_character_id: 0,
             next_memory_id: 0,
@@ -338,77 +1106,659 @@ impl Multiverse {
         }
     }
 
-    /// Creates a new character in the specified timeline.
-    pub fn create_character(&mut self, name: String, timeline: TimelineId) -> CharacterId {
-        let id = CharacterId(self.next_character_id);
-        self.next_character_id += 1;
-
-        let character = Character {
-            id,
-            name,
-            current_timeline: timeline,
-            native_timeline: timeline,
-            memories: HashSet::new(),
-            knowledge_flags: HashSet::new(),
-            alive: true,
-            abilities: HashSet::new(),
-            relationships: HashMap::new(),
-            emotional_state: crate::emotional_system::EmotionalState::new(),
-        };
-
-        self.characters.insert(id, character);
-
-        if let Some(timeline_data) = self.timelines.get_mut(&timeline) {
-            timeline_data.characters.insert(id);
-        }
+    /// Attaches an observer, notified of every event recorded from now on.
+    pub fn add_observer(&mut self, observer: impl MultiverseObserver + 'static) {
+        self.observers.push(Box::new(observer));
+    }
 
-        id
+    /// Detaches and returns every observer.
+    pub fn take_observers(&mut self) -> Vec<Box<dyn MultiverseObserver>> {
+        self.observers.take()
     }
 
-    /// Creates a new timeline branching from a parent.
-    pub fn create_timeline_branch(
-        &mut self,
-        parent: TimelineId,
-        divergence_event: EventId,
-    ) -> TimelineId {
-        let id = TimelineId(self.next_timeline_id);
-        self.next_timeline_id += 1;
+    /// Serializes the multiverse for [`Multiverse::load_from_json`].
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(self)
+    }
 
-        // Copy characters from parent timeline
-        let parent_characters = self
-            .timelines
-            .get(&parent)
-            .map(|t| t.characters.clone())
-            .unwrap_or_default();
+    /// Loads a multiverse saved with [`Multiverse::to_json`], refusing one whose
+    /// cross-references don't resolve (see
+    /// [`Multiverse::validate_structural_integrity`]).
+    pub fn load_from_json(json: &str) -> Result<Multiverse, LoadError> {
+        let multiverse: Multiverse = serde_json::from_str(json)?;
+        multiverse.validate_structural_integrity().map_err(LoadError::Integrity)?;
+        Ok(multiverse)
+    }
 
-        let timeline = Timeline {
-            id,
-            parent: Some(parent),
-            divergence_event: Some(divergence_event),
-            events: Vec::new(),
-            characters: parent_characters,
-            causality_stable: true,
-        };
+    /// Serializes the multiverse for [`Multiverse::from_bincode`]: the same
+    /// state as [`Multiverse::to_json`], id counters included, in a fraction
+    /// of the space.
+    #[cfg(feature = "bincode")]
+    pub fn to_bincode(&self) -> bincode::Result<Vec<u8>> {
+        bincode::serialize(self)
+    }
 
-        self.timelines.insert(id, timeline);
-        id
+    /// Loads a multiverse saved with [`Multiverse::to_bincode`], refusing one
+    /// whose cross-references don't resolve, as [`Multiverse::load_from_json`] does.
+    #[cfg(feature = "bincode")]
+    pub fn from_bincode(bytes: &[u8]) -> Result<Multiverse, LoadError> {
+        let multiverse: Multiverse = bincode::deserialize(bytes)?;
+        multiverse.validate_structural_integrity().map_err(LoadError::Integrity)?;
+        Ok(multiverse)
     }
 
-    /// Creates a memory from a witnessed event.
-    pub fn create_witnessed_memory(
-        &mut self,
-        event: EventId,
-        timeline: TimelineId,
-        character: CharacterId,
-    ) -> MemoryId {
-        let id = MemoryId(self.next_memory_id);
-        self.next_memory_id += 1;
+    /// Checks that every id the multiverse mentions refers to something in it.
+    ///
+    /// This is referential integrity, not narrative sense: a dead character
+    /// speaking passes here and fails the properties, while an event in a
+    /// timeline that doesn't exist fails here, before the properties could
+    /// trip over it. Anything built through the multiverse's own methods
+    /// passes; hand-edited or corrupt JSON may not.
+    pub fn validate_structural_integrity(&self) -> Result<(), Vec<String>> {
+        let mut problems = Vec::new();
+        let timeline = |id: &TimelineId| self.timelines.contains_key(id);
+        let character = |id: &CharacterId| self.characters.contains_key(id);
+        let event = |id: &EventId| self.events.contains_key(id);
+        let memory = |id: &MemoryId| self.memories.contains_key(id);
 
-        let memory = Memory {
+        if !timeline(&self.root_timeline) {
+            problems.push(format!("root timeline {} does not exist", self.root_timeline));
+        }
+        for (id, t) in &self.timelines {
+            if t.id != *id {
+                problems.push(format!("{} is stored under {}", t.id, id));
+            }
+            if let Some(parent) = t.parent.filter(|p| !timeline(p)) {
+                problems.push(format!("{} branches from missing {}", id, parent));
+            }
+            if let Some(divergence) = t.divergence_event.filter(|e| !event(e)) {
+                problems.push(format!("{} diverges at missing {}", id, divergence));
+            }
+            for e in &t.events {
+                match self.events.get(e) {
+                    None => problems.push(format!("{} lists missing {}", id, e)),
+                    Some(recorded) if recorded.timeline != *id => {
+                        problems.push(format!("{} lists {}, which happened in {}", id, e, recorded.timeline))
+                    }
+                    Some(_) => {}
+                }
+            }
+            for c in t.characters.iter().filter(|c| !character(c)) {
+                problems.push(format!("{} is home to missing {}", id, c));
+            }
+        }
+        for (id, e) in &self.events {
+            if e.id != *id {
+                problems.push(format!("{} is stored under {}", e.id, id));
+            }
+            match self.timelines.get(&e.timeline) {
+                None => problems.push(format!("{} happens in missing {}", id, e.timeline)),
+                Some(t) if !t.events.contains(id) => {
+                    problems.push(format!("{} is not listed in its timeline {}", id, e.timeline))
+                }
+                Some(_) => {}
+            }
+            for p in e.participants.iter().filter(|p| !character(p)) {
+                problems.push(format!("{} has missing participant {}", id, p));
+            }
+        }
+        for (id, m) in &self.memories {
+            if m.id != *id {
+                problems.push(format!("{} is stored under {}", m.id, id));
+            }
+            if m.content == MemoryContent::Event && !event(&m.event) {
+                problems.push(format!("{} remembers missing {}", id, m.event));
+            }
+            if !timeline(&m.source_timeline) {
+                problems.push(format!("{} comes from missing {}", id, m.source_timeline));
+            }
+            if let MemoryProvenance::Compound { sources } = &m.provenance {
+                for source in sources.iter().filter(|s| !memory(s)) {
+                    problems.push(format!("{} is blended from missing {}", id, source));
+                }
+            }
+        }
+        for (id, c) in &self.characters {
+            if c.id != *id {
+                problems.push(format!("{} is stored under {}", c.id, id));
+            }
+            for t in [c.current_timeline, c.native_timeline].iter().filter(|t| !timeline(t)) {
+                problems.push(format!("{} lives in missing {}", id, t));
+            }
+            for m in c.memories.iter().filter(|m| !memory(m)) {
+                problems.push(format!("{} holds missing {}", id, m));
+            }
+            for other in c.relationships.keys().filter(|o| !character(o)) {
+                problems.push(format!("{} has a relationship with missing {}", id, other));
+            }
+        }
+        for collective in &self.collectives {
+            for member in collective.members.iter().filter(|m| !character(m)) {
+                problems.push(format!("a collective includes missing {}", member));
+            }
+        }
+        for (c, invariant) in self.character_invariants.iter().filter(|(c, _)| !character(c)) {
+            problems.push(format!("missing {} is held to {:?}", c, invariant));
+        }
+
+        problems.sort();
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(problems)
+        }
+    }
+
+    /// Holds the story to an obligation, checked by
+    /// [`validate_final`](crate::temporal::validate_final).
+    pub fn register_obligation(&mut self, obligation: Obligation) {
+        self.obligations.push(obligation);
+    }
+
+    /// Holds `character` to `invariant`, checked with every other property by
+    /// [`validate_all_properties`](crate::properties::validate_all_properties).
+    /// Registering the same one twice is harmless.
+    pub fn register_character_invariant(
+        &mut self,
+        character: CharacterId,
+        invariant: crate::properties::CharacterInvariant,
+    ) {
+        if !self.character_invariants.contains(&(character, invariant)) {
+            self.character_invariants.push((character, invariant));
+        }
+    }
+
+    /// Whether `character` has been held to `invariant` with
+    /// [`Multiverse::register_character_invariant`].
+    pub fn is_held_to(&self, character: CharacterId, invariant: crate::properties::CharacterInvariant) -> bool {
+        self.character_invariants.contains(&(character, invariant))
+    }
+
+    /// The registered emotion triggers, and every time they fired.
+    pub fn emotion_triggers(&self) -> &EmotionTriggerRegistry {
+        &self.emotion_triggers
+    }
+
+    /// Every registered obligation, in registration order.
+    pub fn obligations(&self) -> impl Iterator<Item = &Obligation> {
+        self.obligations.iter()
+    }
+
+    /// Adds a knowledge flag to the registry, returning the registered flag.
+    /// Registering a flag twice is harmless.
+    pub fn register_flag(&mut self, name: impl Into<KnowledgeFlag>) -> KnowledgeFlag {
+        let flag = name.into();
+        self.flag_registry.insert(flag.clone());
+        flag
+    }
+
+    /// Whether `name` has been registered with [`Multiverse::register_flag`].
+    pub fn is_registered_flag(&self, name: &str) -> bool {
+        self.flag_registry.contains(name)
+    }
+
+    /// Declares someone able to forge memories, so forgeries naming them
+    /// validate. [`KNOWN_FORGERS`] are registered from the start.
+    pub fn register_forger(&mut self, name: impl Into<String>) {
+        self.forger_registry.insert(name.into());
+    }
+
+    /// Declares that flags `a` and `b` can't both be true. Only a precognitive,
+    /// who sees futures that can't all happen, may know both. Declaring a pair
+    /// twice, in either order, is harmless.
+    pub fn declare_mutually_exclusive(&mut self, a: impl Into<String>, b: impl Into<String>) {
+        let (a, b) = (a.into(), b.into());
+        if !self.are_mutually_exclusive(&a, &b) {
+            self.mutually_exclusive.push((a, b));
+        }
+    }
+
+    /// Whether flags `a` and `b` were declared mutually exclusive, in either order.
+    pub fn are_mutually_exclusive(&self, a: &str, b: &str) -> bool {
+        self.mutually_exclusive
+            .iter()
+            .any(|(x, y)| (x == a && y == b) || (x == b && y == a))
+    }
+
+    /// Whether `name` may forge memories; see [`Multiverse::register_forger`].
+    pub fn is_registered_forger(&self, name: &str) -> bool {
+        self.forger_registry.contains(name)
+    }
+
+    /// Creates a new character in the specified timeline.
+    pub fn create_character(&mut self, name: String, timeline: TimelineId) -> CharacterId {
+        let id = CharacterId(self.next_character_id);
+        self.next_character_id += 1;
+
+        let character = Character {
+            id,
+            name,
+            former_names: Vec::new(),
+            current_timeline: timeline,
+            timeline_history: Vec::new(),
+            native_timeline: timeline,
+            memories: HashSet::new(),
+            knowledge_flags: HashSet::new(),
+            alive: true,
+            abilities: HashMap::new(),
+            relationships: HashMap::new(),
+            affinities: HashMap::new(),
+            emotional_state: crate::emotional_system::EmotionalState::new(),
+            temporal_anchor: None,
+            anchor_timeline: None,
+            pull_strength: 0.0,
+        };
+
+        self.characters.insert(id, character);
+
+        if let Some(timeline_data) = self.timelines.get_mut(&timeline) {
+            timeline_data.characters.insert(id);
+        }
+
+        id
+    }
+
+    /// The character currently going by `name`, if any; the lowest id when
+    /// several share it. Former names don't match, so after a
+    /// [`EventEffect::CharacterRename`] only the new name finds them.
+    pub fn character_named(&self, name: &str) -> Option<CharacterId> {
+        self.characters.values().filter(|c| c.name == name).map(|c| c.id).min()
+    }
+
+    /// Spawns a future self of an existing character.
+    ///
+    /// The copy is named `"{source name} {suffix}"`, lives in the source's current
+    /// timeline, and inherits their abilities, knowledge flags, memories, and
+    /// emotional state, but not their relationships: those belong to the present.
+    /// Everything except the emotional state is handed over through a recorded
+    /// event, so the new self passes the same justification properties as the
+    /// original.
+    ///
+    /// Memories are only handed over if the copy could perceive where they're
+    /// from: the copy has never lived anywhere but here, so a memory the source
+    /// brought from a former home stays with the source.
+    ///
+    /// Fails with [`MultiverseError::UnknownCharacter`] if the source character
+    /// doesn't exist.
+    pub fn clone_character(&mut self, source: CharacterId, suffix: &str) -> Result<CharacterId, MultiverseError> {
+        self.spawn_self(source, suffix, false)
+    }
+
+    /// Like [`Multiverse::clone_character`], for a parallel self who also
+    /// inherits the source's relationships, drifted affinities included. They
+    /// arrive as `RelationshipChange`s in the same event as everything else.
+    pub fn clone_character_with_relationships(
+        &mut self,
+        source: CharacterId,
+        suffix: &str,
+    ) -> Result<CharacterId, MultiverseError> {
+        self.spawn_self(source, suffix, true)
+    }
+
+    fn spawn_self(
+        &mut self,
+        source: CharacterId,
+        suffix: &str,
+        copy_relationships: bool,
+    ) -> Result<CharacterId, MultiverseError> {
+        let original = self
+            .characters
+            .get(&source)
+            .ok_or(MultiverseError::UnknownCharacter(source))?
+            .clone();
+        let timeline = original.current_timeline;
+        // The copy sees from here with the source's abilities, as the source does now
+        let perceivable = |memory: &MemoryId| {
+            self.memories.get(memory).is_some_and(|m| {
+                m.source_timeline == timeline || self.can_perceive_timeline(source, m.source_timeline)
+            })
+        };
+        let memories: Vec<MemoryId> = original.memories.iter().copied().filter(perceivable).collect();
+
+        let id = self.create_character(format!("{} {}", original.name, suffix), timeline);
+        if let Some(c) = self.characters.get_mut(&id) {
+            c.emotional_state = original.emotional_state.clone();
+        }
+
+        let mechanism = format!("Parallel self of {}", original.name);
+        let mut effects = Vec::new();
+        for ability in original.abilities.values() {
+            effects.push(EventEffect::AbilityGained {
+                character: id,
+                ability: ability.clone(),
+                mechanism: mechanism.clone(),
+            });
+        }
+        for flag in &original.knowledge_flags {
+            effects.push(EventEffect::KnowledgeGained {
+                character: id,
+                flag: flag.clone(),
+            });
+        }
+        for memory in memories {
+            effects.push(EventEffect::MemoryTransfer {
+                memory,
+                from: Some(source),
+                to: id,
+                exclusive: false,
+            });
+        }
+        if copy_relationships {
+            for (other, state) in &original.relationships {
+                effects.push(EventEffect::RelationshipChange {
+                    character1: id,
+                    character2: *other,
+                    new_state: *state,
+                });
+                // The change lands on the middle of the level; shift to where the source drifted
+                if let Some(affinity) = original.affinities.get(other) {
+                    effects.push(EventEffect::AffinityShift {
+                        character1: id,
+                        character2: *other,
+                        delta: affinity - state.affinity(),
+                    });
+                }
+            }
+        }
+
+        self.record_event(Event {
+            id: EventId(0),
+            timeline,
+            description: format!("{} splits into a parallel self", original.name),
+            participants: HashSet::from([id]),
+            effects,
+            causality_violation: None,
+        });
+
+        Ok(id)
+    }
+
+    /// Grants `character` an ability through a recorded `AbilityGained` event in
+    /// their current timeline, so the ability has a provenance like any other.
+    /// Replaces any ability of the same kind they already hold.
+    ///
+    /// Returns `None` if the character doesn't exist.
+    pub fn grant_ability(&mut self, character: CharacterId, ability: Ability) -> Option<EventId> {
+        let timeline = self.characters.get(&character)?.current_timeline;
+        self.grant_abilities(timeline, "Granted", [(character, ability)])
+    }
+
+    /// Grants several abilities at once, through a single `AbilityGained` event
+    /// in `timeline` with `mechanism` as its justification.
+    ///
+    /// Nobody takes part in the event: a grant is a fact of the story rather
+    /// than a scene, so it doesn't wear down anyone's temporal anchor. Grants to
+    /// characters who don't exist are skipped, and if none are left no event is
+    /// recorded and `None` is returned.
+    pub fn grant_abilities(
+        &mut self,
+        timeline: TimelineId,
+        mechanism: &str,
+        grants: impl IntoIterator<Item = (CharacterId, Ability)>,
+    ) -> Option<EventId> {
+        let grants: Vec<(CharacterId, Ability)> = grants
+            .into_iter()
+            .filter(|(character, _)| self.characters.contains_key(character))
+            .collect();
+        if grants.is_empty() {
+            return None;
+        }
+
+        let granted: Vec<String> = grants
+            .iter()
+            .map(|(character, ability)| format!("{} gains {:?}", character, ability.kind()))
+            .collect();
+        let effects = grants
+            .into_iter()
+            .map(|(character, ability)| EventEffect::AbilityGained {
+                character,
+                ability,
+                mechanism: mechanism.to_string(),
+            })
+            .collect();
+        Some(self.record_event(Event {
+            id: EventId(0),
+            timeline,
+            description: format!("{} ({})", granted.join(", "), mechanism),
+            participants: HashSet::new(),
+            effects,
+            causality_violation: None,
+        }))
+    }
+
+    /// How much strain every causality violation ever recorded has put on the
+    /// Ring, each weighted by its [`CausalityViolation::incoherence_cost`]. It
+    /// only grows: repairing a violation mends the timeline, not the Ring.
+    pub fn incoherence_level(&self) -> f64 {
+        self.incoherence
+    }
+
+    /// Whether incoherence has reached `threshold`, past which the story
+    /// treats the Ring as collapsing: the Great Incoherence, made manifest.
+    pub fn is_ring_collapsing(&self, threshold: f64) -> bool {
+        self.incoherence >= threshold
+    }
+
+    /// The causality violations recorded in `timeline` that no
+    /// [`EventEffect::CausalityRepaired`] has answered yet, in order.
+    pub fn unresolved_violations(&self, timeline: TimelineId) -> Vec<EventId> {
+        let Some(t) = self.timelines.get(&timeline) else {
+            return Vec::new();
+        };
+        let events = || t.events.iter().filter_map(|id| self.events.get(id));
+        let repaired: HashSet<EventId> = events()
+            .flat_map(|e| &e.effects)
+            .filter_map(|effect| match effect {
+                EventEffect::CausalityRepaired { violation, .. } => Some(*violation),
+                _ => None,
+            })
+            .collect();
+        events()
+            .filter(|e| e.causality_violation.is_some() && !repaired.contains(&e.id))
+            .map(|e| e.id)
+            .collect()
+    }
+
+    /// Mends the causality violation in event `violation` by `mechanism`,
+    /// recording the repair in the violation's own timeline.
+    pub fn repair_causality(&mut self, violation: EventId, mechanism: &str) -> Result<EventId, MultiverseError> {
+        let event = self.events.get(&violation).ok_or(MultiverseError::UnknownEvent(violation))?;
+        if event.causality_violation.is_none() {
+            return Err(MultiverseError::NotAViolation(violation));
+        }
+        let timeline = event.timeline;
+        Ok(self.record_event(Event {
+            id: EventId(0),
+            timeline,
+            description: format!("The violation in {} is mended ({})", violation, mechanism),
+            participants: HashSet::new(),
+            effects: vec![EventEffect::CausalityRepaired {
+                violation,
+                mechanism: mechanism.to_string(),
+            }],
+            causality_violation: None,
+        }))
+    }
+
+    /// Marks `timeline` causally sound again, by `mechanism`, and records the
+    /// event that does it. Every violation in it must have been repaired first
+    /// (see [`Multiverse::repair_causality`]); otherwise this fails naming the
+    /// first one that hasn't.
+    ///
+    /// Anyone present who holds [`PREVENT_RING_COLLAPSE`] and can see the
+    /// timeline witnesses it, and takes heart.
+    pub fn stabilize_timeline(&mut self, timeline: TimelineId, mechanism: &str) -> Result<EventId, MultiverseError> {
+        if !self.timelines.contains_key(&timeline) {
+            return Err(MultiverseError::UnknownTimeline(timeline));
+        }
+        if let Some(&event) = self.unresolved_violations(timeline).first() {
+            return Err(MultiverseError::UnresolvedViolation { timeline, event });
+        }
+
+        let mut keepers: Vec<CharacterId> = self
+            .characters
+            .values()
+            .filter(|c| {
+                c.is_present()
+                    && c.emotional_state.goals.contains_key(PREVENT_RING_COLLAPSE)
+                    && self.can_perceive_timeline(c.id, timeline)
+            })
+            .map(|c| c.id)
+            .collect();
+        keepers.sort();
+        let mut effects = vec![EventEffect::TimelineStabilized {
+            mechanism: mechanism.to_string(),
+        }];
+        effects.extend(keepers.iter().map(|&character| EventEffect::AppraisalTrigger {
+            character,
+            belief: crate::emotional_system::Belief {
+                likelihood: 0.5,
+                causal_agent_name: None,
+                affected_goal_names: vec![PREVENT_RING_COLLAPSE.to_string()],
+                goal_congruences: vec![1.0],
+                is_incremental: true,
+                self_caused: false,
+                praiseworthiness: None,
+            },
+        }));
+        Ok(self.record_event(Event {
+            id: EventId(0),
+            timeline,
+            description: format!("{} is stabilized ({})", timeline, mechanism),
+            participants: keepers.into_iter().collect(),
+            effects,
+            causality_violation: None,
+        }))
+    }
+
+    /// Creates a new timeline branching from a parent.
+    ///
+    /// The branch's inherited history is frozen at `divergence_event`, which
+    /// should already be recorded in the parent: the parent may keep recording
+    /// events, but those are never visible from the branch (see
+    /// [`Multiverse::event_visible_in`]).
+    pub fn create_timeline_branch(
+        &mut self,
+        parent: TimelineId,
+        divergence_event: EventId,
+    ) -> TimelineId {
+        let id = TimelineId(self.next_timeline_id);
+        self.next_timeline_id += 1;
+
+        // Copy characters from parent timeline
+        let parent_characters = self
+            .timelines
+            .get(&parent)
+            .map(|t| t.characters.clone())
+            .unwrap_or_default();
+
+        let timeline = Timeline {
+            id,
+            parent: Some(parent),
+            divergence_event: Some(divergence_event),
+            events: Vec::new(),
+            characters: parent_characters,
+            causality_stable: true,
+        };
+
+        self.timelines.insert(id, timeline);
+        id
+    }
+
+    /// Like [`Multiverse::create_timeline_branch`], but refuses to branch past
+    /// `config.max_branch_depth`, or from a timeline that doesn't exist.
+    pub fn try_create_timeline_branch(
+        &mut self,
+        parent: TimelineId,
+        divergence_event: EventId,
+    ) -> Result<TimelineId, MultiverseError> {
+        if !self.timelines.contains_key(&parent) {
+            return Err(MultiverseError::UnknownTimeline(parent));
+        }
+        if let Some(max_depth) = self.config.max_branch_depth {
+            if self.branch_depth(parent) >= max_depth {
+                return Err(MultiverseError::BranchTooDeep { parent, max_depth });
+            }
+        }
+        Ok(self.create_timeline_branch(parent, divergence_event))
+    }
+
+    /// How many branchings separate `timeline` from its root: 0 for a root, 1
+    /// for a branch of a root, and so on. Unknown timelines are at depth 0.
+    pub fn branch_depth(&self, timeline: TimelineId) -> usize {
+        let mut depth = 0;
+        let mut current = self.timelines.get(&timeline);
+        while let Some(parent) = current.and_then(|t| t.parent) {
+            depth += 1;
+            current = self.timelines.get(&parent);
+        }
+        depth
+    }
+
+    /// Like [`Multiverse::try_create_timeline_branch`], but `migrants` cross
+    /// into the new branch and live there from now on.
+    ///
+    /// The split is recorded as the branch's first event: a `TimelineBranch`
+    /// effect, plus a `TimelineMove` for each migrant, so the branch starts with
+    /// someone in it rather than as an empty copy of its parent.
+    pub fn create_timeline_branch_with(
+        &mut self,
+        parent: TimelineId,
+        divergence_event: EventId,
+        migrants: &[CharacterId],
+    ) -> Result<TimelineId, MultiverseError> {
+        let branch = self.try_create_timeline_branch(parent, divergence_event)?;
+        let mut effects = vec![EventEffect::TimelineBranch { new_timeline: branch }];
+        effects.extend(
+            migrants
+                .iter()
+                .map(|&character| EventEffect::TimelineMove { character, to: branch }),
+        );
+        self.record_event(Event {
+            id: EventId(0),
+            timeline: branch,
+            description: format!(
+                "{} splits from {}; {} characters cross over",
+                branch,
+                parent,
+                migrants.len()
+            ),
+            participants: migrants.iter().copied().collect(),
+            effects,
+            causality_violation: None,
+        });
+        Ok(branch)
+    }
+
+    /// Creates a memory from a witnessed event.
+    pub fn create_witnessed_memory(
+        &mut self,
+        event: EventId,
+        timeline: TimelineId,
+        character: CharacterId,
+    ) -> MemoryId {
+        self.create_memory(event, timeline, MemoryProvenance::Witnessed { character })
+    }
+
+    /// Creates a memory with any provenance, at full fidelity.
+    ///
+    /// Nobody holds the new memory yet; give it to a character with a
+    /// `MemoryTransfer` effect.
+    pub fn create_memory(
+        &mut self,
+        event: EventId,
+        source_timeline: TimelineId,
+        provenance: MemoryProvenance,
+    ) -> MemoryId {
+        let id = MemoryId(self.next_memory_id);
+        self.next_memory_id += 1;
+
+        let memory = Memory {
             id,
             event,
-            source_timeline: timeline,
-            provenance: MemoryProvenance::Witnessed { character },
+            content: MemoryContent::Event,
+            source_timeline,
+            provenance,
             fidelity: 1.0,
         };
 
@@ -416,13 +1766,258 @@ impl Multiverse {
         id
     }
 
+    /// Blends existing memories into a new compound memory.
+    ///
+    /// The blend is anchored to the first source's event and timeline, and is
+    /// only as clear as its haziest source. Returns `None` if `sources` is empty
+    /// or any source doesn't exist.
+    pub fn blend_memories(&mut self, sources: &[MemoryId]) -> Option<MemoryId> {
+        let first = self.memories.get(sources.first()?)?.clone();
+        let mut fidelity = first.fidelity;
+        for source in sources {
+            fidelity = fidelity.min(self.memories.get(source)?.fidelity);
+        }
+
+        let id = self.create_memory(
+            first.event,
+            first.source_timeline,
+            MemoryProvenance::Compound {
+                sources: sources.to_vec(),
+            },
+        );
+        if let Some(memory) = self.memories.get_mut(&id) {
+            memory.fidelity = fidelity;
+        }
+        Some(id)
+    }
+
+    /// Links `members` into one collective mind, merging any collectives they
+    /// already belong to, and records the sync that hands every living member
+    /// the memories any of them holds. Linking members already linked just
+    /// syncs them again.
+    ///
+    /// Joining minds that were apart, as when a severed body rejoins, also
+    /// merges what they feel: every living member comes away with the even
+    /// [`EmotionalState::merge`](crate::emotional_system::EmotionalState::merge)
+    /// of all their feelings.
+    ///
+    /// The sync happens in the lowest-numbered member's timeline, attended by
+    /// the members present there.
+    pub fn link_collective(&mut self, members: &[CharacterId]) -> Result<EventId, MultiverseError> {
+        if let Some(unknown) = members.iter().find(|c| !self.characters.contains_key(c)) {
+            return Err(MultiverseError::UnknownCharacter(*unknown));
+        }
+        let mut linked: BTreeSet<CharacterId> = members.iter().copied().collect();
+        let first = *linked.first().ok_or(MultiverseError::EmptyCollective)?;
+        // Each collective joined is one mind, and so is each body not yet in one
+        let mut minds = linked.iter().filter(|c| self.collective_of(**c).is_none()).count();
+        self.collectives.retain(|collective| {
+            if collective.members.is_disjoint(&linked) {
+                true
+            } else {
+                linked.extend(&collective.members);
+                minds += 1;
+                false
+            }
+        });
+
+        let present: Vec<CharacterId> = linked
+            .iter()
+            .copied()
+            .filter(|c| self.characters[c].is_present())
+            .collect();
+        if minds > 1 {
+            let mut feelings: Option<crate::emotional_system::EmotionalState> = None;
+            for (bodies, member) in present.iter().enumerate() {
+                let state = &self.characters[member].emotional_state;
+                let share = 1.0 / (bodies + 1) as f64;
+                feelings = Some(match feelings {
+                    Some(mind) => mind.merge(state, (1.0 - share, share)),
+                    None => state.clone(),
+                });
+            }
+            for member in &present {
+                if let (Some(c), Some(feelings)) = (self.characters.get_mut(member), &feelings) {
+                    c.emotional_state = feelings.clone();
+                }
+            }
+        }
+        let mut pool: BTreeMap<MemoryId, CharacterId> = BTreeMap::new();
+        for member in &present {
+            for memory in &self.characters[member].memories {
+                pool.entry(*memory).or_insert(*member);
+            }
+        }
+        let mut effects = Vec::new();
+        for member in &present {
+            for (memory, holder) in &pool {
+                if !self.characters[member].memories.contains(memory) {
+                    effects.push(EventEffect::MemoryTransfer {
+                        memory: *memory,
+                        from: Some(*holder),
+                        to: *member,
+                        exclusive: false,
+                    });
+                }
+            }
+        }
+
+        let timeline = self.characters[&first].current_timeline;
+        let description = format!("{} bodies share one mind", linked.len());
+        self.collectives.push(CollectiveCharacter {
+            members: linked,
+            last_sync: EventId(self.next_event_id),
+        });
+        Ok(self.record_event(Event {
+            id: EventId(0),
+            timeline,
+            description,
+            participants: present
+                .iter()
+                .copied()
+                .filter(|c| self.characters[c].current_timeline == timeline)
+                .collect(),
+            effects,
+            causality_violation: None,
+        }))
+    }
+
+    /// The collective `character` belongs to, if any.
+    pub fn collective_of(&self, character: CharacterId) -> Option<&CollectiveCharacter> {
+        self.collectives.iter().find(|c| c.members.contains(&character))
+    }
+
+    /// Adds the transfers a collective implies to an event's own: a memory
+    /// handed to one member goes to every present member, and a memory one
+    /// member sells is given up by every other member holding it.
+    fn spread_through_collectives(&self, effects: &[EventEffect]) -> Vec<EventEffect> {
+        let mut spread = effects.to_vec();
+        if self.collectives.is_empty() {
+            return spread;
+        }
+        let fellows = |character: CharacterId| -> Vec<CharacterId> {
+            self.collective_of(character)
+                .map(|collective| {
+                    collective
+                        .members
+                        .iter()
+                        .copied()
+                        .filter(|c| *c != character && self.characters.get(c).is_some_and(|c| c.is_present()))
+                        .collect()
+                })
+                .unwrap_or_default()
+        };
+
+        for effect in effects {
+            if let EventEffect::MemoryTransfer { memory, from, to, exclusive } = effect {
+                let buyers = fellows(*to);
+                if let (true, Some(seller)) = (*exclusive, from) {
+                    for fellow in fellows(*seller) {
+                        let holds = self.characters[&fellow].memories.contains(memory);
+                        if holds && fellow != *to && !buyers.contains(&fellow) {
+                            spread.push(EventEffect::MemoryTransfer {
+                                memory: *memory,
+                                from: Some(fellow),
+                                to: *to,
+                                exclusive: true,
+                            });
+                        }
+                    }
+                }
+                for fellow in buyers {
+                    // The seller really did give it up, even to their own collective
+                    let sold = *exclusive && *from == Some(fellow);
+                    let handed = spread.iter().any(|e| {
+                        matches!(e, EventEffect::MemoryTransfer { memory: m, to: t, .. } if m == memory && *t == fellow)
+                    });
+                    if !sold && !handed && !self.characters[&fellow].memories.contains(memory) {
+                        spread.push(EventEffect::MemoryTransfer {
+                            memory: *memory,
+                            from: Some(*to),
+                            to: fellow,
+                            exclusive: false,
+                        });
+                    }
+                }
+            }
+        }
+        spread
+    }
+
+    /// Adds how the other participants feel about each appraisal in an event:
+    /// anyone with a relationship to the appraising character reacts to their
+    /// fortune (see [`fortune_of_others`](crate::emotional_system::fortune_of_others)).
+    /// Relationships are read as they stood when the event began, and a
+    /// reaction the event already spells out for someone isn't added again.
+    fn spread_to_onlookers(&self, event: &Event) -> Vec<EventEffect> {
+        let mut spread = event.effects.clone();
+        let mut onlookers: Vec<CharacterId> = event.participants.iter().copied().collect();
+        onlookers.sort();
+
+        for effect in &event.effects {
+            let EventEffect::AppraisalTrigger { character: subject, belief } = effect else {
+                continue;
+            };
+            let Some(subject_state) = self.characters.get(subject).map(|c| &c.emotional_state) else {
+                continue;
+            };
+            let desirability = subject_state.desirability(belief);
+            for &observer in onlookers.iter().filter(|o| *o != subject) {
+                let Some(o) = self.characters.get(&observer).filter(|o| o.is_present()) else {
+                    continue;
+                };
+                let told = spread.iter().any(|e| {
+                    matches!(e, EventEffect::SocialAppraisal { observer: w, subject: s, .. }
+                        if *w == observer && s == subject)
+                });
+                let regard = self.relationship_between(o.id, *subject).regard();
+                let felt = crate::emotional_system::fortune_of_others(regard, desirability);
+                if let (false, Some(emotion)) = (told, felt) {
+                    spread.push(EventEffect::SocialAppraisal {
+                        observer,
+                        subject: *subject,
+                        emotion,
+                    });
+                }
+            }
+        }
+        spread
+    }
+
+    /// The id the next recorded event will get. Ids are handed out in order
+    /// and never reused, so every event recorded from here on has one at least
+    /// this large, whatever was removed in between.
+    pub fn next_event_id(&self) -> EventId {
+        EventId(self.next_event_id)
+    }
+
     /// Records a new event in the timeline.
+    ///
+    /// Memory transfers to or from a member of a collective are extended to the
+    /// rest of it first; see [`CollectiveCharacter`]. Other participants then
+    /// react to any appraisal in it, as [`EventEffect::SocialAppraisal`]s.
+    ///
+    /// An effect that needs someone alive after an earlier one killed them
+    /// (see [`Event::validate_effect_order`]) isn't applied or kept on the
+    /// event. It is logged instead, where [`Multiverse::posthumous_effects`]
+    /// and [`prop_death_finality`](crate::properties::prop_death_finality)
+    /// report it.
     pub fn record_event(&mut self, event: Event) -> EventId {
         let id = EventId(self.next_event_id);
         self.next_event_id += 1;
 
         let mut event = event;
         event.id = id;
+        event.effects = self.spread_through_collectives(&event.effects);
+        event.effects = self.spread_to_onlookers(&event);
+        // Whatever was to happen to someone after they died in this event can't
+        let mut posthumous = Vec::new();
+        while let Some((index, _)) = event.first_posthumous_effect() {
+            posthumous.push(event.effects.remove(index));
+        }
+        if !posthumous.is_empty() {
+            self.posthumous_log.insert(id, posthumous);
+        }
 
         // Add to timeline's event list
         if let Some(timeline) = self.timelines.get_mut(&event.timeline) {
@@ -430,14 +2025,121 @@ impl Multiverse {
         }
 
         // Apply event effects
-        self.apply_event_effects(&event);
+        let crossed = self.apply_event_effects(&event);
 
+        let participants: Vec<CharacterId> = event.participants.iter().copied().collect();
+        self.incoherence += event.causality_violation.as_ref().map_or(0.0, CausalityViolation::incoherence_cost);
         self.events.insert(id, event);
+
+        // Every scene an anchored character takes part in loosens their hold on the present
+        for participant in participants {
+            self.tick_anchor(participant, ANCHOR_DECAY_PER_EVENT);
+        }
+
+        // What the appraisals stirred up happens next
+        for (trigger, character) in crossed {
+            self.fire_trigger(trigger, character, id);
+        }
+
         id
     }
 
-    /// Applies the effects of an event to the multiverse state.
-    fn apply_event_effects(&mut self, event: &Event) {
+    /// Erodes a character's temporal anchor by `amount`.
+    ///
+    /// When stability reaches zero, a forced-departure event is recorded in the
+    /// character's current timeline and its ID is returned. Characters without an
+    /// anchor, whose anchor is already exhausted, or who are dead are unaffected.
+    pub fn tick_anchor(&mut self, character: CharacterId, amount: f32) -> Option<EventId> {
+        let c = self.characters.get_mut(&character)?;
+        if !c.alive {
+            return None;
+        }
+        let timeline = c.current_timeline;
+        let anchor = c.temporal_anchor.as_mut()?;
+        if anchor.stability <= 0.0 {
+            return None;
+        }
+
+        anchor.stability = (anchor.stability - amount).max(0.0);
+        if anchor.stability > 0.0 {
+            return None;
+        }
+
+        let name = c.name.clone();
+
+        // Being yanked across a millennium rewrites the present retroactively
+        if let Some(t) = self.timelines.get_mut(&timeline) {
+            t.causality_stable = false;
+        }
+
+        Some(self.record_event(Event {
+            id: EventId(0),
+            timeline,
+            description: format!("{} is pulled back to their era of origin", name),
+            participants: HashSet::from([character]),
+            effects: vec![EventEffect::TemporalDeparture { character }],
+            causality_violation: Some(CausalityViolation::RetroactiveChange {
+                mechanism: "Temporal anchor recall".to_string(),
+            }),
+        }))
+    }
+
+    /// Lets time pass for everyone tethered to an [`Character::anchor_timeline`]:
+    /// each living one away from it feels its pull grow by
+    /// [`TEMPORAL_PULL_PER_ADVANCE`]. Once the pull reaches 1.0 it drags them
+    /// back, in an event in their anchor timeline whose `TimelineMove` is what
+    /// justifies them being there, and the pull starts over. Returns those
+    /// events, in character order.
+    ///
+    /// Whoever is home feels no pull, nor do the dead or departed, and an
+    /// anchor timeline that doesn't exist can't pull anyone.
+    pub fn advance_temporal_pull(&mut self) -> Vec<EventId> {
+        let mut ids: Vec<CharacterId> = self.characters.keys().copied().collect();
+        ids.sort();
+        let mut pulled = Vec::new();
+        for id in ids {
+            let Some(c) = self.characters.get_mut(&id) else {
+                continue;
+            };
+            let Some(home) = c.anchor_timeline.filter(|t| self.timelines.contains_key(t)) else {
+                continue;
+            };
+            if !c.is_present() || c.current_timeline == home {
+                c.pull_strength = 0.0;
+                continue;
+            }
+            c.pull_strength += TEMPORAL_PULL_PER_ADVANCE;
+            if c.pull_strength < 1.0 {
+                continue;
+            }
+            c.pull_strength = 0.0;
+            let description = format!("{} is dragged back to {} by the pull of their own time", c.name, home);
+            pulled.push(self.record_event(Event {
+                id: EventId(0),
+                timeline: home,
+                description,
+                participants: HashSet::from([id]),
+                effects: vec![EventEffect::TimelineMove { character: id, to: home }],
+                causality_violation: None,
+            }));
+        }
+        pulled
+    }
+
+    /// Returns how much anchor stability a character has left, if they are anchored.
+    pub fn remaining_stability(&self, character: CharacterId) -> Option<f32> {
+        self.characters
+            .get(&character)
+            .and_then(|c| c.temporal_anchor.as_ref())
+            .map(|anchor| anchor.stability)
+    }
+
+    /// Applies an event's effects, returning the emotion triggers its
+    /// appraisals set off, by trigger and character, for the caller to fire.
+    /// What the appraisals made anyone feel goes in the emotion log.
+    fn apply_event_effects(&mut self, event: &Event) -> Vec<(usize, CharacterId)> {
+        let mut crossed = Vec::new();
+        let mut feelings = Vec::new();
         for effect in &event.effects {
             match effect {
                 EventEffect::CharacterDeath { character } => {
@@ -450,6 +2152,14 @@ impl Multiverse {
                         c.alive = true;
                     }
                 }
+                EventEffect::CharacterRename { character, new_name } => {
+                    if let Some(c) = self.characters.get_mut(character) {
+                        if c.name != *new_name {
+                            let old = std::mem::replace(&mut c.name, new_name.clone());
+                            c.former_names.push(old);
+                        }
+                    }
+                }
                 EventEffect::RelationshipChange {
                     character1,
                     character2,
@@ -457,9 +2167,24 @@ impl Multiverse {
                 } => {
                     if let Some(c1) = self.characters.get_mut(character1) {
                         c1.relationships.insert(*character2, *new_state);
+                        c1.affinities.remove(character2);
                     }
                     if let Some(c2) = self.characters.get_mut(character2) {
                         c2.relationships.insert(*character1, *new_state);
+                        c2.affinities.remove(character1);
+                    }
+                }
+                EventEffect::AffinityShift {
+                    character1,
+                    character2,
+                    delta,
+                } => {
+                    for (c, other) in [(character1, character2), (character2, character1)] {
+                        if let Some(c) = self.characters.get_mut(c) {
+                            let affinity = (c.affinity(*other) + delta).clamp(-MAX_AFFINITY, MAX_AFFINITY);
+                            c.affinities.insert(*other, affinity);
+                            c.relationships.insert(*other, RelationshipState::from_affinity(affinity));
+                        }
                     }
                 }
                 EventEffect::KnowledgeGained { character, flag } => {
@@ -467,18 +2192,111 @@ impl Multiverse {
                         c.knowledge_flags.insert(flag.clone());
                     }
                 }
-                EventEffect::MemoryTransfer { memory, to, .. } => {
+                EventEffect::KnowledgeShared { to, flag, .. } => {
+                    if let Some(c) = self.characters.get_mut(to) {
+                        c.knowledge_flags.insert(flag.clone());
+                    }
+                }
+                EventEffect::MemoryTransfer {
+                    memory,
+                    from,
+                    to,
+                    exclusive,
+                } => {
+                    if let (true, Some(seller)) = (*exclusive, from) {
+                        if let Some(c) = self.characters.get_mut(seller) {
+                            c.memories.remove(memory);
+                        }
+                    }
                     if let Some(c) = self.characters.get_mut(to) {
                         c.memories.insert(*memory);
                     }
                 }
+                EventEffect::MemoryDegrade { character, amount } => {
+                    // Fidelity lives on the shared memory record, so anyone else
+                    // holding the same memory sees it fade too. A memory someone
+                    // immune also holds can't fade for them, so it's spared
+                    let guarded: HashSet<MemoryId> = self
+                        .characters
+                        .values()
+                        .filter(|c| c.has_ability(AbilityKind::MemoryImmunity))
+                        .flat_map(|c| c.memories.iter().copied())
+                        .collect();
+                    if let Some(c) = self.characters.get_mut(character) {
+                        if !c.has_ability(AbilityKind::MemoryImmunity) {
+                            let memories = &mut self.memories;
+                            c.memories.retain(|id| match memories.get_mut(id).filter(|_| !guarded.contains(id)) {
+                                Some(memory) => {
+                                    memory.fidelity *= 1.0 - amount.clamp(0.0, 1.0);
+                                    memory.fidelity >= MEMORY_FIDELITY_FLOOR
+                                }
+                                None => true,
+                            });
+                        }
+                    }
+                }
+                EventEffect::MemoryWipe { character } => {
+                    if let Some(c) = self.characters.get_mut(character) {
+                        if !c.has_ability(AbilityKind::MemoryImmunity) {
+                            c.memories.clear();
+                        }
+                    }
+                }
+                EventEffect::MemoryRecall { .. } => {
+                    // Remembering changes nothing; it's the acting on it that matters
+                }
                 EventEffect::TimelineBranch { new_timeline } => {
                     // Timeline branching is handled separately
                     let _ = new_timeline;
                 }
+                // A repair is bookkeeping until the timeline is stabilized
+                EventEffect::CausalityRepaired { .. } => {}
+                EventEffect::TimelineStabilized { .. } => {
+                    if let Some(t) = self.timelines.get_mut(&event.timeline) {
+                        t.causality_stable = true;
+                    }
+                }
+                EventEffect::TimelineMove { character, to } => {
+                    let from = match self.characters.get_mut(character) {
+                        Some(c) if self.timelines.contains_key(to) => {
+                            c.timeline_history.push((event.id, *to));
+                            std::mem::replace(&mut c.current_timeline, *to)
+                        }
+                        _ => continue,
+                    };
+                    if let Some(timeline) = self.timelines.get_mut(&from) {
+                        timeline.characters.remove(character);
+                    }
+                    if let Some(timeline) = self.timelines.get_mut(to) {
+                        timeline.characters.insert(*character);
+                    }
+                }
                 EventEffect::AppraisalTrigger { character, belief } => {
                     if let Some(c) = self.characters.get_mut(character) {
-                        c.emotional_state.appraise(belief);
+                        let before = (!self.emotion_triggers.is_empty()).then(|| c.emotional_state.clone());
+                        let felt = c.emotional_state.appraise(belief);
+                        feelings.extend(felt.into_iter().map(|emotion| (*character, emotion)));
+                        if let Some(before) = before {
+                            let fired = self.emotion_triggers.crossed(*character, &before, &c.emotional_state);
+                            crossed.extend(fired.into_iter().map(|trigger| (trigger, *character)));
+                        }
+                    }
+                }
+                EventEffect::SocialAppraisal {
+                    observer,
+                    subject,
+                    emotion,
+                } => {
+                    let Some(subject) = self.characters.get(subject).map(|c| c.name.clone()) else {
+                        continue;
+                    };
+                    if let Some(c) = self.characters.get_mut(observer) {
+                        c.emotional_state.feel_toward(&subject, emotion.clone());
+                    }
+                }
+                EventEffect::EmotionSpread { to, emotion, .. } => {
+                    if let Some(c) = self.characters.get_mut(to) {
+                        c.emotional_state.update_emotional_state(emotion.clone());
                     }
                 }
                 EventEffect::AddGoal { character, goal } => {
@@ -486,23 +2304,233 @@ impl Multiverse {
                         c.emotional_state.add_goal(goal.clone());
                     }
                 }
+                EventEffect::GoalResolved {
+                    character,
+                    goal,
+                    success,
+                } => {
+                    // Resolving a goal they don't hold changes nothing
+                    if let Some(c) = self.characters.get_mut(character) {
+                        let _ = if *success {
+                            c.emotional_state.complete_goal(goal)
+                        } else {
+                            c.emotional_state.abandon_goal(goal)
+                        };
+                    }
+                }
+                EventEffect::AbilityGained {
+                    character, ability, ..
+                } => {
+                    if let Some(c) = self.characters.get_mut(character) {
+                        c.insert_ability(ability.clone());
+                    }
+                }
+                EventEffect::AbilityLost { character, ability } => {
+                    if let Some(c) = self.characters.get_mut(character) {
+                        c.abilities.remove(ability);
+                    }
+                }
+                EventEffect::AnchorGained { character, anchor } => {
+                    if let Some(c) = self.characters.get_mut(character) {
+                        c.temporal_anchor = Some(anchor.clone());
+                        // Wherever they were displaced from is where they're pulled back to
+                        c.anchor_timeline.get_or_insert(event.timeline);
+                    }
+                }
+                EventEffect::TemporalDeparture { character } => {
+                    if let Some(c) = self.characters.get_mut(character) {
+                        if let Some(anchor) = c.temporal_anchor.as_mut() {
+                            anchor.stability = 0.0;
+                        }
+                    }
+                    if let Some(timeline) = self.timelines.get_mut(&event.timeline) {
+                        timeline.characters.remove(character);
+                    }
+                }
             }
         }
+
+        // Observers only see the event once all of it has happened
+        let mut moods = Vec::new();
+        if self.observers.wants_moods() {
+            moods = self.characters.iter().map(|(id, c)| (*id, c.emotional_state.get_pad())).collect();
+            moods.sort_by_key(|(id, _)| *id);
+        }
+        self.observers.notify(event, &feelings, &moods);
+        if !feelings.is_empty() {
+            self.emotion_log.insert(event.id, feelings);
+        }
+        crossed
+    }
+
+    /// What `event`'s appraisals made each character feel, in the order its
+    /// effects were applied: one entry per character and emotion type per
+    /// appraisal, with the intensity it added. Empty for an event that stirred
+    /// nothing, or that isn't in this multiverse.
+    pub fn emotional_outcomes(&self, event: EventId) -> &[(CharacterId, crate::emotional_system::Emotion)] {
+        self.emotion_log.get(&event).map_or(&[], Vec::as_slice)
+    }
+
+    /// The effects [`Multiverse::record_event`] dropped from `event` because
+    /// they needed a character alive after an earlier effect killed them, in
+    /// the order they were given. Empty for an event recorded whole, or that
+    /// isn't in this multiverse.
+    pub fn posthumous_effects(&self, event: EventId) -> &[EventEffect] {
+        self.posthumous_log.get(&event).map_or(&[], Vec::as_slice)
     }
 
     /// Checks if a character can perceive events from a specific timeline.
     ///
     /// Returns true if:
     /// - The character is in that timeline, OR
-    /// - The character has TimelinePerception ability
+    /// - The character has TimelinePerception reaching every timeline, OR
+    /// - The character has sibling-scoped TimelinePerception and the timeline
+    ///   branched from the same parent as their current one
     pub fn can_perceive_timeline(&self, character: CharacterId, timeline: TimelineId) -> bool {
         if let Some(c) = self.characters.get(&character) {
-            c.current_timeline == timeline || c.abilities.contains(&Ability::TimelinePerception)
+            if c.current_timeline == timeline {
+                return true;
+            }
+
+            match c.ability(AbilityKind::TimelinePerception) {
+                Some(Ability::TimelinePerception {
+                    scope: PerceptionScope::AllTimelines,
+                }) => true,
+                Some(Ability::TimelinePerception {
+                    scope: PerceptionScope::SiblingBranches,
+                }) => {
+                    let parent_of = |t: &TimelineId| self.timelines.get(t).and_then(|t| t.parent);
+                    let current_parent = parent_of(&c.current_timeline);
+                    current_parent.is_some() && current_parent == parent_of(&timeline)
+                }
+                _ => false,
+            }
         } else {
             false
         }
     }
 
+    /// Checks if an event is part of a timeline's history: either recorded in
+    /// the timeline itself, or inherited from an ancestor at or before the point
+    /// where the timeline diverged from it.
+    pub fn event_visible_in(&self, event: EventId, timeline: TimelineId) -> bool {
+        let mut current = match self.timelines.get(&timeline) {
+            Some(t) => t,
+            None => return false,
+        };
+        if current.events.contains(&event) {
+            return true;
+        }
+
+        // Walk up the ancestry, looking only at each parent's frozen prefix
+        while let (Some(parent_id), Some(divergence)) = (current.parent, current.divergence_event) {
+            let parent = match self.timelines.get(&parent_id) {
+                Some(p) => p,
+                None => return false,
+            };
+            let inherited = match parent.events.iter().position(|e| *e == divergence) {
+                Some(index) => &parent.events[..=index],
+                None => return false,
+            };
+            if inherited.contains(&event) {
+                return true;
+            }
+            current = parent;
+        }
+        false
+    }
+
+    /// Every event in `timeline`'s history, in order: each ancestor's events up
+    /// to where its child diverged, then the timeline's own.
+    pub fn history(&self, timeline: TimelineId) -> Vec<EventId> {
+        let Some(mut current) = self.timelines.get(&timeline) else {
+            return Vec::new();
+        };
+        let mut segments = vec![current.events.as_slice()];
+        while let (Some(parent_id), Some(divergence)) = (current.parent, current.divergence_event) {
+            let Some(parent) = self.timelines.get(&parent_id) else {
+                break;
+            };
+            let Some(index) = parent.events.iter().position(|e| *e == divergence) else {
+                break;
+            };
+            segments.push(&parent.events[..=index]);
+            current = parent;
+        }
+        segments.into_iter().rev().flatten().copied().collect()
+    }
+
+    /// Checks if two characters can trade memories with each other.
+    ///
+    /// Trades normally need both parties in the same timeline. Characters who
+    /// reach across timelines can trade further: an AnsibleLink into the
+    /// Lattice reaches anyone, in any timeline, and TimelinePerception (the
+    /// Conductor) reaches whichever timelines its scope lets them perceive
+    /// (see [`Multiverse::can_perceive_timeline`]).
+    pub fn can_trade_memory(&self, from: CharacterId, to: CharacterId) -> bool {
+        let (f, t) = match (self.characters.get(&from), self.characters.get(&to)) {
+            (Some(f), Some(t)) => (f, t),
+            _ => return false,
+        };
+        let linked = |c: &Character| c.has_ability(AbilityKind::AnsibleLink);
+
+        f.current_timeline == t.current_timeline
+            || linked(f)
+            || linked(t)
+            || self.can_perceive_timeline(from, t.current_timeline)
+            || self.can_perceive_timeline(to, f.current_timeline)
+    }
+
+    /// Characters this one is on good terms with (Friendly or Allied), by id.
+    pub fn allies_of(&self, character: CharacterId) -> Vec<CharacterId> {
+        self.relations_of(character, |state| state > RelationshipState::Neutral)
+    }
+
+    /// Characters this one is at odds with (Distrustful or Hostile), by id.
+    pub fn enemies_of(&self, character: CharacterId) -> Vec<CharacterId> {
+        self.relations_of(character, |state| state < RelationshipState::Neutral)
+    }
+
+    /// How `a` and `b` stand with each other. Relationship changes set both
+    /// sides, so either side's record will do; a pair with no history between
+    /// them is `Neutral`.
+    pub fn relationship_between(&self, a: CharacterId, b: CharacterId) -> RelationshipState {
+        let view = |from: CharacterId, to: CharacterId| {
+            self.characters.get(&from).and_then(|c| c.relationships.get(&to)).copied()
+        };
+        view(a, b).or_else(|| view(b, a)).unwrap_or(RelationshipState::Neutral)
+    }
+
+    /// Whether `a` and `b` are Allied.
+    pub fn are_allied(&self, a: CharacterId, b: CharacterId) -> bool {
+        self.relationship_between(a, b) == RelationshipState::Allied
+    }
+
+    /// Whether `a` and `b` are Hostile.
+    pub fn are_hostile(&self, a: CharacterId, b: CharacterId) -> bool {
+        self.relationship_between(a, b) == RelationshipState::Hostile
+    }
+
+    fn relations_of(
+        &self,
+        character: CharacterId,
+        matches: impl Fn(RelationshipState) -> bool,
+    ) -> Vec<CharacterId> {
+        let mut others: Vec<CharacterId> = self
+            .characters
+            .get(&character)
+            .map(|c| {
+                c.relationships
+                    .iter()
+                    .filter(|(_, state)| matches(**state))
+                    .map(|(other, _)| *other)
+                    .collect()
+            })
+            .unwrap_or_default();
+        others.sort();
+        others
+    }
+
     /// Checks if a character has a memory of a specific event.
     pub fn has_memory_of_event(&self, character: CharacterId, event: EventId) -> bool {
         if let Some(c) = self.characters.get(&character) {
@@ -517,17 +2545,237 @@ impl Multiverse {
         }
     }
 
-    /// Decays emotions for all characters in all timelines.
-    pub fn decay_emotions(&mut self, decay_factor: f64) {
-        for character in self.characters.values_mut() {
-            character.emotional_state.decay(decay_factor);
-        }
-    }
+    /// Lets `ticks` of narrative time pass for every character in every
+    /// timeline, each one's emotions fading at their own rates.
+    pub fn decay_emotions(&mut self, ticks: u32) {
+        for character in self.characters.values_mut() {
+            character.emotional_state.decay(ticks);
+        }
+    }
+
+    /// Lets feelings spread between characters who have shared a scene in
+    /// `timeline`, recording what spread (see [`Multiverse::contagion`]) as
+    /// one event; `None` if nothing did. Nothing spreads unless this is called.
+    pub fn propagate_emotions(
+        &mut self,
+        timeline: TimelineId,
+        strength: f64,
+    ) -> Result<Option<EventId>, MultiverseError> {
+        if !self.timelines.contains_key(&timeline) {
+            return Err(MultiverseError::UnknownTimeline(timeline));
+        }
+        let effects = self.contagion(timeline, strength);
+        if effects.is_empty() {
+            return Ok(None);
+        }
+
+        let participants: HashSet<CharacterId> = effects
+            .iter()
+            .flat_map(|e| match e {
+                EventEffect::EmotionSpread { from, to, .. } => vec![*from, *to],
+                _ => vec![],
+            })
+            .collect();
+        Ok(Some(self.record_event(Event {
+            id: EventId(0),
+            timeline,
+            description: format!("Feelings spread through {}", timeline),
+            participants,
+            effects,
+            causality_violation: None,
+        })))
+    }
+
+    /// The [`EventEffect::EmotionSpread`]s that
+    /// [`Multiverse::propagate_emotions`] would record, between characters
+    /// present in `timeline` who have shared a scene there.
+    ///
+    /// Each character passes on a `strength` (clamped to [0, 1]) share
+    /// of their dominant emotion, scaled by how each other feels about them
+    /// (see [`RelationshipState::regard`]): friends and allies catch the same
+    /// emotion, rivals and enemies its opposite (see
+    /// [`EmotionType::opposite`](crate::emotional_system::EmotionType::opposite)).
+    /// Nobody catches more than brings them level with whoever they caught it
+    /// from, so spreading again and again settles instead of running away.
+    pub fn contagion(&self, timeline: TimelineId, strength: f64) -> Vec<EventEffect> {
+        use crate::emotional_system::Emotion;
+        let Some(timeline_events) = self.timelines.get(&timeline).map(|t| &t.events) else {
+            return Vec::new();
+        };
+        let strength = strength.clamp(0.0, 1.0);
+        let here = |c: &CharacterId| {
+            self.characters.get(c).is_some_and(|c| c.is_present() && c.current_timeline == timeline)
+        };
+        let mut scenes: BTreeSet<(CharacterId, CharacterId)> = BTreeSet::new();
+        for event in timeline_events.iter().filter_map(|id| self.events.get(id)) {
+            let present: Vec<CharacterId> = event.participants.iter().copied().filter(here).collect();
+            for &from in &present {
+                scenes.extend(present.iter().filter(|to| **to != from).map(|&to| (from, to)));
+            }
+        }
+
+        let mut effects = Vec::new();
+        for (from, to) in scenes {
+            let Some(felt) = self.characters[&from].emotional_state.dominant_emotion() else {
+                continue;
+            };
+            let regard = self.relationship_between(to, from).regard();
+            let emotion_type = if regard < 0.0 { felt.emotion_type.opposite() } else { felt.emotion_type.clone() };
+            let already = self.characters[&to].emotional_state.feels(&emotion_type);
+            let intensity = (strength * regard.abs() * felt.intensity).min(felt.intensity - already);
+            // The same threshold below which decay drops an emotion
+            if intensity > 0.001 {
+                effects.push(EventEffect::EmotionSpread {
+                    from,
+                    to,
+                    emotion: Emotion { emotion_type, intensity },
+                });
+            }
+        }
+        effects
+    }
+
+    /// Lets what characters feel about each other move their relationships,
+    /// one level per call at most, recording each move as a
+    /// [`EventEffect::RelationshipChange`] in the timeline the pair shares.
+    ///
+    /// What counts is what a character holds against someone by name (see
+    /// [`EmotionalState::emotion_toward`](crate::emotional_system::EmotionalState::emotion_toward)):
+    /// Gratitude draws them closer, Anger and Resentment push them apart, and
+    /// once the balance reaches [`RELATIONSHIP_DRIFT_THRESHOLD`] either way the
+    /// relationship moves a level and the feeling is spent, so the same grudge
+    /// can't sour things twice. Both must be present and in the same timeline.
+    /// Nothing drifts unless this is called. Returns the events recorded.
+    pub fn apply_emotional_relationship_drift(&mut self) -> Vec<EventId> {
+        use crate::emotional_system::EmotionType;
+        let mut feelers: Vec<CharacterId> = self.characters.keys().copied().collect();
+        feelers.sort();
+
+        let mut recorded = Vec::new();
+        for feeler in feelers {
+            let mut agents: Vec<String> = self.characters[&feeler].emotional_state.relations.keys().cloned().collect();
+            agents.sort();
+            for agent in agents {
+                let Some(other) = self.character_named(&agent).filter(|other| *other != feeler) else {
+                    continue;
+                };
+                let (c, o) = (&self.characters[&feeler], &self.characters[&other]);
+                if !c.is_present() || !o.is_present() || c.current_timeline != o.current_timeline {
+                    continue;
+                }
+                let balance: f64 = c
+                    .emotional_state
+                    .emotion_toward(&agent)
+                    .iter()
+                    .map(|e| match e.emotion_type {
+                        EmotionType::Gratitude => e.intensity,
+                        EmotionType::Anger | EmotionType::Resentment => -e.intensity,
+                        _ => 0.0,
+                    })
+                    .sum();
+                if balance.abs() < RELATIONSHIP_DRIFT_THRESHOLD {
+                    continue;
+                }
+
+                let (timeline, name) = (c.current_timeline, c.name.clone());
+                let current = self.relationship_between(feeler, other);
+                let new_state = RelationshipState::from_affinity(current.affinity() + balance.signum() as f32);
+                if let Some(c) = self.characters.get_mut(&feeler) {
+                    c.emotional_state.relations.remove(&agent);
+                }
+                if new_state == current {
+                    continue;
+                }
+                let description = if balance > 0.0 {
+                    format!("{}'s gratitude draws them closer to {}", name, agent)
+                } else {
+                    format!("{}'s grudge against {} sours things between them", name, agent)
+                };
+                recorded.push(self.record_event(Event {
+                    id: EventId(0),
+                    timeline,
+                    description,
+                    participants: HashSet::from([feeler, other]),
+                    effects: vec![EventEffect::RelationshipChange {
+                        character1: feeler,
+                        character2: other,
+                        new_state,
+                    }],
+                    causality_violation: None,
+                }));
+            }
+        }
+        recorded
+    }
+
+    /// The emotional climate of `timeline`: the mean PAD of every character
+    /// present there (see [`Character::is_present`]), or all zeros if nobody is.
+    pub fn timeline_mood(&self, timeline: TimelineId) -> [f64; 3] {
+        self.climate_of(timeline).pad
+    }
+
+    /// The emotional climate of every timeline, in timeline order.
+    pub fn mood_report(&self) -> MultiverseMoodReport {
+        let mut timelines: Vec<TimelineId> = self.timelines.keys().copied().collect();
+        timelines.sort();
+        MultiverseMoodReport {
+            timelines: timelines.into_iter().map(|t| self.climate_of(t)).collect(),
+        }
+    }
+
+    fn climate_of(&self, timeline: TimelineId) -> TimelineMood {
+        let pads: Vec<[f64; 3]> = self
+            .characters
+            .values()
+            .filter(|c| c.current_timeline == timeline && c.is_present())
+            .map(|c| c.emotional_state.get_pad())
+            .collect();
+        let mut pad = [0.0; 3];
+        for axis in 0..3 {
+            if !pads.is_empty() {
+                pad[axis] = pads.iter().map(|p| p[axis]).sum::<f64>() / pads.len() as f64;
+            }
+        }
+        TimelineMood {
+            timeline,
+            characters: pads.len(),
+            pad,
+        }
+    }
+}
+
+impl Default for Multiverse {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// How one timeline feels, as [`Multiverse::timeline_mood`] measures it.
+/// Unpleasant and high-arousal is a crisis beat; pleasant and calm, a lull.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct TimelineMood {
+    pub timeline: TimelineId,
+    /// How many characters were averaged
+    pub characters: usize,
+    /// Their mean pleasure, arousal, and dominance
+    pub pad: [f64; 3],
+}
+
+/// The mood of the Ring: every timeline's [`TimelineMood`], in timeline order.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct MultiverseMoodReport {
+    pub timelines: Vec<TimelineMood>,
 }
 
-impl Default for Multiverse {
-    fn default() -> Self {
-        Self::new()
+impl fmt::Display for MultiverseMoodReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{:<14}{:>11}{:>10}{:>10}{:>10}", "Timeline", "Characters", "Pleasure", "Arousal", "Dominance")?;
+        for mood in &self.timelines {
+            let [p, a, d] = mood.pad;
+            let timeline = mood.timeline.to_string();
+            writeln!(f, "{:<14}{:>11}{:>+10.2}{:>+10.2}{:>+10.2}", timeline, mood.characters, p, a, d)?;
+        }
+        Ok(())
     }
 }
 
@@ -583,4 +2831,906 @@ mod tests {
         assert_eq!(branch.parent, Some(root));
         assert!(branch.characters.contains(&char_id));
     }
+
+    #[test]
+    fn test_memory_degrade() {
+        let mut multiverse = Multiverse::new();
+        let timeline = multiverse.root_timeline;
+        let yash = multiverse.create_character("Yash-Tel".to_string(), timeline);
+        let cartographer = multiverse.create_character("The Cartographer".to_string(), timeline);
+        multiverse
+            .characters
+            .get_mut(&cartographer)
+            .unwrap()
+            .insert_ability(Ability::memory_immunity());
+
+        let vivid = multiverse.create_witnessed_memory(EventId(0), timeline, yash);
+        let faint = multiverse.create_witnessed_memory(EventId(0), timeline, yash);
+        multiverse.memories.get_mut(&faint).unwrap().fidelity = 0.15;
+        let charted = multiverse.create_witnessed_memory(EventId(0), timeline, cartographer);
+        multiverse.characters.get_mut(&yash).unwrap().memories.extend([vivid, faint]);
+        multiverse.characters.get_mut(&cartographer).unwrap().memories.insert(charted);
+
+        multiverse.record_event(Event {
+            id: EventId(0),
+            timeline,
+            description: "The Gate's static washes over them".to_string(),
+            participants: HashSet::from([yash, cartographer]),
+            effects: vec![
+                EventEffect::MemoryDegrade { character: yash, amount: 0.5 },
+                EventEffect::MemoryDegrade { character: cartographer, amount: 0.5 },
+            ],
+            causality_violation: None,
+        });
+
+        // The vivid memory fades, the faint one drops below the floor and is lost
+        assert_eq!(multiverse.memories[&vivid].fidelity, 0.5);
+        assert_eq!(multiverse.characters[&yash].memories, HashSet::from([vivid]));
+        // Memory immunity protects the Cartographer entirely
+        assert_eq!(multiverse.memories[&charted].fidelity, 1.0);
+    }
+
+    #[test]
+    fn test_shared_memory_fades_only_if_no_holder_is_immune() {
+        let mut multiverse = Multiverse::new();
+        let timeline = multiverse.root_timeline;
+        let yash = multiverse.create_character("Yash-Tel".to_string(), timeline);
+        let cartographer = multiverse.create_character("The Cartographer".to_string(), timeline);
+        multiverse
+            .characters
+            .get_mut(&cartographer)
+            .unwrap()
+            .insert_ability(Ability::memory_immunity());
+
+        // Both hold the charting; only Yash-Tel holds the crossing
+        let charting = multiverse.create_witnessed_memory(EventId(0), timeline, cartographer);
+        let crossing = multiverse.create_witnessed_memory(EventId(0), timeline, yash);
+        multiverse.characters.get_mut(&yash).unwrap().memories.extend([charting, crossing]);
+        multiverse.characters.get_mut(&cartographer).unwrap().memories.insert(charting);
+
+        multiverse.record_event(Event {
+            id: EventId(0),
+            timeline,
+            description: "The Gate's static washes over Yash-Tel".to_string(),
+            participants: HashSet::from([yash]),
+            effects: vec![EventEffect::MemoryDegrade { character: yash, amount: 0.5 }],
+            causality_violation: None,
+        });
+
+        // Yash-Tel's own memory fades; the one the Cartographer keeps for them both doesn't
+        assert_eq!(multiverse.memories[&crossing].fidelity, 0.5);
+        assert_eq!(multiverse.memories[&charting].fidelity, 1.0);
+        assert!(multiverse.characters[&yash].memories.contains(&charting));
+    }
+
+    #[test]
+    fn test_max_branch_depth() {
+        let mut multiverse = Multiverse::new();
+        multiverse.config.max_branch_depth = Some(2);
+        let root = multiverse.root_timeline;
+        let vera = multiverse.create_character("Vera Kandros".to_string(), root);
+
+        let decide = |multiverse: &mut Multiverse, timeline| {
+            multiverse.record_event(Event {
+                id: EventId(0),
+                timeline,
+                description: "Vera makes a Fold jump".to_string(),
+                participants: HashSet::from([vera]),
+                effects: vec![],
+                causality_violation: None,
+            })
+        };
+
+        let jump = decide(&mut multiverse, root);
+        let child = multiverse.try_create_timeline_branch(root, jump).unwrap();
+        let jump = decide(&mut multiverse, child);
+        let grandchild = multiverse.try_create_timeline_branch(child, jump).unwrap();
+        assert_eq!(multiverse.branch_depth(root), 0);
+        assert_eq!(multiverse.branch_depth(grandchild), 2);
+
+        // One level deeper is refused, and nothing is created
+        let jump = decide(&mut multiverse, grandchild);
+        assert_eq!(
+            multiverse.try_create_timeline_branch(grandchild, jump),
+            Err(MultiverseError::BranchTooDeep {
+                parent: grandchild,
+                max_depth: 2,
+            })
+        );
+        assert_eq!(multiverse.timelines.len(), 3);
+
+        // Shallower timelines can still branch
+        let sibling = multiverse.try_create_timeline_branch(child, jump);
+        assert_eq!(sibling.map(|t| multiverse.branch_depth(t)), Ok(2));
+    }
+
+    #[test]
+    fn test_blend_memories() {
+        let mut multiverse = Multiverse::new();
+        let timeline = multiverse.root_timeline;
+        let vera = multiverse.create_character("Vera".to_string(), timeline);
+        let event = EventId(0);
+
+        let clear = multiverse.create_witnessed_memory(event, timeline, vera);
+        let hazy = multiverse.create_memory(
+            event,
+            timeline,
+            MemoryProvenance::Forged {
+                forger: "Memory Market".to_string(),
+            },
+        );
+        multiverse.memories.get_mut(&hazy).unwrap().fidelity = 0.4;
+
+        let blend = multiverse.blend_memories(&[clear, hazy]).unwrap();
+        assert_eq!(multiverse.memories[&blend].fidelity, 0.4);
+        assert!(matches!(
+            &multiverse.memories[&blend].provenance,
+            MemoryProvenance::Compound { sources } if sources == &vec![clear, hazy]
+        ));
+
+        assert!(multiverse.blend_memories(&[]).is_none());
+        assert!(multiverse.blend_memories(&[clear, MemoryId(999)]).is_none());
+    }
+
+    #[test]
+    fn test_clone_character() {
+        use crate::emotional_system::Goal;
+        let mut multiverse = Multiverse::new();
+        let root = multiverse.root_timeline;
+        let riven = multiverse.create_character("Riven Blackwood".to_string(), root);
+        let corvus = multiverse.create_character("Corvus Shal".to_string(), root);
+
+        multiverse.record_event(Event {
+            id: EventId(0),
+            timeline: root,
+            description: "Riven takes up the revolver and sizes up Corvus".to_string(),
+            participants: HashSet::from([riven, corvus]),
+            effects: vec![
+                EventEffect::AbilityGained {
+                    character: riven,
+                    ability: Ability::causality_hacking(),
+                    mechanism: "Precursor Time-Weapon".to_string(),
+                },
+                EventEffect::KnowledgeGained {
+                    character: riven,
+                    flag: "has_gun".to_string(),
+                },
+                EventEffect::RelationshipChange {
+                    character1: riven,
+                    character2: corvus,
+                    new_state: RelationshipState::Distrustful,
+                },
+                EventEffect::AffinityShift {
+                    character1: riven,
+                    character2: corvus,
+                    delta: 0.25,
+                },
+                EventEffect::AddGoal {
+                    character: riven,
+                    goal: Goal::new("Outdraw Corvus".to_string(), 0.8, false),
+                },
+            ],
+            causality_violation: None,
+        });
+        let sizing_up = multiverse.create_witnessed_memory(EventId(0), root, riven);
+        multiverse.characters.get_mut(&riven).unwrap().memories.insert(sizing_up);
+
+        let parallel = multiverse.clone_character_with_relationships(riven, "(Parallel)").unwrap();
+        let future = multiverse.clone_character(riven, "(Future)").unwrap();
+
+        assert_ne!(parallel, riven);
+        assert_ne!(future, riven);
+
+        let original = &multiverse.characters[&riven];
+        for clone in [parallel, future] {
+            let c = &multiverse.characters[&clone];
+            assert_eq!(c.abilities, original.abilities);
+            assert_eq!(c.knowledge_flags, original.knowledge_flags);
+            assert_eq!(c.memories, HashSet::from([sizing_up]));
+            assert_eq!(c.emotional_state, original.emotional_state);
+            assert_eq!(c.current_timeline, root);
+        }
+        assert_eq!(multiverse.characters[&future].name, "Riven Blackwood (Future)");
+
+        // Only the parallel self inherits present-day relationships, through the event
+        assert_eq!(multiverse.relationship_between(parallel, corvus), RelationshipState::Distrustful);
+        assert_eq!(multiverse.characters[&parallel].affinity(corvus), -0.75);
+        assert_eq!(multiverse.characters[&corvus].affinity(parallel), -0.75);
+        assert!(multiverse.characters[&future].relationships.is_empty());
+        assert!(!multiverse.characters[&corvus].relationships.contains_key(&future));
+        let split = multiverse.events.values().find(|e| e.participants.contains(&parallel)).unwrap();
+        assert!(split.effects.iter().any(|e| e.name() == "RelationshipChange"));
+        assert_eq!(crate::properties::validate_all_properties(&multiverse), Ok(()));
+
+        assert_eq!(
+            multiverse.clone_character(CharacterId(99), "(Ghost)"),
+            Err(MultiverseError::UnknownCharacter(CharacterId(99)))
+        );
+    }
+
+    #[test]
+    fn test_perception_scope() {
+        let mut multiverse = Multiverse::new();
+        let root = multiverse.root_timeline;
+        let vera = multiverse.create_character("Vera".to_string(), root);
+        let conductor = multiverse.create_character("The Conductor".to_string(), root);
+
+        let choice = multiverse.record_event(Event {
+            id: EventId(0),
+            timeline: root,
+            description: "Vera hesitates at the helm".to_string(),
+            participants: HashSet::from([vera]),
+            effects: vec![],
+            causality_violation: None,
+        });
+        let left = multiverse.create_timeline_branch(root, choice);
+        let right = multiverse.create_timeline_branch(root, choice);
+
+        for (character, ability) in [
+            (vera, Ability::sibling_perception()),
+            (conductor, Ability::timeline_perception()),
+        ] {
+            let c = multiverse.characters.get_mut(&character).unwrap();
+            c.current_timeline = left;
+            c.insert_ability(ability);
+        }
+
+        // Sibling perception reaches the other branch, but not back to the root
+        assert!(multiverse.can_perceive_timeline(vera, left));
+        assert!(multiverse.can_perceive_timeline(vera, right));
+        assert!(!multiverse.can_perceive_timeline(vera, root));
+
+        // The Conductor sees everything
+        assert!(multiverse.can_perceive_timeline(conductor, root));
+        assert!(multiverse.can_perceive_timeline(conductor, right));
+
+        // One ability per kind: re-granting replaces the parameters
+        let c = multiverse.characters.get_mut(&vera).unwrap();
+        c.insert_ability(Ability::timeline_perception());
+        assert_eq!(c.abilities.len(), 1);
+        assert!(multiverse.can_perceive_timeline(vera, root));
+    }
+
+    #[test]
+    fn test_grant_ability_records_an_event() {
+        let mut multiverse = Multiverse::new();
+        let root = multiverse.root_timeline;
+        let kor = multiverse.create_character("Kor-Valeth".to_string(), root);
+        multiverse.characters.get_mut(&kor).unwrap().temporal_anchor = Some(TemporalAnchor {
+            origin_era: -1000,
+            stability: 1.0,
+        });
+
+        let grant = multiverse.grant_ability(kor, Ability::memory_immunity()).unwrap();
+        assert!(multiverse.characters[&kor].has_ability(AbilityKind::MemoryImmunity));
+        assert!(matches!(
+            &multiverse.events[&grant].effects[..],
+            [EventEffect::AbilityGained { character, .. }] if *character == kor
+        ));
+        // A grant isn't a scene: the anchor holds
+        assert_eq!(multiverse.remaining_stability(kor), Some(1.0));
+
+        assert_eq!(multiverse.grant_ability(CharacterId(99), Ability::memory_immunity()), None);
+        assert_eq!(multiverse.grant_abilities(root, "Nothing", []), None);
+        assert_eq!(multiverse.events.len(), 1);
+    }
+
+    #[test]
+    fn test_anchor_decays_with_participation() {
+        let mut multiverse = Multiverse::new();
+        let root = multiverse.root_timeline;
+        let kor = multiverse.create_character("Kor-Valeth".to_string(), root);
+        multiverse.characters.get_mut(&kor).unwrap().temporal_anchor = Some(TemporalAnchor {
+            origin_era: -1000,
+            stability: 0.25,
+        });
+
+        multiverse.record_event(Event {
+            id: EventId(0),
+            timeline: root,
+            description: "Kor-Valeth studies the Precursor codes".to_string(),
+            participants: HashSet::from([kor]),
+            effects: vec![],
+            causality_violation: None,
+        });
+        let remaining = multiverse.remaining_stability(kor).unwrap();
+        assert!((remaining - 0.15).abs() < 1e-6);
+
+        // Pushing the anchor past zero forces a departure
+        let departure = multiverse.tick_anchor(kor, 0.5);
+        assert!(departure.is_some());
+        assert_eq!(multiverse.remaining_stability(kor), Some(0.0));
+        assert!(!multiverse.timelines[&root].characters.contains(&kor));
+        assert!(!multiverse.timelines[&root].causality_stable);
+
+        // An exhausted anchor can't depart twice
+        assert!(multiverse.tick_anchor(kor, 0.5).is_none());
+    }
+
+    #[test]
+    fn test_temporal_pull_drags_kor_valeth_home() {
+        let mut multiverse = Multiverse::new();
+        let root = multiverse.root_timeline;
+        let ids = crate::protagonists::create_thirteen_protagonists(&mut multiverse);
+        let (vera, kor) = (ids[0], ids[10]);
+        assert_eq!(multiverse.characters[&kor].anchor_timeline, Some(root));
+        assert_eq!(multiverse.characters[&vera].anchor_timeline, None);
+
+        // Kor-Valeth follows Vera through the Fold, away from their own time
+        let jump = multiverse.record_event(Event {
+            id: EventId(0),
+            timeline: root,
+            description: "The Errant Promise spins up its Fold Drive".to_string(),
+            participants: HashSet::from([vera, kor]),
+            effects: vec![],
+            causality_violation: None,
+        });
+        let fold = multiverse.create_timeline_branch_with(root, jump, &[vera, kor]).unwrap();
+        assert_eq!(multiverse.characters[&kor].current_timeline, fold);
+
+        let mut advances = 0;
+        let pulled = loop {
+            let pulled = multiverse.advance_temporal_pull();
+            advances += 1;
+            if !pulled.is_empty() {
+                break pulled;
+            }
+            assert!(advances < 10, "the past never took Kor-Valeth back");
+            assert_eq!(crate::properties::validate_all_properties(&multiverse), Ok(()));
+        };
+        assert_eq!(advances, 4);
+        assert_eq!(pulled.len(), 1);
+        assert_eq!(multiverse.events[&pulled[0]].timeline, root);
+        let kor_valeth = &multiverse.characters[&kor];
+        assert_eq!(kor_valeth.current_timeline, root);
+        assert_eq!(kor_valeth.pull_strength, 0.0);
+        assert_eq!(multiverse.characters[&vera].current_timeline, fold);
+
+        // Home again, they feel nothing more
+        assert!(multiverse.advance_temporal_pull().is_empty());
+        assert_eq!(multiverse.characters[&kor].pull_strength, 0.0);
+        assert_eq!(crate::properties::validate_all_properties(&multiverse), Ok(()));
+    }
+
+    #[test]
+    fn test_collective_bodies_share_memories() {
+        use crate::emotional_system::{Emotion, EmotionType};
+
+        let mut multiverse = Multiverse::new();
+        let root = multiverse.root_timeline;
+        let khelis = multiverse.create_character("Khelis Tev".to_string(), root);
+        let bodies: Vec<CharacterId> = (1..=3)
+            .map(|n| multiverse.create_character(format!("Synthesis (body {})", n), root))
+            .collect();
+        let gathering = multiverse.record_event(Event {
+            id: EventId(0),
+            timeline: root,
+            description: "Synthesis visits the Dark Spoke".to_string(),
+            participants: bodies.iter().copied().chain([khelis]).collect(),
+            effects: vec![],
+            causality_violation: None,
+        });
+
+        // The third body crosses into a branch of its own
+        let branch = multiverse.create_timeline_branch(root, gathering);
+        multiverse.record_event(Event {
+            id: EventId(0),
+            timeline: root,
+            description: "A body steps through the Fold".to_string(),
+            participants: HashSet::from([bodies[2]]),
+            effects: vec![EventEffect::TimelineMove { character: bodies[2], to: branch }],
+            causality_violation: None,
+        });
+        let sighting = multiverse.record_event(Event {
+            id: EventId(0),
+            timeline: branch,
+            description: "The third body sees the Ring go dark".to_string(),
+            participants: HashSet::from([bodies[2]]),
+            effects: vec![],
+            causality_violation: None,
+        });
+        let dark_ring = multiverse.create_witnessed_memory(sighting, branch, bodies[2]);
+        let third = multiverse.characters.get_mut(&bodies[2]).unwrap();
+        third.memories.insert(dark_ring);
+        third.emotional_state.update_emotional_state(Emotion {
+            emotion_type: EmotionType::Fear,
+            intensity: 0.9,
+        });
+
+        // Linking syncs what the third body saw into the other two, across
+        // timelines, and its fear is spread evenly through the mind
+        multiverse.link_collective(&bodies).unwrap();
+        assert_eq!(multiverse.collectives.len(), 1);
+        let fear = |m: &Multiverse, body| m.characters[body].emotional_state.feels(&EmotionType::Fear);
+        for body in &bodies {
+            assert!(multiverse.characters[body].memories.contains(&dark_ring));
+            assert!((fear(&multiverse, body) - 0.3).abs() < 1e-9);
+        }
+        // A sync of a mind already whole leaves each body's feelings its own
+        multiverse.characters.get_mut(&bodies[0]).unwrap().emotional_state.decay(1);
+        multiverse.link_collective(&bodies[..1]).unwrap();
+        assert!(fear(&multiverse, &bodies[0]) < fear(&multiverse, &bodies[1]));
+
+        // A memory sold to one body reaches them all, and the seller gives it up
+        let ledger = multiverse.create_witnessed_memory(gathering, root, khelis);
+        multiverse.characters.get_mut(&khelis).unwrap().memories.insert(ledger);
+        multiverse.record_event(Event {
+            id: EventId(0),
+            timeline: root,
+            description: "Khelis sells the ledger to Synthesis".to_string(),
+            participants: HashSet::from([khelis, bodies[0]]),
+            effects: vec![EventEffect::MemoryTransfer {
+                memory: ledger,
+                from: Some(khelis),
+                to: bodies[0],
+                exclusive: true,
+            }],
+            causality_violation: None,
+        });
+        for body in &bodies {
+            assert!(multiverse.characters[body].memories.contains(&ledger));
+        }
+        assert!(!multiverse.characters[&khelis].memories.contains(&ledger));
+        assert_eq!(crate::properties::validate_all_properties(&multiverse), Ok(()));
+
+        assert_eq!(multiverse.link_collective(&[]), Err(MultiverseError::EmptyCollective));
+        assert_eq!(
+            multiverse.link_collective(&[bodies[0], CharacterId(99)]),
+            Err(MultiverseError::UnknownCharacter(CharacterId(99)))
+        );
+    }
+
+    #[test]
+    fn test_relationship_between_defaults_to_neutral() {
+        let mut multiverse = Multiverse::new();
+        let root = multiverse.root_timeline;
+        let [riven, kael, vera] = ["Riven Blackwood", "Kael Thornwick", "Vera Kandros"]
+            .map(|name| multiverse.create_character(name.to_string(), root));
+        multiverse.record_event(Event {
+            id: EventId(0),
+            timeline: root,
+            description: "Riven and Kael draw on each other".to_string(),
+            participants: HashSet::from([riven, kael]),
+            effects: vec![EventEffect::RelationshipChange {
+                character1: riven,
+                character2: kael,
+                new_state: RelationshipState::Hostile,
+            }],
+            causality_violation: None,
+        });
+
+        assert_eq!(multiverse.relationship_between(riven, kael), RelationshipState::Hostile);
+        assert_eq!(multiverse.relationship_between(kael, riven), RelationshipState::Hostile);
+        assert!(multiverse.are_hostile(kael, riven) && !multiverse.are_allied(riven, kael));
+
+        // Never introduced, or only recorded on one side
+        assert_eq!(multiverse.relationship_between(riven, vera), RelationshipState::Neutral);
+        assert!(!multiverse.are_hostile(riven, vera) && !multiverse.are_allied(riven, vera));
+        multiverse.characters.get_mut(&vera).unwrap().relationships.insert(riven, RelationshipState::Allied);
+        assert!(multiverse.are_allied(riven, vera));
+        assert_eq!(multiverse.relationship_between(riven, CharacterId(99)), RelationshipState::Neutral);
+    }
+
+    #[test]
+    fn test_timeline_mood_averages_those_present() {
+        use crate::emotional_system::{Emotion, EmotionType};
+        let mut multiverse = Multiverse::new();
+        let root = multiverse.root_timeline;
+        let [vera, riven, corvus] = ["Vera Kandros", "Riven Blackwood", "Corvus Thane"]
+            .map(|name| multiverse.create_character(name.to_string(), root));
+        let feel = |multiverse: &mut Multiverse, character, emotion_type| {
+            let state = &mut multiverse.characters.get_mut(&character).unwrap().emotional_state;
+            state.update_emotional_state(Emotion { emotion_type, intensity: 1.0 });
+            state.get_pad()
+        };
+        let joy = feel(&mut multiverse, vera, EmotionType::Joy);
+        let fear = feel(&mut multiverse, riven, EmotionType::Fear);
+        // The dead don't count toward the mood
+        feel(&mut multiverse, corvus, EmotionType::Distress);
+        multiverse.characters.get_mut(&corvus).unwrap().alive = false;
+
+        let mood = multiverse.timeline_mood(root);
+        for axis in 0..3 {
+            assert!((mood[axis] - (joy[axis] + fear[axis]) / 2.0).abs() < 1e-12);
+        }
+
+        // Nobody lives in the branch yet
+        let branch = multiverse.create_timeline_branch(root, EventId(0));
+        assert_eq!(multiverse.timeline_mood(branch), [0.0; 3]);
+        assert_eq!(multiverse.timeline_mood(TimelineId(99)), [0.0; 3]);
+
+        let report = multiverse.mood_report();
+        let counts: Vec<(TimelineId, usize)> = report.timelines.iter().map(|m| (m.timeline, m.characters)).collect();
+        assert_eq!(counts, vec![(root, 2), (branch, 0)]);
+        assert!(report.to_string().contains("Timeline#1              0     +0.00"));
+    }
+
+    #[test]
+    fn test_fear_spreads_through_synthesis() {
+        use crate::emotional_system::{Emotion, EmotionType};
+        let mut multiverse = Multiverse::new();
+        let root = multiverse.root_timeline;
+        let bodies: Vec<CharacterId> = (1..=7)
+            .map(|n| multiverse.create_character(format!("Synthesis (body {})", n), root))
+            .collect();
+        let riven = multiverse.create_character("Riven Blackwood".to_string(), root);
+        // Every body is allied with every other, and the first has a grudge against Riven
+        let mut effects: Vec<EventEffect> = bodies
+            .iter()
+            .enumerate()
+            .flat_map(|(i, &a)| {
+                bodies[i + 1..].iter().map(move |&b| EventEffect::RelationshipChange {
+                    character1: a,
+                    character2: b,
+                    new_state: RelationshipState::Allied,
+                })
+            })
+            .collect();
+        effects.push(EventEffect::RelationshipChange {
+            character1: bodies[0],
+            character2: riven,
+            new_state: RelationshipState::Hostile,
+        });
+        multiverse.record_event(Event {
+            id: EventId(0),
+            timeline: root,
+            description: "Synthesis corners Riven in the Singing Gardens".to_string(),
+            participants: bodies.iter().copied().chain([riven]).collect(),
+            effects,
+            causality_violation: None,
+        });
+        let feels = |multiverse: &Multiverse, c: CharacterId, emotion| {
+            multiverse.characters[&c].emotional_state.feels(&emotion)
+        };
+
+        // Nothing spreads until somebody feels something
+        assert_eq!(multiverse.propagate_emotions(root, 0.5), Ok(None));
+        let nowhere = TimelineId(99);
+        assert_eq!(multiverse.propagate_emotions(nowhere, 0.5), Err(MultiverseError::UnknownTimeline(nowhere)));
+
+        let fear = Emotion { emotion_type: EmotionType::Fear, intensity: 0.8 };
+        multiverse.characters.get_mut(&bodies[0]).unwrap().emotional_state.update_emotional_state(fear);
+        let spread = multiverse.propagate_emotions(root, 0.5).unwrap().unwrap();
+        for &body in &bodies[1..] {
+            assert_eq!(feels(&multiverse, body, EmotionType::Fear), 0.4);
+        }
+        // Riven takes heart from their enemy's fear
+        assert_eq!(feels(&multiverse, riven, EmotionType::Hope), 0.4);
+        assert_eq!(feels(&multiverse, riven, EmotionType::Fear), 0.0);
+        let effects = &multiverse.events[&spread].effects;
+        assert_eq!(effects.len(), 7);
+        assert!(effects.iter().all(|e| matches!(e, EventEffect::EmotionSpread { from, .. } if *from == bodies[0])));
+
+        // Spread again and again, everyone settles at the first body's fear
+        for _ in 0..20 {
+            multiverse.propagate_emotions(root, 1.0).unwrap();
+            assert_eq!(crate::properties::prop_emotional_state_validity(&multiverse), Ok(()));
+        }
+        for &body in &bodies {
+            assert_eq!(feels(&multiverse, body, EmotionType::Fear), 0.8);
+        }
+        assert_eq!(feels(&multiverse, riven, EmotionType::Hope), 0.8);
+        assert_eq!(multiverse.propagate_emotions(root, 1.0), Ok(None));
+        assert_eq!(crate::properties::validate_all_properties(&multiverse), Ok(()));
+    }
+
+    #[test]
+    fn test_anger_drives_allies_apart() {
+        use crate::emotional_system::{Belief, Goal};
+        let mut multiverse = Multiverse::new();
+        let root = multiverse.root_timeline;
+        let vera = multiverse.create_character("Vera Kandros".to_string(), root);
+        let riven = multiverse.create_character("Riven Blackwood".to_string(), root);
+        let goals = ["Protect Crew", "Keep the Promise Flying", "Get Paid"];
+        for goal in goals {
+            let state = &mut multiverse.characters.get_mut(&vera).unwrap().emotional_state;
+            state.add_goal(Goal::new(goal.to_string(), 1.0, false));
+        }
+        multiverse.record_event(Event {
+            id: EventId(0),
+            timeline: root,
+            description: "Vera and Riven swear to watch each other's backs".to_string(),
+            participants: HashSet::from([vera, riven]),
+            effects: vec![EventEffect::RelationshipChange {
+                character1: vera,
+                character2: riven,
+                new_state: RelationshipState::Allied,
+            }],
+            causality_violation: None,
+        });
+        // Opt-in: nothing has been felt, and nothing drifts on its own
+        assert!(multiverse.apply_emotional_relationship_drift().is_empty());
+
+        // Riven lets Vera down three times over, a different way each time
+        let mut levels = Vec::new();
+        for goal in goals {
+            multiverse.record_event(Event {
+                id: EventId(0),
+                timeline: root,
+                description: format!("Riven's recklessness costs Vera: {}", goal),
+                participants: HashSet::from([vera, riven]),
+                effects: vec![EventEffect::AppraisalTrigger {
+                    character: vera,
+                    belief: Belief {
+                        likelihood: 0.3,
+                        causal_agent_name: Some("Riven Blackwood".to_string()),
+                        affected_goal_names: vec![goal.to_string()],
+                        goal_congruences: vec![-1.0],
+                        is_incremental: true,
+                        self_caused: false,
+                        praiseworthiness: None,
+                    },
+                }],
+                causality_violation: None,
+            });
+            assert_eq!(multiverse.apply_emotional_relationship_drift().len(), 1);
+            // The grudge is spent on the change it made
+            assert!(multiverse.apply_emotional_relationship_drift().is_empty());
+            levels.push(multiverse.relationship_between(vera, riven));
+        }
+        assert_eq!(
+            levels,
+            vec![RelationshipState::Friendly, RelationshipState::Neutral, RelationshipState::Distrustful]
+        );
+        assert_eq!(multiverse.characters[&riven].relationships[&vera], RelationshipState::Distrustful);
+        assert_eq!(crate::properties::validate_all_properties(&multiverse), Ok(()));
+    }
+
+    #[test]
+    fn test_appraisals_are_logged_with_their_event() {
+        use crate::emotional_system::{Belief, Emotion, EmotionType, Goal};
+        let mut multiverse = Multiverse::new();
+        let root = multiverse.root_timeline;
+        let vera = multiverse.create_character("Vera Kandros".to_string(), root);
+        let riven = multiverse.create_character("Riven Blackwood".to_string(), root);
+        multiverse.characters.get_mut(&vera).unwrap().emotional_state.add_goal(Goal::new(
+            "Protect Crew".to_string(),
+            1.0,
+            true,
+        ));
+        let scene = |multiverse: &mut Multiverse, congruence| {
+            multiverse.record_event(Event {
+                id: EventId(0),
+                timeline: root,
+                description: "Riven stands between the crew and the posse".to_string(),
+                participants: HashSet::from([vera, riven]),
+                effects: vec![EventEffect::AppraisalTrigger {
+                    character: vera,
+                    belief: Belief {
+                        likelihood: 0.12,
+                        causal_agent_name: Some("Riven Blackwood".to_string()),
+                        affected_goal_names: vec!["Protect Crew".to_string()],
+                        goal_congruences: vec![congruence],
+                        is_incremental: true,
+                        self_caused: false,
+                        praiseworthiness: None,
+                    },
+                }],
+                causality_violation: None,
+            })
+        };
+
+        let helped = scene(&mut multiverse, 1.0);
+        let felt: Vec<(CharacterId, EmotionType)> = multiverse
+            .emotional_outcomes(helped)
+            .iter()
+            .map(|(c, e)| (*c, e.emotion_type.clone()))
+            .collect();
+        assert_eq!(felt, vec![(vera, EmotionType::Hope), (vera, EmotionType::Gratitude)]);
+        assert!((multiverse.emotional_outcomes(helped)[0].1.intensity - 0.12).abs() < 1e-12);
+
+        // A goal with nothing at stake stirs nothing, and nothing is logged
+        let shrugged = scene(&mut multiverse, 0.0);
+        assert!(multiverse.emotional_outcomes(shrugged).is_empty());
+        assert!(multiverse.emotional_outcomes(EventId(99)).is_empty());
+
+        let saved = serde_json::to_string(&multiverse).unwrap();
+        let loaded: Multiverse = serde_json::from_str(&saved).unwrap();
+        assert_eq!(
+            loaded.emotional_outcomes(helped)[1],
+            (vera, Emotion { emotion_type: EmotionType::Gratitude, intensity: 0.12 })
+        );
+    }
+
+    #[test]
+    fn test_nothing_happens_to_the_dead() {
+        let mut multiverse = Multiverse::new();
+        let root = multiverse.root_timeline;
+        let [riven, mara] = ["Riven Blackwood", "Mara Vex"].map(|name| multiverse.create_character(name.to_string(), root));
+        let learns = |character| EventEffect::KnowledgeGained {
+            character,
+            flag: "future_self_exists".to_string(),
+        };
+        let mut event = Event {
+            id: EventId(0),
+            timeline: root,
+            description: "Riven is shot by their future self".to_string(),
+            participants: HashSet::from([riven, mara]),
+            effects: vec![learns(mara), EventEffect::CharacterDeath { character: riven }, learns(riven)],
+            causality_violation: None,
+        };
+        let error = event.validate_effect_order().unwrap_err();
+        assert!(error.contains("effect 2 (KnowledgeGained)"), "{}", error);
+
+        // Learning first, or coming back in between, is fine
+        event.effects.swap(1, 2);
+        assert_eq!(event.validate_effect_order(), Ok(()));
+        let brought_back = EventEffect::CharacterResurrection {
+            character: riven,
+            mechanism: "Gate resurrection".to_string(),
+        };
+        let mut resurrected = event.clone();
+        resurrected.effects.push(brought_back);
+        resurrected.effects.push(learns(riven));
+        assert_eq!(resurrected.validate_effect_order(), Ok(()));
+
+        // Recording it drops what came too late, and flags it
+        let mut kept_whole = multiverse.clone();
+        let whole = kept_whole.record_event(event.clone());
+        assert_eq!(kept_whole.posthumous_effects(whole), &[]);
+        event.effects.swap(1, 2);
+        let id = multiverse.record_event(event);
+        assert_eq!(multiverse.events[&id].effects.len(), 2);
+        assert_eq!(multiverse.posthumous_effects(id), &[learns(riven)]);
+        assert!(multiverse.characters[&mara].knowledge_flags.contains("future_self_exists"));
+        assert!(multiverse.characters[&riven].knowledge_flags.is_empty());
+        let violation = crate::properties::prop_death_finality(&multiverse).unwrap_err();
+        assert!(violation.message.contains("death in it: KnowledgeGained"), "{}", violation);
+
+        // So is a history that kept it
+        multiverse.posthumous_log.clear();
+        assert_eq!(crate::properties::prop_death_finality(&multiverse), Ok(()));
+        multiverse.events.get_mut(&id).unwrap().effects.push(learns(riven));
+        let violation = crate::properties::prop_death_finality(&multiverse).unwrap_err();
+        assert!(violation.message.contains("needs Char#0 alive"), "{}", violation);
+    }
+
+    #[test]
+    fn test_stabilize_timeline() {
+        use crate::emotional_system::{EmotionType, Goal};
+        let mut multiverse = Multiverse::new();
+        let root = multiverse.root_timeline;
+        let [riven, conductor] =
+            ["Riven Blackwood", "The Conductor"].map(|name| multiverse.create_character(name.to_string(), root));
+        let keeper = Goal::new(PREVENT_RING_COLLAPSE.to_string(), 1.0, true);
+        multiverse.characters.get_mut(&conductor).unwrap().emotional_state.add_goal(keeper);
+        multiverse.timelines.get_mut(&root).unwrap().causality_stable = false;
+        let mut shot = |description: &str| {
+            multiverse.record_event(Event {
+                id: EventId(0),
+                timeline: root,
+                description: description.to_string(),
+                participants: HashSet::from([riven]),
+                effects: vec![],
+                causality_violation: Some(CausalityViolation::EffectBeforeCause {
+                    mechanism: "Time-weapon".to_string(),
+                }),
+            })
+        };
+        let (first, second) = (shot("Riven's bullet lands first"), shot("and again"));
+        assert_eq!(multiverse.unresolved_violations(root), vec![first, second]);
+
+        // Blocked while either shot is unrepaired
+        assert_eq!(
+            multiverse.stabilize_timeline(root, "Conductor's intervention"),
+            Err(MultiverseError::UnresolvedViolation { timeline: root, event: first })
+        );
+        let mended = multiverse.repair_causality(first, "Gate resonance").unwrap();
+        assert_eq!(
+            multiverse.stabilize_timeline(root, "Conductor's intervention"),
+            Err(MultiverseError::UnresolvedViolation { timeline: root, event: second })
+        );
+        assert!(!multiverse.timelines[&root].causality_stable);
+        assert_eq!(
+            multiverse.repair_causality(mended, "Gate resonance"),
+            Err(MultiverseError::NotAViolation(mended))
+        );
+
+        multiverse.repair_causality(second, "Gate resonance").unwrap();
+        let calm = multiverse.stabilize_timeline(root, "Conductor's intervention").unwrap();
+        assert!(multiverse.timelines[&root].causality_stable);
+        assert_eq!(multiverse.events[&calm].participants, HashSet::from([conductor]));
+        let conductor = &multiverse.characters[&conductor].emotional_state;
+        assert!(conductor.emotions.iter().any(|e| e.emotion_type == EmotionType::Joy));
+        assert_eq!(crate::properties::validate_all_properties(&multiverse), Ok(()));
+    }
+
+    #[test]
+    fn test_affinity_levels() {
+        use RelationshipState::*;
+        for (affinity, level) in [
+            (-2.0, Hostile),
+            (-1.5, Hostile),
+            (-1.49, Distrustful),
+            (-0.5, Distrustful),
+            (-0.49, Neutral),
+            (0.49, Neutral),
+            (0.5, Friendly),
+            (1.49, Friendly),
+            (1.5, Allied),
+            (7.0, Allied),
+            (f32::NAN, Neutral),
+        ] {
+            assert_eq!(RelationshipState::from_affinity(affinity), level, "{}", affinity);
+        }
+        for level in [Hostile, Distrustful, Neutral, Friendly, Allied] {
+            assert_eq!(RelationshipState::from_affinity(level.affinity()), level);
+        }
+
+        // Kael cools on Vera a little, then a little more, then enough to tell
+        let mut multiverse = Multiverse::new();
+        let root = multiverse.root_timeline;
+        let [vera, kael] =
+            ["Vera Kandros", "Kael Thornwick"].map(|name| multiverse.create_character(name.to_string(), root));
+        let mut shift = |effect: EventEffect| {
+            multiverse.record_event(Event {
+                id: EventId(0),
+                timeline: root,
+                description: "Kael and Vera argue over the Fold route".to_string(),
+                participants: HashSet::from([vera, kael]),
+                effects: vec![effect],
+                causality_violation: None,
+            });
+            let kael = &multiverse.characters[&kael];
+            (kael.affinity(vera), kael.relationships[&vera])
+        };
+        let cool = |delta| EventEffect::AffinityShift {
+            character1: kael,
+            character2: vera,
+            delta,
+        };
+        assert_eq!(
+            shift(EventEffect::RelationshipChange {
+                character1: vera,
+                character2: kael,
+                new_state: Friendly,
+            }),
+            (1.0, Friendly)
+        );
+        assert_eq!(shift(cool(-0.25)), (0.75, Friendly));
+        assert_eq!(shift(cool(-0.25)), (0.5, Friendly));
+        assert_eq!(shift(cool(-0.25)), (0.25, Neutral));
+        assert_eq!(shift(cool(-9.0)), (-MAX_AFFINITY, Hostile));
+        assert_eq!(multiverse.characters[&vera].affinity(kael), -MAX_AFFINITY);
+    }
+
+    #[test]
+    fn test_load_reports_dangling_references() {
+        let mut multiverse = Multiverse::new();
+        let root = multiverse.root_timeline;
+        let vera = multiverse.create_character("Vera Kandros".to_string(), root);
+        let launch = multiverse.record_event(Event {
+            id: EventId(0),
+            timeline: root,
+            description: "Vera launches the Fold Drive".to_string(),
+            participants: HashSet::from([vera]),
+            effects: vec![],
+            causality_violation: None,
+        });
+        let json = multiverse.to_json().unwrap();
+        assert_eq!(Multiverse::load_from_json(&json).unwrap(), multiverse);
+
+        // Hand-edit the save: the launch moves to a timeline that was never
+        // made, with a stranger aboard, and Vera's memory of it is lost
+        let mut broken: serde_json::Value = serde_json::from_str(&json).unwrap();
+        let event = &mut broken["events"][launch.0.to_string()];
+        event["timeline"] = serde_json::json!(7);
+        event["participants"] = serde_json::json!([vera.0, 42]);
+        broken["characters"][vera.0.to_string()]["memories"] = serde_json::json!([3]);
+
+        let problems = match Multiverse::load_from_json(&broken.to_string()) {
+            Err(LoadError::Integrity(problems)) => problems,
+            other => panic!("expected dangling references, got {:?}", other),
+        };
+        assert_eq!(
+            problems,
+            vec![
+                format!("{} holds missing Memory#3", vera),
+                format!("{} happens in missing Timeline#7", launch),
+                format!("{} has missing participant Char#42", launch),
+                format!("{} lists {}, which happened in Timeline#7", root, launch),
+            ]
+        );
+        assert!(matches!(Multiverse::load_from_json("{"), Err(LoadError::Json(_))));
+    }
 }
@@ -0,0 +1,191 @@
+//! # Observers: Reacting to the Story as It Happens
+//!
+//! Coverage counters, loggers, and a UI all want to know when something
+//! happens, and polling the multiverse after every action means diffing it to
+//! find out what. A [`MultiverseObserver`] is told instead: the multiverse
+//! calls it once per recorded event, after every effect of that event has been
+//! applied, and then once for each death, resurrection, or departure the event
+//! caused, and for each emotion its appraisals stirred. An observer that asks
+//! for them also hears where every character's mood stands afterwards; see
+//! [`PadRecorder`](crate::pad_recorder::PadRecorder).
+//!
+//! Observers see the event, not the multiverse. That keeps the borrow simple:
+//! the multiverse finishes changing itself before it hands anything out, and an
+//! observer can't reach back in and change the story it is watching.
+
+use crate::emotional_system::Emotion;
+use crate::narrative_core::{CharacterId, Event, EventEffect, EventId};
+use std::fmt;
+
+/// Callbacks for changes to a [`Multiverse`](crate::narrative_core::Multiverse).
+///
+/// Every method does nothing by default, so an observer only implements what it
+/// cares about. Observers must be `Send + Sync` so the multiverse holding them
+/// still is.
+pub trait MultiverseObserver: Send + Sync {
+    /// An event was recorded and its effects applied.
+    fn on_event(&mut self, _event: &Event) {}
+
+    /// A character died.
+    fn on_death(&mut self, _character: CharacterId) {}
+
+    /// A character came back from the dead.
+    fn on_resurrection(&mut self, _character: CharacterId) {}
+
+    /// A character was pulled back to their era of origin.
+    fn on_departure(&mut self, _character: CharacterId) {}
+
+    /// An appraisal made a character feel something; see
+    /// [`Multiverse::emotional_outcomes`](crate::narrative_core::Multiverse::emotional_outcomes).
+    fn on_feeling(&mut self, _character: CharacterId, _emotion: &Emotion) {}
+
+    /// Whether this observer wants [`MultiverseObserver::on_mood`]. Working
+    /// out every character's PAD isn't free, so the multiverse only does it
+    /// when some observer asks.
+    fn wants_moods(&self) -> bool {
+        false
+    }
+
+    /// Where `character`'s Pleasure/Arousal/Dominance stood once `event` was
+    /// over. Sent for every character, in id order, after the other callbacks.
+    fn on_mood(&mut self, _event: EventId, _character: CharacterId, _pad: [f64; 3]) {}
+}
+
+/// The observers a multiverse notifies.
+///
+/// Observers are attached to one particular multiverse: a clone starts with
+/// none, they are never serialized, and they don't count towards equality.
+#[derive(Default)]
+pub(crate) struct Observers(Vec<Box<dyn MultiverseObserver>>);
+
+impl Observers {
+    pub(crate) fn push(&mut self, observer: Box<dyn MultiverseObserver>) {
+        self.0.push(observer);
+    }
+
+    pub(crate) fn take(&mut self) -> Vec<Box<dyn MultiverseObserver>> {
+        std::mem::take(&mut self.0)
+    }
+
+    /// Whether any observer wants to hear about moods.
+    pub(crate) fn wants_moods(&self) -> bool {
+        self.0.iter().any(|observer| observer.wants_moods())
+    }
+
+    /// Tells every observer about `event`, then about the changes it made and
+    /// the `feelings` it stirred, and those who asked about the `moods` it left.
+    pub(crate) fn notify(
+        &mut self,
+        event: &Event,
+        feelings: &[(CharacterId, Emotion)],
+        moods: &[(CharacterId, [f64; 3])],
+    ) {
+        for observer in &mut self.0 {
+            observer.on_event(event);
+            for effect in &event.effects {
+                match effect {
+                    EventEffect::CharacterDeath { character } => observer.on_death(*character),
+                    EventEffect::CharacterResurrection { character, .. } => {
+                        observer.on_resurrection(*character)
+                    }
+                    EventEffect::TemporalDeparture { character } => observer.on_departure(*character),
+                    _ => {}
+                }
+            }
+            for (character, emotion) in feelings {
+                observer.on_feeling(*character, emotion);
+            }
+            if observer.wants_moods() {
+                for (character, pad) in moods {
+                    observer.on_mood(event.id, *character, *pad);
+                }
+            }
+        }
+    }
+}
+
+impl Clone for Observers {
+    fn clone(&self) -> Self {
+        Observers::default()
+    }
+}
+
+impl PartialEq for Observers {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl fmt::Debug for Observers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} observers", self.0.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::narrative_core::*;
+    use std::collections::HashSet;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    /// Counts deaths into a counter the test keeps a handle on.
+    struct DeathCounter(Arc<AtomicUsize>);
+
+    impl MultiverseObserver for DeathCounter {
+        fn on_death(&mut self, _character: CharacterId) {
+            self.0.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    #[test]
+    fn test_observer_counts_deaths() {
+        let mut multiverse = Multiverse::new();
+        let root = multiverse.root_timeline;
+        let riven = multiverse.create_character("Riven Blackwood".to_string(), root);
+        let nameless = multiverse.create_character("Nameless".to_string(), root);
+        let deaths = Arc::new(AtomicUsize::new(0));
+        multiverse.add_observer(DeathCounter(deaths.clone()));
+
+        let scene = |multiverse: &mut Multiverse, effects| {
+            multiverse.record_event(Event {
+                id: EventId(0),
+                timeline: root,
+                description: "A duel at the Living Gate".to_string(),
+                participants: HashSet::from([riven, nameless]),
+                effects,
+                causality_violation: None,
+            })
+        };
+        scene(&mut multiverse, vec![EventEffect::CharacterDeath { character: nameless }]);
+        scene(
+            &mut multiverse,
+            vec![EventEffect::CharacterResurrection {
+                character: nameless,
+                mechanism: "Living Gate".to_string(),
+            }],
+        );
+        scene(
+            &mut multiverse,
+            vec![
+                EventEffect::CharacterDeath { character: nameless },
+                EventEffect::CharacterDeath { character: riven },
+            ],
+        );
+        assert_eq!(deaths.load(Ordering::SeqCst), 3);
+
+        // A clone is a separate story, and goes unobserved
+        let mut copy = multiverse.clone();
+        copy.record_event(Event {
+            id: EventId(0),
+            timeline: root,
+            description: "Riven falls again, elsewhere".to_string(),
+            participants: HashSet::from([riven]),
+            effects: vec![EventEffect::CharacterDeath { character: riven }],
+            causality_violation: None,
+        });
+        assert_eq!(deaths.load(Ordering::SeqCst), 3);
+        assert_eq!(multiverse.take_observers().len(), 1);
+    }
+}
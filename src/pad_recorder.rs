@@ -0,0 +1,152 @@
+//! # PAD Trajectories: Charting How the Cast Feels
+//!
+//! A character's mood is a point in Pleasure/Arousal/Dominance space, and the
+//! story moves it. To see an arc (Mara's slide into panic, the Cartographer's
+//! unshaken calm) you need that point after every event, not just at the end.
+//!
+//! A [`PadRecorder`] is an observer that samples it. Attach a clone to the
+//! multiverse and keep the original: clones share their samples, so the one
+//! you kept sees everything the attached one recorded. Export the samples as
+//! CSV for a spreadsheet or plotting script, or as JSON.
+
+use crate::narrative_core::{CharacterId, EventId};
+use crate::observers::MultiverseObserver;
+use std::collections::BTreeSet;
+use std::io;
+use std::sync::{Arc, Mutex, PoisonError};
+
+/// One sample: where a character's mood stood once an event was over.
+pub type PadSample = (EventId, CharacterId, [f64; 3]);
+
+/// Records every character's PAD after every recorded event, or only those of
+/// the characters it was made [`for`](PadRecorder::for_characters).
+#[derive(Debug, Clone, Default)]
+pub struct PadRecorder {
+    /// Whose moods to keep; `None` keeps everyone's
+    characters: Option<BTreeSet<CharacterId>>,
+    samples: Arc<Mutex<Vec<PadSample>>>,
+}
+
+impl PadRecorder {
+    /// A recorder for the whole cast, including anyone created later.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A recorder for just `characters`.
+    pub fn for_characters(characters: impl IntoIterator<Item = CharacterId>) -> Self {
+        PadRecorder {
+            characters: Some(characters.into_iter().collect()),
+            ..Self::default()
+        }
+    }
+
+    /// Everything recorded so far, by event and then by character.
+    pub fn samples(&self) -> Vec<PadSample> {
+        self.samples.lock().unwrap_or_else(PoisonError::into_inner).clone()
+    }
+
+    /// Writes the samples as CSV, one row per sample under the header
+    /// `event,character,pleasure,arousal,dominance`. Ids are written as bare
+    /// numbers so plotting tools can read them.
+    pub fn to_csv(&self, mut writer: impl io::Write) -> io::Result<()> {
+        writeln!(writer, "event,character,pleasure,arousal,dominance")?;
+        for (event, character, [pleasure, arousal, dominance]) in self.samples() {
+            writeln!(writer, "{},{},{},{},{}", event.0, character.0, pleasure, arousal, dominance)?;
+        }
+        Ok(())
+    }
+
+    /// Writes the samples as a JSON array of `[event, character, [p, a, d]]`.
+    pub fn to_json(&self, writer: impl io::Write) -> serde_json::Result<()> {
+        serde_json::to_writer_pretty(writer, &self.samples())
+    }
+}
+
+impl MultiverseObserver for PadRecorder {
+    fn wants_moods(&self) -> bool {
+        true
+    }
+
+    fn on_mood(&mut self, event: EventId, character: CharacterId, pad: [f64; 3]) {
+        if self.characters.as_ref().is_none_or(|kept| kept.contains(&character)) {
+            self.samples.lock().unwrap_or_else(PoisonError::into_inner).push((event, character, pad));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::emotional_system::{Emotion, EmotionType};
+    use crate::narrative_core::*;
+    use crate::protagonists::create_thirteen_protagonists;
+    use std::collections::HashSet;
+
+    /// The cast, and `scenes` events that frighten Mara a little more each time.
+    fn frightened_mara(recorder: &PadRecorder, scenes: usize) -> (Multiverse, CharacterId) {
+        let mut multiverse = Multiverse::new();
+        let root = multiverse.root_timeline;
+        let mara = create_thirteen_protagonists(&mut multiverse)[9];
+        multiverse.add_observer(recorder.clone());
+        for _ in 0..scenes {
+            multiverse.characters.get_mut(&mara).unwrap().emotional_state.update_emotional_state(Emotion {
+                emotion_type: EmotionType::Fear,
+                intensity: 0.2,
+            });
+            multiverse.record_event(Event {
+                id: EventId(0),
+                timeline: root,
+                description: "The Ring shudders again".to_string(),
+                participants: HashSet::from([mara]),
+                effects: vec![],
+                causality_violation: None,
+            });
+        }
+        (multiverse, mara)
+    }
+
+    #[test]
+    fn test_one_sample_per_event_and_character() {
+        let everyone = PadRecorder::new();
+        let (multiverse, mara) = frightened_mara(&everyone, 4);
+        assert_eq!(everyone.samples().len(), 4 * multiverse.characters.len());
+
+        let just_mara = PadRecorder::for_characters([mara]);
+        frightened_mara(&just_mara, 4);
+        let samples = just_mara.samples();
+        assert_eq!(samples.len(), 4);
+        assert!(samples.iter().all(|(_, character, _)| *character == mara));
+        // Growing fear pushes Pleasure down, scene after scene
+        assert!(samples.windows(2).all(|pair| pair[1].2[0] < pair[0].2[0]));
+    }
+
+    #[test]
+    fn test_csv_and_json_round_trip() {
+        let recorder = PadRecorder::new();
+        frightened_mara(&recorder, 3);
+
+        let mut csv = Vec::new();
+        recorder.to_csv(&mut csv).unwrap();
+        let csv = String::from_utf8(csv).unwrap();
+        let mut lines = csv.lines();
+        assert_eq!(lines.next(), Some("event,character,pleasure,arousal,dominance"));
+        let parsed: Vec<PadSample> = lines
+            .map(|line| {
+                let fields: Vec<&str> = line.split(',').collect();
+                let number = |i: usize| fields[i].parse::<f64>().unwrap();
+                (
+                    EventId(fields[0].parse().unwrap()),
+                    CharacterId(fields[1].parse().unwrap()),
+                    [number(2), number(3), number(4)],
+                )
+            })
+            .collect();
+        assert_eq!(parsed, recorder.samples());
+
+        let mut json = Vec::new();
+        recorder.to_json(&mut json).unwrap();
+        let parsed: Vec<PadSample> = serde_json::from_slice(&json).unwrap();
+        assert_eq!(parsed, recorder.samples());
+    }
+}
@@ -25,8 +25,222 @@
 //! 4. **Relationship Persistence**: Relationships stay consistent within a timeline
 //! 5. **Death Finality**: Dead characters can't act (unless resurrected)
 //! 6. **Knowledge Propagation**: Knowledge flags are set correctly after events
+//! 7. **Anchor Departure**: Characters pulled back to their era never reappear
+//! 8. **Ability Justification**: Every ability, starting ones included, is granted by an event not since undone
+//! 9. **Branch History Frozen**: A branch never sees parent events recorded after it diverged
+//! 10. **Memory Wipe**: A wiped character holds nothing from before the wipe
+//! 11. **Exclusive Trades**: A memory sold is a memory the seller no longer has
+//! 12. **Usable Recall**: Characters only act on memories that haven't faded
+//! 13. **Shared Mind**: Members of a collective remember the same things
+//! 14. **Present at the Scene**: Event participants belong to the event's timeline
+//! 15. **Event Ids Agree**: Every event is stored under its own id
+//! 16. **Memories Are Of Something**: A memory's event and timeline exist, unless it's abstract hearsay
+//! 17. **Social Emotions Need Witnesses**: Only those who saw or perceived an appraisal react to it
+//! 18. **Contradictions Are Foresight**: Only precognitives know both of two mutually exclusive flags
+//! 19. **Exiles Answer to Their Anchor**: An anchored character only drifts to newer timelines by a crossing
+//! 20. **Character Invariants**: Each character keeps the rules registered for them alone
 
+use crate::combinators::*;
 use crate::narrative_core::*;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::time::{Duration, Instant};
+
+/// ## Property Violations
+///
+/// What a failed property check reports: which property failed, and an
+/// account of the offending state. Violations serialize, so a failing trace can
+/// be saved together with what went wrong.
+///
+/// A violation may also carry its evidence: the events that led to it and the
+/// memory it concerns. [`PropertyViolation::narrate`] turns that into a short
+/// account of what went wrong, in story order.
+///
+/// Not every finding is fatal. A `Warning` marks something suspicious but
+/// legal—a memory faded almost to nothing, an ally turned enemy overnight—and
+/// never fails validation; it is only collected for the report.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PropertyViolation {
+    /// The property that failed, e.g. "death_finality"
+    pub property: String,
+    /// What went wrong, in terms of characters, events, and timelines
+    pub message: String,
+    /// Whether this fails validation
+    #[serde(default)]
+    pub severity: Severity,
+    /// Events that explain the violation, e.g. the death a corpse is walking past
+    #[serde(default)]
+    pub trace: Vec<EventId>,
+    /// The memory the violation concerns, whose provenance is part of the story
+    #[serde(default)]
+    pub memory: Option<MemoryId>,
+    /// What the violation is about, outermost last, as the
+    /// [combinators](crate::combinators) that found it recorded
+    #[serde(default)]
+    pub context: Vec<ViolationContext>,
+}
+
+/// Something a [`PropertyViolation`] is about.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ViolationContext {
+    Character(CharacterId),
+    Event(EventId),
+    Timeline(TimelineId),
+}
+
+impl fmt::Display for ViolationContext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ViolationContext::Character(id) => write!(f, "{}", id),
+            ViolationContext::Event(id) => write!(f, "{}", id),
+            ViolationContext::Timeline(id) => write!(f, "{}", id),
+        }
+    }
+}
+
+/// How seriously to take a [`PropertyViolation`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Severity {
+    /// The state is invalid
+    #[default]
+    Error,
+    /// The state is valid, but worth a second look
+    Warning,
+}
+
+impl PropertyViolation {
+    pub fn new(property: impl Into<String>, message: impl Into<String>) -> Self {
+        PropertyViolation {
+            property: property.into(),
+            message: message.into(),
+            severity: Severity::Error,
+            trace: Vec::new(),
+            memory: None,
+            context: Vec::new(),
+        }
+    }
+
+    /// Records what this violation is about, once. An event also joins the trace.
+    pub fn about(mut self, context: ViolationContext) -> Self {
+        if !self.context.contains(&context) {
+            self.context.push(context);
+            if let ViolationContext::Event(event) = context {
+                self.trace.push(event);
+            }
+        }
+        self
+    }
+
+    /// Attaches the events that explain this violation.
+    pub fn with_trace(mut self, events: impl IntoIterator<Item = EventId>) -> Self {
+        self.trace.extend(events);
+        self
+    }
+
+    /// Attaches the memory this violation concerns.
+    pub fn with_memory(mut self, memory: MemoryId) -> Self {
+        self.memory = Some(memory);
+        self
+    }
+
+    /// A finding that is reported but doesn't fail validation.
+    pub fn warning(property: impl Into<String>, message: impl Into<String>) -> Self {
+        PropertyViolation {
+            severity: Severity::Warning,
+            ..Self::new(property, message)
+        }
+    }
+
+    pub fn is_error(&self) -> bool {
+        self.severity == Severity::Error
+    }
+
+    /// Tells the story behind the violation: what went wrong, then every traced
+    /// event in chronological order, then where the memory involved came from.
+    pub fn narrate(&self, multiverse: &Multiverse) -> String {
+        use std::fmt::Write;
+        let mut story = self.to_string();
+        if !self.context.is_empty() {
+            let about: Vec<String> = self.context.iter().map(ToString::to_string).collect();
+            write!(story, "\nConcerning {}", about.join(", ")).expect("writing to a String");
+        }
+        let mut trace = self.trace.clone();
+        trace.sort();
+        trace.dedup();
+        if !trace.is_empty() {
+            story.push_str("\nHow it came to this:");
+        }
+        for id in trace {
+            match multiverse.events.get(&id) {
+                Some(event) => write!(story, "\n  {} in {}: {}", id, event.timeline, event.description),
+                None => write!(story, "\n  {}: (no such event)", id),
+            }
+            .expect("writing to a String");
+        }
+        if let Some(memory) = self.memory {
+            story.push_str("\nWhere the memory came from:");
+            narrate_provenance(multiverse, memory, 1, &mut story);
+        }
+        story
+    }
+}
+
+/// Compound memories rarely nest deeper than this; anything past it is elided.
+const MAX_PROVENANCE_DEPTH: usize = 8;
+
+/// One line per memory in the provenance chain, sources indented under blends.
+fn narrate_provenance(multiverse: &Multiverse, id: MemoryId, depth: usize, story: &mut String) {
+    use std::fmt::Write;
+    let indent = "  ".repeat(depth);
+    let Some(memory) = multiverse.memories.get(&id) else {
+        write!(story, "\n{}{} doesn't exist", indent, id).expect("writing to a String");
+        return;
+    };
+    let who = |c: CharacterId| match multiverse.characters.get(&c) {
+        Some(character) => format!("{} ({})", c, character.name),
+        None => c.to_string(),
+    };
+    let origin = match &memory.provenance {
+        MemoryProvenance::Witnessed { character } => format!("witnessed by {}", who(*character)),
+        MemoryProvenance::Traded {
+            original_owner,
+            acquired_via,
+        } => format!("traded from {} via {}", who(*original_owner), acquired_via),
+        MemoryProvenance::Forged { forger } => format!("forged by {}", forger),
+        MemoryProvenance::Compound { sources } => format!("blended from {} memories", sources.len()),
+    };
+    let event = match multiverse.events.get(&memory.event) {
+        Some(event) => format!("\"{}\"", event.description),
+        None => "(no such event)".to_string(),
+    };
+    write!(
+        story,
+        "\n{}{} of {} in {} {}, {}, fidelity {:.2}",
+        indent, id, memory.event, memory.source_timeline, event, origin, memory.fidelity
+    )
+    .expect("writing to a String");
+    if let MemoryProvenance::Compound { sources } = &memory.provenance {
+        if depth >= MAX_PROVENANCE_DEPTH {
+            write!(story, "\n{}  …", indent).expect("writing to a String");
+            return;
+        }
+        for source in sources {
+            narrate_provenance(multiverse, *source, depth + 1, story);
+        }
+    }
+}
+
+impl fmt::Display for PropertyViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.severity {
+            Severity::Error => write!(f, "[{}] {}", self.property, self.message),
+            Severity::Warning => write!(f, "[{}] warning: {}", self.property, self.message),
+        }
+    }
+}
+
+impl std::error::Error for PropertyViolation {}
 
 /// ## Property 1: Memory Consistency
 ///
@@ -36,13 +250,17 @@ use crate::narrative_core::*;
 ///
 /// This prevents the common bug where characters mysteriously "know" things
 /// they shouldn't.
-pub fn prop_memory_consistency(multiverse: &Multiverse) -> Result<(), String> {
+pub fn prop_memory_consistency(multiverse: &Multiverse) -> Result<(), PropertyViolation> {
+    let fail = |message: String| PropertyViolation::new("memory_consistency", message);
     for (char_id, character) in &multiverse.characters {
         for memory_id in &character.memories {
             let memory = multiverse
                 .memories
                 .get(memory_id)
-                .ok_or_else(|| format!("Memory {} not found in multiverse", memory_id))?;
+                .ok_or_else(|| {
+                    fail(format!("Memory {} not found in multiverse", memory_id)).with_memory(*memory_id)
+                })?;
+            let fail = |message: String| fail(message).with_memory(*memory_id).with_trace([memory.event]);
 
             // Check if memory is justified
             match &memory.provenance {
@@ -50,10 +268,10 @@ pub fn prop_memory_consistency(multiverse: &Multiverse) -> Result<(), String> {
                     // Verify the character was actually present at the event
                     if let Some(event) = multiverse.events.get(&memory.event) {
                         if !event.participants.contains(witness) {
-                            return Err(format!(
+                            return Err(fail(format!(
                                 "{} has witnessed memory of event {}, but was not present",
                                 char_id, memory.event.0
-                            ));
+                            )));
                         }
                     }
                 }
@@ -62,22 +280,28 @@ pub fn prop_memory_consistency(multiverse: &Multiverse) -> Result<(), String> {
                     // (validated elsewhere)
                 }
                 MemoryProvenance::Forged { forger } => {
-                    // Forged memories must have a justification
+                    // Forged memories must have a justification: a forger the story knows
                     if forger.is_empty() {
-                        return Err(format!(
+                        return Err(fail(format!(
                             "{} has forged memory {} with no forger specified",
                             char_id, memory_id.0
-                        ));
+                        )));
+                    }
+                    if !multiverse.is_registered_forger(forger) {
+                        return Err(fail(format!(
+                            "{} has forged memory {} from unknown forger '{}'",
+                            char_id, memory_id.0, forger
+                        )));
                     }
                 }
                 MemoryProvenance::Compound { sources } => {
                     // Verify all source memories exist
                     for source_id in sources {
                         if !multiverse.memories.contains_key(source_id) {
-                            return Err(format!(
+                            return Err(fail(format!(
                                 "Compound memory {} references non-existent source {}",
                                 memory_id.0, source_id.0
-                            ));
+                            )));
                         }
                     }
                 }
@@ -91,28 +315,40 @@ pub fn prop_memory_consistency(multiverse: &Multiverse) -> Result<(), String> {
 ///
 /// **Invariant**: A character can only reference/perceive events from timelines if:
 /// - They are currently in that timeline, OR
+/// - They have lived there before (see `Character::timeline_history`), OR
 /// - They have the TimelinePerception ability
 ///
 /// This prevents timeline-specific knowledge from bleeding across branches.
-pub fn prop_timeline_perception(multiverse: &Multiverse) -> Result<(), String> {
+/// Memories a traveller brings along from a former home aren't bleed: they were
+/// formed while the traveller was there. Neither are a collective's memories:
+/// one mind perceives wherever any of its bodies can.
+pub fn prop_timeline_perception(multiverse: &Multiverse) -> Result<(), PropertyViolation> {
+    let fail = |message: String| PropertyViolation::new("timeline_perception", message);
     for (char_id, character) in &multiverse.characters {
         // Check all memories this character has
         for memory_id in &character.memories {
             let memory = multiverse
                 .memories
                 .get(memory_id)
-                .ok_or_else(|| format!("Memory {} not found", memory_id))?;
+                .ok_or_else(|| fail(format!("Memory {} not found", memory_id)))?;
 
-            // If memory is from a different timeline, character must be able to perceive it
-            if memory.source_timeline != character.current_timeline
-                && !character
-                    .abilities
-                    .contains(&Ability::TimelinePerception)
-            {
-                return Err(format!(
-                    "{} ({}) has memory from {} but is in {} without TimelinePerception ability",
+            // If memory is from a different timeline, character must have lived
+            // there or be able to perceive it
+            let minds = multiverse
+                .collective_of(*char_id)
+                .map_or_else(|| vec![*char_id], |c| c.members.iter().copied().collect());
+            let perceived = minds.iter().any(|mind| {
+                multiverse
+                    .characters
+                    .get(mind)
+                    .is_some_and(|m| m.was_in_timeline(memory.source_timeline))
+                    || multiverse.can_perceive_timeline(*mind, memory.source_timeline)
+            });
+            if !perceived {
+                return Err(fail(format!(
+                    "{} ({}) has memory from {} but is in {} and cannot perceive it",
                     character.name, char_id, memory.source_timeline, character.current_timeline
-                ));
+                )));
             }
         }
     }
@@ -124,36 +360,94 @@ pub fn prop_timeline_perception(multiverse: &Multiverse) -> Result<(), String> {
 /// **Invariant**: Events can only violate causality if they have an explicit
 /// in-universe mechanism (Gates, time weapons, etc.)
 ///
+/// A `Bootstrap` also needs someone at the scene with `LoopMemory`: an item
+/// from nowhere only makes sense to someone who remembers the loop it came
+/// around. What they bring back of it is only as clear as their `retention`,
+/// which like any memory's fidelity must be above `min_usable_fidelity` to act
+/// on. Abilities can be lost, so a participant who loses `LoopMemory` in a
+/// later event counts as having had it.
+///
+/// A timeline with a violation stays unstable until the violation is repaired
+/// by a later event of the same timeline (see [`Multiverse::repair_causality`]),
+/// and a repair must answer a real violation.
+///
 /// This prevents arbitrary causality breaks that confuse players.
-pub fn prop_causality_justification(multiverse: &Multiverse) -> Result<(), String> {
-    for event in multiverse.events.values() {
-        if let Some(violation) = &event.causality_violation {
-            // Verify the violation has a mechanism
-            let mechanism = match violation {
-                CausalityViolation::EffectBeforeCause { mechanism } => mechanism,
-                CausalityViolation::RetroactiveChange { mechanism } => mechanism,
-                CausalityViolation::Superposition { mechanism } => mechanism,
-            };
-
-            if mechanism.is_empty() {
-                return Err(format!(
-                    "Event {} violates causality without specified mechanism",
-                    event.id.0
-                ));
+pub fn prop_causality_justification(multiverse: &Multiverse) -> Result<(), PropertyViolation> {
+    let fail = |message: String| PropertyViolation::new("causality_justification", message);
+    let usable = multiverse.config.validation.min_usable_fidelity;
+    let check = for_all_events(|multiverse, event| {
+        for effect in &event.effects {
+            if let EventEffect::CausalityRepaired { violation, .. } = effect {
+                let answers = multiverse.events.get(violation).is_some_and(|v| {
+                    v.causality_violation.is_some() && v.timeline == event.timeline && v.id < event.id
+                });
+                implies(!answers, || {
+                    Err(fail(format!(
+                        "Event {} repairs {}, which isn't an earlier causality violation in {}",
+                        event.id.0, violation, event.timeline
+                    )))
+                })?;
             }
+        }
+        let Some(violation) = &event.causality_violation else {
+            return Ok(());
+        };
+        let mechanism = match violation {
+            CausalityViolation::EffectBeforeCause { mechanism }
+            | CausalityViolation::RetroactiveChange { mechanism }
+            | CausalityViolation::Superposition { mechanism }
+            | CausalityViolation::Bootstrap { mechanism, .. } => mechanism,
+        };
+        if mechanism.is_empty() {
+            return Err(fail(format!(
+                "Event {} violates causality without specified mechanism",
+                event.id.0
+            )));
+        }
 
-            // Timeline should be marked as causality-unstable
-            if let Some(timeline) = multiverse.timelines.get(&event.timeline) {
-                if timeline.causality_stable {
-                    return Err(format!(
-                        "Event {} violates causality but timeline {} is marked stable",
-                        event.id.0, timeline.id
-                    ));
-                }
-            }
+        let lost_later = |character: CharacterId| {
+            multiverse.events.values().any(|later| {
+                later.id > event.id
+                    && later.effects.contains(&EventEffect::AbilityLost {
+                        character,
+                        ability: AbilityKind::LoopMemory,
+                    })
+            })
+        };
+        let looped = || {
+            event.participants.iter().any(|p| {
+                multiverse.characters.get(p).is_some_and(|c| c.loop_retention() > usable)
+                    || lost_later(*p)
+            })
+        };
+        if let CausalityViolation::Bootstrap { looped_item, .. } = violation {
+            implies(!looped(), || {
+                Err(fail(format!(
+                    "Event {} bootstraps {} but no participant remembers the loop",
+                    event.id.0, looped_item
+                )))
+            })?;
         }
+
+        // Timeline should be marked as causality-unstable, until it's repaired
+        let stable = multiverse.timelines.get(&event.timeline).is_some_and(|t| t.causality_stable);
+        implies(stable && multiverse.unresolved_violations(event.timeline).contains(&event.id), || {
+            Err(fail(format!(
+                "Event {} violates causality but timeline {} is marked stable",
+                event.id.0, event.timeline
+            )))
+        })
+    });
+    check(multiverse)
+}
+
+/// Orders a character pair so (a, b) and (b, a) share one relationship history.
+fn relationship_key(a: CharacterId, b: CharacterId) -> (CharacterId, CharacterId) {
+    if a.0 <= b.0 {
+        (a, b)
+    } else {
+        (b, a)
     }
-    Ok(())
 }
 
 /// ## Property 4: Relationship Consistency
@@ -161,47 +455,70 @@ pub fn prop_causality_justification(multiverse: &Multiverse) -> Result<(), Strin
 /// **Invariant**: Within a single timeline, character relationships must remain
 /// consistent—they can only change via explicit relationship-change events.
 ///
-/// This prevents relationships from randomly fluctuating.
-pub fn prop_relationship_consistency(multiverse: &Multiverse) -> Result<(), String> {
+/// This prevents relationships from randomly fluctuating. Affinity may drift
+/// within a level, but the level is always the one the timeline's changes and
+/// shifts add up to, and the one the drifted affinity falls in.
+pub fn prop_relationship_consistency(multiverse: &Multiverse) -> Result<(), PropertyViolation> {
+    let fail = |message: String| PropertyViolation::new("relationship_consistency", message);
     use std::collections::HashMap;
 
     // For each timeline, verify relationships are justified by events
     for timeline in multiverse.timelines.values() {
-        let mut relationship_history: HashMap<(CharacterId, CharacterId), Vec<RelationshipState>> =
-            HashMap::new();
+        // Where each pair's affinity stands after the timeline's events, once a
+        // change has fixed it; shifts before any change start from unknown
+        let mut relationship_history: HashMap<(CharacterId, CharacterId), Option<f32>> = HashMap::new();
 
         // Walk through events in order
         for event_id in &timeline.events {
             if let Some(event) = multiverse.events.get(event_id) {
                 for effect in &event.effects {
-                    if let EventEffect::RelationshipChange {
-                        character1,
-                        character2,
-                        new_state,
-                    } = effect
-                    {
-                        relationship_history
-                            .entry((*character1, *character2))
-                            .or_insert_with(Vec::new)
-                            .push(*new_state);
+                    match effect {
+                        // Changes apply to both sides, so key on the unordered pair
+                        EventEffect::RelationshipChange {
+                            character1,
+                            character2,
+                            new_state,
+                        } => {
+                            relationship_history
+                                .insert(relationship_key(*character1, *character2), Some(new_state.affinity()));
+                        }
+                        EventEffect::AffinityShift {
+                            character1,
+                            character2,
+                            delta,
+                        } => {
+                            let affinity = relationship_history
+                                .entry(relationship_key(*character1, *character2))
+                                .or_default();
+                            *affinity = affinity.map(|a| (a + delta).clamp(-MAX_AFFINITY, MAX_AFFINITY));
+                        }
+                        _ => {}
                     }
                 }
             }
         }
 
-        // Now verify current relationships match the last recorded change
+        // Now verify current relationships match where the events left them
         for char_id in &timeline.characters {
             if let Some(character) = multiverse.characters.get(char_id) {
                 for (other_id, current_state) in &character.relationships {
-                    let key = (*char_id, *other_id);
-                    if let Some(history) = relationship_history.get(&key) {
-                        if let Some(last_state) = history.last() {
-                            if last_state != current_state {
-                                return Err(format!(
-                                    "Relationship between {} and {} is {:?} but last event set it to {:?}",
-                                    char_id, other_id, current_state, last_state
-                                ));
-                            }
+                    if let Some(affinity) = character.affinities.get(other_id) {
+                        let level = RelationshipState::from_affinity(*affinity);
+                        if level != *current_state {
+                            return Err(fail(format!(
+                                "Relationship between {} and {} is {:?} but their affinity {} is {:?}",
+                                char_id, other_id, current_state, affinity, level
+                            )));
+                        }
+                    }
+                    let key = relationship_key(*char_id, *other_id);
+                    if let Some(Some(affinity)) = relationship_history.get(&key) {
+                        let last_state = RelationshipState::from_affinity(*affinity);
+                        if last_state != *current_state {
+                            return Err(fail(format!(
+                                "Relationship between {} and {} is {:?} but last event set it to {:?}",
+                                char_id, other_id, current_state, last_state
+                            )));
                         }
                     }
                 }
@@ -214,37 +531,79 @@ pub fn prop_relationship_consistency(multiverse: &Multiverse) -> Result<(), Stri
 /// ## Property 5: Death Finality
 ///
 /// **Invariant**: Dead characters cannot participate in events unless
-/// they've been explicitly resurrected via a resurrection mechanism.
-pub fn prop_death_finality(multiverse: &Multiverse) -> Result<(), String> {
+/// they've been explicitly resurrected via a resurrection mechanism. Within an
+/// event, nothing that needs a character alive follows their death (see
+/// [`Event::validate_effect_order`]), and no recorded event had such an effect
+/// dropped (see [`Multiverse::posthumous_effects`]).
+///
+/// A branch starts with whoever was alive where it split off, as its inherited
+/// history has it (see [`Multiverse::ancestor_cache`]): a death in the parent
+/// after the split never reaches it.
+pub fn prop_death_finality(multiverse: &Multiverse) -> Result<(), PropertyViolation> {
+    let fail = |message: String| PropertyViolation::new("death_finality", message);
     use std::collections::HashMap;
 
     // Track death/resurrection events in each timeline
     let mut character_alive_state: HashMap<TimelineId, HashMap<CharacterId, bool>> =
         HashMap::new();
+    let ancestry = multiverse.ancestor_cache();
+    let settle = |alive: &mut HashMap<CharacterId, bool>, event: &Event| {
+        for effect in &event.effects {
+            match effect {
+                EventEffect::CharacterDeath { character } => {
+                    alive.insert(*character, false);
+                }
+                EventEffect::CharacterResurrection { character, .. } => {
+                    alive.insert(*character, true);
+                }
+                _ => {}
+            }
+        }
+    };
 
-    // Sort timelines by ID to ensure we process parents before children
-    // (TimelineId is sequential)
-    let mut timelines: Vec<_> = multiverse.timelines.values().collect();
-    timelines.sort_by_key(|t| t.id.0);
-
-    for timeline in timelines {
+    for timeline in multiverse.timelines_parents_first() {
         let mut alive_in_timeline = HashMap::new();
+        let line = ancestry.ancestors(timeline.id);
 
-        // If this is a branched timeline, inherit the state from parent
-        if let Some(parent_id) = timeline.parent {
-            if let Some(parent_state) = character_alive_state.get(&parent_id) {
-                alive_in_timeline = parent_state.clone();
+        // Everyone in the oldest timeline of the line starts alive
+        let oldest = line.last().map_or(timeline.id, |a| a.timeline);
+        for char_id in &multiverse.timelines[&oldest].characters {
+            alive_in_timeline.insert(*char_id, true);
+        }
+
+        // Characters born into this timeline or one it branched from start alive
+        // too, even if they have since left it (e.g. pulled back by a temporal anchor)
+        for character in multiverse.characters.values() {
+            let native = character.native_timeline;
+            if native == timeline.id || line.iter().any(|a| a.timeline == native) {
+                alive_in_timeline.entry(character.id).or_insert(true);
             }
-        } else {
-            // Root timeline: all characters start alive
-            for char_id in &timeline.characters {
-                alive_in_timeline.insert(*char_id, true);
+        }
+
+        // A branch lives through what it inherited, oldest first, up to each split
+        for inheritance in line.iter().rev() {
+            let inherited = &multiverse.timelines[&inheritance.timeline].events[..inheritance.through];
+            for event in inherited.iter().filter_map(|id| multiverse.events.get(id)) {
+                settle(&mut alive_in_timeline, event);
             }
         }
 
         // Process events in order
-        for event_id in &timeline.events {
+        for (position, event_id) in timeline.events.iter().enumerate() {
             if let Some(event) = multiverse.events.get(event_id) {
+                if let Err(message) = event.validate_effect_order() {
+                    return Err(fail(format!("Event {}: {}", event.id.0, message)));
+                }
+                let dropped = multiverse.posthumous_effects(event.id);
+                if !dropped.is_empty() {
+                    let names: Vec<_> = dropped.iter().map(EventEffect::name).collect();
+                    return Err(fail(format!(
+                        "Event {} dropped effects that came after a death in it: {}",
+                        event.id.0,
+                        names.join(", ")
+                    )));
+                }
+
                 // Check participants are all alive
                 for participant in &event.participants {
                     if !alive_in_timeline.get(participant).copied().unwrap_or(false) {
@@ -255,32 +614,27 @@ pub fn prop_death_finality(multiverse: &Multiverse) -> Result<(), String> {
 
                         if !is_resurrection {
                             let name = multiverse.characters.get(participant).map(|c| c.name.as_str()).unwrap_or("Unknown");
-                            return Err(format!(
+                            return Err(fail(format!(
                                 "Dead character {} ({}) participates in event {} without resurrection",
                                 participant, name, event.id.0
-                            ));
+                            ))
+                            .with_trace(since_death(multiverse, &timeline.events[..=position], *participant)));
                         }
                     }
                 }
 
                 // Apply death/resurrection effects
                 for effect in &event.effects {
-                    match effect {
-                        EventEffect::CharacterDeath { character } => {
-                            alive_in_timeline.insert(*character, false);
-                        }
-                        EventEffect::CharacterResurrection { character, mechanism } => {
-                            if mechanism.is_empty() {
-                                return Err(format!(
-                                    "Character {} resurrected without mechanism",
-                                    character
-                                ));
-                            }
-                            alive_in_timeline.insert(*character, true);
+                    if let EventEffect::CharacterResurrection { character, mechanism } = effect {
+                        if mechanism.is_empty() {
+                            return Err(fail(format!(
+                                "Character {} resurrected without mechanism",
+                                character
+                            )));
                         }
-                        _ => {}
                     }
                 }
+                settle(&mut alive_in_timeline, event);
             }
         }
 
@@ -296,10 +650,12 @@ pub fn prop_death_finality(multiverse: &Multiverse) -> Result<(), String> {
                 .unwrap_or(true);
 
             if character.alive != expected_alive {
-                return Err(format!(
+                let events = &multiverse.timelines[&character.current_timeline].events;
+                return Err(fail(format!(
                     "Character {} ({}) alive status is {} but should be {} based on events in timeline {}",
                     character.id, character.name, character.alive, expected_alive, character.current_timeline
-                ));
+                ))
+                .with_trace(since_death(multiverse, events, character.id)));
             }
         }
     }
@@ -307,53 +663,146 @@ pub fn prop_death_finality(multiverse: &Multiverse) -> Result<(), String> {
     Ok(())
 }
 
+/// The last death of `character` among `events`, and every event after it that
+/// involves them. Without a death, every event that involves them.
+fn since_death(multiverse: &Multiverse, events: &[EventId], character: CharacterId) -> Vec<EventId> {
+    let recorded: Vec<&Event> = events.iter().filter_map(|id| multiverse.events.get(id)).collect();
+    let death = recorded.iter().rposition(|event| {
+        event
+            .effects
+            .iter()
+            .any(|effect| matches!(effect, EventEffect::CharacterDeath { character: c } if *c == character))
+    });
+    recorded[death.unwrap_or(0)..]
+        .iter()
+        .filter(|event| event.involves(character))
+        .map(|event| event.id)
+        .collect()
+}
+
 /// ## Property 6: Knowledge Flag Propagation
 ///
 /// **Invariant**: If a character has a knowledge flag set, there must be
-/// an event in their timeline that granted that knowledge.
-pub fn prop_knowledge_flags(multiverse: &Multiverse) -> Result<(), String> {
+/// an event in their timeline's history (its own, or inherited from the
+/// timelines it branched from) that granted that knowledge—or one in a timeline
+/// they lived in at the time and have since left, bringing the knowledge along.
+///
+/// The one exception is the Ansible Lattice: a character with `AnsibleLink` may
+/// receive a flag from another linked character in any timeline, since the
+/// Lattice doesn't respect branch boundaries.
+///
+/// Under `strict_flags`, every flag granted or held must also be in the
+/// multiverse's flag registry, which catches a misspelled flag at the event
+/// that introduced it.
+pub fn prop_knowledge_flags(multiverse: &Multiverse) -> Result<(), PropertyViolation> {
+    let fail = |message: String| PropertyViolation::new("knowledge_flags", message);
     use std::collections::{HashMap, HashSet};
 
-    // Track knowledge granted in each timeline
-    let mut knowledge_granted: HashMap<TimelineId, HashMap<CharacterId, HashSet<String>>> =
-        HashMap::new();
+    if multiverse.config.strict_flags {
+        for event in multiverse.events.values() {
+            for effect in &event.effects {
+                if let EventEffect::KnowledgeGained { flag, .. } | EventEffect::KnowledgeShared { flag, .. } =
+                    effect
+                {
+                    if !multiverse.is_registered_flag(flag) {
+                        return Err(fail(format!(
+                            "{} grants knowledge flag '{}', which was never registered",
+                            event.id, flag
+                        ))
+                        .with_trace([event.id]));
+                    }
+                }
+            }
+        }
+        for character in multiverse.characters.values() {
+            if let Some(flag) = character.knowledge_flags.iter().find(|f| !multiverse.is_registered_flag(f)) {
+                return Err(fail(format!(
+                    "Character {} has knowledge flag '{}', which was never registered",
+                    character.id, flag
+                )));
+            }
+        }
+    }
 
-    for timeline in multiverse.timelines.values() {
-        let mut granted = HashMap::new();
+    let linked = |id: &CharacterId| {
+        multiverse
+            .characters
+            .get(id)
+            .map(|c| c.has_ability(AbilityKind::AnsibleLink))
+            .unwrap_or(false)
+    };
+
+    // Track the events that granted each character knowledge
+    let mut knowledge_granted: HashMap<CharacterId, Vec<(EventId, &String)>> = HashMap::new();
+    // Knowledge shared over the Lattice, valid in every timeline
+    let mut lattice_granted: HashMap<CharacterId, HashSet<String>> = HashMap::new();
+    // Knowledge gained in a timeline the character has since moved out of
+    let mut carried: HashMap<CharacterId, HashSet<String>> = HashMap::new();
+    let mut carry = |recipient: &CharacterId, event: &Event, flag: &String| {
+        let moved_on = multiverse
+            .characters
+            .get(recipient)
+            .map(|c| c.lived_in_timeline_at(event.timeline, event.id))
+            .unwrap_or(false);
+        if moved_on {
+            carried.entry(*recipient).or_default().insert(flag.clone());
+        }
+    };
 
+    for timeline in multiverse.timelines.values() {
         for event_id in &timeline.events {
             if let Some(event) = multiverse.events.get(event_id) {
                 for effect in &event.effects {
-                    if let EventEffect::KnowledgeGained { character, flag } = effect {
-                        granted
-                            .entry(*character)
-                            .or_insert_with(HashSet::new)
-                            .insert(flag.clone());
+                    match effect {
+                        EventEffect::KnowledgeGained { character, flag } => {
+                            carry(character, event, flag);
+                            knowledge_granted.entry(*character).or_default().push((event.id, flag));
+                        }
+                        EventEffect::KnowledgeShared { from, to, flag } => {
+                            carry(to, event, flag);
+                            knowledge_granted.entry(*to).or_default().push((event.id, flag));
+                            if linked(from) && linked(to) {
+                                lattice_granted.entry(*to).or_default().insert(flag.clone());
+                            }
+                        }
+                        _ => {}
                     }
                 }
             }
         }
-
-        knowledge_granted.insert(timeline.id, granted);
     }
 
     // Verify each character's knowledge flags are justified
+    let ancestry = multiverse.ancestor_cache();
+    let no_knowledge = HashSet::new();
     for character in multiverse.characters.values() {
-        if let Some(granted) = knowledge_granted.get(&character.current_timeline) {
-            if let Some(char_knowledge) = granted.get(&character.id) {
+        if multiverse.timelines.contains_key(&character.current_timeline) {
+            let via_lattice = lattice_granted.get(&character.id).unwrap_or(&no_knowledge);
+            let brought = carried.get(&character.id).unwrap_or(&no_knowledge);
+            let elsewhere = |flag: &String| via_lattice.contains(flag) || brought.contains(flag);
+            let char_knowledge: HashSet<&String> = knowledge_granted
+                .get(&character.id)
+                .into_iter()
+                .flatten()
+                .filter(|(event, _)| ancestry.event_visible_in(*event, character.current_timeline))
+                .map(|(_, flag)| *flag)
+                .collect();
+            if !char_knowledge.is_empty() {
                 for flag in &character.knowledge_flags {
-                    if !char_knowledge.contains(flag) {
-                        return Err(format!(
+                    if !char_knowledge.contains(flag) && !elsewhere(flag) {
+                        return Err(fail(format!(
                             "Character {} has knowledge flag '{}' but no event granted it",
                             character.id, flag
-                        ));
+                        ))
+                        .with_trace(grants_of(multiverse, flag)));
                     }
                 }
-            } else if !character.knowledge_flags.is_empty() {
-                return Err(format!(
+            } else if let Some(flag) = character.knowledge_flags.iter().find(|f| !elsewhere(f)) {
+                return Err(fail(format!(
                     "Character {} has knowledge flags but no events granted any",
                     character.id
-                ));
+                ))
+                .with_trace(grants_of(multiverse, flag)));
             }
         }
     }
@@ -361,126 +810,2735 @@ pub fn prop_knowledge_flags(multiverse: &Multiverse) -> Result<(), String> {
     Ok(())
 }
 
-/// ## Combined Property Validator
+/// Every event that grants `flag` to anyone, anywhere: the events that could
+/// have explained it, had they reached the right character or timeline.
+fn grants_of(multiverse: &Multiverse, flag: &str) -> Vec<EventId> {
+    multiverse
+        .events
+        .values()
+        .filter(|event| {
+            event.effects.iter().any(|effect| {
+                matches!(effect, EventEffect::KnowledgeGained { flag: f, .. }
+                    | EventEffect::KnowledgeShared { flag: f, .. } if f == flag)
+            })
+        })
+        .map(|event| event.id)
+        .collect()
+}
+
+/// ## Property 7: Temporal Anchor Departure
 ///
-/// Runs all property checks on a multiverse state.
-/// Returns Ok(()) if all properties hold, or Err with details of the first violation.
-pub fn validate_all_properties(multiverse: &Multiverse) -> Result<(), String> {
-    prop_memory_consistency(multiverse)?;
-    prop_timeline_perception(multiverse)?;
-    prop_causality_justification(multiverse)?;
-    prop_relationship_consistency(multiverse)?;
-    prop_death_finality(multiverse)?;
-    prop_knowledge_flags(multiverse)?;
-    prop_emotional_state_validity(multiverse)?;
+/// **Invariant**: Once an anchored character has been pulled back to their era
+/// of origin, they never appear in a later event of that timeline.
+///
+/// The past doesn't hand its exiles back. If Kor-Valeth shows up after their
+/// departure event, some scene was written without noticing they'd gone.
+pub fn prop_anchor_departure_final(multiverse: &Multiverse) -> Result<(), PropertyViolation> {
+    let fail = |message: String| PropertyViolation::new("anchor_departure_final", message);
+    use std::collections::HashMap;
+
+    for timeline in multiverse.timelines.values() {
+        let mut departed: HashMap<CharacterId, EventId> = HashMap::new();
+
+        for event_id in &timeline.events {
+            if let Some(event) = multiverse.events.get(event_id) {
+                for participant in &event.participants {
+                    if let Some(departure) = departed.get(participant) {
+                        return Err(fail(format!(
+                            "Character {} participates in event {} after departing to their era of origin in event {}",
+                            participant, event.id.0, departure.0
+                        )));
+                    }
+                }
+
+                for effect in &event.effects {
+                    if let EventEffect::TemporalDeparture { character } = effect {
+                        departed.insert(*character, event.id);
+                    }
+                }
+            }
+        }
+    }
     Ok(())
 }
 
-/// Invariant: Emotional PAD values must always be between -1.0 and 1.0.
-pub fn prop_emotional_state_validity(multiverse: &Multiverse) -> Result<(), String> {
+/// ## Property 8: Ability Justification
+///
+/// **Invariant**: Every ability a character holds must be explained by an
+/// `AbilityGained` event in a timeline they can perceive, or one they lived in
+/// at the time. A grant no longer counts once a later `AbilityLost` takes the
+/// same ability away; getting it back takes a fresh grant.
+///
+/// Starting abilities are no exception: `create_thirteen_protagonists` records
+/// each profile's starting set as `AbilityGained` events.
+///
+/// Abilities grant exceptions to other properties (TimelinePerception excuses
+/// cross-timeline memories), so an unexplained ability silently weakens every
+/// other check.
+pub fn prop_ability_justification(multiverse: &Multiverse) -> Result<(), PropertyViolation> {
+    let fail = |message: String| PropertyViolation::new("ability_justification", message);
+
     for character in multiverse.characters.values() {
-        let pad = character.emotional_state.get_pad();
-        for (i, val) in pad.iter().enumerate() {
-            if *val < -1.0 || *val > 1.0 {
-                return Err(format!(
-                    "Character {} has invalid PAD value at index {}: {}",
-                    character.name, i, val
-                ));
+        for (kind, ability) in &character.abilities {
+            let lost_after = |grant: EventId| {
+                multiverse.events.values().any(|later| {
+                    later.id > grant
+                        && later.effects.contains(&EventEffect::AbilityLost { character: character.id, ability: *kind })
+                })
+            };
+
+            let granted = multiverse.events.values().any(|event| {
+                (multiverse.can_perceive_timeline(character.id, event.timeline)
+                    || character.lived_in_timeline_at(event.timeline, event.id))
+                    && event.effects.iter().any(|effect| {
+                        matches!(
+                            effect,
+                            EventEffect::AbilityGained { character: c, ability: a, .. }
+                                if *c == character.id && a.kind() == *kind
+                        )
+                    })
+                    && !lost_after(event.id)
+            });
+
+            if !granted {
+                return Err(fail(format!(
+                    "Character {} ({}) has ability {:?} but no event granted it",
+                    character.id, character.name, ability
+                )));
             }
         }
     }
     Ok(())
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use std::collections::HashSet;
+/// ## Property 9: Branch History Frozen
+///
+/// **Invariant**: A branch inherits its parent's history up to and including
+/// its divergence event, and nothing after. The parent may keep recording
+/// events once a child has branched, but those never become part of the
+/// child's past.
+///
+/// Concretely:
+/// - Every branch's divergence event is recorded in its parent, so the
+///   inherited prefix is well-defined
+/// - Every witnessed memory sourced in a timeline refers to an event visible
+///   there (see [`Multiverse::event_visible_in`]; the check itself looks it
+///   up in an [`AncestorIndex`](crate::ancestry::AncestorIndex) built for the pass)
+///
+/// Without this, appending to a parent after branching would leave the child's
+/// "inherited history" ambiguous: did the child live through that event or not?
+pub fn prop_branch_history_frozen(multiverse: &Multiverse) -> Result<(), PropertyViolation> {
+    let fail = |message: String| PropertyViolation::new("branch_history_frozen", message);
+    for timeline in multiverse.timelines.values() {
+        if let Some(parent_id) = timeline.parent {
+            let parent = multiverse.timelines.get(&parent_id).ok_or_else(|| {
+                fail(format!("{} branched from missing parent {}", timeline.id, parent_id))
+            })?;
+            let divergence = timeline
+                .divergence_event
+                .ok_or_else(|| fail(format!("{} has a parent but no divergence event", timeline.id)))?;
 
-    #[test]
-    fn test_memory_consistency_witnessed() {
-        let mut multiverse = Multiverse::new();
-        let timeline = multiverse.root_timeline;
-        let char1 = multiverse.create_character("Alice".to_string(), timeline);
+            if !parent.events.contains(&divergence) {
+                return Err(fail(format!(
+                    "{} diverged at event {}, which is not recorded in parent {}",
+                    timeline.id, divergence.0, parent_id
+                )));
+            }
+        }
+    }
 
-        // Create event with Alice as participant
-        let event_id = multiverse.record_event(Event {
-            id: EventId(0),
-            timeline,
-            description: "Alice sees something".to_string(),
-            participants: HashSet::from([char1]),
-            effects: vec![],
-            causality_violation: None,
-        });
+    let ancestry = multiverse.ancestor_cache();
+    for memory in multiverse.memories.values() {
+        if let MemoryProvenance::Witnessed { character } = &memory.provenance {
+            if !ancestry.event_visible_in(memory.event, memory.source_timeline) {
+                return Err(fail(format!(
+                    "{} witnessed event {} in {}, but that event is not part of its history",
+                    character, memory.event.0, memory.source_timeline
+                )));
+            }
+        }
+    }
+    Ok(())
+}
 
-        // Create witnessed memory
-        let memory_id = multiverse.create_witnessed_memory(event_id, timeline, char1);
+/// ## Property 10: Memory Wipe
+///
+/// **Invariant**: After a `MemoryWipe`, a character holds no memories from
+/// before it. Anything they remember afterwards must be of a later event, or
+/// handed to them by a later `MemoryTransfer`.
+///
+/// Characters with `MemoryImmunity` shrug off wipes and are exempt.
+pub fn prop_memory_wipe_final(multiverse: &Multiverse) -> Result<(), PropertyViolation> {
+    let fail = |message: String| PropertyViolation::new("memory_wipe_final", message);
 
-        // Add memory to character
-        if let Some(character) = multiverse.characters.get_mut(&char1) {
-            character.memories.insert(memory_id);
+    // EventIds are assigned in recording order, so the latest wipe has the largest id
+    let mut last_wipe: std::collections::HashMap<CharacterId, EventId> = Default::default();
+    for event in multiverse.events.values() {
+        for effect in &event.effects {
+            if let EventEffect::MemoryWipe { character } = effect {
+                let wipe = last_wipe.entry(*character).or_insert(event.id);
+                if event.id.0 > wipe.0 {
+                    *wipe = event.id;
+                }
+            }
         }
-
-        // Should pass memory consistency check
-        assert!(prop_memory_consistency(&multiverse).is_ok());
     }
 
-    #[test]
-    fn test_memory_consistency_violation() {
-        let mut multiverse = Multiverse::new();
-        let timeline = multiverse.root_timeline;
-        let char1 = multiverse.create_character("Alice".to_string(), timeline);
-        let char2 = multiverse.create_character("Bob".to_string(), timeline);
+    for (character_id, wipe) in &last_wipe {
+        let character = match multiverse.characters.get(character_id) {
+            Some(c) if !c.has_ability(AbilityKind::MemoryImmunity) => c,
+            _ => continue,
+        };
 
-        // Event where only Alice is present
-        let event_id = multiverse.record_event(Event {
-            id: EventId(0),
-            timeline,
-            description: "Alice-only event".to_string(),
-            participants: HashSet::from([char1]),
-            effects: vec![],
-            causality_violation: None,
-        });
+        for memory_id in &character.memories {
+            let of_later_event = multiverse
+                .memories
+                .get(memory_id)
+                .map(|m| m.event.0 > wipe.0)
+                .unwrap_or(false);
+            let transferred_later = multiverse.events.values().any(|event| {
+                event.id.0 > wipe.0
+                    && event.effects.iter().any(|effect| {
+                        matches!(
+                            effect,
+                            EventEffect::MemoryTransfer { memory, to, .. }
+                                if memory == memory_id && to == character_id
+                        )
+                    })
+            });
 
-        // Create witnessed memory but claim Bob witnessed it (violation!)
-        let memory_id = multiverse.create_witnessed_memory(event_id, timeline, char2);
+            if !of_later_event && !transferred_later {
+                return Err(fail(format!(
+                    "{} ({}) was wiped in event {} but still holds memory {}",
+                    character.name, character_id, wipe.0, memory_id
+                )));
+            }
+        }
+    }
+    Ok(())
+}
 
-        // Add memory to Bob
-        if let Some(character) = multiverse.characters.get_mut(&char2) {
-            character.memories.insert(memory_id);
+/// ## Property 11: Exclusive Trades
+///
+/// **Invariant**: After an exclusive `MemoryTransfer` (a sale), the seller no
+/// longer holds the memory—unless a later transfer hands it back to them.
+///
+/// Without this, Khelis could sell the same memory to every customer in the
+/// Dark Spoke and still have it to sell again.
+pub fn prop_exclusive_trade_final(multiverse: &Multiverse) -> Result<(), PropertyViolation> {
+    let fail = |message: String| PropertyViolation::new("exclusive_trade_final", message);
+
+    // EventIds are assigned in recording order, so the latest transfer has the largest id
+    let mut last_received: std::collections::HashMap<(CharacterId, MemoryId), EventId> = Default::default();
+    let mut sales = Vec::new();
+    for event in multiverse.events.values() {
+        for effect in &event.effects {
+            if let EventEffect::MemoryTransfer {
+                memory,
+                from,
+                to,
+                exclusive,
+            } = effect
+            {
+                let received = last_received.entry((*to, *memory)).or_insert(event.id);
+                if event.id.0 > received.0 {
+                    *received = event.id;
+                }
+                if let (true, Some(seller)) = (*exclusive, from) {
+                    if seller != to {
+                        sales.push((event.id, *seller, *memory));
+                    }
+                }
+            }
         }
+    }
 
-        // Should FAIL memory consistency check
-        assert!(prop_memory_consistency(&multiverse).is_err());
+    for (sale, seller, memory) in sales {
+        let character = match multiverse.characters.get(&seller) {
+            Some(c) => c,
+            None => continue,
+        };
+        let bought_back = last_received
+            .get(&(seller, memory))
+            .map(|received| received.0 > sale.0)
+            .unwrap_or(false);
+
+        if character.memories.contains(&memory) && !bought_back {
+            return Err(fail(format!(
+                "{} ({}) sold memory {} in event {} but still holds it",
+                character.name, seller, memory, sale.0
+            )));
+        }
     }
+    Ok(())
+}
 
-    #[test]
-    fn test_death_finality() {
-        let mut multiverse = Multiverse::new();
-        let timeline = multiverse.root_timeline;
-        let char1 = multiverse.create_character("Victim".to_string(), timeline);
+/// ## Property 12: Usable Recall
+///
+/// **Invariant**: A `MemoryRecall` only happens while the memory's fidelity is
+/// above `min_usable_fidelity` in the multiverse's `ValidationConfig`.
+///
+/// A memory degraded almost to nothing is still held, but nobody should be
+/// acting on it. Fidelity only falls, and only through `MemoryDegrade` on
+/// someone holding it, so undoing every later degrade of anyone who ever held
+/// the memory bounds its fidelity at the time of the recall from above. Only a
+/// recall that fails even that bound is reported.
+pub fn prop_memory_recall_usable(multiverse: &Multiverse) -> Result<(), PropertyViolation> {
+    let fail = |message: String| PropertyViolation::new("memory_recall_usable", message);
+    let threshold = multiverse.config.validation.min_usable_fidelity;
 
-        // Event that kills character
-        multiverse.record_event(Event {
-            id: EventId(0),
-            timeline,
-            description: "Character dies".to_string(),
-            participants: HashSet::from([char1]),
-            effects: vec![EventEffect::CharacterDeath { character: char1 }],
-            causality_violation: None,
-        });
+    let mut events: Vec<&Event> = multiverse.events.values().collect();
+    events.sort_by_key(|e| e.id);
+    for (position, event) in events.iter().enumerate() {
+        for effect in &event.effects {
+            let (character, memory_id) = match effect {
+                EventEffect::MemoryRecall { character, memory } => (*character, *memory),
+                _ => continue,
+            };
+            let memory = multiverse
+                .memories
+                .get(&memory_id)
+                .ok_or_else(|| fail(format!("{} recalls unknown memory {}", character, memory_id)))?;
 
-        // Try to have dead character participate in another event (violation!)
-        multiverse.record_event(Event {
-            id: EventId(1),
+            // Everyone who could ever have held it: the witness, the current
+            // holders, and both sides of every transfer
+            let mut holders: std::collections::HashSet<CharacterId> = multiverse
+                .characters
+                .values()
+                .filter(|c| c.memories.contains(&memory_id))
+                .map(|c| c.id)
+                .collect();
+            holders.insert(character);
+            if let MemoryProvenance::Witnessed { character: witness } = memory.provenance {
+                holders.insert(witness);
+            }
+            for other in &events {
+                for effect in &other.effects {
+                    if let EventEffect::MemoryTransfer { memory, from, to, .. } = effect {
+                        if *memory == memory_id {
+                            holders.insert(*to);
+                            holders.extend(*from);
+                        }
+                    }
+                }
+            }
+
+            let mut fidelity_then = memory.fidelity;
+            for later in &events[position + 1..] {
+                for effect in &later.effects {
+                    if let EventEffect::MemoryDegrade { character, amount } = effect {
+                        let kept = 1.0 - amount.clamp(0.0, 1.0);
+                        if holders.contains(character) {
+                            // A total wipe-out leaves nothing to undo it from
+                            fidelity_then = if kept > 0.0 { fidelity_then / kept } else { f32::INFINITY };
+                        }
+                    }
+                }
+            }
+
+            if fidelity_then <= threshold {
+                return Err(fail(format!(
+                    "{} recalls memory {} in event {} at fidelity {:.2}, at or below the usable threshold {:.2}",
+                    character, memory_id, event.id.0, fidelity_then, threshold
+                )));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// ## Property 13: Shared Mind
+///
+/// **Invariant**: Every present member of a collective holds every memory any
+/// other present member holds—unless, since the collective's last sync, it
+/// lost memories of its own: wiped, faded, sold, or dead for a while.
+///
+/// Those losses belong to one body, and `Multiverse::link_collective` heals
+/// them. Anything else means a memory reached one body and not the rest.
+pub fn prop_collective_memory_shared(multiverse: &Multiverse) -> Result<(), PropertyViolation> {
+    let fail = |message: String| PropertyViolation::new("collective_memory_shared", message);
+    for collective in &multiverse.collectives {
+        let present: Vec<&Character> = collective
+            .members
+            .iter()
+            .filter_map(|id| multiverse.characters.get(id))
+            .filter(|c| c.is_present())
+            .collect();
+
+        let mut lost = std::collections::HashSet::new();
+        for event in multiverse.events.values().filter(|e| e.id > collective.last_sync) {
+            for effect in &event.effects {
+                match effect {
+                    EventEffect::MemoryWipe { character }
+                    | EventEffect::MemoryDegrade { character, .. }
+                    | EventEffect::CharacterDeath { character }
+                    | EventEffect::MemoryTransfer {
+                        from: Some(character),
+                        exclusive: true,
+                        ..
+                    } => {
+                        lost.insert(*character);
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        for holder in &present {
+            for member in present.iter().filter(|m| !lost.contains(&m.id)) {
+                if let Some(missing) = holder.memories.difference(&member.memories).min() {
+                    return Err(fail(format!(
+                        "{} holds memory {} but fellow member {} of the same collective doesn't",
+                        holder.id, missing, member.id
+                    )));
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// ## Property 14: Present at the Scene
+///
+/// **Invariant**: Everyone taking part in an event belongs to the event's
+/// timeline: they lived there when it happened (see `Character::timeline_at`),
+/// or they can perceive it from where they are, with `TimelinePerception`.
+///
+/// An event that moves someone between timelines may be recorded on either
+/// side of the crossing, so it finds them in both. Without this, a scene in one
+/// branch could be acted out by someone who never left another.
+pub fn prop_participants_in_timeline(multiverse: &Multiverse) -> Result<(), PropertyViolation> {
+    let check = for_all_events(|multiverse, event| {
+        let mut participants: Vec<CharacterId> = event.participants.iter().copied().collect();
+        participants.sort();
+        participants.into_iter().try_for_each(|participant| {
+            let crossing = event.effects.iter().any(
+                |effect| matches!(effect, EventEffect::TimelineMove { character, .. } if *character == participant),
+            );
+            let lived_there = multiverse.characters.get(&participant).is_some_and(|c| {
+                c.timeline_at(event.id) == event.timeline
+                    || (crossing && c.timeline_before(event.id) == event.timeline)
+            });
+            let present = lived_there || multiverse.can_perceive_timeline(participant, event.timeline);
+            ensure(
+                present,
+                "participants_in_timeline",
+                format!(
+                    "{} takes part in {} in {} without being there",
+                    participant, event.id, event.timeline
+                ),
+            )
+            .map_err(|v| v.about(ViolationContext::Character(participant)))
+        })
+    });
+    check(multiverse)
+}
+
+/// ## Property 15: Event Ids Agree
+///
+/// **Invariant**: Every event is stored under its own id, and every id a
+/// timeline lists is a recorded event.
+///
+/// `record_event` assigns ids itself, overwriting the `EventId(0)` callers
+/// pass; an event inserted into the map by hand skips that, and can end up
+/// under a key that disagrees with the id every other check reads.
+pub fn prop_event_ids_consistent(multiverse: &Multiverse) -> Result<(), PropertyViolation> {
+    let fail = |message: String| PropertyViolation::new("event_ids_consistent", message);
+    let mut keys: Vec<&EventId> = multiverse.events.keys().collect();
+    keys.sort();
+    for key in keys {
+        let event = &multiverse.events[key];
+        if event.id != *key {
+            return Err(fail(format!("{} is stored under {}", event.id, key))
+                .about(ViolationContext::Event(*key)));
+        }
+    }
+
+    let mut timelines: Vec<&Timeline> = multiverse.timelines.values().collect();
+    timelines.sort_by_key(|t| t.id);
+    for timeline in timelines {
+        if let Some(missing) = timeline.events.iter().find(|e| !multiverse.events.contains_key(e)) {
+            return Err(fail(format!("{} lists {}, which was never recorded", timeline.id, missing))
+                .about(ViolationContext::Timeline(timeline.id)));
+        }
+    }
+    Ok(())
+}
+
+/// ## Property 16: Memories Are Of Something
+///
+/// **Invariant**: Every memory comes from a timeline that exists, and is of an
+/// event that was recorded—unless it is explicitly
+/// [`MemoryContent::Abstract`], hearsay with no scene behind it.
+///
+/// A memory pointing at an event id that was never recorded reads as a memory
+/// of whichever event later takes that id, or of nothing at all.
+pub fn prop_memory_references_resolve(multiverse: &Multiverse) -> Result<(), PropertyViolation> {
+    let fail = |message: String| PropertyViolation::new("memory_references_resolve", message);
+    let mut memories: Vec<&Memory> = multiverse.memories.values().collect();
+    memories.sort_by_key(|m| m.id);
+    for memory in memories {
+        if !multiverse.timelines.contains_key(&memory.source_timeline) {
+            return Err(fail(format!(
+                "{} comes from {}, which doesn't exist",
+                memory.id, memory.source_timeline
+            ))
+            .with_memory(memory.id));
+        }
+        if memory.content == MemoryContent::Event && !multiverse.events.contains_key(&memory.event) {
+            return Err(fail(format!(
+                "{} is a memory of {}, which was never recorded",
+                memory.id, memory.event
+            ))
+            .with_memory(memory.id));
+        }
+    }
+    Ok(())
+}
+
+/// ## Property 17: Social Emotions Need Witnesses
+///
+/// **Invariant**: Every [`EventEffect::SocialAppraisal`] reacts to an
+/// appraisal by its subject in the same event, and its observer either took
+/// part in the event or perceives its timeline from afar.
+///
+/// Being happy for someone, or gloating over them, means knowing what happened
+/// to them. Someone elsewhere in the timeline, who wasn't in the scene, doesn't.
+pub fn prop_social_emotions_witnessed(multiverse: &Multiverse) -> Result<(), PropertyViolation> {
+    let check = for_all_events(|multiverse, event| {
+        event.effects.iter().try_for_each(|effect| {
+            let EventEffect::SocialAppraisal { observer, subject, .. } = effect else {
+                return Ok(());
+            };
+            let triggered = event.effects.iter().any(
+                |e| matches!(e, EventEffect::AppraisalTrigger { character, .. } if character == subject),
+            );
+            let perceives = multiverse.characters.get(observer).is_some_and(|c| {
+                c.has_ability(AbilityKind::TimelinePerception)
+                    && multiverse.can_perceive_timeline(*observer, event.timeline)
+            });
+            ensure(
+                triggered,
+                "social_emotions_witnessed",
+                format!(
+                    "{} reacts to {}'s fortune in {}, but {} appraises nothing",
+                    observer, subject, event.id, subject
+                ),
+            )
+            .and_then(|()| {
+                ensure(
+                    event.participants.contains(observer) || perceives,
+                    "social_emotions_witnessed",
+                    format!("{} reacts to {}'s fortune in {} without seeing it", observer, subject, event.id),
+                )
+            })
+            .map_err(|v| v.about(ViolationContext::Character(*observer)))
+        })
+    });
+    check(multiverse)
+}
+
+/// ## Property 18: Contradictions Are Foresight
+///
+/// **Invariant**: A character who knows both flags of a pair declared with
+/// [`Multiverse::declare_mutually_exclusive`] has Precognition, and has held
+/// both for no more than its `horizon_events` events of their timeline.
+///
+/// Mara Vex has seen Corvus live and seen Corvus die, and holds both futures
+/// at once; that's what being precognitive is. Anyone else who "knows" both
+/// has been told something that can't be so. A seer's futures only reach so
+/// far ahead, though: once more events than that have passed since they came
+/// to hold both, one of them should have come to pass. Flags no event gave
+/// them can't be dated, so only their Precognition is asked for.
+pub fn prop_precog_contradictions_allowed(multiverse: &Multiverse) -> Result<(), PropertyViolation> {
+    let cache = multiverse.ancestor_cache();
+    let check = for_all_characters(|multiverse, character| {
+        let horizon = match character.ability(AbilityKind::Precognition) {
+            Some(Ability::Precognition { horizon_events }) => *horizon_events,
+            _ => None,
+        };
+        // When the character first learned `flag`, if an event says
+        let learned = |flag: &String| {
+            let gained = |e: &&Event| {
+                e.effects.iter().any(|effect| {
+                    matches!(effect, EventEffect::KnowledgeGained { character: c, flag: f }
+                        if *c == character.id && f == flag)
+                })
+            };
+            multiverse.events.values().filter(gained).map(|e| e.id).min()
+        };
+        multiverse.mutually_exclusive.iter().try_for_each(|(a, b)| {
+            if !(character.knowledge_flags.contains(a) && character.knowledge_flags.contains(b)) {
+                return Ok(());
+            }
+            ensure(
+                character.has_ability(AbilityKind::Precognition),
+                "precog_contradictions_allowed",
+                format!(
+                    "{} knows both '{}' and '{}', which can't both be true, without Precognition",
+                    character.name, a, b
+                ),
+            )?;
+            let (Some(horizon), Some(first), Some(second)) = (horizon, learned(a), learned(b)) else {
+                return Ok(());
+            };
+            let held_since = first.max(second);
+            let passed = multiverse
+                .events
+                .keys()
+                .filter(|e| **e > held_since && cache.event_visible_in(**e, character.current_timeline))
+                .count();
+            ensure(
+                passed <= horizon as usize,
+                "precog_contradictions_allowed",
+                format!(
+                    "{} has held both '{}' and '{}' for {} events since {}, past their Precognition horizon of {}",
+                    character.name, a, b, passed, held_since, horizon
+                ),
+            )
+        })
+        .map_err(|v| v.about(ViolationContext::Character(character.id)))
+    });
+    check(multiverse)
+}
+
+/// ## Property 19: Exiles Answer to Their Anchor
+///
+/// **Invariant**: A character with an anchor timeline who lives in a newer
+/// timeline (one descended from the anchor) got there by a recorded
+/// `TimelineMove`, unless it's where they're from.
+///
+/// Kor-Valeth is always being pulled back to the past (see
+/// [`Multiverse::advance_temporal_pull`]). Drifting further from it, into a
+/// branch that didn't exist when they arrived, takes a crossing the story can
+/// point to; a character simply found there has slipped their anchor unseen.
+/// Newer follows the parent links, not the ids (see
+/// [`Multiverse::timelines_parents_first`]).
+pub fn prop_anchor_timeline_respected(multiverse: &Multiverse) -> Result<(), PropertyViolation> {
+    use std::collections::HashSet;
+
+    let order = multiverse.timelines_parents_first();
+    // Everything branched off `anchor`, however far down: parents come first,
+    // so each child's parent is settled by the time it's reached
+    let newer_than = |anchor: TimelineId| {
+        let mut line = HashSet::from([anchor]);
+        for timeline in &order {
+            if timeline.parent.is_some_and(|p| line.contains(&p)) {
+                line.insert(timeline.id);
+            }
+        }
+        line.remove(&anchor);
+        line
+    };
+
+    let check = for_all_characters(|multiverse, character| {
+        let Some(anchor) = character.anchor_timeline else {
+            return Ok(());
+        };
+        let current = character.current_timeline;
+        let newer = newer_than(anchor).contains(&current);
+        if !newer || current == character.native_timeline {
+            return Ok(());
+        }
+        let moved_here = |effect: &EventEffect| {
+            matches!(effect, EventEffect::TimelineMove { character: c, to } if *c == character.id && *to == current)
+        };
+        let crossed = character.timeline_history.last().is_some_and(|(moved_at, to)| {
+            *to == current && multiverse.events.get(moved_at).is_some_and(|event| event.effects.iter().any(moved_here))
+        });
+        ensure(
+            crossed,
+            "anchor_timeline_respected",
+            format!(
+                "{} is anchored to {} but lives in the newer {} with no crossing to show for it",
+                character.name, anchor, current
+            ),
+        )
+        .map_err(|v| v.about(ViolationContext::Character(character.id)))
+    });
+    check(multiverse)
+}
+
+/// A rule one character's gimmick implies, held to with
+/// [`Multiverse::register_character_invariant`] and checked by
+/// [`prop_character_invariants`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CharacterInvariant {
+    /// Still holds every memory an event ever gave them, as the Cartographer,
+    /// immune to memory manipulation, does
+    NeverLosesMemories,
+    /// Never witnesses anything: no memory anywhere is theirs first-hand, as
+    /// Khelis, who can't form memories of their own, has none
+    NeverWitnesses,
+    /// Lives in every timeline there is, as the Conductor does
+    Omnipresent,
+}
+
+impl CharacterInvariant {
+    /// Whether `character` keeps this rule.
+    pub fn check(self, multiverse: &Multiverse, character: &Character) -> Result<(), PropertyViolation> {
+        let name = &character.name;
+        match self {
+            CharacterInvariant::NeverLosesMemories => {
+                let mut events: Vec<&Event> = multiverse.events.values().collect();
+                events.sort_by_key(|e| e.id);
+                let lost = |memory: &MemoryId| {
+                    multiverse.memories.contains_key(memory) && !character.memories.contains(memory)
+                };
+                let first_lost = events.iter().find_map(|event| {
+                    event.effects.iter().find_map(|effect| match effect {
+                        EventEffect::MemoryTransfer { memory, to, .. } if *to == character.id && lost(memory) => {
+                            Some((event.id, *memory))
+                        }
+                        _ => None,
+                    })
+                });
+                first_lost.map_or(Ok(()), |(event, memory)| {
+                    Err(PropertyViolation::new(
+                        "character_invariants",
+                        format!(
+                            "{} no longer holds {}, given to them in {}, yet they never forget",
+                            name, memory, event
+                        ),
+                    )
+                    .with_memory(memory))
+                })
+            }
+            CharacterInvariant::NeverWitnesses => {
+                let witnessed = multiverse
+                    .memories
+                    .values()
+                    .filter(|m| m.provenance == MemoryProvenance::Witnessed { character: character.id })
+                    .map(|m| m.id)
+                    .min();
+                witnessed.map_or(Ok(()), |memory| {
+                    Err(PropertyViolation::new(
+                        "character_invariants",
+                        format!("{} witnessed {}, yet they can't form memories of their own", name, memory),
+                    )
+                    .with_memory(memory))
+                })
+            }
+            CharacterInvariant::Omnipresent => {
+                let mut missing: Vec<TimelineId> = multiverse
+                    .timelines
+                    .values()
+                    .filter(|t| !t.characters.contains(&character.id))
+                    .map(|t| t.id)
+                    .collect();
+                missing.sort();
+                ensure(
+                    missing.is_empty(),
+                    "character_invariants",
+                    format!("{} is missing from {:?}, yet they live in every timeline", name, missing),
+                )
+            }
+        }
+    }
+}
+
+/// ## Property 20: Character Invariants
+///
+/// **Invariant**: Everyone keeps the rules registered for them alone with
+/// [`Multiverse::register_character_invariant`].
+///
+/// The protagonists' gimmicks are more than prose: the Cartographer can't be
+/// made to forget, Khelis can't witness, the Conductor is everywhere at once.
+/// Their profiles list what that means as [`CharacterInvariant`]s, and a cast
+/// built from them is held to those here.
+/// [`apply_narrative_action`](crate::generators::apply_narrative_action) skips
+/// whatever would break one, with `SkipReason::BreaksInvariant`.
+pub fn prop_character_invariants(multiverse: &Multiverse) -> Result<(), PropertyViolation> {
+    multiverse.character_invariants.iter().try_for_each(|&(id, invariant)| {
+        multiverse.characters.get(&id).map_or(Ok(()), |character| {
+            invariant.check(multiverse, character).map_err(|v| v.about(ViolationContext::Character(id)))
+        })
+    })
+}
+
+/// ## Roster Check
+///
+/// **Invariant**: The multiverse has the cast its profiles describe. For each
+/// profile, whoever plays its role in `cast` exists, goes by the profile's name
+/// (or has been renamed from it), is alive, and comes from the root timeline,
+/// living there still unless a recorded crossing took them elsewhere. They hold
+/// every starting ability, and nothing else that no event gave them; every
+/// starting goal, unless an event resolved it, and nothing else that no event
+/// added.
+///
+/// Not a built-in: it needs the profiles and cast to compare against. Run it
+/// right after [`CastBuilder::build`](crate::protagonists::CastBuilder::build),
+/// so a change that quietly drops Vera's TimelinePerception fails here rather
+/// than somewhere subtle much later.
+pub fn prop_roster_matches_profiles(
+    multiverse: &Multiverse,
+    profiles: &[crate::protagonists::ProtagonistProfile],
+    cast: &crate::protagonists::Cast,
+) -> Result<(), PropertyViolation> {
+    let fail = |message: String| PropertyViolation::new("roster_matches_profiles", message);
+    let mut events: Vec<&Event> = multiverse.events.values().collect();
+    events.sort_by_key(|e| e.id);
+    let effects = || events.iter().flat_map(|e| &e.effects);
+    let root = multiverse.root_timeline;
+
+    for profile in profiles {
+        let role = profile.role();
+        let id = cast.get(role).ok_or_else(|| fail(format!("the cast has no {} to be {}", role, profile.name)))?;
+        let character = multiverse
+            .characters
+            .get(&id)
+            .ok_or_else(|| fail(format!("{} ({}) is in the cast but not the multiverse", profile.name, id)))?;
+        let fail = |message: String| fail(message).about(ViolationContext::Character(id));
+        let name = &profile.name;
+
+        if character.name != *name && !character.former_names.contains(name) {
+            return Err(fail(format!("the {} should be {}, not {}", role, name, character.name)));
+        }
+        if !character.alive {
+            return Err(fail(format!("{} is dead", name)));
+        }
+        let crossed = character.timeline_history.last().map(|(_, t)| *t) == Some(character.current_timeline);
+        if character.native_timeline != root || (character.current_timeline != root && !crossed) {
+            return Err(fail(format!(
+                "{} is from {} and lives in {}, not the root {}",
+                name, character.native_timeline, character.current_timeline, root
+            )));
+        }
+
+        // The starting grant only counts for what the profile lists
+        let granted = |ability: &Ability| {
+            effects().any(|effect| {
+                matches!(effect, EventEffect::AbilityGained { character: c, ability: a, mechanism }
+                    if *c == id && a == ability && mechanism != crate::protagonists::STARTING_ABILITIES)
+            })
+        };
+        if let Some(lost) = profile.starting_abilities.iter().find(|a| !character.has_ability(a.kind())) {
+            return Err(fail(format!("{} has lost their starting {:?}", name, lost.kind())));
+        }
+        let mut held: Vec<&Ability> = character.abilities.values().collect();
+        held.sort_by_key(|a| a.kind());
+        if let Some(unexplained) = held.into_iter().find(|a| !profile.starting_abilities.contains(a) && !granted(a)) {
+            let message =
+                format!("{} has {:?}, which neither their profile nor any event gave them", name, unexplained);
+            return Err(fail(message));
+        }
+
+        let resolved = |goal: &str| {
+            effects().any(|effect| {
+                matches!(effect, EventEffect::GoalResolved { character: c, goal: g, .. } if *c == id && g == goal)
+            })
+        };
+        let added = |goal: &str| {
+            effects().any(|effect| {
+                matches!(effect, EventEffect::AddGoal { character: c, goal: g } if *c == id && g.name == goal)
+            })
+        };
+        let goals = &character.emotional_state.goals;
+        if let Some(lost) = profile.starting_goals.iter().find(|g| !goals.contains_key(&g.name) && !resolved(&g.name)) {
+            return Err(fail(format!("{} has dropped their starting goal {:?} unresolved", name, lost.name)));
+        }
+        let mut held: Vec<&String> = goals.keys().collect();
+        held.sort();
+        let from_profile = |goal: &str| profile.starting_goals.iter().any(|g| g.name == goal);
+        if let Some(unexplained) = held.into_iter().find(|g| !from_profile(g) && !added(g)) {
+            let message =
+                format!("{} wants {:?}, which neither their profile nor any event gave them", name, unexplained);
+            return Err(fail(message));
+        }
+    }
+    Ok(())
+}
+
+/// ## Warnings
+///
+/// Checks for states that are legal but suspicious. Each returns every finding,
+/// all at [`Severity::Warning`], rather than stopping at the first.
+pub type WarningCheck = fn(&Multiverse) -> Vec<PropertyViolation>;
+
+/// Every built-in warning check, by the name its findings carry.
+pub const WARNINGS: [(&str, WarningCheck); 4] = [
+    ("low_fidelity", warn_low_fidelity),
+    ("relationship_whiplash", warn_relationship_whiplash),
+    ("orphaned_memories", warn_orphaned_memories),
+    ("malformed_beliefs", warn_malformed_beliefs),
+];
+
+/// Memories someone still holds though they have faded below
+/// [`MEMORY_FIDELITY_FLOOR`]. Degrading drops those, so they got in some other way.
+pub fn warn_low_fidelity(multiverse: &Multiverse) -> Vec<PropertyViolation> {
+    let mut held: Vec<(CharacterId, MemoryId, f32)> = multiverse
+        .characters
+        .values()
+        .flat_map(|c| c.memories.iter().map(move |m| (c.id, *m)))
+        .filter_map(|(c, m)| multiverse.memories.get(&m).map(|memory| (c, m, memory.fidelity)))
+        .filter(|(_, _, fidelity)| *fidelity < MEMORY_FIDELITY_FLOOR)
+        .collect();
+    held.sort_by_key(|(c, m, _)| (*c, *m));
+    held.into_iter()
+        .map(|(c, m, fidelity)| {
+            PropertyViolation::warning(
+                "low_fidelity",
+                format!("{} holds {} at fidelity {:.2}, too faint to trust", c, m, fidelity),
+            )
+        })
+        .collect()
+}
+
+/// Relationships that swing all the way between Allied and Hostile in a single
+/// change, with nothing in between.
+pub fn warn_relationship_whiplash(multiverse: &Multiverse) -> Vec<PropertyViolation> {
+    let mut findings = Vec::new();
+    let mut timelines: Vec<&Timeline> = multiverse.timelines.values().collect();
+    timelines.sort_by_key(|t| t.id);
+    for timeline in timelines {
+        let mut last: std::collections::HashMap<(CharacterId, CharacterId), RelationshipState> =
+            Default::default();
+        for event in timeline.events.iter().filter_map(|id| multiverse.events.get(id)) {
+            for effect in &event.effects {
+                if let EventEffect::RelationshipChange {
+                    character1,
+                    character2,
+                    new_state,
+                } = effect
+                {
+                    let key = relationship_key(*character1, *character2);
+                    if let Some(old) = last.insert(key, *new_state) {
+                        if (old as i8 - *new_state as i8).abs() == 4 {
+                            findings.push(PropertyViolation::warning(
+                                "relationship_whiplash",
+                                format!(
+                                    "{} and {} go from {:?} to {:?} in event {}",
+                                    key.0, key.1, old, new_state, event.id
+                                ),
+                            ));
+                        }
+                    }
+                }
+            }
+        }
+    }
+    findings
+}
+
+/// Memories nobody holds. Harmless, but usually a sale or install that never
+/// reached its buyer.
+pub fn warn_orphaned_memories(multiverse: &Multiverse) -> Vec<PropertyViolation> {
+    let held: std::collections::HashSet<MemoryId> =
+        multiverse.characters.values().flat_map(|c| c.memories.iter().copied()).collect();
+    let mut orphans: Vec<MemoryId> = multiverse.memories.keys().copied().filter(|m| !held.contains(m)).collect();
+    orphans.sort();
+    orphans
+        .into_iter()
+        .map(|m| PropertyViolation::warning("orphaned_memories", format!("{} is held by nobody", m)))
+        .collect()
+}
+
+/// Beliefs recorded in appraisals that fail
+/// [`Belief::validate`](crate::emotional_system::Belief::validate). Appraising
+/// one changes nothing, so whoever it was meant for never felt it.
+pub fn warn_malformed_beliefs(multiverse: &Multiverse) -> Vec<PropertyViolation> {
+    let mut events: Vec<&Event> = multiverse.events.values().collect();
+    events.sort_by_key(|e| e.id);
+    events
+        .into_iter()
+        .flat_map(|event| event.effects.iter().map(move |effect| (event.id, effect)))
+        .filter_map(|(event, effect)| match effect {
+            EventEffect::AppraisalTrigger { character, belief } => {
+                belief.validate().err().map(|error| {
+                    PropertyViolation::warning(
+                        "malformed_beliefs",
+                        format!("{} ignores a belief in event {}: {}", character, event, error),
+                    )
+                })
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// Every finding of every built-in warning check.
+pub fn collect_warnings(multiverse: &Multiverse) -> Vec<PropertyViolation> {
+    WARNINGS.iter().flat_map(|(_, check)| check(multiverse)).collect()
+}
+
+/// ## Combined Property Validator
+///
+/// Runs all property checks on a multiverse state.
+/// Returns Ok(()) if all properties hold, or Err with details of the first violation.
+pub fn validate_all_properties(multiverse: &Multiverse) -> Result<(), PropertyViolation> {
+    PropertyRegistry::builtin().validate(multiverse)
+}
+
+/// Runs every property, even past a violation, and reports how each did and
+/// how long it took. Record the reports of a long run in a [`PropertyTimings`]
+/// to see which property the run spent its time in.
+pub fn validate_all_properties_timed(multiverse: &Multiverse) -> ValidationReport {
+    PropertyRegistry::builtin().validate_timed(multiverse)
+}
+
+/// Like [`validate_all_properties_timed`], also collecting every warning.
+/// Warnings never make the report fail.
+pub fn validate_with_warnings(multiverse: &Multiverse) -> ValidationReport {
+    let mut report = validate_all_properties_timed(multiverse);
+    report.warnings.extend(collect_warnings(multiverse));
+    report
+}
+
+/// A built-in property check.
+pub type PropertyCheck = fn(&Multiverse) -> Result<(), PropertyViolation>;
+
+/// Every built-in property, by the name its violations carry, in the order they
+/// run. A property added here is validated, timed, and reported everywhere.
+pub const PROPERTIES: [(&str, PropertyCheck); 21] = [
+    ("memory_consistency", prop_memory_consistency),
+    ("timeline_perception", prop_timeline_perception),
+    ("causality_justification", prop_causality_justification),
+    ("relationship_consistency", prop_relationship_consistency),
+    ("death_finality", prop_death_finality),
+    ("knowledge_flags", prop_knowledge_flags),
+    ("emotional_state_validity", prop_emotional_state_validity),
+    ("anchor_departure_final", prop_anchor_departure_final),
+    ("ability_justification", prop_ability_justification),
+    ("branch_history_frozen", prop_branch_history_frozen),
+    ("memory_wipe_final", prop_memory_wipe_final),
+    ("exclusive_trade_final", prop_exclusive_trade_final),
+    ("memory_recall_usable", prop_memory_recall_usable),
+    ("collective_memory_shared", prop_collective_memory_shared),
+    ("participants_in_timeline", prop_participants_in_timeline),
+    ("event_ids_consistent", prop_event_ids_consistent),
+    ("memory_references_resolve", prop_memory_references_resolve),
+    ("social_emotions_witnessed", prop_social_emotions_witnessed),
+    ("precog_contradictions_allowed", prop_precog_contradictions_allowed),
+    ("anchor_timeline_respected", prop_anchor_timeline_respected),
+    ("character_invariants", prop_character_invariants),
+];
+
+/// ## Property Registry
+///
+/// Stories have rules of their own ("the MacGuffin is only ever in one pair of
+/// hands") that don't belong among the built-ins. A `PropertyRegistry` holds
+/// named checks, starting from the built-ins, that a game can add to or switch
+/// off without touching this module.
+pub struct PropertyRegistry {
+    properties: Vec<RegisteredProperty>,
+}
+
+/// A property check that can capture state, such as a limit to check against.
+pub type CustomPropertyCheck = Box<dyn Fn(&Multiverse) -> Result<(), PropertyViolation> + Send + Sync>;
+
+struct RegisteredProperty {
+    name: String,
+    check: CustomPropertyCheck,
+    enabled: bool,
+}
+
+impl PropertyRegistry {
+    /// A registry with every built-in property in [`PROPERTIES`].
+    pub fn new() -> Self {
+        let mut registry = Self::empty();
+        for (name, check) in PROPERTIES {
+            registry.register(name, check);
+        }
+        registry
+    }
+
+    /// A registry with no properties at all.
+    pub fn empty() -> Self {
+        PropertyRegistry { properties: Vec::new() }
+    }
+
+    /// The shared registry of built-ins behind [`validate_all_properties`].
+    pub fn builtin() -> &'static PropertyRegistry {
+        static BUILTIN: std::sync::OnceLock<PropertyRegistry> = std::sync::OnceLock::new();
+        BUILTIN.get_or_init(PropertyRegistry::new)
+    }
+
+    /// Adds a property, run after those already registered. Registering a name
+    /// again replaces that property's check (and re-enables it) in place.
+    pub fn register(
+        &mut self,
+        name: impl Into<String>,
+        check: impl Fn(&Multiverse) -> Result<(), PropertyViolation> + Send + Sync + 'static,
+    ) -> &mut Self {
+        let name = name.into();
+        let check: CustomPropertyCheck = Box::new(check);
+        match self.properties.iter_mut().find(|p| p.name == name) {
+            Some(existing) => {
+                existing.check = check;
+                existing.enabled = true;
+            }
+            None => self.properties.push(RegisteredProperty { name, check, enabled: true }),
+        }
+        self
+    }
+
+    /// Stops checking a property. Returns whether it was registered.
+    pub fn disable(&mut self, name: &str) -> bool {
+        self.set_enabled(name, false)
+    }
+
+    /// Resumes checking a disabled property. Returns whether it was registered.
+    pub fn enable(&mut self, name: &str) -> bool {
+        self.set_enabled(name, true)
+    }
+
+    fn set_enabled(&mut self, name: &str, enabled: bool) -> bool {
+        match self.properties.iter_mut().find(|p| p.name == name) {
+            Some(property) => {
+                property.enabled = enabled;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// The names of the properties that will be checked, in order.
+    pub fn names(&self) -> Vec<&str> {
+        self.enabled().map(|p| p.name.as_str()).collect()
+    }
+
+    fn enabled(&self) -> impl Iterator<Item = &RegisteredProperty> {
+        self.properties.iter().filter(|p| p.enabled)
+    }
+
+    /// Checks every enabled property, stopping at the first error. A property
+    /// that reports a warning passes.
+    pub fn validate(&self, multiverse: &Multiverse) -> Result<(), PropertyViolation> {
+        self.enabled().try_for_each(|p| errors_only((p.check)(multiverse)))
+    }
+
+    /// Checks every enabled property, even past a violation, timing each. A
+    /// property that reports a warning passes, and the warning is collected.
+    pub fn validate_timed(&self, multiverse: &Multiverse) -> ValidationReport {
+        let mut warnings = Vec::new();
+        let properties: Vec<PropertyResult> = self
+            .enabled()
+            .map(|p| {
+                let start = Instant::now();
+                let mut violation = (p.check)(multiverse).err();
+                let elapsed = start.elapsed();
+                if violation.as_ref().is_some_and(|v| !v.is_error()) {
+                    warnings.extend(violation.take());
+                }
+                PropertyResult {
+                    property: p.name.clone(),
+                    elapsed,
+                    passed: violation.is_none(),
+                    violation,
+                }
+            })
+            .collect();
+        ValidationReport {
+            total: properties.iter().map(|p| p.elapsed).sum(),
+            properties,
+            warnings,
+        }
+    }
+}
+
+/// Lets warnings through as a pass.
+fn errors_only(result: Result<(), PropertyViolation>) -> Result<(), PropertyViolation> {
+    match result {
+        Err(violation) if !violation.is_error() => Ok(()),
+        result => result,
+    }
+}
+
+impl Default for PropertyRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for PropertyRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PropertyRegistry").field("properties", &self.names()).finish()
+    }
+}
+
+/// How one property fared in a [`ValidationReport`].
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PropertyResult {
+    pub property: String,
+    pub elapsed: Duration,
+    pub passed: bool,
+    pub violation: Option<PropertyViolation>,
+}
+
+/// The outcome of [`validate_all_properties_timed`]: every property, in the
+/// order they ran.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ValidationReport {
+    pub properties: Vec<PropertyResult>,
+    pub total: Duration,
+    /// Findings that don't fail validation; see [`Severity::Warning`]
+    pub warnings: Vec<PropertyViolation>,
+}
+
+impl ValidationReport {
+    /// Whether every property held.
+    pub fn is_ok(&self) -> bool {
+        self.properties.iter().all(|p| p.passed)
+    }
+
+    /// The first violation, as [`validate_all_properties`] would report it.
+    pub fn first_violation(&self) -> Option<&PropertyViolation> {
+        self.errors().next()
+    }
+
+    /// Every error, in the order the properties ran.
+    pub fn errors(&self) -> impl Iterator<Item = &PropertyViolation> {
+        self.properties.iter().filter_map(|p| p.violation.as_ref())
+    }
+}
+
+impl fmt::Display for ValidationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{:<26}{:>12}  Result", "Property", "Time")?;
+        for result in &self.properties {
+            let elapsed = format!("{:.1?}", result.elapsed);
+            match &result.violation {
+                None => writeln!(f, "{:<26}{:>12}  ok", result.property, elapsed)?,
+                Some(violation) => {
+                    writeln!(f, "{:<26}{:>12}  FAILED: {}", result.property, elapsed, violation.message)?
+                }
+            }
+        }
+        writeln!(f, "{:<26}{:>12}", "Total", format!("{:.1?}", self.total))?;
+        if !self.warnings.is_empty() {
+            writeln!(f, "{} warnings:", self.warnings.len())?;
+            for warning in &self.warnings {
+                writeln!(f, "  {}", warning)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Time spent in each property, summed over every [`ValidationReport`]
+/// recorded into it.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PropertyTimings {
+    elapsed: BTreeMap<String, Duration>,
+    validations: usize,
+}
+
+impl PropertyTimings {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the time each property took in `report`.
+    pub fn record(&mut self, report: &ValidationReport) {
+        self.validations += 1;
+        for result in &report.properties {
+            *self.elapsed.entry(result.property.clone()).or_default() += result.elapsed;
+        }
+    }
+
+    /// Time spent in one property, by the name its violations carry.
+    pub fn elapsed(&self, property: &str) -> Duration {
+        self.elapsed.get(property).copied().unwrap_or_default()
+    }
+
+    /// Time spent in all properties together.
+    pub fn total(&self) -> Duration {
+        self.elapsed.values().sum()
+    }
+
+    /// How many reports were recorded.
+    pub fn validations(&self) -> usize {
+        self.validations
+    }
+
+    /// Each property and its time, slowest first.
+    pub fn slowest(&self) -> Vec<(&str, Duration)> {
+        let mut by_time: Vec<(&str, Duration)> =
+            self.elapsed.iter().map(|(name, elapsed)| (name.as_str(), *elapsed)).collect();
+        by_time.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+        by_time
+    }
+}
+
+impl fmt::Display for PropertyTimings {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{:?} over {} validations", self.total(), self.validations)?;
+        for (name, elapsed) in self.slowest() {
+            writeln!(f, "  {:<26}{:?}", name, elapsed)?;
+        }
+        Ok(())
+    }
+}
+
+/// Invariant: Emotional PAD values and moods must always be between -1.0 and
+/// 1.0, every character's gain within (0, `MAX_GAIN`] and mood inertia within
+/// [0, 1], and every goal's likelihood within [0, 1].
+///
+/// Squashing should make this impossible, so a violation means appraisal fed
+/// in something broken (NaN, infinite intensity). The error lists the emotions
+/// pushing hardest on the offending axis to show where it came from.
+pub fn prop_emotional_state_validity(multiverse: &Multiverse) -> Result<(), PropertyViolation> {
+    for_all_characters(|_, character| {
+        let gain = character.emotional_state.gain;
+        if let Err(e) = crate::emotional_system::EmotionalState::check_gain(gain) {
+            return Err(PropertyViolation::new(
+                "emotional_state_validity",
+                format!("Character {} has {}", character.name, e),
+            ));
+        }
+        let state = &character.emotional_state;
+        if !(0.0..=1.0).contains(&state.mood_inertia) {
+            return Err(PropertyViolation::new(
+                "emotional_state_validity",
+                format!("Character {} has mood inertia {} outside [0, 1]", character.name, state.mood_inertia),
+            ));
+        }
+        if let Some(axis) = state.mood.iter().position(|m| !(-1.0..=1.0).contains(m)) {
+            return Err(PropertyViolation::new(
+                "emotional_state_validity",
+                format!("Character {} has invalid mood at index {}: {}", character.name, axis, state.mood[axis]),
+            ));
+        }
+        let mut goals: Vec<_> = character.emotional_state.goals.values().collect();
+        goals.sort_by(|a, b| a.name.cmp(&b.name));
+        if let Some(goal) = goals.iter().find(|g| !(0.0..=1.0).contains(&g.likelihood)) {
+            return Err(PropertyViolation::new(
+                "emotional_state_validity",
+                format!(
+                    "Character {}'s goal '{}' has likelihood {} outside [0, 1]",
+                    character.name, goal.name, goal.likelihood
+                ),
+            ));
+        }
+        let pad = character.emotional_state.get_pad();
+        pad.iter().enumerate().try_for_each(|(i, val)| {
+            implies(!(-1.0..=1.0).contains(val), || {
+                let mut contributions = character.emotional_state.pad_contributions();
+                contributions.sort_by(|a, b| b.1[i].abs().total_cmp(&a.1[i].abs()));
+                let top: Vec<String> = contributions
+                    .iter()
+                    .take(3)
+                    .map(|(emotion, c)| format!("{} {:+.3}", emotion.as_str(), c[i]))
+                    .collect();
+                Err(PropertyViolation::new(
+                    "emotional_state_validity",
+                    format!(
+                        "Character {} has invalid PAD value at index {}: {} (top contributors: {})",
+                        character.name,
+                        i,
+                        val,
+                        top.join(", ")
+                    ),
+                ))
+            })
+        })
+    })(multiverse)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_memory_consistency_witnessed() {
+        let mut multiverse = Multiverse::new();
+        let timeline = multiverse.root_timeline;
+        let char1 = multiverse.create_character("Alice".to_string(), timeline);
+
+        // Create event with Alice as participant
+        let event_id = multiverse.record_event(Event {
+            id: EventId(0),
             timeline,
-            description: "Dead character speaks".to_string(),
+            description: "Alice sees something".to_string(),
             participants: HashSet::from([char1]),
             effects: vec![],
             causality_violation: None,
         });
 
-        // Should FAIL death finality check
-        assert!(prop_death_finality(&multiverse).is_err());
+        // Create witnessed memory
+        let memory_id = multiverse.create_witnessed_memory(event_id, timeline, char1);
+
+        // Add memory to character
+        if let Some(character) = multiverse.characters.get_mut(&char1) {
+            character.memories.insert(memory_id);
+        }
+
+        // Should pass memory consistency check
+        assert!(prop_memory_consistency(&multiverse).is_ok());
+    }
+
+    #[test]
+    fn test_memory_consistency_violation() {
+        let mut multiverse = Multiverse::new();
+        let timeline = multiverse.root_timeline;
+        let char1 = multiverse.create_character("Alice".to_string(), timeline);
+        let char2 = multiverse.create_character("Bob".to_string(), timeline);
+
+        // Event where only Alice is present
+        let event_id = multiverse.record_event(Event {
+            id: EventId(0),
+            timeline,
+            description: "Alice-only event".to_string(),
+            participants: HashSet::from([char1]),
+            effects: vec![],
+            causality_violation: None,
+        });
+
+        // Create witnessed memory but claim Bob witnessed it (violation!)
+        let memory_id = multiverse.create_witnessed_memory(event_id, timeline, char2);
+
+        // Add memory to Bob
+        if let Some(character) = multiverse.characters.get_mut(&char2) {
+            character.memories.insert(memory_id);
+        }
+
+        // Should FAIL memory consistency check
+        assert!(prop_memory_consistency(&multiverse).is_err());
+    }
+
+    #[test]
+    fn test_participants_belong_to_the_timeline() {
+        let mut multiverse = Multiverse::new();
+        let root = multiverse.root_timeline;
+        let vera = multiverse.create_character("Vera Kandros".to_string(), root);
+        let riven = multiverse.create_character("Riven Blackwood".to_string(), root);
+        let conductor = multiverse.create_character("The Conductor".to_string(), root);
+        multiverse.grant_ability(conductor, Ability::timeline_perception());
+        let departure = multiverse.record_event(Event {
+            id: EventId(0),
+            timeline: root,
+            description: "The Errant Promise folds away".to_string(),
+            participants: HashSet::from([vera, riven]),
+            effects: vec![],
+            causality_violation: None,
+        });
+        let branch = multiverse.create_timeline_branch_with(root, departure, &[vera]).unwrap();
+        let scene = |multiverse: &mut Multiverse, participants: HashSet<CharacterId>| {
+            multiverse.record_event(Event {
+                id: EventId(0),
+                timeline: branch,
+                description: "A duel on the far side of the Fold".to_string(),
+                participants,
+                effects: vec![],
+                causality_violation: None,
+            })
+        };
+        // The Conductor never crossed, but stands in every timeline at once
+        scene(&mut multiverse, HashSet::from([vera, conductor]));
+        assert_eq!(prop_participants_in_timeline(&multiverse), Ok(()));
+
+        // Riven never crossed (violation!)
+        let duel = scene(&mut multiverse, HashSet::from([vera, riven]));
+        let violation = prop_participants_in_timeline(&multiverse).unwrap_err();
+        assert_eq!(violation.trace, vec![duel]);
+        assert!(violation.context.contains(&ViolationContext::Character(riven)));
+    }
+
+    #[test]
+    fn test_event_ids_match_their_keys() {
+        let mut multiverse = Multiverse::new();
+        let root = multiverse.root_timeline;
+        let vera = multiverse.create_character("Vera Kandros".to_string(), root);
+        let launch = multiverse.record_event(Event {
+            id: EventId(0),
+            timeline: root,
+            description: "The Errant Promise launches".to_string(),
+            participants: HashSet::from([vera]),
+            effects: vec![],
+            causality_violation: None,
+        });
+        assert_eq!(prop_event_ids_consistent(&multiverse), Ok(()));
+
+        // Inserted by hand, under the wrong key (violation!)
+        let mut copy = multiverse.events[&launch].clone();
+        copy.description = "The Errant Promise launches again".to_string();
+        multiverse.events.insert(EventId(7), copy);
+        let violation = prop_event_ids_consistent(&multiverse).unwrap_err();
+        assert!(violation.message.contains("stored under Event#7"));
+        multiverse.events.remove(&EventId(7));
+
+        // A timeline listing an event that was never recorded (violation!)
+        multiverse.timelines.get_mut(&root).unwrap().events.push(EventId(9));
+        let violation = prop_event_ids_consistent(&multiverse).unwrap_err();
+        assert_eq!(violation.context, vec![ViolationContext::Timeline(root)]);
+    }
+
+    #[test]
+    fn test_memory_references_resolve() {
+        let mut multiverse = Multiverse::new();
+        let root = multiverse.root_timeline;
+        let khelis = multiverse.create_character("Khelis Tev".to_string(), root);
+        let haggle = multiverse.record_event(Event {
+            id: EventId(0),
+            timeline: root,
+            description: "Khelis haggles in the Dark Spoke".to_string(),
+            participants: HashSet::from([khelis]),
+            effects: vec![],
+            causality_violation: None,
+        });
+        let witnessed = multiverse.create_witnessed_memory(haggle, root, khelis);
+        assert_eq!(prop_memory_references_resolve(&multiverse), Ok(()));
+
+        // A memory of an event nobody recorded (violation!)
+        multiverse.memories.get_mut(&witnessed).unwrap().event = EventId(42);
+        let violation = prop_memory_references_resolve(&multiverse).unwrap_err();
+        assert!(violation.message.contains("Event#42"));
+        assert_eq!(violation.memory, Some(witnessed));
+
+        // ...unless it's a rumour, of no scene at all
+        multiverse.memories.get_mut(&witnessed).unwrap().content = MemoryContent::Abstract {
+            gist: "the Gate Cult is buying".to_string(),
+        };
+        assert_eq!(prop_memory_references_resolve(&multiverse), Ok(()));
+
+        // Even a rumour comes from somewhere that exists (violation!)
+        multiverse.memories.get_mut(&witnessed).unwrap().source_timeline = TimelineId(7);
+        assert!(prop_memory_references_resolve(&multiverse).is_err());
+    }
+
+    #[test]
+    fn test_social_emotions_need_witnesses() {
+        use crate::emotional_system::{Belief, EmotionType, Goal};
+        let mut multiverse = Multiverse::new();
+        let root = multiverse.root_timeline;
+        let [vera, kael, riven, nameless] = ["Vera Kandros", "Kael Thornwick", "Riven Blackwood", "Nameless"]
+            .map(|name| multiverse.create_character(name.to_string(), root));
+        let protect_crew = Goal::new("Protect Crew".to_string(), 1.0, true);
+        multiverse.characters.get_mut(&vera).unwrap().emotional_state.add_goal(protect_crew);
+        let scene = |multiverse: &mut Multiverse, participants: &[CharacterId], effects: Vec<EventEffect>| {
+            multiverse.record_event(Event {
+                id: EventId(0),
+                timeline: root,
+                description: "Aboard the Errant Promise".to_string(),
+                participants: participants.iter().copied().collect(),
+                effects,
+                causality_violation: None,
+            })
+        };
+        let relate = |other: CharacterId, new_state| EventEffect::RelationshipChange {
+            character1: other,
+            character2: vera,
+            new_state,
+        };
+        scene(
+            &mut multiverse,
+            &[vera, kael, riven, nameless],
+            vec![
+                relate(kael, RelationshipState::Allied),
+                relate(riven, RelationshipState::Hostile),
+                relate(nameless, RelationshipState::Friendly),
+            ],
+        );
+        let hull_breach = EventEffect::AppraisalTrigger {
+            character: vera,
+            belief: Belief {
+                likelihood: 0.8,
+                causal_agent_name: None,
+                affected_goal_names: vec!["Protect Crew".to_string()],
+                goal_congruences: vec![-1.0],
+                is_incremental: true,
+                self_caused: false,
+                praiseworthiness: None,
+            },
+        };
+
+        // Nameless is in the timeline but not the scene, and feels nothing
+        let breach = scene(&mut multiverse, &[vera, kael, riven], vec![hull_breach.clone()]);
+        let felt = |multiverse: &Multiverse, c: CharacterId| {
+            let state = &multiverse.characters[&c].emotional_state;
+            state.emotion_toward("Vera Kandros").iter().map(|e| e.emotion_type.clone()).collect::<Vec<_>>()
+        };
+        assert_eq!(felt(&multiverse, kael), vec![EmotionType::Pity]);
+        assert_eq!(felt(&multiverse, riven), vec![EmotionType::Gloating]);
+        assert!(felt(&multiverse, nameless).is_empty());
+        assert_eq!(multiverse.events[&breach].effects.len(), 3);
+        assert_eq!(prop_social_emotions_witnessed(&multiverse), Ok(()));
+
+        // Pity for a breach they never saw (violation!)
+        let pity = EventEffect::SocialAppraisal {
+            observer: nameless,
+            subject: vera,
+            emotion: crate::emotional_system::Emotion {
+                emotion_type: EmotionType::Pity,
+                intensity: 0.4,
+            },
+        };
+        let mut unseen = multiverse.clone();
+        scene(&mut unseen, &[vera], vec![hull_breach, pity.clone()]);
+        let violation = prop_social_emotions_witnessed(&unseen).unwrap_err();
+        assert!(violation.message.contains("without seeing it"), "{}", violation);
+        assert!(violation.context.contains(&ViolationContext::Character(nameless)));
+
+        // Pity with nothing to pity (violation!)
+        scene(&mut multiverse, &[vera, nameless], vec![pity]);
+        let violation = prop_social_emotions_witnessed(&multiverse).unwrap_err();
+        assert!(violation.message.contains("appraises nothing"), "{}", violation);
+    }
+
+    #[test]
+    fn test_forgeries_need_a_registered_forger() {
+        let mut multiverse = Multiverse::new();
+        let timeline = multiverse.root_timeline;
+        let vera = multiverse.create_character("Vera Kandros".to_string(), timeline);
+        let event = multiverse.record_event(Event {
+            id: EventId(0),
+            timeline,
+            description: "Vera wakes in the Dark Spoke".to_string(),
+            participants: HashSet::from([vera]),
+            effects: vec![],
+            causality_violation: None,
+        });
+        let forge = |multiverse: &mut Multiverse, forger: &str| {
+            let memory = multiverse.create_memory(
+                event,
+                timeline,
+                MemoryProvenance::Forged {
+                    forger: forger.to_string(),
+                },
+            );
+            multiverse.characters.get_mut(&vera).unwrap().memories.insert(memory);
+        };
+
+        forge(&mut multiverse, "Gate Cult");
+        assert_eq!(prop_memory_consistency(&multiverse), Ok(()));
+
+        forge(&mut multiverse, "x");
+        let violation = prop_memory_consistency(&multiverse).unwrap_err();
+        assert!(violation.message.contains("unknown forger 'x'"));
+
+        multiverse.register_forger("x");
+        assert_eq!(prop_memory_consistency(&multiverse), Ok(()));
+    }
+
+    #[test]
+    fn test_death_finality() {
+        let mut multiverse = Multiverse::new();
+        let timeline = multiverse.root_timeline;
+        let char1 = multiverse.create_character("Victim".to_string(), timeline);
+
+        // Event that kills character
+        multiverse.record_event(Event {
+            id: EventId(0),
+            timeline,
+            description: "Character dies".to_string(),
+            participants: HashSet::from([char1]),
+            effects: vec![EventEffect::CharacterDeath { character: char1 }],
+            causality_violation: None,
+        });
+
+        // Try to have dead character participate in another event (violation!)
+        multiverse.record_event(Event {
+            id: EventId(1),
+            timeline,
+            description: "Dead character speaks".to_string(),
+            participants: HashSet::from([char1]),
+            effects: vec![],
+            causality_violation: None,
+        });
+
+        // Should FAIL death finality check
+        assert!(prop_death_finality(&multiverse).is_err());
+    }
+
+    #[test]
+    fn test_death_finality_follows_parents_not_ids() {
+        let mut multiverse = Multiverse::new();
+        let root = multiverse.root_timeline;
+        let riven = multiverse.create_character("Riven Blackwood".to_string(), root);
+        let vera = multiverse.create_character("Vera Kandros".to_string(), root);
+        let scene = |multiverse: &mut Multiverse, timeline, who: CharacterId, effects| {
+            multiverse.record_event(Event {
+                id: EventId(0),
+                timeline,
+                description: "A scene".to_string(),
+                participants: HashSet::from([who]),
+                effects,
+                causality_violation: None,
+            })
+        };
+        let shot = scene(&mut multiverse, root, vera, vec![EventEffect::CharacterDeath { character: vera }]);
+        let parent = multiverse.create_timeline_branch(root, shot);
+        let flight = scene(&mut multiverse, parent, riven, vec![]);
+        let child = multiverse.create_timeline_branch(parent, flight);
+
+        // Stitched together elsewhere, the child ends up with the lower id
+        let renumber = |t: &mut TimelineId| {
+            *t = if *t == parent { child } else if *t == child { parent } else { *t };
+        };
+        let mut timelines: Vec<Timeline> = multiverse.timelines.drain().map(|(_, t)| t).collect();
+        for timeline in &mut timelines {
+            renumber(&mut timeline.id);
+            timeline.parent.as_mut().map(renumber);
+        }
+        multiverse.timelines = timelines.into_iter().map(|t| (t.id, t)).collect();
+        for event in multiverse.events.values_mut() {
+            renumber(&mut event.timeline);
+        }
+        let (parent, child) = (child, parent);
+        assert!(child < parent);
+        let mut multiverse: Multiverse = serde_json::from_str(&serde_json::to_string(&multiverse).unwrap()).unwrap();
+        assert_eq!(multiverse.timelines_parents_first().iter().map(|t| t.id).collect::<Vec<_>>(), vec![
+            root, parent, child
+        ]);
+
+        // Riven lives on in the grandchild, which still knows Vera is dead
+        scene(&mut multiverse, child, riven, vec![]);
+        assert_eq!(prop_death_finality(&multiverse), Ok(()));
+        scene(&mut multiverse, child, vera, vec![]);
+        let violation = prop_death_finality(&multiverse).unwrap_err();
+        assert!(violation.message.starts_with("Dead character"), "{}", violation);
+    }
+
+    #[test]
+    fn test_roster_matches_profiles() {
+        use crate::emotional_system::Goal;
+        use crate::protagonists::{protagonist_profiles, CastBuilder};
+        let profiles = protagonist_profiles();
+        let mut multiverse = Multiverse::new();
+        let cast = CastBuilder::thirteen().build(&mut multiverse).unwrap();
+        let root = multiverse.root_timeline;
+        assert_eq!(prop_roster_matches_profiles(&multiverse, profiles, &cast), Ok(()));
+        let vera = cast.require("Fold Captain").unwrap();
+
+        // Learning to hear the Lattice, and taking on a new goal, are on the record
+        let mut grown = multiverse.clone();
+        grown.grant_abilities(root, "Ansible implant", [(vera, Ability::ansible_link())]);
+        grown.record_event(Event {
+            id: EventId(0),
+            timeline: root,
+            description: "Vera swears to find the Conductor".to_string(),
+            participants: HashSet::from([vera]),
+            effects: vec![EventEffect::AddGoal {
+                character: vera,
+                goal: Goal::new("Find the Conductor".to_string(), 0.6, false),
+            }],
+            causality_violation: None,
+        });
+        assert_eq!(prop_roster_matches_profiles(&grown, profiles, &cast), Ok(()));
+
+        // A refactor quietly drops her TimelinePerception
+        let mut broken = multiverse.clone();
+        broken.characters.get_mut(&vera).unwrap().abilities.remove(&AbilityKind::TimelinePerception);
+        let violation = prop_roster_matches_profiles(&broken, profiles, &cast).unwrap_err();
+        assert_eq!(violation.message, "Vera Kandros has lost their starting TimelinePerception");
+        assert_eq!(violation.context, vec![ViolationContext::Character(vera)]);
+
+        // ...or hands her something nobody gave her
+        let mut broken = multiverse.clone();
+        broken.characters.get_mut(&vera).unwrap().insert_ability(Ability::loop_memory());
+        let violation = prop_roster_matches_profiles(&broken, profiles, &cast).unwrap_err();
+        assert!(violation.message.contains("neither their profile nor any event"), "{}", violation);
+
+        // A cast missing a role the profiles name
+        let trio = CastBuilder::new().profiles(profiles[..3].iter().cloned()).build(&mut Multiverse::new()).unwrap();
+        let violation = prop_roster_matches_profiles(&multiverse, profiles, &trio).unwrap_err();
+        assert!(violation.message.starts_with("the cast has no Gate-Touched"), "{}", violation);
+    }
+
+    #[test]
+    fn test_violations_narrate_their_trace() {
+        let mut multiverse = Multiverse::new();
+        let root = multiverse.root_timeline;
+        let riven = multiverse.create_character("Riven Blackwood".to_string(), root);
+        let nameless = multiverse.create_character("Nameless".to_string(), root);
+        let mut scene = |description: &str, participants: Vec<CharacterId>, effects| {
+            multiverse.record_event(Event {
+                id: EventId(0),
+                timeline: root,
+                description: description.to_string(),
+                participants: participants.into_iter().collect(),
+                effects,
+                causality_violation: None,
+            })
+        };
+        scene("Nameless and Riven share a drink", vec![riven, nameless], vec![]);
+        let death = scene(
+            "Nameless falls at the Living Gate",
+            vec![nameless],
+            vec![EventEffect::CharacterDeath { character: nameless }],
+        );
+        scene("Riven cleans the time-gun alone", vec![riven], vec![]);
+        let seance = scene("Nameless whispers from beyond", vec![riven, nameless], vec![]);
+
+        let violation = prop_death_finality(&multiverse).unwrap_err();
+        assert_eq!(violation.trace, vec![death, seance]);
+        let story = violation.narrate(&multiverse);
+        let falls = story.find("Nameless falls at the Living Gate").unwrap();
+        assert!(falls < story.find("Nameless whispers from beyond").unwrap());
+        assert!(!story.contains("time-gun"));
+
+        // A memory violation tells where the memory came from
+        let mut multiverse = Multiverse::new();
+        let vera = multiverse.create_character("Vera Kandros".to_string(), root);
+        let rumour = multiverse.record_event(Event {
+            id: EventId(0),
+            timeline: root,
+            description: "A rumour spreads through the Dark Spoke".to_string(),
+            participants: HashSet::new(),
+            effects: vec![],
+            causality_violation: None,
+        });
+        let forged = multiverse.create_memory(rumour, root, MemoryProvenance::Forged { forger: String::new() });
+        multiverse.characters.get_mut(&vera).unwrap().memories.insert(forged);
+        let violation = prop_memory_consistency(&multiverse).unwrap_err();
+        assert_eq!(violation.memory, Some(forged));
+        let story = violation.narrate(&multiverse);
+        assert!(story.contains("A rumour spreads through the Dark Spoke"));
+        assert!(story.contains(&format!("{} of {} in {}", forged, rumour, root)));
+        assert!(story.contains("forged by ,"));
+    }
+
+    #[test]
+    fn test_relationship_change_from_either_side() {
+        let mut multiverse = Multiverse::new();
+        let timeline = multiverse.root_timeline;
+        let vera = multiverse.create_character("Vera".to_string(), timeline);
+        let riven = multiverse.create_character("Riven".to_string(), timeline);
+
+        // The second change names the pair in the opposite order
+        for (a, b, state) in [
+            (vera, riven, RelationshipState::Distrustful),
+            (riven, vera, RelationshipState::Hostile),
+        ] {
+            multiverse.record_event(Event {
+                id: EventId(0),
+                timeline,
+                description: "The relationship shifts".to_string(),
+                participants: HashSet::from([a, b]),
+                effects: vec![EventEffect::RelationshipChange {
+                    character1: a,
+                    character2: b,
+                    new_state: state,
+                }],
+                causality_violation: None,
+            });
+        }
+
+        assert!(prop_relationship_consistency(&multiverse).is_ok());
+    }
+
+    #[test]
+    fn test_affinity_drifts_but_levels_are_earned() {
+        let mut multiverse = Multiverse::new();
+        let timeline = multiverse.root_timeline;
+        let vera = multiverse.create_character("Vera".to_string(), timeline);
+        let riven = multiverse.create_character("Riven".to_string(), timeline);
+        for effect in [
+            EventEffect::RelationshipChange {
+                character1: vera,
+                character2: riven,
+                new_state: RelationshipState::Friendly,
+            },
+            EventEffect::AffinityShift {
+                character1: riven,
+                character2: vera,
+                delta: 0.3,
+            },
+        ] {
+            multiverse.record_event(Event {
+                id: EventId(0),
+                timeline,
+                description: "Riven covers Vera's retreat".to_string(),
+                participants: HashSet::from([vera, riven]),
+                effects: vec![effect],
+                causality_violation: None,
+            });
+        }
+        // Warmer, but still Friendly
+        assert_eq!(multiverse.characters[&vera].affinity(riven), 1.3);
+        assert_eq!(prop_relationship_consistency(&multiverse), Ok(()));
+
+        // Drift within the level that no event explains is tolerated...
+        multiverse.characters.get_mut(&vera).unwrap().affinities.insert(riven, 1.1);
+        assert_eq!(prop_relationship_consistency(&multiverse), Ok(()));
+
+        // ...but a level no event reached is not (violation!)
+        let mut allied = multiverse.clone();
+        let vera_mut = allied.characters.get_mut(&vera).unwrap();
+        vera_mut.affinities.insert(riven, 1.6);
+        vera_mut.relationships.insert(riven, RelationshipState::Allied);
+        let violation = prop_relationship_consistency(&allied).unwrap_err();
+        assert!(violation.message.contains("last event set it to Friendly"), "{}", violation);
+
+        // Nor is a level the affinity doesn't fall in (violation!)
+        let vera_mut = multiverse.characters.get_mut(&vera).unwrap();
+        vera_mut.affinities.insert(riven, -0.4);
+        let violation = prop_relationship_consistency(&multiverse).unwrap_err();
+        assert!(violation.message.contains("their affinity -0.4 is Neutral"), "{}", violation);
+    }
+
+    #[test]
+    fn test_emotional_validity_names_contributors() {
+        let mut multiverse = Multiverse::new();
+        let vera = multiverse.create_character("Vera".to_string(), multiverse.root_timeline);
+        let state = &mut multiverse.characters.get_mut(&vera).unwrap().emotional_state;
+        state.update_emotional_state(crate::emotional_system::Emotion {
+            emotion_type: crate::emotional_system::EmotionType::Fear,
+            intensity: f64::NAN,
+        });
+
+        let err = prop_emotional_state_validity(&multiverse).unwrap_err();
+        assert_eq!(err.property, "emotional_state_validity");
+        assert!(err.message.contains("top contributors: fear"), "{}", err);
+    }
+
+    #[test]
+    fn test_emotional_validity_catches_bad_gain() {
+        let mut multiverse = Multiverse::new();
+        let vera = multiverse.create_character("Vera".to_string(), multiverse.root_timeline);
+        assert_eq!(prop_emotional_state_validity(&multiverse), Ok(()));
+        // Written directly, bypassing set_gain
+        multiverse.characters.get_mut(&vera).unwrap().emotional_state.gain = 0.0;
+
+        let err = prop_emotional_state_validity(&multiverse).unwrap_err();
+        assert!(err.message.contains("gain 0 is outside"), "{}", err);
+    }
+
+    #[test]
+    fn test_emotional_validity_catches_bad_likelihood() {
+        use crate::emotional_system::Goal;
+        let mut multiverse = Multiverse::new();
+        let vera = multiverse.create_character("Vera".to_string(), multiverse.root_timeline);
+        let goal = Goal { likelihood: -0.5, ..Goal::new("Protect Crew".to_string(), 1.0, true) };
+        multiverse.characters.get_mut(&vera).unwrap().emotional_state.add_goal(goal);
+
+        let err = prop_emotional_state_validity(&multiverse).unwrap_err();
+        assert!(err.message.contains("'Protect Crew' has likelihood -0.5"), "{}", err);
+    }
+
+    #[test]
+    fn test_emotional_validity_catches_bad_mood() {
+        let mut multiverse = Multiverse::new();
+        let vera = multiverse.create_character("Vera".to_string(), multiverse.root_timeline);
+        let state = &mut multiverse.characters.get_mut(&vera).unwrap().emotional_state;
+        state.mood = [0.2, -1.5, 0.0];
+
+        let err = prop_emotional_state_validity(&multiverse).unwrap_err();
+        assert!(err.message.contains("invalid mood at index 1: -1.5"), "{}", err);
+    }
+
+    #[test]
+    fn test_anchor_departure_final() {
+        let mut multiverse = Multiverse::new();
+        let timeline = multiverse.root_timeline;
+        let kor = multiverse.create_character("Kor-Valeth".to_string(), timeline);
+        multiverse.characters.get_mut(&kor).unwrap().temporal_anchor = Some(TemporalAnchor {
+            origin_era: -1000,
+            stability: 0.1,
+        });
+
+        // A single scene exhausts the anchor and Kor-Valeth departs
+        multiverse.record_event(Event {
+            id: EventId(0),
+            timeline,
+            description: "Kor-Valeth draws their blade".to_string(),
+            participants: HashSet::from([kor]),
+            effects: vec![],
+            causality_violation: None,
+        });
+        assert!(prop_anchor_departure_final(&multiverse).is_ok());
+        assert!(validate_all_properties(&multiverse).is_ok());
+
+        // Appearing after the departure is a violation
+        multiverse.record_event(Event {
+            id: EventId(0),
+            timeline,
+            description: "Kor-Valeth returns without explanation".to_string(),
+            participants: HashSet::from([kor]),
+            effects: vec![],
+            causality_violation: None,
+        });
+        assert!(prop_anchor_departure_final(&multiverse).is_err());
+    }
+
+    #[test]
+    fn test_strict_flags_catch_typos() {
+        let mut multiverse = Multiverse::new();
+        let root = multiverse.root_timeline;
+        let conductor = multiverse.create_character("The Conductor".to_string(), root);
+        let summons = multiverse.register_flag("conductor_summons");
+        let grant = |multiverse: &mut Multiverse, flag: &str| {
+            multiverse.record_event(Event {
+                id: EventId(0),
+                timeline: root,
+                description: "The Conductor hears the summons".to_string(),
+                participants: HashSet::from([conductor]),
+                effects: vec![EventEffect::KnowledgeGained {
+                    character: conductor,
+                    flag: flag.to_string(),
+                }],
+                causality_violation: None,
+            });
+        };
+
+        grant(&mut multiverse, summons.as_str());
+        grant(&mut multiverse, "conductor_summon");
+        // Without strict mode the typo is just another flag
+        assert!(prop_knowledge_flags(&multiverse).is_ok());
+
+        multiverse.config.strict_flags = true;
+        let violation = prop_knowledge_flags(&multiverse).unwrap_err();
+        assert!(violation.message.contains("'conductor_summon'"), "{}", violation);
+
+        multiverse.register_flag(String::from("conductor_summon"));
+        assert!(prop_knowledge_flags(&multiverse).is_ok());
+    }
+
+    #[test]
+    fn test_lattice_shares_across_timelines() {
+        let mut multiverse = Multiverse::new();
+        let root = multiverse.root_timeline;
+        let corvus = multiverse.create_character("Corvus Shal".to_string(), root);
+        let linked = multiverse.create_character("Lattice Adept".to_string(), root);
+        let outsider = multiverse.create_character("Outsider".to_string(), root);
+
+        let choice = multiverse.record_event(Event {
+            id: EventId(0),
+            timeline: root,
+            description: "Corvus and the adept are linked into the Lattice".to_string(),
+            participants: HashSet::from([corvus, linked]),
+            effects: vec![
+                EventEffect::AbilityGained {
+                    character: corvus,
+                    ability: Ability::ansible_link(),
+                    mechanism: "Lattice Singer".to_string(),
+                },
+                EventEffect::AbilityGained {
+                    character: linked,
+                    ability: Ability::ansible_link(),
+                    mechanism: "Lattice initiation".to_string(),
+                },
+            ],
+            causality_violation: None,
+        });
+        let branch = multiverse.create_timeline_branch(root, choice);
+        for character in [linked, outsider] {
+            multiverse.characters.get_mut(&character).unwrap().current_timeline = branch;
+        }
+
+        // Corvus sings to a linked mind in another timeline
+        multiverse.record_event(Event {
+            id: EventId(0),
+            timeline: root,
+            description: "Corvus sings the prophecy into the Lattice".to_string(),
+            participants: HashSet::from([corvus]),
+            effects: vec![EventEffect::KnowledgeShared {
+                from: corvus,
+                to: linked,
+                flag: "heard_lattice_prophecy".to_string(),
+            }],
+            causality_violation: None,
+        });
+        assert!(prop_knowledge_flags(&multiverse).is_ok());
+
+        // ...but an unlinked mind in that timeline can't receive it (violation!)
+        multiverse.record_event(Event {
+            id: EventId(0),
+            timeline: root,
+            description: "Corvus tries to reach the outsider".to_string(),
+            participants: HashSet::from([corvus]),
+            effects: vec![EventEffect::KnowledgeShared {
+                from: corvus,
+                to: outsider,
+                flag: "heard_lattice_prophecy".to_string(),
+            }],
+            causality_violation: None,
+        });
+        assert!(prop_knowledge_flags(&multiverse).is_err());
+    }
+
+    #[test]
+    fn test_exclusive_trade_leaves_seller_empty() {
+        let mut multiverse = Multiverse::new();
+        let root = multiverse.root_timeline;
+        let khelis = multiverse.create_character("Khelis Tev".to_string(), root);
+        let vera = multiverse.create_character("Vera Kandros".to_string(), root);
+
+        let sell = |multiverse: &mut Multiverse, memory, from, to| {
+            multiverse.record_event(Event {
+                id: EventId(0),
+                timeline: root,
+                description: "A sale in the Dark Spoke".to_string(),
+                participants: HashSet::from([from, to]),
+                effects: vec![EventEffect::MemoryTransfer {
+                    memory,
+                    from: Some(from),
+                    to,
+                    exclusive: true,
+                }],
+                causality_violation: None,
+            })
+        };
+
+        let stock = multiverse.create_witnessed_memory(EventId(0), root, khelis);
+        multiverse.characters.get_mut(&khelis).unwrap().memories.insert(stock);
+        sell(&mut multiverse, stock, khelis, vera);
+        assert!(!multiverse.characters[&khelis].memories.contains(&stock));
+        assert_eq!(prop_exclusive_trade_final(&multiverse), Ok(()));
+
+        // Khelis can buy it back...
+        sell(&mut multiverse, stock, vera, khelis);
+        assert_eq!(prop_exclusive_trade_final(&multiverse), Ok(()));
+
+        // ...but a seller who keeps what they sold is running a con
+        sell(&mut multiverse, stock, khelis, vera);
+        multiverse.characters.get_mut(&khelis).unwrap().memories.insert(stock);
+        assert!(prop_exclusive_trade_final(&multiverse).is_err());
+    }
+
+    #[test]
+    fn test_recalling_a_faded_memory_fails() {
+        let mut multiverse = Multiverse::new();
+        let root = multiverse.root_timeline;
+        let yash = multiverse.create_character("Yash-Tel".to_string(), root);
+        let scene = |multiverse: &mut Multiverse, effects| {
+            multiverse.record_event(Event {
+                id: EventId(0),
+                timeline: root,
+                description: "Yash-Tel reaches for the Shimmer route".to_string(),
+                participants: HashSet::from([yash]),
+                effects,
+                causality_violation: None,
+            })
+        };
+
+        let charting = scene(&mut multiverse, vec![]);
+        let route = multiverse.create_witnessed_memory(charting, root, yash);
+        multiverse.characters.get_mut(&yash).unwrap().memories.insert(route);
+        let recall = EventEffect::MemoryRecall { character: yash, memory: route };
+
+        // A clear memory can be acted on, and fading afterwards doesn't undo that
+        scene(&mut multiverse, vec![recall.clone()]);
+        scene(&mut multiverse, vec![EventEffect::MemoryDegrade { character: yash, amount: 0.8 }]);
+        assert!(multiverse.characters[&yash].memories.contains(&route));
+        assert_eq!(prop_memory_recall_usable(&multiverse), Ok(()));
+
+        // At 0.2 it is still held, but too faint to act on
+        scene(&mut multiverse, vec![recall]);
+        let violation = prop_memory_recall_usable(&multiverse).unwrap_err();
+        assert_eq!(violation.property, "memory_recall_usable");
+
+        // A looser threshold lets it through
+        multiverse.config.validation.min_usable_fidelity = 0.1;
+        assert_eq!(prop_memory_recall_usable(&multiverse), Ok(()));
+    }
+
+    #[test]
+    fn test_memories_from_former_timelines() {
+        let mut multiverse = Multiverse::new();
+        let root = multiverse.root_timeline;
+        let yash = multiverse.create_character("Yash-Tel".to_string(), root);
+        let stay_at_home = multiverse.create_character("Khelis Tev".to_string(), root);
+
+        let scene = |multiverse: &mut Multiverse, timeline, description: &str, effects| {
+            multiverse.record_event(Event {
+                id: EventId(0),
+                timeline,
+                description: description.to_string(),
+                participants: HashSet::from([yash]),
+                effects,
+                causality_violation: None,
+            })
+        };
+        let remember = |multiverse: &mut Multiverse, event, timeline| {
+            let memory = multiverse.create_witnessed_memory(event, timeline, yash);
+            multiverse.characters.get_mut(&yash).unwrap().memories.insert(memory);
+            memory
+        };
+
+        let departure = scene(&mut multiverse, root, "Yash-Tel charts a shimmer route", vec![]);
+        remember(&mut multiverse, departure, root);
+        let branch = multiverse.create_timeline_branch(root, departure);
+
+        let jump = vec![EventEffect::TimelineMove { character: yash, to: branch }];
+        let crossing = scene(&mut multiverse, branch, "Yash-Tel slips into the branch", jump);
+        let abroad = remember(&mut multiverse, crossing, branch);
+        assert_eq!(multiverse.characters[&yash].current_timeline, branch);
+
+        let home = vec![EventEffect::TimelineMove { character: yash, to: root }];
+        let homecoming = scene(&mut multiverse, root, "Yash-Tel navigates home", home);
+        remember(&mut multiverse, homecoming, root);
+
+        // Back in the root, holding memories of the root before, the branch, and the root after
+        let character = &multiverse.characters[&yash];
+        assert_eq!(character.timeline_history, vec![(crossing, branch), (homecoming, root)]);
+        assert!(character.was_in_timeline(branch));
+        assert_eq!(character.memories.len(), 3);
+        assert_eq!(prop_timeline_perception(&multiverse), Ok(()));
+
+        // Someone who never went can't remember the branch
+        multiverse.characters.get_mut(&stay_at_home).unwrap().memories.insert(abroad);
+        assert!(prop_timeline_perception(&multiverse).is_err());
+    }
+
+    #[test]
+    fn test_branch_history_frozen() {
+        let mut multiverse = Multiverse::new();
+        let root = multiverse.root_timeline;
+        let vera = multiverse.create_character("Vera Kandros".to_string(), root);
+
+        let scene = |description: &str| Event {
+            id: EventId(0),
+            timeline: root,
+            description: description.to_string(),
+            participants: HashSet::from([vera]),
+            effects: vec![],
+            causality_violation: None,
+        };
+        let before = multiverse.record_event(scene("Vera boards the Ring"));
+        let choice = multiverse.record_event(scene("Vera chooses the left corridor"));
+        let branch = multiverse.create_timeline_branch(root, choice);
+
+        // The parent keeps going after the branch; that's allowed
+        let after = multiverse.record_event(scene("Vera reaches the observatory"));
+        assert!(prop_branch_history_frozen(&multiverse).is_ok());
+
+        // The branch inherited everything up to and including the choice...
+        assert!(multiverse.event_visible_in(before, branch));
+        assert!(multiverse.event_visible_in(choice, branch));
+        assert!(!multiverse.event_visible_in(after, branch));
+
+        let inherited = multiverse.create_witnessed_memory(choice, branch, vera);
+        multiverse.characters.get_mut(&vera).unwrap().memories.insert(inherited);
+        assert!(prop_branch_history_frozen(&multiverse).is_ok());
+
+        // ...but remembering the parent's later event from inside the branch is ambiguous
+        multiverse.create_witnessed_memory(after, branch, vera);
+        assert!(prop_branch_history_frozen(&multiverse).is_err());
+    }
+
+    #[test]
+    fn test_branch_divergence_must_be_in_parent() {
+        let mut multiverse = Multiverse::new();
+        let root = multiverse.root_timeline;
+        multiverse.create_timeline_branch(root, EventId(99));
+        assert!(prop_branch_history_frozen(&multiverse).is_err());
+    }
+
+    #[test]
+    fn test_ability_justification() {
+        let mut multiverse = Multiverse::new();
+        let timeline = multiverse.root_timeline;
+        let riven = multiverse.create_character("Riven Blackwood".to_string(), timeline);
+        let drifter = multiverse.create_character("Drifter".to_string(), timeline);
+
+        // Riven's CausalityHacking is part of their profile, but still needs a grant
+        multiverse
+            .characters
+            .get_mut(&riven)
+            .unwrap()
+            .insert_ability(Ability::causality_hacking());
+        assert!(prop_ability_justification(&multiverse).is_err());
+        multiverse.grant_ability(riven, Ability::causality_hacking());
+        assert!(prop_ability_justification(&multiverse).is_ok());
+
+        // An ability granted through an event is justified
+        multiverse.record_event(Event {
+            id: EventId(0),
+            timeline,
+            description: "The drifter touches a Living Gate".to_string(),
+            participants: HashSet::from([drifter]),
+            effects: vec![EventEffect::AbilityGained {
+                character: drifter,
+                ability: Ability::loop_memory_with(0.6),
+                mechanism: "Living Gate".to_string(),
+            }],
+            causality_violation: None,
+        });
+        assert!(prop_ability_justification(&multiverse).is_ok());
+
+        // One that appears from nowhere is not (violation!)
+        multiverse
+            .characters
+            .get_mut(&drifter)
+            .unwrap()
+            .insert_ability(Ability::precognition());
+        assert!(prop_ability_justification(&multiverse).is_err());
+    }
+
+    #[test]
+    fn test_lost_abilities_need_a_fresh_grant() {
+        let mut multiverse = Multiverse::new();
+        let timeline = multiverse.root_timeline;
+        let mara = multiverse.create_character("Mara Vex".to_string(), timeline);
+        multiverse.grant_ability(mara, Ability::precognition());
+        multiverse.record_event(Event {
+            id: EventId(0),
+            timeline,
+            description: "The Gate takes Mara's sight".to_string(),
+            participants: HashSet::from([mara]),
+            effects: vec![EventEffect::AbilityLost {
+                character: mara,
+                ability: AbilityKind::Precognition,
+            }],
+            causality_violation: None,
+        });
+        assert!(!multiverse.characters[&mara].has_ability(AbilityKind::Precognition));
+        assert!(prop_ability_justification(&multiverse).is_ok());
+
+        // The old grant doesn't cover putting it straight back (violation!)
+        multiverse.characters.get_mut(&mara).unwrap().insert_ability(Ability::precognition());
+        let violation = prop_ability_justification(&multiverse).unwrap_err();
+        assert!(violation.message.contains("Precognition"), "{}", violation);
+
+        // A second grant does
+        multiverse.grant_ability(mara, Ability::precognition());
+        assert!(prop_ability_justification(&multiverse).is_ok());
+    }
+
+    #[test]
+    fn test_only_precogs_hold_contradictions() {
+        let mut multiverse = Multiverse::new();
+        let root = multiverse.root_timeline;
+        let [mara, riven] = ["Mara Vex", "Riven Blackwood"].map(|name| multiverse.create_character(name.to_string(), root));
+        multiverse.characters.get_mut(&mara).unwrap().abilities =
+            std::collections::HashMap::from([(AbilityKind::Precognition, Ability::precognition())]);
+        multiverse.declare_mutually_exclusive("corvus_lives", "corvus_dies");
+        multiverse.declare_mutually_exclusive("corvus_dies", "corvus_lives");
+        assert_eq!(multiverse.mutually_exclusive.len(), 1);
+
+        let foresee = |multiverse: &mut Multiverse, seer: CharacterId| {
+            multiverse.record_event(Event {
+                id: EventId(0),
+                timeline: root,
+                description: "Two futures at once: Corvus lives, and Corvus dies".to_string(),
+                participants: HashSet::from([seer]),
+                effects: ["corvus_lives", "corvus_dies"]
+                    .map(|flag| EventEffect::KnowledgeGained {
+                        character: seer,
+                        flag: flag.to_string(),
+                    })
+                    .to_vec(),
+                causality_violation: None,
+            });
+        };
+        foresee(&mut multiverse, mara);
+        assert_eq!(prop_precog_contradictions_allowed(&multiverse), Ok(()));
+
+        // The same two futures, told to someone who can't see ahead (violation!)
+        foresee(&mut multiverse, riven);
+        let violation = prop_precog_contradictions_allowed(&multiverse).unwrap_err();
+        assert!(violation.message.starts_with("Riven Blackwood knows both"), "{}", violation);
+    }
+
+    #[test]
+    fn test_precog_contradictions_stay_within_the_horizon() {
+        let mut multiverse = Multiverse::new();
+        let root = multiverse.root_timeline;
+        let mara = multiverse.create_character("Mara Vex".to_string(), root);
+        multiverse.grant_ability(mara, Ability::precognition_within(2));
+        multiverse.declare_mutually_exclusive("corvus_lives", "corvus_dies");
+        let scene = |multiverse: &mut Multiverse, description: &str, effects| {
+            multiverse.record_event(Event {
+                id: EventId(0),
+                timeline: root,
+                description: description.to_string(),
+                participants: HashSet::from([mara]),
+                effects,
+                causality_violation: None,
+            })
+        };
+        let [lives, dies] = ["corvus_lives", "corvus_dies"].map(|flag| EventEffect::KnowledgeGained {
+            character: mara,
+            flag: flag.to_string(),
+        });
+        scene(&mut multiverse, "Mara sees Corvus walk out of the Gate", vec![lives]);
+        scene(&mut multiverse, "Mara sees Corvus fall in the Gate", vec![dies]);
+
+        // Both futures are still ahead of her for two events
+        for _ in 0..2 {
+            scene(&mut multiverse, "Mara waits at the Gate", vec![]);
+            assert_eq!(prop_precog_contradictions_allowed(&multiverse), Ok(()));
+        }
+
+        // By the third, one of them should have come (violation!)
+        scene(&mut multiverse, "Mara waits at the Gate", vec![]);
+        let violation = prop_precog_contradictions_allowed(&multiverse).unwrap_err();
+        assert!(violation.message.contains("for 3 events since Event#2"), "{}", violation);
+
+        // Sight with no horizon holds them for good
+        multiverse.grant_ability(mara, Ability::precognition());
+        assert_eq!(prop_precog_contradictions_allowed(&multiverse), Ok(()));
+    }
+
+    #[test]
+    fn test_anchor_timeline_respected() {
+        let mut multiverse = Multiverse::new();
+        let root = multiverse.root_timeline;
+        let kor = multiverse.create_character("Kor-Valeth".to_string(), root);
+        multiverse.characters.get_mut(&kor).unwrap().anchor_timeline = Some(root);
+        let split = multiverse.record_event(Event {
+            id: EventId(0),
+            timeline: root,
+            description: "The Living Gate opens on two futures".to_string(),
+            participants: HashSet::from([kor]),
+            effects: vec![],
+            causality_violation: None,
+        });
+
+        // Crossing into a branch through the Gate is a story anyone can follow
+        let crossed = multiverse.create_timeline_branch_with(root, split, &[kor]).unwrap();
+        assert_eq!(prop_anchor_timeline_respected(&multiverse), Ok(()));
+
+        // Turning up in a newer branch still has to be explained (violation!)
+        let unseen = multiverse.create_timeline_branch(crossed, split);
+        multiverse.characters.get_mut(&kor).unwrap().current_timeline = unseen;
+        let violation = prop_anchor_timeline_respected(&multiverse).unwrap_err();
+        assert!(violation.message.starts_with("Kor-Valeth is anchored to"), "{}", violation);
+    }
+
+    #[test]
+    fn test_anchor_timeline_follows_parentage_not_ids() {
+        let mut multiverse = Multiverse::new();
+        let root = multiverse.root_timeline;
+        let kor = multiverse.create_character("Kor-Valeth".to_string(), root);
+        let scene = |multiverse: &mut Multiverse, timeline: TimelineId| {
+            multiverse.record_event(Event {
+                id: EventId(0),
+                timeline,
+                description: "The Living Gate opens".to_string(),
+                participants: HashSet::from([kor]),
+                effects: vec![],
+                causality_violation: None,
+            })
+        };
+        let split = scene(&mut multiverse, root);
+        let anchor = multiverse.create_timeline_branch(root, split);
+        let sibling = multiverse.create_timeline_branch(root, split);
+        let fork = scene(&mut multiverse, anchor);
+        let descendant = multiverse.create_timeline_branch(anchor, fork);
+
+        // Stitched together elsewhere: the sibling outnumbers the anchor, and
+        // the anchor outnumbers its own branch
+        let renumber = |t: &mut TimelineId| {
+            *t = match *t {
+                t if t == anchor => sibling,
+                t if t == sibling => descendant,
+                t if t == descendant => anchor,
+                t => t,
+            };
+        };
+        let mut timelines: Vec<Timeline> = multiverse.timelines.drain().map(|(_, t)| t).collect();
+        for timeline in &mut timelines {
+            renumber(&mut timeline.id);
+            timeline.parent.as_mut().map(renumber);
+        }
+        multiverse.timelines = timelines.into_iter().map(|t| (t.id, t)).collect();
+        for event in multiverse.events.values_mut() {
+            renumber(&mut event.timeline);
+        }
+        let (anchor, sibling, descendant) = (sibling, descendant, anchor);
+        assert!(descendant < anchor && anchor < sibling);
+        multiverse.characters.get_mut(&kor).unwrap().anchor_timeline = Some(anchor);
+
+        // A sibling branch isn't newer than the anchor, whatever its id
+        multiverse.characters.get_mut(&kor).unwrap().current_timeline = sibling;
+        assert_eq!(prop_anchor_timeline_respected(&multiverse), Ok(()));
+
+        // The anchor's own branch is, however low its id (violation!)
+        multiverse.characters.get_mut(&kor).unwrap().current_timeline = descendant;
+        let violation = prop_anchor_timeline_respected(&multiverse).unwrap_err();
+        assert!(violation.message.starts_with("Kor-Valeth is anchored to"), "{}", violation);
+    }
+
+    #[test]
+    fn test_repairs_answer_violations() {
+        let mut multiverse = Multiverse::new();
+        let root = multiverse.root_timeline;
+        let riven = multiverse.create_character("Riven Blackwood".to_string(), root);
+        multiverse.timelines.get_mut(&root).unwrap().causality_stable = false;
+        let shot = multiverse.record_event(Event {
+            id: EventId(0),
+            timeline: root,
+            description: "Riven's bullet lands before it's fired".to_string(),
+            participants: HashSet::from([riven]),
+            effects: vec![],
+            causality_violation: Some(CausalityViolation::EffectBeforeCause {
+                mechanism: "Time-weapon".to_string(),
+            }),
+        });
+
+        // Calling it stable doesn't make it so (violation!)
+        let mut declared = multiverse.clone();
+        declared.timelines.get_mut(&root).unwrap().causality_stable = true;
+        assert!(prop_causality_justification(&declared).unwrap_err().message.contains("marked stable"));
+
+        multiverse.repair_causality(shot, "Gate resonance").unwrap();
+        multiverse.stabilize_timeline(root, "Conductor's intervention").unwrap();
+        assert_eq!(prop_causality_justification(&multiverse), Ok(()));
+
+        // A repair of something that never broke (violation!)
+        multiverse.record_event(Event {
+            id: EventId(0),
+            timeline: root,
+            description: "The Conductor mends what isn't torn".to_string(),
+            participants: HashSet::new(),
+            effects: vec![EventEffect::CausalityRepaired {
+                violation: EventId(42),
+                mechanism: "Conductor's intervention".to_string(),
+            }],
+            causality_violation: None,
+        });
+        let violation = prop_causality_justification(&multiverse).unwrap_err();
+        assert!(violation.message.contains("Event#42"), "{}", violation);
+    }
+
+    #[test]
+    fn test_bootstrap_needs_loop_memory() {
+        let mut multiverse = Multiverse::new();
+        let root = multiverse.root_timeline;
+        let riven = multiverse.create_character("Riven Blackwood".to_string(), root);
+        let nameless = multiverse.create_character("Nameless".to_string(), root);
+        multiverse.timelines.get_mut(&root).unwrap().causality_stable = false;
+        let manual = |participants| Event {
+            id: EventId(0),
+            timeline: root,
+            description: "The gun's manual, in Riven's own hand, from no one".to_string(),
+            participants,
+            effects: vec![],
+            causality_violation: Some(CausalityViolation::Bootstrap {
+                mechanism: "Precursor Time-Weapon".to_string(),
+                looped_item: "Time-gun schematics".to_string(),
+            }),
+        };
+
+        let mut alone = multiverse.clone();
+        alone.record_event(manual(HashSet::from([riven])));
+        let violation = prop_causality_justification(&alone).unwrap_err();
+        assert!(violation.message.contains("Time-gun schematics"));
+
+        // Too faint a recall of the loop is no use to anyone (violation!)
+        let mut hazy = multiverse.clone();
+        hazy.characters.get_mut(&nameless).unwrap().insert_ability(Ability::loop_memory_with(0.2));
+        hazy.record_event(manual(HashSet::from([riven, nameless])));
+        assert!(prop_causality_justification(&hazy).is_err());
+        hazy.config.validation.min_usable_fidelity = 0.1;
+        assert_eq!(prop_causality_justification(&hazy), Ok(()));
+
+        // Nameless remembers the loop, even after losing the knack for it
+        multiverse.characters.get_mut(&nameless).unwrap().insert_ability(Ability::loop_memory());
+        multiverse.record_event(manual(HashSet::from([riven, nameless])));
+        assert_eq!(prop_causality_justification(&multiverse), Ok(()));
+        multiverse.record_event(Event {
+            id: EventId(0),
+            timeline: root,
+            description: "The Gate takes Nameless's loop".to_string(),
+            participants: HashSet::from([nameless]),
+            effects: vec![EventEffect::AbilityLost {
+                character: nameless,
+                ability: AbilityKind::LoopMemory,
+            }],
+            causality_violation: None,
+        });
+        assert_eq!(prop_causality_justification(&multiverse), Ok(()));
+    }
+
+    #[test]
+    fn test_collective_memories_stay_in_sync() {
+        let mut multiverse = Multiverse::new();
+        let root = multiverse.root_timeline;
+        let (first, second) = (
+            multiverse.create_character("Synthesis (body 1)".to_string(), root),
+            multiverse.create_character("Synthesis (body 2)".to_string(), root),
+        );
+        multiverse.link_collective(&[first, second]).unwrap();
+        let scene = |multiverse: &mut Multiverse, effects| {
+            multiverse.record_event(Event {
+                id: EventId(0),
+                timeline: root,
+                description: "The first body tends the archive".to_string(),
+                participants: HashSet::from([first]),
+                effects,
+                causality_violation: None,
+            })
+        };
+
+        // Slipping a memory into one body directly bypasses the shared mind
+        let archive = scene(&mut multiverse, vec![]);
+        let filing = multiverse.create_witnessed_memory(archive, root, first);
+        multiverse.characters.get_mut(&first).unwrap().memories.insert(filing);
+        let violation = prop_collective_memory_shared(&multiverse).unwrap_err();
+        assert_eq!(violation.property, "collective_memory_shared");
+
+        // A sync heals it
+        multiverse.link_collective(&[first]).unwrap();
+        assert_eq!(prop_collective_memory_shared(&multiverse), Ok(()));
+
+        // A wipe is one body's loss, until the next sync
+        scene(&mut multiverse, vec![EventEffect::MemoryWipe { character: first }]);
+        assert!(multiverse.characters[&first].memories.is_empty());
+        assert_eq!(prop_collective_memory_shared(&multiverse), Ok(()));
+        multiverse.link_collective(&[second]).unwrap();
+        assert!(multiverse.characters[&first].memories.contains(&filing));
+        assert_eq!(validate_all_properties(&multiverse), Ok(()));
+    }
+
+    #[test]
+    fn test_registry_custom_and_disabled_properties() {
+        let mut multiverse = Multiverse::new();
+        let root = multiverse.root_timeline;
+        let vera = multiverse.create_character("Vera Kandros".to_string(), root);
+        multiverse.characters.get_mut(&vera).unwrap().memories.insert(MemoryId(404));
+
+        let mut registry = PropertyRegistry::new();
+        assert_eq!(registry.names().len(), PROPERTIES.len());
+        assert_eq!(registry.validate(&multiverse).unwrap_err().property, "memory_consistency");
+
+        // Switching off the two memory properties lets the dangling memory through
+        assert!(registry.disable("memory_consistency"));
+        assert!(registry.disable("timeline_perception"));
+        assert!(!registry.disable("no_such_property"));
+        assert_eq!(registry.validate(&multiverse), Ok(()));
+
+        // A story rule of its own, with state captured in the check
+        let crew_limit = 1;
+        registry.register("crew_limit", move |m: &Multiverse| {
+            if m.characters.len() > crew_limit {
+                return Err(PropertyViolation::new("crew_limit", "too many aboard the Errant Promise"));
+            }
+            Ok(())
+        });
+        assert_eq!(registry.validate(&multiverse), Ok(()));
+        multiverse.create_character("Corvus Shal".to_string(), root);
+        assert_eq!(registry.validate(&multiverse).unwrap_err().property, "crew_limit");
+        assert_eq!(registry.names().last(), Some(&"crew_limit"));
+
+        // Re-registering replaces the check in place
+        registry.register("crew_limit", |_: &Multiverse| Ok(()));
+        assert_eq!(registry.names().len(), PROPERTIES.len() - 1);
+        assert_eq!(registry.validate(&multiverse), Ok(()));
+        assert!(registry.enable("memory_consistency"));
+        assert!(registry.validate(&multiverse).is_err());
+    }
+
+    #[test]
+    fn test_timed_report_runs_past_violations() {
+        let mut multiverse = Multiverse::new();
+        let root = multiverse.root_timeline;
+        let vera = multiverse.create_character("Vera Kandros".to_string(), root);
+        multiverse.characters.get_mut(&vera).unwrap().memories.insert(MemoryId(404));
+
+        let report = validate_all_properties_timed(&multiverse);
+        assert!(!report.is_ok());
+        assert_eq!(report.properties.len(), PROPERTIES.len());
+        assert_eq!(report.first_violation(), validate_all_properties(&multiverse).err().as_ref());
+        let failed: Vec<&str> =
+            report.properties.iter().filter(|p| !p.passed).map(|p| p.property.as_str()).collect();
+        assert_eq!(failed, vec!["memory_consistency", "timeline_perception"]);
+
+        assert!(report.to_string().contains("FAILED: Memory Memory#404 not found"));
+        let json = serde_json::to_value(&report).unwrap();
+        assert_eq!(json["properties"][0]["passed"], false);
+    }
+
+    #[test]
+    fn test_warnings_are_reported_but_never_fail() {
+        let mut multiverse = Multiverse::new();
+        let root = multiverse.root_timeline;
+        let vera = multiverse.create_character("Vera Kandros".to_string(), root);
+        let kael = multiverse.create_character("Kael Thornwick".to_string(), root);
+        let scene = |multiverse: &mut Multiverse, new_state| {
+            multiverse.record_event(Event {
+                id: EventId(0),
+                timeline: root,
+                description: "Vera and Kael settle their accounts".to_string(),
+                participants: HashSet::from([vera, kael]),
+                effects: vec![EventEffect::RelationshipChange {
+                    character1: vera,
+                    character2: kael,
+                    new_state,
+                }],
+                causality_violation: None,
+            })
+        };
+        let pact = scene(&mut multiverse, RelationshipState::Allied);
+        assert!(collect_warnings(&multiverse).is_empty());
+
+        // Allies to enemies overnight
+        let betrayal = scene(&mut multiverse, RelationshipState::Hostile);
+        // A memory worn almost to nothing, and one nobody was ever given
+        let faded = multiverse.create_witnessed_memory(pact, root, vera);
+        multiverse.memories.get_mut(&faded).unwrap().fidelity = 0.05;
+        multiverse.characters.get_mut(&vera).unwrap().memories.insert(faded);
+        multiverse.create_witnessed_memory(betrayal, root, kael);
+
+        let warnings = collect_warnings(&multiverse);
+        let flagged: Vec<&str> = warnings.iter().map(|w| w.property.as_str()).collect();
+        assert_eq!(flagged, vec!["low_fidelity", "relationship_whiplash", "orphaned_memories"]);
+        assert!(warnings.iter().all(|w| w.severity == Severity::Warning));
+        assert!(warnings[1].to_string().starts_with("[relationship_whiplash] warning:"));
+
+        assert_eq!(validate_all_properties(&multiverse), Ok(()));
+        let report = validate_with_warnings(&multiverse);
+        assert!(report.is_ok());
+        assert_eq!(report.warnings, warnings);
+        assert!(report.to_string().contains("3 warnings:"));
+
+        // A registered property may warn too, and still passes
+        let mut registry = PropertyRegistry::new();
+        registry.register("house_quiet", |_| {
+            Err(PropertyViolation::warning("house_quiet", "the house is too quiet"))
+        });
+        assert_eq!(registry.validate(&multiverse), Ok(()));
+        let report = registry.validate_timed(&multiverse);
+        assert!(report.is_ok() && report.properties.iter().all(|p| p.passed));
+        assert_eq!(report.warnings.len(), 1);
+    }
+
+    #[test]
+    fn test_malformed_beliefs_warn() {
+        use crate::emotional_system::Belief;
+        let mut multiverse = Multiverse::new();
+        let root = multiverse.root_timeline;
+        let vera = multiverse.create_character("Vera Kandros".to_string(), root);
+        let event = multiverse.record_event(Event {
+            id: EventId(0),
+            timeline: root,
+            description: "Vera hears a garbled distress call".to_string(),
+            participants: HashSet::from([vera]),
+            effects: vec![EventEffect::AppraisalTrigger {
+                character: vera,
+                belief: Belief {
+                    likelihood: 0.7,
+                    causal_agent_name: None,
+                    affected_goal_names: vec!["Protect Crew".to_string(), "Survive".to_string()],
+                    goal_congruences: vec![-0.5],
+                    is_incremental: true,
+                    self_caused: false,
+                    praiseworthiness: None,
+                },
+            }],
+            causality_violation: None,
+        });
+
+        let warnings = warn_malformed_beliefs(&multiverse);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(
+            warnings[0].message,
+            format!("{} ignores a belief in event {}: belief names 2 goals but gives 1 congruences", vera, event)
+        );
+        assert_eq!(validate_all_properties(&multiverse), Ok(()));
+    }
+
+    #[test]
+    fn test_timings_sum_every_report() {
+        let mut multiverse = Multiverse::new();
+        let root = multiverse.root_timeline;
+        multiverse.create_character("Vera Kandros".to_string(), root);
+
+        let mut timings = PropertyTimings::new();
+        let mut memory_recall = Duration::ZERO;
+        for _ in 0..3 {
+            let report = validate_all_properties_timed(&multiverse);
+            assert!(report.is_ok());
+            memory_recall += report.properties.iter().find(|p| p.property == "memory_recall_usable").unwrap().elapsed;
+            timings.record(&report);
+        }
+        assert_eq!(timings.elapsed("memory_recall_usable"), memory_recall);
+        assert_eq!(timings.validations(), 3);
+        assert_eq!(timings.slowest().len(), 21);
+        assert_eq!(timings.total(), timings.slowest().iter().map(|(_, elapsed)| *elapsed).sum());
+        assert!(timings.to_string().contains("memory_recall_usable"));
     }
 }
@@ -5,8 +5,27 @@
 //!
 //! Each protagonist perceives reality differently, granting them unique capabilities
 //! that affect which narrative properties apply to them.
+//!
+//! The profiles themselves are data: the thirteen ship as
+//! `data/protagonists.json`, and a writer can hand
+//! [`create_protagonists_from_profiles`] an edited copy, loaded with
+//! [`load_profiles_from_reader`], without touching the code.
+//!
+//! Nor is the story tied to thirteen. A [`CastBuilder`] takes any number of
+//! profiles and hands back a [`Cast`], which finds its members by role
+//! (`cast.get("Memory Merchant")`) rather than by where they sat in a list, so
+//! the threads and relationships written for the thirteen work with any
+//! roster that has the roles they need, and say which one is missing when it
+//! doesn't.
 
+use crate::emotional_system::{EmotionError, EmotionalState, Goal, GoalError};
 use crate::narrative_core::*;
+use crate::properties::CharacterInvariant;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet};
+use std::io::Read;
+use std::sync::OnceLock;
+use thiserror::Error;
 
 /// The 13 protagonist names in the order they're introduced
 pub const PROTAGONIST_NAMES: [&str; 13] = [
@@ -26,18 +45,48 @@ pub const PROTAGONIST_NAMES: [&str; 13] = [
 ];
 
 /// Protagonist-specific abilities that grant narrative exceptions
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ProtagonistProfile {
-    pub name: &'static str,
-    pub title: &'static str,
-    pub description: &'static str,
+    pub name: String,
+    pub title: String,
+    pub description: String,
+    #[serde(default)]
     pub starting_abilities: Vec<Ability>,
     pub narrative_role: NarrativeRole,
-    pub starting_goals: Vec<crate::emotional_system::Goal>,
+    #[serde(default)]
+    pub starting_goals: Vec<Goal>,
+    /// How this protagonist stands with others, by name, before the story
+    /// begins; see [`initialize_relationships`]. A relationship holds both
+    /// ways, so listing it on either side is enough, and names not in the
+    /// cast are passed over
+    #[serde(default)]
+    pub starting_relationships: Vec<(String, RelationshipState)>,
+    /// Rules their gimmick implies, which the cast is held to from creation;
+    /// see [`CharacterInvariant`]
+    #[serde(default)]
+    pub character_invariants: Vec<CharacterInvariant>,
+    /// Tether to another era, for protagonists displaced in time
+    #[serde(default)]
+    pub temporal_anchor: Option<TemporalAnchor>,
+    /// Emotional reactivity, used as the character's `EmotionalState::gain`
+    #[serde(default = "default_emotional_gain")]
+    pub emotional_gain: f64,
+}
+
+fn default_emotional_gain() -> f64 {
+    1.0
+}
+
+impl ProtagonistProfile {
+    /// The role this protagonist plays, as a [`Cast`] knows it: their title
+    /// without its article ("Memory Merchant" for "The Memory Merchant").
+    pub fn role(&self) -> &str {
+        self.title.strip_prefix("The ").unwrap_or(&self.title)
+    }
 }
 
 /// Narrative roles that affect story generation
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum NarrativeRole {
     /// Can navigate timeline branches
     TimelineNavigator,
@@ -67,234 +116,311 @@ pub enum NarrativeRole {
     UniversalNexus,
 }
 
-/// Initialize a multiverse with all 13 protagonists
-pub fn create_thirteen_protagonists(multiverse: &mut Multiverse) -> Vec<CharacterId> {
-    let timeline = multiverse.root_timeline;
-    let mut ids = Vec::new();
+/// What starting abilities are granted by, in the event that grants them.
+pub const STARTING_ABILITIES: &str = "Protagonist profile";
 
-    for profile in protagonist_profiles() {
-        let char_id = multiverse.create_character(profile.name.to_string(), timeline);
+/// The thirteen as shipped, in [`load_profiles_from_reader`]'s format. Copy it
+/// to tweak the cast without recompiling.
+pub const DEFAULT_PROFILES_JSON: &str = include_str!("../data/protagonists.json");
+
+/// Why a set of profiles was refused.
+#[derive(Debug, Error)]
+pub enum ProfileError {
+    /// Not JSON, not readable, or not shaped like a list of profiles (an
+    /// ability or role nobody has heard of, say)
+    #[error("malformed profiles: {0}")]
+    Malformed(#[from] serde_json::Error),
+    #[error("more than one protagonist is named {0:?}")]
+    DuplicateName(String),
+    #[error("more than one protagonist plays the {0}")]
+    DuplicateRole(String),
+    #[error("{profile}'s goal {goal:?} is invalid: {error}")]
+    InvalidGoal {
+        profile: String,
+        goal: String,
+        #[source]
+        error: GoalError,
+    },
+    #[error("{profile} starts with {ability:?} more than once")]
+    DuplicateAbility { profile: String, ability: AbilityKind },
+    #[error("{profile} is {state:?} toward {other}, who has them down as {other_state:?}")]
+    AsymmetricRelationship {
+        profile: String,
+        other: String,
+        state: RelationshipState,
+        other_state: RelationshipState,
+    },
+    #[error("{0} has a starting relationship with themselves")]
+    SelfRelationship(String),
+    #[error("{profile} has {error}")]
+    InvalidGain {
+        profile: String,
+        #[source]
+        error: EmotionError,
+    },
+}
 
-        // Grant starting abilities
-        if let Some(character) = multiverse.characters.get_mut(&char_id) {
-            for ability in profile.starting_abilities {
-                character.abilities.insert(ability);
+/// Reads a JSON list of profiles, such as [`DEFAULT_PROFILES_JSON`], and
+/// checks them with [`validate_profiles`].
+pub fn load_profiles_from_reader(reader: impl Read) -> Result<Vec<ProtagonistProfile>, ProfileError> {
+    let profiles: Vec<ProtagonistProfile> = serde_json::from_reader(reader)?;
+    validate_profiles(&profiles)?;
+    Ok(profiles)
+}
+
+/// Checks that no two profiles share a name or a role, every goal is in range,
+/// nobody starts with two abilities of one kind, every gain is one an
+/// emotional state accepts, and two profiles that list each other agree on
+/// where they stand.
+pub fn validate_profiles(profiles: &[ProtagonistProfile]) -> Result<(), ProfileError> {
+    let mut names = HashSet::new();
+    let mut roles = HashSet::new();
+    let mut relationships = BTreeMap::new();
+    for profile in profiles {
+        if !names.insert(profile.name.as_str()) {
+            return Err(ProfileError::DuplicateName(profile.name.clone()));
+        }
+        if !roles.insert(profile.role()) {
+            return Err(ProfileError::DuplicateRole(profile.role().to_string()));
+        }
+        for goal in &profile.starting_goals {
+            goal.validate().map_err(|error| ProfileError::InvalidGoal {
+                profile: profile.name.clone(),
+                goal: goal.name.clone(),
+                error,
+            })?;
+        }
+        let mut kinds = HashSet::new();
+        if let Some(ability) = profile.starting_abilities.iter().map(Ability::kind).find(|&kind| !kinds.insert(kind)) {
+            return Err(ProfileError::DuplicateAbility {
+                profile: profile.name.clone(),
+                ability,
+            });
+        }
+        EmotionalState::check_gain(profile.emotional_gain).map_err(|error| ProfileError::InvalidGain {
+            profile: profile.name.clone(),
+            error,
+        })?;
+        for (other, state) in &profile.starting_relationships {
+            if *other == profile.name {
+                return Err(ProfileError::SelfRelationship(profile.name.clone()));
             }
-            for goal in profile.starting_goals {
-                character.emotional_state.add_goal(goal);
+            relationships.insert((profile.name.as_str(), other.as_str()), *state);
+        }
+    }
+    for (&(profile, other), &state) in &relationships {
+        match relationships.get(&(other, profile)) {
+            Some(&other_state) if other_state != state => {
+                return Err(ProfileError::AsymmetricRelationship {
+                    profile: profile.to_string(),
+                    other: other.to_string(),
+                    state,
+                    other_state,
+                })
             }
+            _ => {}
         }
-
-        ids.push(char_id);
     }
+    Ok(())
+}
 
-    ids
+/// Initialize a multiverse with all 13 protagonists.
+///
+/// Their starting abilities are granted together, in one event in the root
+/// timeline, so every ability has an event behind it from the start.
+pub fn create_thirteen_protagonists(multiverse: &mut Multiverse) -> Vec<CharacterId> {
+    let cast = CastBuilder::thirteen().build(multiverse).expect("the default profiles are valid");
+    cast.ids().to_vec()
 }
 
-/// Get the detailed profiles for all 13 protagonists
-pub fn protagonist_profiles() -> Vec<ProtagonistProfile> {
-    use crate::emotional_system::Goal;
-    vec![
-        ProtagonistProfile {
-            name: "Vera Kandros",
-            title: "The Fold Captain",
-            description: "Captain of the Errant Promise. Experiences all possible timeline \
-                         branches of her decisions simultaneously due to Fold Drive exposure.",
-            starting_abilities: vec![Ability::TimelinePerception],
-            narrative_role: NarrativeRole::TimelineNavigator,
-            starting_goals: vec![Goal::new("Protect Crew".to_string(), 1.0, true)],
-        },
-        ProtagonistProfile {
-            name: "Khelis Tev",
-            title: "The Memory Merchant",
-            description: "Memory broker in the Dark Spoke. Has perfect memory of things that \
-                         didn't happen to them; cannot form their own memories.",
-            starting_abilities: vec![],
-            narrative_role: NarrativeRole::MemoryManipulator,
-            starting_goals: vec![Goal::new("Acquire Unique Memories".to_string(), 0.7, false)],
-        },
-        ProtagonistProfile {
-            name: "Dr. Elian Saros",
-            title: "The Probabilist",
-            description: "Mathematician modeling the Ring's future using probability clouds. \
-                         Can calculate the 'most likely' timeline.",
-            starting_abilities: vec![Ability::Precognition],
-            narrative_role: NarrativeRole::FutureSeer,
-            starting_goals: vec![Goal::new("Predict Great Incoherence".to_string(), 0.9, false)],
-        },
-        ProtagonistProfile {
-            name: "Nameless",
-            title: "The Gate-Touched",
-            description: "A drifter who exists as a causality paradox. Technically, they were \
-                         never born—their past has been rewritten so many times by Living Gates \
-                         that they exist outside normal causality.",
-            starting_abilities: vec![Ability::TimelinePerception, Ability::LoopMemory],
-            narrative_role: NarrativeRole::CausalityAnomaly,
-            starting_goals: vec![Goal::new("Find Origin".to_string(), 0.8, false)],
-        },
-        ProtagonistProfile {
-            name: "Corvus Shal",
-            title: "The Lattice Singer",
-            description: "Ansible operator who hears the network's consciousness. The Lattice \
-                         has chosen them as its 'voice' to the physical world.",
-            starting_abilities: vec![],
-            narrative_role: NarrativeRole::LatticeInterface,
-            starting_goals: vec![Goal::new("Harmonize Lattice".to_string(), 0.6, true)],
-        },
-        ProtagonistProfile {
-            name: "Yash-Tel",
-            title: "The Shimmer Navigator",
-            description: "Vrynn pilot who exists in quantum superposition even when not traveling. \
-                         Slowly merging with their parallel selves.",
-            starting_abilities: vec![Ability::TimelinePerception],
-            narrative_role: NarrativeRole::QuantumEntity,
-            starting_goals: vec![Goal::new("Maintain Coherence".to_string(), 1.0, true)],
-        },
-        ProtagonistProfile {
-            name: "Riven Blackwood",
-            title: "The Gunslinger",
-            description: "Bounty hunter with a semi-sentient Precursor revolver that fires \
-                         bullets backward through time. Being hunted by their own future self.",
-            starting_abilities: vec![Ability::CausalityHacking],
-            narrative_role: NarrativeRole::CausalityManipulator,
-            starting_goals: vec![Goal::new("Survive Future Self".to_string(), 1.0, true)],
-        },
-        ProtagonistProfile {
-            name: "The Cartographer",
-            title: "The Ring Historian",
-            description: "Obsessively mapping Dead Zones and causality failures. Immune to \
-                         memory manipulation; remembers the original timeline before the Incoherence.",
-            starting_abilities: vec![Ability::MemoryImmunity, Ability::LoopMemory],
-            narrative_role: NarrativeRole::HistoryKeeper,
-            starting_goals: vec![Goal::new("Map All Dead Zones".to_string(), 0.8, false)],
-        },
-        ProtagonistProfile {
-            name: "Synthesis",
-            title: "The Hybrid Consciousness",
-            description: "A merged entity of seven minds connected through illegal neural lace. \
-                         Seven bodies, one mind (usually). The bodies are diverging into separate people.",
-            starting_abilities: vec![],
-            narrative_role: NarrativeRole::CollectiveEntity,
-            starting_goals: vec![Goal::new("Achieve Individualism".to_string(), 0.5, false)],
-        },
-        ProtagonistProfile {
-            name: "Mara Vex",
-            title: "The Precognitive",
-            description: "Afflicted with precognitive flashes showing contradictory futures. \
-                         Cannot distinguish which future will occur. Living Gates feed her information \
-                         from her future selves.",
-            starting_abilities: vec![Ability::Precognition],
-            narrative_role: NarrativeRole::PrecognitiveOracle,
-            starting_goals: vec![Goal::new("Find True Future".to_string(), 0.9, false)],
-        },
-        ProtagonistProfile {
-            name: "Kor-Valeth",
-            title: "The Time-Exiled Warrior",
-            description: "A warrior from 1,000 years in the Ring's past. Anchored to their original \
-                         time; slowly being pulled back. Carries Precursor activation codes.",
-            starting_abilities: vec![],
-            narrative_role: NarrativeRole::TemporalExile,
-            starting_goals: vec![Goal::new("Return to Past".to_string(), 1.0, false)],
-        },
-        ProtagonistProfile {
-            name: "Dr. Theo Lux",
-            title: "The Reality Hacker",
-            description: "Rogue physicist who treats spacetime like code. Can create localized \
-                         causality inversions. Secretly caused the Great Incoherence.",
-            starting_abilities: vec![Ability::CausalityHacking],
-            narrative_role: NarrativeRole::RealityHacker,
-            starting_goals: vec![Goal::new("Rewrite Reality".to_string(), 0.7, false)],
-        },
-        ProtagonistProfile {
-            name: "The Conductor",
-            title: "The Mysterious Unifier",
-            description: "Unknown identity; appears differently to each protagonist. Exists \
-                         simultaneously in all timelines. The only truly coherent entity.",
-            starting_abilities: vec![
-                Ability::TimelinePerception,
-                Ability::Precognition,
-                Ability::MemoryImmunity,
-                Ability::LoopMemory,
-            ],
-            narrative_role: NarrativeRole::UniversalNexus,
-            starting_goals: vec![Goal::new("Prevent Ring Collapse".to_string(), 1.0, true)],
-        },
-    ]
+/// Creates a protagonist in the root timeline for each profile, in order, as
+/// [`create_thirteen_protagonists`] does for the default cast. Profiles that
+/// fail [`validate_profiles`] are refused before anyone is created.
+pub fn create_protagonists_from_profiles(
+    multiverse: &mut Multiverse,
+    profiles: &[ProtagonistProfile],
+) -> Result<Vec<CharacterId>, ProfileError> {
+    let cast = CastBuilder::new().profiles(profiles.iter().cloned()).build(multiverse)?;
+    Ok(cast.ids().to_vec())
 }
 
-/// Get a protagonist's starting relationships with others
-pub fn initialize_relationships(multiverse: &mut Multiverse, char_ids: &[CharacterId]) {
-    // Vera and Corvus start as allies (both trying to understand the Incoherence)
-    if char_ids.len() >= 5 {
-        add_relationship(multiverse, char_ids[0], char_ids[4], RelationshipState::Allied);
-    }
+/// A role the story needed that nobody in the cast plays.
+#[derive(Debug, Clone, PartialEq, Error)]
+#[error("the cast has no {role}")]
+pub struct CastError {
+    pub role: String,
+}
 
-    // Khelis and The Cartographer are rivals (memory vs history)
-    if char_ids.len() >= 8 {
-        add_relationship(
-            multiverse,
-            char_ids[1],
-            char_ids[7],
-            RelationshipState::Distrustful,
-        );
+/// The protagonists of a story, found by the role they play.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Cast {
+    /// Everyone, in the order their profiles were given
+    ids: Vec<CharacterId>,
+    roles: BTreeMap<String, CharacterId>,
+    /// Starting relationships between members, each pair once (lower id first)
+    relationships: BTreeMap<(CharacterId, CharacterId), RelationshipState>,
+}
+
+impl Cast {
+    /// Whoever plays `role` ("Fold Captain", "Memory Merchant"...), if anyone
+    /// does.
+    pub fn get(&self, role: &str) -> Option<CharacterId> {
+        self.roles.get(role).copied()
     }
 
-    // Dr. Saros and Dr. Lux are hostile (Saros suspects Lux caused the Incoherence)
-    if char_ids.len() >= 12 {
-        add_relationship(
-            multiverse,
-            char_ids[2],
-            char_ids[11],
-            RelationshipState::Hostile,
-        );
+    /// Whoever plays `role`, or an error naming it for a story that can't go
+    /// on without them.
+    pub fn require(&self, role: &str) -> Result<CharacterId, CastError> {
+        self.get(role).ok_or_else(|| CastError { role: role.to_string() })
     }
 
-    // Nameless and The Conductor have a mysterious connection
-    if char_ids.len() >= 13 {
-        add_relationship(
-            multiverse,
-            char_ids[3],
-            char_ids[12],
-            RelationshipState::Neutral,
-        );
+    /// Everyone in the cast, in the order they were created.
+    pub fn ids(&self) -> &[CharacterId] {
+        &self.ids
     }
 
-    // Riven is being hunted by their future self (self-hostility!)
-    // This would require duplicating Riven, which we'll handle in story scenarios
+    /// Every role and who plays it, by role.
+    pub fn roles(&self) -> impl Iterator<Item = (&str, CharacterId)> {
+        self.roles.iter().map(|(role, id)| (role.as_str(), *id))
+    }
 
-    // Synthesis views The Cartographer as an ally (shared interest in preserving coherence)
-    if char_ids.len() >= 9 {
-        add_relationship(
-            multiverse,
-            char_ids[8],
-            char_ids[7],
-            RelationshipState::Friendly,
-        );
+    pub fn len(&self) -> usize {
+        self.ids.len()
     }
 
-    // Mara and Corvus are friendly (both receive prophecies)
-    if char_ids.len() >= 10 {
-        add_relationship(
-            multiverse,
-            char_ids[9],
-            char_ids[4],
-            RelationshipState::Friendly,
-        );
+    pub fn is_empty(&self) -> bool {
+        self.ids.is_empty()
     }
 }
 
-fn add_relationship(
-    multiverse: &mut Multiverse,
-    char1: CharacterId,
-    char2: CharacterId,
-    state: RelationshipState,
-) {
-    if let Some(c1) = multiverse.characters.get_mut(&char1) {
-        c1.relationships.insert(char2, state);
+/// Gathers profiles for a [`Cast`] of any size, then creates them all at once.
+///
+/// ```
+/// # use propyarn::narrative_core::Multiverse;
+/// # use propyarn::protagonists::{protagonist_profiles, CastBuilder};
+/// let mut multiverse = Multiverse::new();
+/// let cast = CastBuilder::new()
+///     .profiles(protagonist_profiles()[..3].iter().cloned())
+///     .build(&mut multiverse)
+///     .unwrap();
+/// assert!(cast.get("Memory Merchant").is_some());
+/// assert!(cast.require("Gunslinger").is_err());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CastBuilder {
+    profiles: Vec<ProtagonistProfile>,
+}
+
+impl CastBuilder {
+    /// An empty cast, to be filled with [`profile`](Self::profile).
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The thirteen, from [`protagonist_profiles`].
+    pub fn thirteen() -> Self {
+        Self::new().profiles(protagonist_profiles().iter().cloned())
+    }
+
+    pub fn profile(mut self, profile: ProtagonistProfile) -> Self {
+        self.profiles.push(profile);
+        self
+    }
+
+    pub fn profiles(mut self, profiles: impl IntoIterator<Item = ProtagonistProfile>) -> Self {
+        self.profiles.extend(profiles);
+        self
+    }
+
+    /// Creates a protagonist in the root timeline for each profile, in order.
+    /// Profiles that fail [`validate_profiles`] are refused before anyone is
+    /// created.
+    pub fn build(self, multiverse: &mut Multiverse) -> Result<Cast, ProfileError> {
+        validate_profiles(&self.profiles)?;
+        let timeline = multiverse.root_timeline;
+        let mut cast = Cast::default();
+        let mut grants = Vec::new();
+
+        for profile in &self.profiles {
+            let char_id = multiverse.create_character(profile.name.clone(), timeline);
+            grants.extend(profile.starting_abilities.iter().map(|ability| (char_id, ability.clone())));
+
+            if let Some(character) = multiverse.characters.get_mut(&char_id) {
+                for goal in &profile.starting_goals {
+                    character.emotional_state.add_goal(goal.clone());
+                }
+                character.temporal_anchor = profile.temporal_anchor.clone();
+                // The displaced are pulled back to where the story starts
+                if character.temporal_anchor.is_some() {
+                    character.anchor_timeline = Some(timeline);
+                }
+                // Validated above, so this can't be refused
+                let _ = character.emotional_state.set_gain(profile.emotional_gain);
+            }
+
+            for &invariant in &profile.character_invariants {
+                multiverse.register_character_invariant(char_id, invariant);
+            }
+
+            cast.ids.push(char_id);
+            cast.roles.insert(profile.role().to_string(), char_id);
+        }
+
+        let names: BTreeMap<&str, CharacterId> =
+            self.profiles.iter().map(|p| p.name.as_str()).zip(cast.ids.iter().copied()).collect();
+        for (profile, &id) in self.profiles.iter().zip(&cast.ids) {
+            for (other, state) in &profile.starting_relationships {
+                if let Some(&other) = names.get(other.as_str()) {
+                    cast.relationships.insert((id.min(other), id.max(other)), *state);
+                }
+            }
+        }
+
+        multiverse.grant_abilities(timeline, STARTING_ABILITIES, grants);
+        Ok(cast)
     }
-    if let Some(c2) = multiverse.characters.get_mut(&char2) {
-        c2.relationships.insert(char1, state);
+}
+
+/// Get the detailed profiles for all 13 protagonists, as shipped in
+/// [`DEFAULT_PROFILES_JSON`]
+pub fn protagonist_profiles() -> &'static [ProtagonistProfile] {
+    static PROFILES: OnceLock<Vec<ProtagonistProfile>> = OnceLock::new();
+    PROFILES.get_or_init(|| {
+        load_profiles_from_reader(DEFAULT_PROFILES_JSON.as_bytes()).expect("the embedded profiles are valid")
+    })
+}
+
+/// What the cast's starting relationships are for. Installing them is one
+/// event in the root timeline, and this is its description.
+pub const STARTING_RELATIONSHIPS: &str = "Old ties and grudges, from before the story begins";
+
+/// Sets up the cast's [`starting_relationships`](ProtagonistProfile::starting_relationships),
+/// each pair both ways, in one event in the root timeline so every
+/// relationship has a history behind it. Returns that event, or `None` if
+/// the cast has no relationships to set up.
+pub fn initialize_relationships(multiverse: &mut Multiverse, cast: &Cast) -> Option<EventId> {
+    if cast.relationships.is_empty() {
+        return None;
     }
+    let participants = cast.relationships.keys().flat_map(|&(c1, c2)| [c1, c2]).collect();
+    let effects = cast
+        .relationships
+        .iter()
+        .map(|(&(character1, character2), &new_state)| EventEffect::RelationshipChange {
+            character1,
+            character2,
+            new_state,
+        })
+        .collect();
+    let timeline = multiverse.root_timeline;
+    Some(multiverse.record_event(Event {
+        id: EventId(0),
+        timeline,
+        description: STARTING_RELATIONSHIPS.to_string(),
+        participants,
+        effects,
+        causality_violation: None,
+    }))
 }
 
 #[cfg(test)]
@@ -309,18 +435,267 @@ mod tests {
         assert_eq!(char_ids.len(), 13);
         assert_eq!(multiverse.characters.len(), 13);
 
-        // Verify Vera has TimelinePerception
-        assert!(multiverse.characters[&char_ids[0]]
-            .abilities
-            .contains(&Ability::TimelinePerception));
+        // Verify Vera has TimelinePerception, limited to her own branches
+        assert_eq!(
+            multiverse.characters[&char_ids[0]].ability(AbilityKind::TimelinePerception),
+            Some(&Ability::sibling_perception())
+        );
 
         // Verify Nameless has both TimelinePerception and LoopMemory
-        assert!(multiverse.characters[&char_ids[3]]
-            .abilities
-            .contains(&Ability::TimelinePerception));
-        assert!(multiverse.characters[&char_ids[3]]
-            .abilities
-            .contains(&Ability::LoopMemory));
+        assert!(multiverse.characters[&char_ids[3]].has_ability(AbilityKind::TimelinePerception));
+        assert!(multiverse.characters[&char_ids[3]].has_ability(AbilityKind::LoopMemory));
+
+        // Corvus is the Lattice Singer
+        assert!(multiverse.characters[&char_ids[4]].has_ability(AbilityKind::AnsibleLink));
+
+        // Kor-Valeth arrives firmly anchored to their era of origin
+        assert_eq!(multiverse.remaining_stability(char_ids[10]), Some(1.0));
+        assert_eq!(multiverse.remaining_stability(char_ids[0]), None);
+
+        // Every documented ability came from the one setup event
+        assert_eq!(multiverse.events.len(), 1);
+        let setup = multiverse.events.values().next().unwrap();
+        for (id, profile) in char_ids.iter().zip(protagonist_profiles()) {
+            for ability in &profile.starting_abilities {
+                assert_eq!(multiverse.characters[id].ability(ability.kind()), Some(ability));
+                assert!(setup.effects.contains(&EventEffect::AbilityGained {
+                    character: *id,
+                    ability: ability.clone(),
+                    mechanism: STARTING_ABILITIES.to_string(),
+                }));
+            }
+        }
+        assert_eq!(crate::properties::validate_all_properties(&multiverse), Ok(()));
+    }
+
+    #[test]
+    fn test_reactive_protagonists_feel_more() {
+        use crate::emotional_system::{Belief, Goal};
+        let mut multiverse = Multiverse::new();
+        let cast = CastBuilder::thirteen().build(&mut multiverse).unwrap();
+        let (mara, cartographer) = (cast.get("Precognitive").unwrap(), cast.get("Ring Historian").unwrap());
+
+        // The same bad news, against the same goal
+        let belief = Belief {
+            likelihood: 1.0,
+            causal_agent_name: None,
+            affected_goal_names: vec!["Ring Survives".to_string()],
+            goal_congruences: vec![-0.8],
+            is_incremental: false,
+            self_caused: false,
+            praiseworthiness: None,
+        };
+        let magnitude = |character: CharacterId, multiverse: &mut Multiverse| {
+            let state = &mut multiverse.characters.get_mut(&character).unwrap().emotional_state;
+            state.add_goal(Goal::new("Ring Survives".to_string(), 1.0, false));
+            state.appraise(&belief);
+            state.get_pad().iter().map(|x| x * x).sum::<f64>().sqrt()
+        };
+
+        let overwhelmed = magnitude(mara, &mut multiverse);
+        let stoic = magnitude(cartographer, &mut multiverse);
+        assert!(overwhelmed > stoic, "Mara {} vs the Cartographer {}", overwhelmed, stoic);
+        assert!(stoic > 0.0);
+    }
+
+    #[test]
+    fn test_profiles_load_from_an_edited_file() {
+        // A writer cuts the Conductor and makes Vera the Lattice's voice instead
+        let mut profiles: Vec<ProtagonistProfile> = serde_json::from_str(DEFAULT_PROFILES_JSON).unwrap();
+        profiles.pop();
+        profiles[0].starting_abilities = vec![Ability::ansible_link(), Ability::precognition_within(5)];
+        profiles[0].starting_goals.push(Goal::new("Find the Conductor".to_string(), 0.6, false));
+        let path = std::env::temp_dir().join(format!("propyarn-profiles-{}.json", std::process::id()));
+        std::fs::write(&path, serde_json::to_string_pretty(&profiles).unwrap()).unwrap();
+        let loaded = load_profiles_from_reader(std::fs::File::open(&path).unwrap());
+        std::fs::remove_file(&path).unwrap();
+        let loaded = loaded.unwrap();
+        assert_eq!(loaded, profiles);
+
+        let mut multiverse = Multiverse::new();
+        let char_ids = create_protagonists_from_profiles(&mut multiverse, &loaded).unwrap();
+        assert_eq!(char_ids.len(), 12);
+        assert_eq!(multiverse.character_named("The Conductor"), None);
+        let vera = &multiverse.characters[&char_ids[0]];
+        assert!(vera.has_ability(AbilityKind::AnsibleLink));
+        assert_eq!(vera.ability(AbilityKind::Precognition), Some(&Ability::precognition_within(5)));
+        assert!(!vera.has_ability(AbilityKind::TimelinePerception));
+        assert!(vera.emotional_state.goals.contains_key("Find the Conductor"));
+        assert_eq!(crate::properties::validate_all_properties(&multiverse), Ok(()));
+    }
+
+    #[test]
+    fn test_bad_profiles_are_refused() {
+        let refused = |edit: fn(&mut Vec<ProtagonistProfile>)| {
+            let mut profiles = protagonist_profiles().to_vec();
+            edit(&mut profiles);
+            let mut multiverse = Multiverse::new();
+            let error = create_protagonists_from_profiles(&mut multiverse, &profiles).unwrap_err();
+            assert!(multiverse.characters.is_empty(), "nobody is created from bad profiles");
+            error
+        };
+        assert!(matches!(
+            refused(|p| p[1].name = p[0].name.clone()),
+            ProfileError::DuplicateName(name) if name == "Vera Kandros"
+        ));
+        assert!(matches!(
+            refused(|p| p[2].starting_goals[0].utility = 1.5),
+            ProfileError::InvalidGoal { error: GoalError::UtilityOutOfRange(_), .. }
+        ));
+        assert!(matches!(
+            refused(|p| p[3].starting_abilities.push(Ability::loop_memory())),
+            ProfileError::DuplicateAbility { ability: AbilityKind::LoopMemory, .. }
+        ));
+        assert!(matches!(refused(|p| p[9].emotional_gain = 0.0), ProfileError::InvalidGain { .. }));
+
+        // Abilities are a closed set
+        let unknown = DEFAULT_PROFILES_JSON.replacen("\"AnsibleLink\"", "\"Flight\"", 1);
+        assert!(matches!(load_profiles_from_reader(unknown.as_bytes()), Err(ProfileError::Malformed(_))));
+    }
+
+    #[test]
+    fn test_casts_of_any_size() {
+        use crate::story_scenarios::{thread_alpha_memory_of_god, thread_beta_gunslinger_paradox};
+
+        // A three-hander: the Captain, the Merchant and the Historian
+        let mut trio = Multiverse::new();
+        let profiles = protagonist_profiles();
+        let cast = CastBuilder::new()
+            .profile(profiles[0].clone())
+            .profile(profiles[1].clone())
+            .profile(profiles[7].clone())
+            .build(&mut trio)
+            .unwrap();
+        assert_eq!(cast.len(), 3);
+        let (khelis, cartographer) = (cast.get("Memory Merchant").unwrap(), cast.get("Ring Historian").unwrap());
+        assert_eq!(trio.characters[&khelis].name, "Khelis Tev");
+        assert_eq!(cast.get("Gunslinger"), None);
+        initialize_relationships(&mut trio, &cast);
+        assert_eq!(trio.characters[&khelis].relationships[&cartographer], RelationshipState::Distrustful);
+        assert_eq!(crate::properties::validate_all_properties(&trio), Ok(()));
+
+        // Threads that need someone the trio lacks say who, and leave the story alone
+        let events = trio.events.len();
+        let missing = thread_alpha_memory_of_god(&mut trio, &cast).unwrap_err();
+        assert_eq!(missing.to_string(), "the cast has no Probabilist");
+        let missing = thread_beta_gunslinger_paradox(&mut trio, &cast).unwrap_err();
+        assert_eq!(missing, CastError { role: "Gunslinger".to_string() });
+        assert_eq!(trio.events.len(), events);
+
+        // The thirteen and a dozen deckhands besides
+        let mut crowd = Multiverse::new();
+        let deckhands = (1..=12).map(|i| ProtagonistProfile {
+            name: format!("Deckhand {}", i),
+            title: format!("The Errant Promise's Deckhand #{}", i),
+            ..profiles[0].clone()
+        });
+        let cast = CastBuilder::thirteen().profiles(deckhands).build(&mut crowd).unwrap();
+        assert_eq!(cast.len(), 25);
+        assert_eq!(cast.roles().count(), 25);
+        let twelfth = cast.get("Errant Promise's Deckhand #12").unwrap();
+        assert_eq!(crowd.characters[&twelfth].name, "Deckhand 12");
+        assert!(crowd.characters[&twelfth].has_ability(AbilityKind::TimelinePerception));
+        initialize_relationships(&mut crowd, &cast);
+        thread_alpha_memory_of_god(&mut crowd, &cast).unwrap();
+        assert_eq!(crate::properties::validate_all_properties(&crowd), Ok(()));
+
+        // Roles are how the cast is found, so no two may share one
+        let twins = CastBuilder::thirteen().profile(ProtagonistProfile {
+            name: "Vera's Echo".to_string(),
+            ..profiles[0].clone()
+        });
+        assert!(matches!(
+            twins.build(&mut Multiverse::new()),
+            Err(ProfileError::DuplicateRole(role)) if role == "Fold Captain"
+        ));
+    }
+
+    #[test]
+    fn test_every_pair_starts_somewhere() {
+        let mut multiverse = Multiverse::new();
+        let cast = CastBuilder::thirteen().build(&mut multiverse).unwrap();
+        let history = initialize_relationships(&mut multiverse, &cast).unwrap();
+        assert_eq!(multiverse.events[&history].effects.len(), 13 * 12 / 2);
+
+        for &one in cast.ids() {
+            let relationships = &multiverse.characters[&one].relationships;
+            assert_eq!(relationships.len(), 12, "{} is missing someone", multiverse.characters[&one].name);
+            assert!(!relationships.contains_key(&one));
+            for (other, state) in relationships {
+                assert_eq!(multiverse.characters[other].relationships.get(&one), Some(state));
+            }
+        }
+        let between = |a: &str, b: &str| {
+            multiverse.characters[&cast.get(a).unwrap()].relationships[&cast.get(b).unwrap()]
+        };
+        assert_eq!(between("Fold Captain", "Lattice Singer"), RelationshipState::Allied);
+        assert_eq!(between("Reality Hacker", "Probabilist"), RelationshipState::Hostile);
+        assert_eq!(between("Gate-Touched", "Mysterious Unifier"), RelationshipState::Neutral);
+        assert_eq!(crate::properties::validate_all_properties(&multiverse), Ok(()));
+
+        // Where both sides list each other, they have to agree
+        let mut profiles = protagonist_profiles().to_vec();
+        let vera = &mut profiles[0].starting_relationships;
+        vera.iter_mut().find(|(name, _)| name == "Corvus Shal").unwrap().1 = RelationshipState::Hostile;
+        assert!(matches!(
+            validate_profiles(&profiles),
+            Err(ProfileError::AsymmetricRelationship { state: RelationshipState::Allied, .. })
+        ));
+        // ...but one side is enough
+        profiles[0].starting_relationships.retain(|(name, _)| name != "Corvus Shal");
+        let mut multiverse = Multiverse::new();
+        let cast = CastBuilder::new().profiles(profiles).build(&mut multiverse).unwrap();
+        initialize_relationships(&mut multiverse, &cast);
+        let (vera, corvus) = (cast.get("Fold Captain").unwrap(), cast.get("Lattice Singer").unwrap());
+        assert_eq!(multiverse.characters[&vera].relationships[&corvus], RelationshipState::Allied);
+    }
+
+    #[test]
+    fn test_gimmicks_are_enforced() {
+        use crate::properties::{validate_all_properties, ViolationContext};
+        let mut multiverse = Multiverse::new();
+        let cast = CastBuilder::thirteen().build(&mut multiverse).unwrap();
+        let root = multiverse.root_timeline;
+        let khelis = cast.require("Memory Merchant").unwrap();
+        let cartographer = cast.require("Ring Historian").unwrap();
+        let conductor = cast.require("Mysterious Unifier").unwrap();
+        assert!(multiverse.character_invariants.contains(&(cartographer, CharacterInvariant::NeverLosesMemories)));
+        assert!(multiverse.character_invariants.contains(&(conductor, CharacterInvariant::Omnipresent)));
+
+        // The Cartographer is shown the Ring as it was, and a branch splits off
+        let map = multiverse.create_memory(EventId(0), root, MemoryProvenance::Forged {
+            forger: "Gate Cult".to_string(),
+        });
+        let shown = multiverse.record_event(Event {
+            id: EventId(0),
+            timeline: root,
+            description: "The Cartographer studies a chart of the original Ring".to_string(),
+            participants: HashSet::from([cartographer]),
+            effects: vec![EventEffect::MemoryTransfer { memory: map, from: None, to: cartographer, exclusive: false }],
+            causality_violation: None,
+        });
+        let branch = multiverse.create_timeline_branch(root, shown);
+        assert_eq!(validate_all_properties(&multiverse), Ok(()));
+
+        // Somehow the chart slips their mind
+        let mut forgetful = multiverse.clone();
+        forgetful.characters.get_mut(&cartographer).unwrap().memories.remove(&map);
+        let violation = validate_all_properties(&forgetful).unwrap_err();
+        assert_eq!(violation.property, "character_invariants");
+        assert!(violation.message.starts_with("The Cartographer no longer holds"), "{}", violation);
+        assert_eq!(violation.context, vec![ViolationContext::Character(cartographer)]);
+
+        // The Conductor is somewhere they can't be: absent
+        let mut absent = multiverse.clone();
+        absent.timelines.get_mut(&branch).unwrap().characters.remove(&conductor);
+        let violation = validate_all_properties(&absent).unwrap_err();
+        assert!(violation.message.starts_with("The Conductor is missing from"), "{}", violation);
+
+        // Khelis has no first-hand memories; a forger giving them one is caught too
+        let mut witness = multiverse.clone();
+        witness.create_memory(shown, root, MemoryProvenance::Witnessed { character: khelis });
+        let violation = crate::properties::prop_character_invariants(&witness).unwrap_err();
+        assert!(violation.message.starts_with("Khelis Tev witnessed"), "{}", violation);
     }
 
     #[test]
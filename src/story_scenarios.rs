@@ -8,11 +8,17 @@
 //! - **Thread Delta**: The Lattice Prophecy (Thirteen must become One)
 //!
 //! Each scenario demonstrates complex narrative properties being validated.
+//!
+//! The threads find their characters in a [`Cast`] by role, so they run with
+//! any roster that has the roles they need, and fail with a [`CastError`]
+//! naming the first one missing, before recording anything, when it doesn't.
 
 use crate::narrative_core::*;
 use crate::protagonists::*;
 use crate::properties::*;
 use crate::emotional_system::*;
+use crate::observers::MultiverseObserver;
+use std::collections::HashMap;
 
 /// ## Thread Alpha: The Memory of God
 ///
@@ -22,12 +28,12 @@ use crate::emotional_system::*;
 ///
 /// **Branching Point**: Trust Cartographer (destroy memory), trust Conductor (trade memory),
 /// or trust Saros (decode it).
-pub fn thread_alpha_memory_of_god(multiverse: &mut Multiverse, char_ids: &[CharacterId]) {
-    let khelis = char_ids[1]; // Memory Merchant
-    let vera = char_ids[0]; // Fold Captain
-    let _saros = char_ids[2]; // Probabilist
-    let cartographer = char_ids[7]; // Ring Historian
-    let conductor = char_ids[12]; // Mysterious Unifier
+pub fn thread_alpha_memory_of_god(multiverse: &mut Multiverse, cast: &Cast) -> Result<(), CastError> {
+    let khelis = cast.require("Memory Merchant")?;
+    let vera = cast.require("Fold Captain")?;
+    let _saros = cast.require("Probabilist")?;
+    let cartographer = cast.require("Ring Historian")?;
+    let conductor = cast.require("Mysterious Unifier")?;
 
     let timeline = multiverse.root_timeline;
 
@@ -42,21 +48,23 @@ pub fn thread_alpha_memory_of_god(multiverse: &mut Multiverse, char_ids: &[Chara
         participants: vec![khelis].into_iter().collect(),
         effects: vec![EventEffect::AppraisalTrigger {
             character: khelis,
-            belief: Belief {
-                likelihood: 1.0,
-                causal_agent_name: None,
-                affected_goal_names: vec!["Acquire Unique Memories".to_string()],
-                goal_congruences: vec![0.5], // Progress towards the goal
-                is_incremental: true,
-            },
+            belief: Belief::builder()
+                .likelihood(1.0)
+                .affects("Acquire Unique Memories", 0.5) // Progress towards the goal
+                .incremental()
+                .build()
+                .expect("Khelis's discovery is a well-formed belief"),
         }],
         causality_violation: None,
     });
 
-    // Create the Precursor memory (source is from before the Incoherence)
+    // Create the Precursor memory (source is from before the Incoherence).
+    // Nobody knows who made it, but the story admits someone did.
+    multiverse.register_forger("Unknown Precursor Entity");
     let precursor_memory = Memory {
         id: MemoryId(1000),
         event: discovery_event,
+        content: MemoryContent::Event,
         source_timeline: timeline,
         provenance: MemoryProvenance::Forged {
             forger: "Unknown Precursor Entity".to_string(),
@@ -78,6 +86,7 @@ pub fn thread_alpha_memory_of_god(multiverse: &mut Multiverse, char_ids: &[Chara
                 memory: MemoryId(1000),
                 from: None,
                 to: khelis,
+                exclusive: false,
             },
             EventEffect::KnowledgeGained {
                 character: khelis,
@@ -104,13 +113,13 @@ pub fn thread_alpha_memory_of_god(multiverse: &mut Multiverse, char_ids: &[Chara
             },
             EventEffect::AppraisalTrigger {
                 character: vera,
-                belief: Belief {
-                    likelihood: 0.2,
-                    causal_agent_name: Some("Khelis Tev".to_string()),
-                    affected_goal_names: vec!["Protect Crew".to_string()],
-                    goal_congruences: vec![-0.1], // Taking a risk
-                    is_incremental: true,
-                },
+                belief: Belief::builder()
+                    .likelihood(0.2)
+                    .caused_by("Khelis Tev")
+                    .affects("Protect Crew", -0.1) // Taking a risk
+                    .incremental()
+                    .build()
+                    .expect("Vera's risk is a well-formed belief"),
             },
         ],
         causality_violation: None,
@@ -165,6 +174,7 @@ pub fn thread_alpha_memory_of_god(multiverse: &mut Multiverse, char_ids: &[Chara
     println!("The Cartographer says it's a forgery.");
     println!("The Conductor offers Khelis their lost identity in exchange.");
     println!("\nThree timelines diverge from this moment...\n");
+    Ok(())
 }
 
 /// ## Thread Beta: The Gunslinger's Paradox
@@ -176,24 +186,33 @@ pub fn thread_alpha_memory_of_god(multiverse: &mut Multiverse, char_ids: &[Chara
 ///
 /// **Branching Point**: Trust future-Riven (avoid Corvus), trust Mara (kill Corvus),
 /// or seek Kor-Valeth (learn the gun's true purpose).
-pub fn thread_beta_gunslinger_paradox(multiverse: &mut Multiverse, char_ids: &[CharacterId]) {
-    let riven = char_ids[6]; // Gunslinger
-    let mara = char_ids[9]; // Precognitive
-    let _kor_valeth = char_ids[10]; // Time-Exiled Warrior
-    let corvus = char_ids[4]; // Lattice Singer
+pub fn thread_beta_gunslinger_paradox(multiverse: &mut Multiverse, cast: &Cast) -> Result<(), CastError> {
+    let riven = cast.require("Gunslinger")?;
+    let mara = cast.require("Precognitive")?;
+    let kor_valeth = cast.require("Time-Exiled Warrior")?;
+    let corvus = cast.require("Lattice Singer")?;
 
     let timeline = multiverse.root_timeline;
 
     // === ACT 1: Future-Riven appears ===
 
-    // First, we need to create "Future-Riven" as a separate entity
-    let future_riven = multiverse.create_character("Riven Blackwood (Future)".to_string(), timeline);
+    // First, we need to create "Future-Riven" as a separate entity. They carry the
+    // same gun, but none of the present's relationships.
+    let future_riven = multiverse.clone_character(riven, "(Future)").expect("Riven exists");
 
-    // Grant Future-Riven the same abilities
-    if let Some(fr) = multiverse.characters.get_mut(&future_riven) {
-        fr.abilities.insert(Ability::CausalityHacking);
-        fr.abilities.insert(Ability::TimelinePerception); // Knows the future
-    }
+    // Future-Riven has lived through what's to come
+    multiverse.record_event(Event {
+        id: EventId(0),
+        timeline,
+        description: "Future-Riven steps out of the shimmer, remembering the next three days".to_string(),
+        participants: vec![future_riven].into_iter().collect(),
+        effects: vec![EventEffect::AbilityGained {
+            character: future_riven,
+            ability: Ability::timeline_perception(), // Knows the future
+            mechanism: "Lived through the next three days".to_string(),
+        }],
+        causality_violation: None,
+    });
 
     // Event: Future-Riven ambushes Present-Riven
     multiverse.record_event(Event {
@@ -214,6 +233,15 @@ pub fn thread_beta_gunslinger_paradox(multiverse: &mut Multiverse, char_ids: &[C
                 character2: corvus,
                 new_state: RelationshipState::Neutral, // Riven now wary
             },
+            EventEffect::AppraisalTrigger {
+                character: riven,
+                belief: Belief::builder()
+                    .likelihood(0.9)
+                    .affects("Survive Future Self", -0.8) // Their own gun, pointed back at them
+                    .incremental()
+                    .build()
+                    .expect("Future-Riven's threat is a well-formed belief"),
+            },
         ],
         causality_violation: Some(CausalityViolation::EffectBeforeCause {
             mechanism: "Precursor Time-Weapon (Future-Riven's gun)".to_string(),
@@ -227,6 +255,9 @@ pub fn thread_beta_gunslinger_paradox(multiverse: &mut Multiverse, char_ids: &[C
 
     // === ACT 2: Mara's Contradictory Vision ===
 
+    // Corvus can't both live and die, but Mara sees both
+    multiverse.declare_mutually_exclusive("corvus_lives", "corvus_dies");
+
     multiverse.record_event(Event {
         id: EventId(0),
         timeline,
@@ -236,10 +267,29 @@ pub fn thread_beta_gunslinger_paradox(multiverse: &mut Multiverse, char_ids: &[C
                      Riven. You must pull the trigger.'"
             .to_string(),
         participants: vec![mara, riven].into_iter().collect(),
-        effects: vec![EventEffect::KnowledgeGained {
-            character: riven,
-            flag: "mara_says_must_kill_corvus".to_string(),
-        }],
+        effects: vec![
+            EventEffect::KnowledgeGained {
+                character: mara,
+                flag: "corvus_lives".to_string(),
+            },
+            EventEffect::KnowledgeGained {
+                character: mara,
+                flag: "corvus_dies".to_string(),
+            },
+            EventEffect::KnowledgeGained {
+                character: riven,
+                flag: "mara_says_must_kill_corvus".to_string(),
+            },
+            EventEffect::AppraisalTrigger {
+                character: riven,
+                belief: Belief::builder()
+                    .likelihood(0.7)
+                    .affects("Survive Future Self", -0.6) // Pulling the trigger makes the future that hunts them
+                    .incremental()
+                    .build()
+                    .expect("Mara's vision is a well-formed belief"),
+            },
+        ],
         causality_violation: None,
     });
 
@@ -260,11 +310,44 @@ pub fn thread_beta_gunslinger_paradox(multiverse: &mut Multiverse, char_ids: &[C
         causality_violation: None,
     });
 
+    // Kor-Valeth reads the manual, and learns what they hoped it wouldn't say
+    multiverse.record_event(Event {
+        id: EventId(0),
+        timeline,
+        description: "Kor-Valeth traces the Precursor script with a scarred finger. 'It fires \
+                     backward. Bullets, light, sound. Nothing that lives.' A long silence. \
+                     'Then there is no road home.'"
+            .to_string(),
+        participants: vec![kor_valeth, riven].into_iter().collect(),
+        effects: vec![
+            EventEffect::KnowledgeGained {
+                character: riven,
+                flag: "gun_fires_backward".to_string(),
+            },
+            EventEffect::AppraisalTrigger {
+                character: riven,
+                belief: Belief::builder()
+                    .likelihood(0.8)
+                    .affects("Survive Future Self", -0.5) // A shot from the future can come from anywhere
+                    .incremental()
+                    .build()
+                    .expect("the manual's warning is a well-formed belief"),
+            },
+            EventEffect::GoalResolved {
+                character: kor_valeth,
+                goal: "Return to Past".to_string(),
+                success: false,
+            },
+        ],
+        causality_violation: None,
+    });
+
     println!("\n=== THREAD BETA: The Gunslinger's Paradox ===");
     println!("Riven is hunted by their future self.");
     println!("Future-Riven says: Don't kill Corvus or thousands die.");
     println!("Mara Vex says: Kill Corvus or humanity is subsumed.");
-    println!("The gun's manual might explain everything—if Kor-Valeth can translate it.\n");
+    println!("Kor-Valeth translates the gun's manual: it can't carry them home.\n");
+    Ok(())
 }
 
 /// ## Thread Gamma: The Shimmer Convergence
@@ -273,11 +356,11 @@ pub fn thread_beta_gunslinger_paradox(multiverse: &mut Multiverse, char_ids: &[C
 /// Synthesis offers neural lace stabilization (but it's failing too). Dr. Lux proposes
 /// a causality hack (but it destabilizes the Ring). Nameless knows a Living Gate that
 /// could "fix" Yash-Tel by rewriting their past (but erases all memories).
-pub fn thread_gamma_shimmer_convergence(multiverse: &mut Multiverse, char_ids: &[CharacterId]) {
-    let yash_tel = char_ids[5]; // Shimmer Navigator
-    let synthesis = char_ids[8]; // Hybrid Consciousness
-    let lux = char_ids[11]; // Reality Hacker
-    let nameless = char_ids[3]; // Gate-Touched
+pub fn thread_gamma_shimmer_convergence(multiverse: &mut Multiverse, cast: &Cast) -> Result<(), CastError> {
+    let yash_tel = cast.require("Shimmer Navigator")?;
+    let synthesis = cast.require("Hybrid Consciousness")?;
+    let lux = cast.require("Reality Hacker")?;
+    let nameless = cast.require("Gate-Touched")?;
 
     let timeline = multiverse.root_timeline;
 
@@ -369,6 +452,7 @@ pub fn thread_gamma_shimmer_convergence(multiverse: &mut Multiverse, char_ids: &
     println!("Option 1: Synthesis's neural lace (risk: both die)");
     println!("Option 2: Dr. Lux's causality hack (risk: Ring destabilizes)");
     println!("Option 3: Living Gate rewrite (risk: lose all memories)\n");
+    Ok(())
 }
 
 /// ## Thread Delta: The Lattice Prophecy
@@ -376,11 +460,11 @@ pub fn thread_gamma_shimmer_convergence(multiverse: &mut Multiverse, char_ids: &
 /// **Story**: The Ansible Lattice tells Corvus Shal: "Thirteen must become One, or all
 /// become None." The Conductor is gathering all thirteen protagonists. Multiple factions
 /// interpret the prophecy differently.
-pub fn thread_delta_lattice_prophecy(multiverse: &mut Multiverse, char_ids: &[CharacterId]) {
-    let corvus = char_ids[4]; // Lattice Singer
-    let _conductor = char_ids[12]; // Mysterious Unifier
-    let saros = char_ids[2]; // Probabilist
-    let cartographer = char_ids[7]; // Ring Historian
+pub fn thread_delta_lattice_prophecy(multiverse: &mut Multiverse, cast: &Cast) -> Result<(), CastError> {
+    let corvus = cast.require("Lattice Singer")?;
+    let _conductor = cast.require("Mysterious Unifier")?;
+    let saros = cast.require("Probabilist")?;
+    let cartographer = cast.require("Ring Historian")?;
 
     let timeline = multiverse.root_timeline;
 
@@ -411,8 +495,8 @@ pub fn thread_delta_lattice_prophecy(multiverse: &mut Multiverse, char_ids: &[Ch
                      (somehow). To each, they say: 'The Lattice has spoken. You must come to the \
                      center of the Ring. All thirteen. When the time is right.' Then they vanish."
             .to_string(),
-        participants: char_ids.iter().copied().collect(),
-        effects: char_ids
+        participants: cast.ids().iter().copied().collect(),
+        effects: cast.ids()
             .iter()
             .map(|&char_id| EventEffect::KnowledgeGained {
                 character: char_id,
@@ -509,6 +593,35 @@ pub fn thread_delta_lattice_prophecy(multiverse: &mut Multiverse, char_ids: &[Ch
     println!("- Gate Cult: Merge with Gates (transcendence)");
     println!("- Causality Purists: Kill the thirteen (elimination)");
     println!("- The Cartographer: Follow Precursor instructions (reboot)\n");
+    Ok(())
+}
+
+/// How a character delivers their next line, going by what they feel most:
+/// an adverb to hang on the dialogue tag ("Vera says, bitterly, ...").
+pub fn dialogue_flavor(state: &EmotionalState) -> &'static str {
+    match state.dominant_emotion().map(|e| &e.emotion_type) {
+        None => "evenly",
+        Some(EmotionType::Joy | EmotionType::Satisfaction | EmotionType::Gratification) => "warmly",
+        Some(EmotionType::Hope | EmotionType::Relief) => "hopefully",
+        Some(EmotionType::HappyFor | EmotionType::Gratitude) => "kindly",
+        Some(EmotionType::Fear | EmotionType::FearConfirmed) => "nervously",
+        Some(EmotionType::Distress | EmotionType::Disappointment | EmotionType::Pity) => "heavily",
+        Some(EmotionType::Anger | EmotionType::Resentment) => "bitterly",
+        Some(EmotionType::Gloating) => "smugly",
+        Some(EmotionType::Remorse) => "quietly",
+    }
+}
+
+/// Says what each appraisal makes someone feel, as the threads run.
+struct FeelingNarrator {
+    names: HashMap<CharacterId, String>,
+}
+
+impl MultiverseObserver for FeelingNarrator {
+    fn on_feeling(&mut self, character: CharacterId, emotion: &Emotion) {
+        let name = self.names.get(&character).map_or("Someone", String::as_str);
+        println!("  {} feels {:?} ({:.2})", name, emotion.emotion_type, emotion.intensity);
+    }
 }
 
 /// Run all four story threads in sequence, demonstrating narrative coherence
@@ -520,8 +633,12 @@ pub fn run_full_demo(multiverse: &mut Multiverse) {
     println!("Initializing the Kaladrius Ring...");
     println!("Creating thirteen protagonists...\n");
 
-    let char_ids = create_thirteen_protagonists(multiverse);
-    initialize_relationships(multiverse, &char_ids);
+    let cast = CastBuilder::thirteen().build(multiverse).expect("the default profiles are valid");
+    initialize_relationships(multiverse, &cast);
+    match prop_roster_matches_profiles(multiverse, protagonist_profiles(), &cast) {
+        Ok(()) => println!("✓ The roster matches the profiles\n"),
+        Err(e) => println!("✗ The roster doesn't match the profiles: {}\n", e),
+    }
 
     // Display protagonist roster
     println!("═══ THE THIRTEEN PROTAGONISTS ═══\n");
@@ -539,11 +656,20 @@ pub fn run_full_demo(multiverse: &mut Multiverse) {
         println!();
     }
 
-    // Run each story thread
-    thread_alpha_memory_of_god(multiverse, &char_ids);
-    thread_beta_gunslinger_paradox(multiverse, &char_ids);
-    thread_gamma_shimmer_convergence(multiverse, &char_ids);
-    thread_delta_lattice_prophecy(multiverse, &char_ids);
+    // Run each story thread, hearing how it lands with everyone
+    let names = multiverse.characters.values().map(|c| (c.id, c.name.clone())).collect();
+    multiverse.add_observer(FeelingNarrator { names });
+    let threads = [
+        thread_alpha_memory_of_god,
+        thread_beta_gunslinger_paradox,
+        thread_gamma_shimmer_convergence,
+        thread_delta_lattice_prophecy,
+    ];
+    for thread in threads {
+        if let Err(missing) = thread(multiverse, &cast) {
+            println!("✗ A thread could not be told: {}", missing);
+        }
+    }
 
     // Validate all properties still hold
     println!("\n═══ NARRATIVE VALIDATION ═══\n");
@@ -567,17 +693,15 @@ pub fn run_full_demo(multiverse: &mut Multiverse) {
 
     // Display final state summary
     println!("\n═══ NARRATIVE STATE SUMMARY ═══\n");
-    println!("Timelines: {}", multiverse.timelines.len());
-    println!("Characters: {}", multiverse.characters.len());
-    println!("Events recorded: {}", multiverse.events.len());
-    println!("Memories in circulation: {}", multiverse.memories.len());
-
-    let causality_unstable_count = multiverse
-        .timelines
-        .values()
-        .filter(|t| !t.causality_stable)
-        .count();
-    println!("Causality-unstable timelines: {}", causality_unstable_count);
+    let stats = multiverse.view().stats();
+    println!("Timelines: {}", stats.timelines);
+    println!("Characters: {}", stats.characters);
+    println!("Events recorded: {}", stats.events);
+    println!("Memories in circulation: {}", stats.memories);
+    println!("Causality-unstable timelines: {}", stats.unstable_timelines);
+
+    println!("\n═══ MOOD OF THE RING ═══\n");
+    print!("{}", multiverse.view().mood_report());
 
     println!("\n═══ PLAYER CHOICE POINTS ═══\n");
     println!("The narrative has reached four major branching points.");
@@ -599,25 +723,98 @@ pub fn run_full_demo(multiverse: &mut Multiverse) {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::temporal::{validate_final, Obligation};
 
     #[test]
     fn test_thread_alpha_maintains_properties() {
         let mut multiverse = Multiverse::new();
-        let char_ids = create_thirteen_protagonists(&mut multiverse);
-        thread_alpha_memory_of_god(&mut multiverse, &char_ids);
+        let cast = CastBuilder::thirteen().build(&mut multiverse).unwrap();
+        thread_alpha_memory_of_god(&mut multiverse, &cast).unwrap();
 
         // All properties should still hold
         assert!(validate_all_properties(&multiverse).is_ok());
+
+        // Khelis put Vera's crew at risk, and Vera holds it against them
+        let vera = &multiverse.characters[&cast.require("Fold Captain").unwrap()].emotional_state;
+        let toward_khelis = vera.emotion_toward("Khelis Tev");
+        assert_eq!(toward_khelis.len(), 1);
+        assert_eq!(toward_khelis[0].emotion_type, crate::emotional_system::EmotionType::Anger);
+    }
+
+    #[test]
+    fn test_dialogue_follows_the_strongest_feeling() {
+        let mut state = EmotionalState::new();
+        assert_eq!(dialogue_flavor(&state), "evenly");
+        state.update_emotional_state(Emotion { emotion_type: EmotionType::Hope, intensity: 0.4 });
+        assert_eq!(dialogue_flavor(&state), "hopefully");
+        state.update_emotional_state(Emotion { emotion_type: EmotionType::Anger, intensity: 0.9 });
+        assert_eq!(dialogue_flavor(&state), "bitterly");
     }
 
     #[test]
     fn test_thread_beta_causality_justified() {
         let mut multiverse = Multiverse::new();
-        let char_ids = create_thirteen_protagonists(&mut multiverse);
-        thread_beta_gunslinger_paradox(&mut multiverse, &char_ids);
+        let cast = CastBuilder::thirteen().build(&mut multiverse).unwrap();
+        thread_beta_gunslinger_paradox(&mut multiverse, &cast).unwrap();
 
         // Causality violations should be justified
         assert!(prop_causality_justification(&multiverse).is_ok());
+
+        // Kor-Valeth gives up on going home
+        let kor_valeth = &multiverse.characters[&cast.require("Time-Exiled Warrior").unwrap()].emotional_state;
+        assert!(!kor_valeth.goals.contains_key("Return to Past"));
+        assert_eq!(kor_valeth.feels(&EmotionType::Disappointment), 0.5);
+    }
+
+    #[test]
+    fn test_thread_delta_summons_must_be_answered() {
+        let mut multiverse = Multiverse::new();
+        let cast = CastBuilder::thirteen().build(&mut multiverse).unwrap();
+        let grants = |event: &Event, c: CharacterId, flag: &str| {
+            event.effects.iter().any(|effect| {
+                matches!(effect, EventEffect::KnowledgeGained { character, flag: f }
+                    if *character == c && f == flag)
+            })
+        };
+        multiverse.register_obligation(Obligation::leads_to(
+            "summons_answered",
+            move |_, event, c| grants(event, c, "conductor_summons"),
+            move |_, event, c| {
+                let at_center = event.description.contains("center of the Ring");
+                (at_center && event.participants.contains(&c)) || grants(event, c, "refused_summons")
+            },
+        ));
+        thread_delta_lattice_prophecy(&mut multiverse, &cast).unwrap();
+
+        // Mid-story, an unanswered summons is just an open thread
+        assert_eq!(validate_all_properties(&multiverse), Ok(()));
+        let violation = validate_final(&multiverse).unwrap_err();
+        assert_eq!(violation.property, "summons_answered");
+
+        // Kor-Valeth refuses; everyone else comes
+        let kor_valeth = cast.require("Time-Exiled Warrior").unwrap();
+        let root = multiverse.root_timeline;
+        multiverse.record_event(Event {
+            id: EventId(0),
+            timeline: root,
+            description: "Kor-Valeth breaks the Conductor's token: 'I answer to my own age.'".to_string(),
+            participants: [kor_valeth].into_iter().collect(),
+            effects: vec![EventEffect::KnowledgeGained {
+                character: kor_valeth,
+                flag: "refused_summons".to_string(),
+            }],
+            causality_violation: None,
+        });
+        assert!(validate_final(&multiverse).is_err());
+        multiverse.record_event(Event {
+            id: EventId(0),
+            timeline: root,
+            description: "Twelve of the thirteen gather at the center of the Ring".to_string(),
+            participants: cast.ids().iter().copied().filter(|c| *c != kor_valeth).collect(),
+            effects: vec![],
+            causality_violation: None,
+        });
+        assert_eq!(validate_final(&multiverse), Ok(()));
     }
 
     #[test]
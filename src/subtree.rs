@@ -0,0 +1,438 @@
+//! # Subtrees: One Branch, on Its Own
+//!
+//! Sharing a branch of the story shouldn't mean shipping the whole multiverse.
+//! [`Multiverse::extract_subtree`] deep-copies one timeline and everything that
+//! branched from it into a standalone multiverse, which serializes, validates
+//! and grows like any other.
+//!
+//! The cut is made above the chosen timeline, which becomes the new root. What
+//! it inherited from its ancestors isn't lost: those events are folded into the
+//! new root's own history, in order, and anyone who lived in an ancestor is
+//! taken to have lived in the new root. Nothing the ancestors recorded after
+//! the split comes along, and neither does anything from sibling branches.
+//!
+//! Characters come along if the subtree's timelines count them or its events
+//! involve them, together with the rest of any collective they belong to.
+//! Memories come along if they're held or handed over by those characters and
+//! are of something the subtree recorded: a memory of a scene elsewhere has
+//! nothing to point at, and is left behind, along with any recall of it.
+//!
+//! A character's life may have gone on outside the cut, if they stayed behind
+//! in an ancestor or left for a sibling branch. What happened to them there
+//! happened in events the subtree doesn't have, so it's undone: they live
+//! where they last lived inside the cut, and are alive or dead, know, can do,
+//! remember and stand with each other as the subtree's own events left them.
+//! A memory traded to them outside the cut is one they never received.
+//! Their emotions are the exception. Appraisals fade and blend with nothing to
+//! replay them from, so feelings come along as they are.
+
+use crate::narrative_core::*;
+use std::collections::{HashMap, HashSet};
+
+impl Multiverse {
+    /// A standalone copy of `root` and its descendant timelines, with only the
+    /// characters, memories and events they reference, and `root` as the root
+    /// timeline. See the module docs for what is folded in and what is left
+    /// behind. An unknown `root` gives an empty multiverse rooted there.
+    pub fn extract_subtree(&self, root: TimelineId) -> Multiverse {
+        let mut subtree = self.clone();
+        subtree.timelines.clear();
+        subtree.characters.clear();
+        subtree.memories.clear();
+        subtree.events.clear();
+        subtree.collectives.clear();
+        subtree.root_timeline = root;
+        let Some(root_timeline) = self.timelines.get(&root) else {
+            return subtree;
+        };
+
+        // Everything above the cut becomes the new root
+        let mut above = HashSet::new();
+        let mut parent = root_timeline.parent;
+        while let Some(timeline) = parent.filter(|p| above.insert(*p)) {
+            parent = self.timelines.get(&timeline).and_then(|t| t.parent);
+        }
+        let fold = |timeline: TimelineId| if above.contains(&timeline) { root } else { timeline };
+        let mut within = vec![root];
+        let mut next = 0;
+        while let Some(&timeline) = within.get(next) {
+            let mut branches: Vec<TimelineId> =
+                self.timelines.values().filter(|t| t.parent == Some(timeline)).map(|t| t.id).collect();
+            branches.sort();
+            within.extend(branches);
+            next += 1;
+        }
+
+        for timeline in &within {
+            let mut copy = self.timelines[timeline].clone();
+            if *timeline == root {
+                copy.parent = None;
+                copy.divergence_event = None;
+                copy.events = self.history(root);
+            }
+            for id in &copy.events {
+                if let Some(event) = self.events.get(id) {
+                    subtree.events.insert(*id, Event { timeline: *timeline, ..event.clone() });
+                }
+            }
+            subtree.timelines.insert(*timeline, copy);
+        }
+
+        let mut characters: HashSet<CharacterId> =
+            within.iter().flat_map(|t| self.timelines[t].characters.iter().copied()).collect();
+        characters.extend(self.characters.keys().filter(|c| subtree.events.values().any(|e| e.involves(**c))));
+        for collective in &self.collectives {
+            if collective.members.iter().any(|m| characters.contains(m)) {
+                characters.extend(&collective.members);
+                subtree.collectives.push(collective.clone());
+            }
+        }
+        characters.retain(|c| self.characters.contains_key(c));
+        subtree.character_invariants.retain(|(c, _)| characters.contains(c));
+
+        // Memories anyone in the subtree holds or passes on, and what they're made of
+        // Who was handed which memory, in the whole multiverse and within the cut
+        let handed = |events: &mut dyn Iterator<Item = &Event>| -> HashSet<(CharacterId, MemoryId)> {
+            events
+                .flat_map(|e| &e.effects)
+                .filter_map(|effect| match effect {
+                    EventEffect::MemoryTransfer { memory, to, .. } => Some((*to, *memory)),
+                    _ => None,
+                })
+                .collect()
+        };
+        let handed_anywhere = handed(&mut self.events.values());
+        let handed_here = handed(&mut subtree.events.values());
+        let mut candidates: Vec<MemoryId> =
+            characters.iter().flat_map(|c| self.characters[c].memories.iter().copied()).collect();
+        candidates.extend(subtree.events.values().flat_map(|e| &e.effects).filter_map(|effect| match effect {
+            EventEffect::MemoryTransfer { memory, .. } | EventEffect::MemoryRecall { memory, .. } => Some(*memory),
+            _ => None,
+        }));
+        let source = |t: TimelineId| Some(fold(t)).filter(|t| within.contains(t));
+        for memory in candidates {
+            self.keep_memory(memory, &subtree.events, &source, &mut subtree.memories);
+        }
+        self.undo_fading_elsewhere(&mut subtree.memories, &subtree.events);
+        // A memory left behind can't be recalled here either
+        let kept = &subtree.memories;
+        for event in subtree.events.values_mut() {
+            event
+                .effects
+                .retain(|e| !matches!(e, EventEffect::MemoryRecall { memory, .. } if !kept.contains_key(memory)));
+        }
+
+        for id in &characters {
+            let mut character = self.characters[id].clone();
+            // Only their time inside the cut counts: where they first lived in it
+            // is where they're from, and where they last lived in it is home.
+            // Someone who never lived in it, only looked in, is put in the root
+            let stays: Vec<(Option<EventId>, TimelineId)> = std::iter::once((None, character.native_timeline))
+                .chain(character.timeline_history.iter().map(|(moved_at, t)| (Some(*moved_at), *t)))
+                .map(|(moved_at, t)| (moved_at, fold(t)))
+                .filter(|(_, t)| within.contains(t))
+                .collect();
+            character.native_timeline = stays.first().map_or(root, |(_, t)| *t);
+            character.current_timeline = stays.last().map_or(root, |(_, t)| *t);
+            character.timeline_history =
+                stays.iter().skip(1).filter_map(|(moved_at, t)| moved_at.map(|e| (e, *t))).collect();
+            // An anchor above the cut pulls toward its root; one off to the side is gone
+            character.anchor_timeline = character.anchor_timeline.map(fold).filter(|t| within.contains(t));
+            // A memory only ever handed to them outside the cut isn't theirs here
+            character.memories.retain(|m| {
+                subtree.memories.contains_key(m)
+                    && (!handed_anywhere.contains(&(*id, *m)) || handed_here.contains(&(*id, *m)))
+            });
+            character.relationships.retain(|other, _| characters.contains(other));
+            character.affinities.retain(|other, _| characters.contains(other));
+            subtree.characters.insert(*id, character);
+        }
+        subtree.settle_characters();
+
+        // An inherited violation the ancestor repaired after the split stays unrepaired here
+        let stable = subtree.unresolved_violations(root).is_empty();
+        if let Some(root) = subtree.timelines.get_mut(&root) {
+            root.causality_stable &= stable;
+        }
+        let kept = |firing: &crate::triggers::TriggerFiring| subtree.events.contains_key(&firing.event);
+        let firings = subtree.emotion_triggers.firings().iter().copied().filter(kept).collect();
+        subtree.emotion_triggers.set_firings(firings);
+        let events = &subtree.events;
+        subtree.emotion_log.retain(|event, _| events.contains_key(event));
+        subtree.posthumous_log.retain(|event, _| events.contains_key(event));
+        // Only the violations it kept weigh on the subtree's Ring
+        let violations = events.values().filter_map(|e| e.causality_violation.as_ref());
+        subtree.incoherence = violations.map(CausalityViolation::incoherence_cost).sum();
+        subtree
+    }
+
+    /// Copies `memory` into `kept`, from the timeline `source` takes its source
+    /// to, if it and every memory it's compounded from are of something in
+    /// `events` and come from a timeline `source` keeps. Returns whether it was kept.
+    fn keep_memory(
+        &self,
+        memory: MemoryId,
+        events: &HashMap<EventId, Event>,
+        source: &impl Fn(TimelineId) -> Option<TimelineId>,
+        kept: &mut HashMap<MemoryId, Memory>,
+    ) -> bool {
+        if kept.contains_key(&memory) {
+            return true;
+        }
+        let Some(original) = self.memories.get(&memory) else {
+            return false;
+        };
+        let resolves = original.content != MemoryContent::Event || events.contains_key(&original.event);
+        let Some(source_timeline) = source(original.source_timeline).filter(|_| resolves) else {
+            return false;
+        };
+        if let MemoryProvenance::Compound { sources } = &original.provenance {
+            if !sources.iter().all(|source_memory| self.keep_memory(*source_memory, events, source, kept)) {
+                return false;
+            }
+        }
+        kept.insert(memory, Memory { source_timeline, ..original.clone() });
+        true
+    }
+
+    /// Restores the fidelity `memories` lost to fading outside `events`: any
+    /// [`EventEffect::MemoryDegrade`] left behind that struck someone who ever
+    /// held the memory, as [`prop_memory_recall_usable`](crate::properties::prop_memory_recall_usable)
+    /// counts holders.
+    fn undo_fading_elsewhere(&self, memories: &mut HashMap<MemoryId, Memory>, events: &HashMap<EventId, Event>) {
+        let left_behind: Vec<&EventEffect> =
+            self.events.values().filter(|e| !events.contains_key(&e.id)).flat_map(|e| &e.effects).collect();
+        for (id, memory) in memories.iter_mut() {
+            let mut holders: HashSet<CharacterId> =
+                self.characters.values().filter(|c| c.memories.contains(id)).map(|c| c.id).collect();
+            if let MemoryProvenance::Witnessed { character } = memory.provenance {
+                holders.insert(character);
+            }
+            for effect in self.events.values().flat_map(|e| &e.effects) {
+                match effect {
+                    EventEffect::MemoryTransfer { memory, from, to, .. } if memory == id => {
+                        holders.insert(*to);
+                        holders.extend(*from);
+                    }
+                    EventEffect::MemoryRecall { memory, character } if memory == id => {
+                        holders.insert(*character);
+                    }
+                    _ => {}
+                }
+            }
+            for effect in &left_behind {
+                if let EventEffect::MemoryDegrade { character, amount } = effect {
+                    let kept = 1.0 - amount.clamp(0.0, 1.0);
+                    if holders.contains(character) && kept > 0.0 {
+                        memory.fidelity = (memory.fidelity / kept).min(1.0);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Brings every character in line with the events recorded here, undoing
+    /// what happened to them in events that aren't: the names they took, the
+    /// knowledge flags and abilities the events granted, whether they're alive, the memories a wipe
+    /// or sale here took, and every relationship a change fixed outright.
+    /// Shifts of a pair no change has fixed are left alone, having no known start.
+    fn settle_characters(&mut self) {
+        let mut ids: Vec<EventId> = self.events.keys().copied().collect();
+        ids.sort();
+        let mut taught: HashSet<(CharacterId, String)> = HashSet::new();
+        let mut abilities: HashMap<(CharacterId, AbilityKind), Option<Ability>> = HashMap::new();
+        let mut fixed = HashSet::new();
+        let mut renamed: HashMap<CharacterId, Vec<String>> = HashMap::new();
+        // When each character was last wiped, and each memory last sold or received
+        let mut wiped: HashMap<CharacterId, EventId> = HashMap::new();
+        let mut sold: HashMap<(CharacterId, MemoryId), EventId> = HashMap::new();
+        let mut received: HashMap<(CharacterId, MemoryId), EventId> = HashMap::new();
+        for (id, effect) in ids.iter().flat_map(|id| self.events[id].effects.iter().map(move |e| (*id, e))) {
+            match effect {
+                EventEffect::MemoryWipe { character } => {
+                    wiped.insert(*character, id);
+                }
+                EventEffect::CharacterRename { character, new_name } => {
+                    renamed.entry(*character).or_default().push(new_name.clone());
+                }
+                EventEffect::MemoryTransfer {
+                    memory,
+                    from,
+                    to,
+                    exclusive,
+                } => {
+                    received.insert((*to, *memory), id);
+                    if let (true, Some(seller)) = (*exclusive, from.filter(|seller| seller != to)) {
+                        sold.insert((seller, *memory), id);
+                    }
+                }
+                EventEffect::KnowledgeGained { character, flag }
+                | EventEffect::KnowledgeShared { to: character, flag, .. } => {
+                    taught.insert((*character, flag.clone()));
+                }
+                EventEffect::AbilityGained { character, ability, .. } => {
+                    abilities.insert((*character, ability.kind()), Some(ability.clone()));
+                }
+                EventEffect::AbilityLost { character, ability } => {
+                    abilities.insert((*character, *ability), None);
+                }
+                EventEffect::RelationshipChange {
+                    character1,
+                    character2,
+                    new_state,
+                } => {
+                    fixed.insert((*character1.min(character2), *character1.max(character2)));
+                    for (c, other) in [(character1, character2), (character2, character1)] {
+                        if let Some(c) = self.characters.get_mut(c) {
+                            c.relationships.insert(*other, *new_state);
+                            c.affinities.remove(other);
+                        }
+                    }
+                }
+                EventEffect::AffinityShift {
+                    character1,
+                    character2,
+                    delta,
+                } if fixed.contains(&(*character1.min(character2), *character1.max(character2))) => {
+                    for (c, other) in [(character1, character2), (character2, character1)] {
+                        if let Some(c) = self.characters.get_mut(c) {
+                            let affinity = (c.affinity(*other) + delta).clamp(-MAX_AFFINITY, MAX_AFFINITY);
+                            c.affinities.insert(*other, affinity);
+                            c.relationships.insert(*other, RelationshipState::from_affinity(affinity));
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        // Alive or dead as of the last death or resurrection their home saw
+        let alive: Vec<(CharacterId, bool)> = self
+            .characters
+            .values()
+            .map(|character| {
+                let last = self.history(character.current_timeline).into_iter().rev().find_map(|e| {
+                    self.events[&e].effects.iter().rev().find_map(|effect| match effect {
+                        EventEffect::CharacterDeath { character: c } if *c == character.id => Some(false),
+                        EventEffect::CharacterResurrection { character: c, .. } if *c == character.id => Some(true),
+                        _ => None,
+                    })
+                });
+                (character.id, last.unwrap_or(true))
+            })
+            .collect();
+
+        for (id, alive) in alive {
+            let Some(character) = self.characters.get_mut(&id) else {
+                continue;
+            };
+            character.alive = alive;
+            let mut names = vec![character.original_name().to_string()];
+            for name in renamed.get(&id).into_iter().flatten() {
+                if names.last() != Some(name) {
+                    names.push(name.clone());
+                }
+            }
+            character.name = names.pop().unwrap_or_default();
+            character.former_names = names;
+            character.knowledge_flags.retain(|flag| taught.contains(&(id, flag.clone())));
+            character.abilities.retain(|kind, _| abilities.contains_key(&(id, *kind)));
+            for ((_, kind), ability) in abilities.iter().filter(|((c, _), _)| *c == id) {
+                match ability {
+                    Some(ability) => {
+                        character.abilities.entry(*kind).or_insert_with(|| ability.clone());
+                    }
+                    None => {
+                        character.abilities.remove(kind);
+                    }
+                }
+            }
+            let memories = &self.memories;
+            let held_since = |memory: &MemoryId, since: EventId| {
+                received.get(&(id, *memory)).is_some_and(|r| *r > since)
+                    || memories.get(memory).is_some_and(|m| m.event > since)
+            };
+            let wipe = wiped.get(&id).filter(|_| !character.has_ability(AbilityKind::MemoryImmunity));
+            character.memories.retain(|memory| {
+                wipe.is_none_or(|w| held_since(memory, *w))
+                    && sold.get(&(id, *memory)).is_none_or(|s| received.get(&(id, *memory)).is_some_and(|r| r > s))
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::properties::validate_all_properties;
+
+    #[test]
+    fn test_extracted_branch_stands_alone() {
+        let mut multiverse = Multiverse::new();
+        let root = multiverse.root_timeline;
+        let [vera, kael] =
+            ["Vera Kandros", "Kael Thornwick"].map(|name| multiverse.create_character(name.to_string(), root));
+        let scene = |multiverse: &mut Multiverse, timeline, character, flag: &str| {
+            let event = multiverse.record_event(Event {
+                id: EventId(0),
+                timeline,
+                description: format!("{} learns {}", character, flag),
+                participants: HashSet::from([character]),
+                effects: vec![EventEffect::KnowledgeGained {
+                    character,
+                    flag: flag.to_string(),
+                }],
+                causality_violation: None,
+            });
+            let memory = multiverse.create_witnessed_memory(event, timeline, character);
+            multiverse.characters.get_mut(&character).unwrap().memories.insert(memory);
+            event
+        };
+        let plot = multiverse.record_event(Event {
+            id: EventId(0),
+            timeline: root,
+            description: "Vera and Kael plot two courses".to_string(),
+            participants: HashSet::from([vera, kael]),
+            effects: vec![EventEffect::RelationshipChange {
+                character1: vera,
+                character2: kael,
+                new_state: RelationshipState::Allied,
+            }],
+            causality_violation: None,
+        });
+        let fold = multiverse.create_timeline_branch_with(root, plot, &[vera]).unwrap();
+        let jump = scene(&mut multiverse, fold, vera, "made_the_jump");
+        let deeper = multiverse.create_timeline_branch_with(fold, jump, &[vera]).unwrap();
+        scene(&mut multiverse, deeper, vera, "saw_the_far_side");
+        let stayed = multiverse.create_timeline_branch_with(root, plot, &[kael]).unwrap();
+        let left_behind = scene(&mut multiverse, stayed, kael, "held_the_station");
+        assert_eq!(validate_all_properties(&multiverse), Ok(()));
+
+        let subtree = multiverse.extract_subtree(fold);
+        assert_eq!(subtree.root_timeline, fold);
+        let mut timelines: Vec<TimelineId> = subtree.timelines.keys().copied().collect();
+        timelines.sort();
+        assert_eq!(timelines, vec![fold, deeper]);
+        assert_eq!(subtree.timelines[&fold].parent, None);
+        // The plot was inherited, and now belongs to the branch itself
+        assert_eq!(subtree.history(fold), multiverse.history(fold));
+        assert_eq!(subtree.timelines[&fold].events[0], plot);
+        assert_eq!(subtree.events[&plot].timeline, fold);
+        assert_eq!(subtree.history(deeper), multiverse.history(deeper));
+        assert!(!subtree.events.contains_key(&left_behind));
+        assert_eq!(subtree.events.len(), 5);
+
+        // Kael comes along for the plot, but not what they learned at the station
+        let kael_there = &subtree.characters[&kael];
+        assert_eq!(kael_there.current_timeline, fold);
+        assert!(kael_there.knowledge_flags.is_empty());
+        assert!(kael_there.memories.is_empty());
+        assert_eq!(subtree.relationship_between(vera, kael), RelationshipState::Allied);
+        assert_eq!(subtree.characters[&vera].memories.len(), 2);
+        assert_eq!(subtree.memories.len(), 2);
+
+        assert_eq!(validate_all_properties(&subtree), Ok(()));
+        let loaded = Multiverse::load_from_json(&subtree.to_json().unwrap()).unwrap();
+        assert_eq!(loaded, subtree);
+    }
+}
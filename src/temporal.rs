@@ -0,0 +1,192 @@
+//! # Temporal Obligations: Threads That Must Be Tied Off
+//!
+//! Every property so far is a state invariant: it holds after every event or
+//! the story is broken. Some rules are about sequences instead. When the
+//! Conductor summons the thirteen, each of them must *eventually* answer—come to
+//! the center of the Ring, or refuse outright. Until the story ends, an
+//! unanswered summons isn't a bug; it's a thread still open.
+//!
+//! An [`Obligation`] captures a rule of that shape: a trigger that puts a
+//! character under obligation, and a resolution that discharges it in some
+//! *later* event. Obligations are registered on the multiverse, like knowledge
+//! flags, and checked by walking each timeline's history (its ancestors' events
+//! up to the divergence, then its own) in order.
+//!
+//! Only [`validate_final`] reports unresolved obligations: call it when the story
+//! is over. [`validate_all_properties`] never does, so an open story doesn't fail.
+//! Every timeline is an ending of its own, so a branch that split off before an
+//! obligation was met has to meet it too.
+
+use crate::narrative_core::*;
+use crate::properties::{validate_all_properties, PropertyViolation, ViolationContext};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::sync::Arc;
+
+/// Whether `event` bears on `character` for an obligation.
+pub type ObligationPredicate = dyn Fn(&Multiverse, &Event, CharacterId) -> bool + Send + Sync;
+
+/// A rule that whatever triggers it must eventually be resolved.
+///
+/// **Invariant** (at the end of the story): in every timeline's history, each
+/// event that triggers the obligation for a character is followed by an event
+/// that resolves it for them.
+#[derive(Clone)]
+pub struct Obligation {
+    /// The name violations report, e.g. "summons_answered"
+    pub name: String,
+    trigger: Arc<ObligationPredicate>,
+    resolution: Arc<ObligationPredicate>,
+}
+
+impl Obligation {
+    /// `trigger` leads to `resolution`: every event that triggers the
+    /// obligation for a character must eventually be followed by one that
+    /// resolves it. The triggering event itself doesn't count.
+    pub fn leads_to(
+        name: impl Into<String>,
+        trigger: impl Fn(&Multiverse, &Event, CharacterId) -> bool + Send + Sync + 'static,
+        resolution: impl Fn(&Multiverse, &Event, CharacterId) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        Obligation {
+            name: name.into(),
+            trigger: Arc::new(trigger),
+            resolution: Arc::new(resolution),
+        }
+    }
+
+    /// The obligations left open at the end of `timeline`'s history, by
+    /// character, with the event that opened each.
+    pub fn unresolved_in(
+        &self,
+        multiverse: &Multiverse,
+        timeline: TimelineId,
+    ) -> BTreeMap<CharacterId, EventId> {
+        let mut characters: Vec<CharacterId> = multiverse.characters.keys().copied().collect();
+        characters.sort();
+        let mut open = BTreeMap::new();
+        for event in multiverse.history(timeline).iter().filter_map(|id| multiverse.events.get(id)) {
+            open.retain(|character, _| !(self.resolution)(multiverse, event, *character));
+            for character in &characters {
+                if !open.contains_key(character) && (self.trigger)(multiverse, event, *character) {
+                    open.insert(*character, event.id);
+                }
+            }
+        }
+        open
+    }
+
+    /// Fails on the first obligation left open, in any timeline.
+    pub fn check(&self, multiverse: &Multiverse) -> Result<(), PropertyViolation> {
+        let mut timelines: Vec<TimelineId> = multiverse.timelines.keys().copied().collect();
+        timelines.sort();
+        for timeline in timelines {
+            let unresolved = self.unresolved_in(multiverse, timeline);
+            if let Some((&character, &opened)) = unresolved.iter().next() {
+                let name = multiverse.characters.get(&character).map_or("Unknown", |c| c.name.as_str());
+                return Err(PropertyViolation::new(
+                    self.name.clone(),
+                    format!(
+                        "{} ({}) was left with an open obligation from {} when {} ended",
+                        character, name, opened, timeline
+                    ),
+                )
+                .about(ViolationContext::Event(opened))
+                .about(ViolationContext::Character(character))
+                .about(ViolationContext::Timeline(timeline)));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for Obligation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Obligation").field("name", &self.name).finish_non_exhaustive()
+    }
+}
+
+/// The obligations a multiverse is held to.
+///
+/// Obligations are rules of the story, so unlike observers a clone keeps them.
+/// They are code, so they aren't serialized, and they don't count towards
+/// equality.
+#[derive(Clone, Default)]
+pub(crate) struct Obligations(Vec<Obligation>);
+
+impl Obligations {
+    pub(crate) fn push(&mut self, obligation: Obligation) {
+        self.0.push(obligation);
+    }
+
+    pub(crate) fn iter(&self) -> impl Iterator<Item = &Obligation> {
+        self.0.iter()
+    }
+}
+
+impl PartialEq for Obligations {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl fmt::Debug for Obligations {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} obligations", self.0.len())
+    }
+}
+
+/// Checks every property, then every registered obligation. For a finished
+/// story: an obligation still open here is a thread that was never tied off.
+pub fn validate_final(multiverse: &Multiverse) -> Result<(), PropertyViolation> {
+    validate_all_properties(multiverse)?;
+    multiverse.obligations().try_for_each(|obligation| obligation.check(multiverse))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_obligations_follow_timeline_history() {
+        let mut multiverse = Multiverse::new();
+        let root = multiverse.root_timeline;
+        let riven = multiverse.create_character("Riven Blackwood".to_string(), root);
+        // Riven's time-gun lets them settle accounts in any timeline
+        multiverse.grant_ability(riven, Ability::timeline_perception());
+        multiverse.register_obligation(Obligation::leads_to(
+            "debts_repaid",
+            |_, event, c| event.participants.contains(&c) && event.description.contains("borrows"),
+            |_, event, c| event.participants.contains(&c) && event.description.contains("repays"),
+        ));
+        let scene = |multiverse: &mut Multiverse, timeline, description: &str| {
+            multiverse.record_event(Event {
+                id: EventId(0),
+                timeline,
+                description: description.to_string(),
+                participants: HashSet::from([riven]),
+                effects: vec![],
+                causality_violation: None,
+            })
+        };
+
+        // Borrowing and repaying in one breath doesn't count
+        let loan = scene(&mut multiverse, root, "Riven borrows and repays a bullet");
+        assert_eq!(validate_all_properties(&multiverse), Ok(()));
+        let violation = validate_final(&multiverse).unwrap_err();
+        assert_eq!(violation.property, "debts_repaid");
+        assert_eq!(violation.trace, vec![loan]);
+
+        // A branch inherits the debt, but not the repayment made after it split
+        let branch = multiverse.create_timeline_branch(root, loan);
+        scene(&mut multiverse, root, "Riven repays the bullet");
+        assert!(multiverse.obligations().all(|o| o.unresolved_in(&multiverse, root).is_empty()));
+        assert_eq!(multiverse.obligations().next().unwrap().unresolved_in(&multiverse, branch)[&riven], loan);
+        assert!(validate_final(&multiverse).is_err());
+
+        scene(&mut multiverse, branch, "Riven repays the bullet, elsewhere");
+        assert_eq!(validate_final(&multiverse), Ok(()));
+        assert_eq!(multiverse.clone().obligations().count(), 1);
+    }
+}
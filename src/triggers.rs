@@ -0,0 +1,250 @@
+//! # Emotion Triggers: Feelings That Move the Story
+//!
+//! Appraisal changes how characters feel, but nothing a character feels has
+//! ever made them *do* anything. A trigger is a rule of the form "when Riven's
+//! Fear rises past 0.7, Riven flees": registered on the multiverse with
+//! [`Multiverse::register_trigger`], it watches every
+//! [`EventEffect::AppraisalTrigger`], and when one carries its emotion across
+//! its threshold, the [`TriggerAction`] is recorded as an event of its own,
+//! right after the event that caused it.
+//!
+//! A trigger fires on the crossing, not on every appraisal while the emotion
+//! stays high, so a frightened character flees once rather than every scene.
+//! Every firing is logged (see [`EmotionTriggerRegistry::firings`]), so any
+//! event a trigger produced can be traced back to the trigger and the
+//! appraisal behind it. Triggered events are ordinary events in every other
+//! way, and the properties hold for them like any others.
+
+use crate::emotional_system::{EmotionType, EmotionalState};
+use crate::narrative_core::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+/// What a trigger does when it fires, to the character who crossed its threshold.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum TriggerAction {
+    /// They learn a knowledge flag
+    GrantFlag(String),
+    /// Their relationship with `with` is set to `new_state`. Nothing happens
+    /// unless `with` is present in the same timeline
+    ChangeRelationship {
+        with: CharacterId,
+        new_state: RelationshipState,
+    },
+    /// An event they take part in, with `{name}` in the description replaced
+    /// by their name
+    Event {
+        description: String,
+        effects: Vec<EventEffect>,
+    },
+}
+
+/// A registered trigger; see [`Multiverse::register_trigger`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EmotionTrigger {
+    /// Whose feelings it watches, or everyone's if `None`
+    pub character: Option<CharacterId>,
+    pub emotion: EmotionType,
+    /// It fires when the emotion's intensity goes from at most this to above it
+    pub threshold: f64,
+    pub action: TriggerAction,
+}
+
+/// One time a trigger fired.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct TriggerFiring {
+    /// The trigger's index in [`EmotionTriggerRegistry::triggers`]
+    pub trigger: usize,
+    pub character: CharacterId,
+    /// The event whose appraisal crossed the threshold
+    pub cause: EventId,
+    /// The event the trigger recorded
+    pub event: EventId,
+}
+
+/// The triggers registered on a multiverse, and every time they fired.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct EmotionTriggerRegistry {
+    triggers: Vec<EmotionTrigger>,
+    firings: Vec<TriggerFiring>,
+}
+
+impl EmotionTriggerRegistry {
+    pub fn triggers(&self) -> &[EmotionTrigger] {
+        &self.triggers
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.triggers.is_empty()
+    }
+
+    /// Every firing so far, in the order the events were recorded.
+    pub fn firings(&self) -> &[TriggerFiring] {
+        &self.firings
+    }
+
+    /// The firing that recorded `event`, if a trigger did.
+    pub fn firing_of(&self, event: EventId) -> Option<&TriggerFiring> {
+        self.firings.iter().find(|f| f.event == event)
+    }
+
+    /// Replaces the firing log, such as with the firings whose events made it
+    /// into an extracted subtree.
+    pub(crate) fn set_firings(&mut self, firings: Vec<TriggerFiring>) {
+        self.firings = firings;
+    }
+
+    /// The triggers watching `character` whose emotion went from at most
+    /// their threshold in `before` to above it in `after`.
+    pub(crate) fn crossed(
+        &self,
+        character: CharacterId,
+        before: &EmotionalState,
+        after: &EmotionalState,
+    ) -> Vec<usize> {
+        self.triggers
+            .iter()
+            .enumerate()
+            .filter(|(_, t)| t.character.is_none_or(|c| c == character))
+            .filter(|(_, t)| before.feels(&t.emotion) <= t.threshold && after.feels(&t.emotion) > t.threshold)
+            .map(|(index, _)| index)
+            .collect()
+    }
+}
+
+impl Multiverse {
+    /// Registers a trigger: when an appraisal carries `character`'s (or, with
+    /// `None`, anyone's) `emotion` above `threshold`, `action` is recorded as
+    /// an event. Returns the trigger's index. See the module docs.
+    pub fn register_trigger(
+        &mut self,
+        character: Option<CharacterId>,
+        emotion: EmotionType,
+        threshold: f64,
+        action: TriggerAction,
+    ) -> usize {
+        self.emotion_triggers.triggers.push(EmotionTrigger {
+            character,
+            emotion,
+            threshold,
+            action,
+        });
+        self.emotion_triggers.triggers.len() - 1
+    }
+
+    /// Records the event trigger `index` brings about for `character`, because
+    /// of event `cause`. Returns `None` if the action has nobody to act on.
+    pub(crate) fn fire_trigger(&mut self, index: usize, character: CharacterId, cause: EventId) -> Option<EventId> {
+        let trigger = self.emotion_triggers.triggers.get(index)?.clone();
+        let c = self.characters.get(&character).filter(|c| c.is_present())?;
+        let (timeline, name) = (c.current_timeline, c.name.clone());
+        let feeling = trigger.emotion.as_str();
+
+        let mut participants = HashSet::from([character]);
+        let (description, effects) = match trigger.action {
+            TriggerAction::GrantFlag(flag) => (
+                format!("{}'s {} drives them to a conclusion", name, feeling),
+                vec![EventEffect::KnowledgeGained { character, flag }],
+            ),
+            TriggerAction::ChangeRelationship { with, new_state } => {
+                let other = self.characters.get(&with).filter(|o| o.is_present() && o.current_timeline == timeline)?;
+                participants.insert(with);
+                (
+                    format!("{}'s {} changes how they see {}", name, feeling, other.name),
+                    vec![EventEffect::RelationshipChange {
+                        character1: character,
+                        character2: with,
+                        new_state,
+                    }],
+                )
+            }
+            TriggerAction::Event { description, effects } => (description.replace("{name}", &name), effects),
+        };
+
+        let event = self.record_event(Event {
+            id: EventId(0),
+            timeline,
+            description,
+            participants,
+            effects,
+            causality_violation: None,
+        });
+        self.emotion_triggers.firings.push(TriggerFiring {
+            trigger: index,
+            character,
+            cause,
+            event,
+        });
+        Some(event)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::emotional_system::{Belief, Goal};
+    use crate::properties::validate_all_properties;
+
+    #[test]
+    fn test_triggers_fire_on_the_crossing() {
+        let mut multiverse = Multiverse::new();
+        let root = multiverse.root_timeline;
+        let [vera, kael] =
+            ["Vera Kandros", "Kael Thornwick"].map(|name| multiverse.create_character(name.to_string(), root));
+        multiverse
+            .characters
+            .get_mut(&vera)
+            .unwrap()
+            .emotional_state
+            .add_goal(Goal::new("Protect Crew".to_string(), 1.0, true));
+        let angry_at_kael = multiverse.register_trigger(
+            Some(vera),
+            EmotionType::Anger,
+            0.3,
+            TriggerAction::ChangeRelationship {
+                with: kael,
+                new_state: RelationshipState::Distrustful,
+            },
+        );
+        // Watches everyone, but only Vera has anything to fear for
+        let shaken = TriggerAction::GrantFlag("shaken".to_string());
+        let afraid = multiverse.register_trigger(None, EmotionType::Fear, 0.3, shaken);
+        let blunder = |multiverse: &mut Multiverse| {
+            multiverse.record_event(Event {
+                id: EventId(0),
+                timeline: root,
+                description: "Kael vents the wrong airlock".to_string(),
+                participants: HashSet::from([vera, kael]),
+                effects: vec![EventEffect::AppraisalTrigger {
+                    character: vera,
+                    belief: Belief::new(
+                        0.8,
+                        Some("Kael Thornwick".to_string()),
+                        vec!["Protect Crew".to_string()],
+                        vec![-0.6],
+                        true,
+                    )
+                    .unwrap(),
+                }],
+                causality_violation: None,
+            })
+        };
+
+        let first = blunder(&mut multiverse);
+        let firings = multiverse.emotion_triggers().firings().to_vec();
+        assert_eq!(firings.iter().map(|f| (f.trigger, f.character, f.cause)).collect::<Vec<_>>(), vec![
+            (angry_at_kael, vera, first),
+            (afraid, vera, first),
+        ]);
+        assert_eq!(multiverse.relationship_between(vera, kael), RelationshipState::Distrustful);
+        assert!(multiverse.characters[&vera].knowledge_flags.contains("shaken"));
+        assert!(multiverse.characters[&kael].knowledge_flags.is_empty());
+        let shaken = multiverse.emotion_triggers().firing_of(firings[1].event).unwrap();
+        assert_eq!(shaken.trigger, afraid);
+        assert_eq!(validate_all_properties(&multiverse), Ok(()));
+
+        // Still angry and afraid, but already past both thresholds
+        blunder(&mut multiverse);
+        assert_eq!(multiverse.emotion_triggers().firings().len(), 2);
+    }
+}
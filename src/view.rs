@@ -0,0 +1,275 @@
+//! # Views: Looking Without Touching
+//!
+//! Validation and rendering code only ever reads the multiverse, but a
+//! `&mut Multiverse` passed along for convenience can record an event as
+//! easily as it can look one up. A [`MultiverseView`] is a borrow that can
+//! only look: the queries are all there, and nothing that changes the story
+//! is. Taking one from [`Multiverse::view`] says so in the signature.
+//!
+//! ```compile_fail
+//! use propyarn::narrative_core::*;
+//!
+//! let mut multiverse = Multiverse::new();
+//! let root = multiverse.root_timeline;
+//! let view = multiverse.view();
+//! view.create_character("Nameless".to_string(), root);
+//! ```
+
+use crate::diff::MultiverseDiff;
+use crate::narrative_core::*;
+use crate::properties::{validate_all_properties, PropertyViolation};
+use std::collections::HashMap;
+
+/// Read-only access to a [`Multiverse`]; see the module docs.
+#[derive(Debug, Clone, Copy)]
+pub struct MultiverseView<'a> {
+    multiverse: &'a Multiverse,
+}
+
+/// How big a multiverse has grown, and how much of it is unstable.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct MultiverseStats {
+    pub timelines: usize,
+    pub characters: usize,
+    pub events: usize,
+    pub memories: usize,
+    pub unstable_timelines: usize,
+}
+
+impl Multiverse {
+    /// A read-only view of this multiverse.
+    pub fn view(&self) -> MultiverseView<'_> {
+        MultiverseView { multiverse: self }
+    }
+}
+
+impl<'a> MultiverseView<'a> {
+    pub fn root_timeline(&self) -> TimelineId {
+        self.multiverse.root_timeline
+    }
+
+    pub fn config(&self) -> &'a MultiverseConfig {
+        &self.multiverse.config
+    }
+
+    pub fn timelines(&self) -> &'a HashMap<TimelineId, Timeline> {
+        &self.multiverse.timelines
+    }
+
+    pub fn characters(&self) -> &'a HashMap<CharacterId, Character> {
+        &self.multiverse.characters
+    }
+
+    pub fn memories(&self) -> &'a HashMap<MemoryId, Memory> {
+        &self.multiverse.memories
+    }
+
+    pub fn events(&self) -> &'a HashMap<EventId, Event> {
+        &self.multiverse.events
+    }
+
+    pub fn timeline(&self, timeline: TimelineId) -> Option<&'a Timeline> {
+        self.multiverse.timelines.get(&timeline)
+    }
+
+    pub fn character(&self, character: CharacterId) -> Option<&'a Character> {
+        self.multiverse.characters.get(&character)
+    }
+
+    /// See [`Multiverse::character_named`].
+    pub fn character_named(&self, name: &str) -> Option<&'a Character> {
+        self.multiverse.character_named(name).and_then(|id| self.character(id))
+    }
+
+    pub fn memory(&self, memory: MemoryId) -> Option<&'a Memory> {
+        self.multiverse.memories.get(&memory)
+    }
+
+    pub fn event(&self, event: EventId) -> Option<&'a Event> {
+        self.multiverse.events.get(&event)
+    }
+
+    /// Counts of everything in the multiverse.
+    pub fn stats(&self) -> MultiverseStats {
+        let multiverse = self.multiverse;
+        MultiverseStats {
+            timelines: multiverse.timelines.len(),
+            characters: multiverse.characters.len(),
+            events: multiverse.events.len(),
+            memories: multiverse.memories.len(),
+            unstable_timelines: multiverse.timelines.values().filter(|t| !t.causality_stable).count(),
+        }
+    }
+
+    /// See [`Multiverse::can_perceive_timeline`].
+    pub fn can_perceive_timeline(&self, character: CharacterId, timeline: TimelineId) -> bool {
+        self.multiverse.can_perceive_timeline(character, timeline)
+    }
+
+    /// See [`Multiverse::has_memory_of_event`].
+    pub fn has_memory_of_event(&self, character: CharacterId, event: EventId) -> bool {
+        self.multiverse.has_memory_of_event(character, event)
+    }
+
+    /// See [`Multiverse::event_visible_in`].
+    pub fn event_visible_in(&self, event: EventId, timeline: TimelineId) -> bool {
+        self.multiverse.event_visible_in(event, timeline)
+    }
+
+    /// See [`Multiverse::history`].
+    pub fn history(&self, timeline: TimelineId) -> Vec<EventId> {
+        self.multiverse.history(timeline)
+    }
+
+    /// See [`Multiverse::branch_depth`].
+    pub fn branch_depth(&self, timeline: TimelineId) -> usize {
+        self.multiverse.branch_depth(timeline)
+    }
+
+    /// See [`Multiverse::unresolved_violations`].
+    pub fn unresolved_violations(&self, timeline: TimelineId) -> Vec<EventId> {
+        self.multiverse.unresolved_violations(timeline)
+    }
+
+    /// See [`Multiverse::can_trade_memory`].
+    pub fn can_trade_memory(&self, from: CharacterId, to: CharacterId) -> bool {
+        self.multiverse.can_trade_memory(from, to)
+    }
+
+    /// See [`Multiverse::collective_of`].
+    pub fn collective_of(&self, character: CharacterId) -> Option<&'a CollectiveCharacter> {
+        self.multiverse.collective_of(character)
+    }
+
+    /// See [`Multiverse::remaining_stability`].
+    pub fn remaining_stability(&self, character: CharacterId) -> Option<f32> {
+        self.multiverse.remaining_stability(character)
+    }
+
+    /// See [`Multiverse::relationship_between`].
+    pub fn relationship_between(&self, a: CharacterId, b: CharacterId) -> RelationshipState {
+        self.multiverse.relationship_between(a, b)
+    }
+
+    /// See [`Multiverse::allies_of`].
+    pub fn allies_of(&self, character: CharacterId) -> Vec<CharacterId> {
+        self.multiverse.allies_of(character)
+    }
+
+    /// See [`Multiverse::enemies_of`].
+    pub fn enemies_of(&self, character: CharacterId) -> Vec<CharacterId> {
+        self.multiverse.enemies_of(character)
+    }
+
+    /// See [`Multiverse::are_allied`].
+    pub fn are_allied(&self, a: CharacterId, b: CharacterId) -> bool {
+        self.multiverse.are_allied(a, b)
+    }
+
+    /// See [`Multiverse::are_hostile`].
+    pub fn are_hostile(&self, a: CharacterId, b: CharacterId) -> bool {
+        self.multiverse.are_hostile(a, b)
+    }
+
+    /// See [`Multiverse::contagion`].
+    pub fn contagion(&self, timeline: TimelineId, strength: f64) -> Vec<EventEffect> {
+        self.multiverse.contagion(timeline, strength)
+    }
+
+    /// See [`Multiverse::timeline_mood`].
+    pub fn timeline_mood(&self, timeline: TimelineId) -> [f64; 3] {
+        self.multiverse.timeline_mood(timeline)
+    }
+
+    /// See [`Multiverse::mood_report`].
+    pub fn mood_report(&self) -> MultiverseMoodReport {
+        self.multiverse.mood_report()
+    }
+
+    /// See [`Multiverse::is_registered_flag`].
+    pub fn is_registered_flag(&self, name: &str) -> bool {
+        self.multiverse.is_registered_flag(name)
+    }
+
+    /// See [`Multiverse::are_mutually_exclusive`].
+    pub fn are_mutually_exclusive(&self, a: &str, b: &str) -> bool {
+        self.multiverse.are_mutually_exclusive(a, b)
+    }
+
+    /// See [`Multiverse::is_registered_forger`].
+    pub fn is_registered_forger(&self, name: &str) -> bool {
+        self.multiverse.is_registered_forger(name)
+    }
+
+    /// See [`Multiverse::extract_subtree`].
+    pub fn extract_subtree(&self, root: TimelineId) -> Multiverse {
+        self.multiverse.extract_subtree(root)
+    }
+
+    /// See [`Multiverse::diff`].
+    pub fn diff(&self, after: MultiverseView<'_>) -> MultiverseDiff {
+        self.multiverse.diff(after.multiverse)
+    }
+
+    /// Checks every built-in property; see [`validate_all_properties`].
+    pub fn validate(&self) -> Result<(), PropertyViolation> {
+        validate_all_properties(self.multiverse)
+    }
+
+    /// See [`Multiverse::to_json`].
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        self.multiverse.to_json()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_view_answers_queries() {
+        let mut multiverse = Multiverse::new();
+        let root = multiverse.root_timeline;
+        let [vera, khelis] = ["Vera Kandros", "Khelis Tev"].map(|name| multiverse.create_character(name.to_string(), root));
+        let event = multiverse.record_event(Event {
+            id: EventId(0),
+            timeline: root,
+            description: "Vera and Khelis shake on the deal".to_string(),
+            participants: HashSet::from([vera, khelis]),
+            effects: vec![EventEffect::RelationshipChange {
+                character1: vera,
+                character2: khelis,
+                new_state: RelationshipState::Allied,
+            }],
+            causality_violation: None,
+        });
+        let memory = multiverse.create_witnessed_memory(event, root, vera);
+        multiverse.characters.get_mut(&vera).unwrap().memories.insert(memory);
+        let before = multiverse.clone();
+
+        let view = multiverse.view();
+        assert_eq!(view.root_timeline(), root);
+        assert_eq!(view.character(vera).unwrap().name, "Vera Kandros");
+        assert_eq!(view.event(event).unwrap().participants.len(), 2);
+        assert!(view.memory(memory).is_some());
+        assert!(view.can_perceive_timeline(vera, root));
+        assert!(view.has_memory_of_event(vera, event));
+        assert!(!view.has_memory_of_event(khelis, event));
+        assert!(view.are_allied(vera, khelis));
+        assert_eq!(view.allies_of(vera), vec![khelis]);
+        assert_eq!(view.history(root), vec![event]);
+        assert_eq!(
+            view.stats(),
+            MultiverseStats {
+                timelines: 1,
+                characters: 2,
+                events: 1,
+                memories: 1,
+                unstable_timelines: 0,
+            }
+        );
+        assert_eq!(view.validate(), Ok(()));
+        assert!(view.diff(before.view()).is_empty());
+    }
+}